@@ -0,0 +1,179 @@
+//! Event dispatch benchmark.
+//! Events borrow strings and arrays from the receive buffer, dispatching
+//! must not touch the heap.
+//!
+//! Both runs use the current decoder. The owning handler detaches every
+//! event with `into_owned`, which copies each string and array to the heap
+//! like the decoder did before events borrowed from the buffer. It stands
+//! in for the old cost, the old decoder itself is not measured.
+
+use railway::connection::WaylandConnection;
+use railway::types::events::*;
+use railway::types::handler::EventHandler;
+use railway::types::*;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::Write;
+use std::os::unix::net::UnixStream;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const ROUNDS: usize = 10_000;
+// each group is registry global, pointer motion and keyboard enter,
+// a batch has to fit into the receive buffer
+const GROUPS: u32 = 64;
+const EVENTS_PER_ROUND: usize = GROUPS as usize * 3;
+
+/// Handler reading event payloads in place
+#[derive(Default)]
+struct Borrowing {
+    bytes: usize,
+}
+
+impl EventHandler for Borrowing {
+    fn on_wl_registry_global(&mut self, event: WlRegistryGlobalEvent<'_>, _connection: &WaylandConnection) {
+        self.bytes += event.interface.len();
+    }
+
+    fn on_wl_pointer_motion(&mut self, event: WlPointerMotionEvent, _connection: &WaylandConnection) {
+        self.bytes += (event.surface_x.0 & 1) as usize;
+    }
+
+    fn on_wl_keyboard_enter(&mut self, event: WlKeyboardEnterEvent<'_>, _connection: &WaylandConnection) {
+        self.bytes += event.keys.len();
+    }
+}
+
+/// Handler copying event payloads out of the receive buffer
+#[derive(Default)]
+struct Owning {
+    bytes: usize,
+}
+
+impl EventHandler for Owning {
+    fn on_wl_registry_global(&mut self, event: WlRegistryGlobalEvent<'_>, _connection: &WaylandConnection) {
+        self.bytes += event.into_owned().interface.len();
+    }
+
+    fn on_wl_pointer_motion(&mut self, event: WlPointerMotionEvent, _connection: &WaylandConnection) {
+        self.bytes += (event.surface_x.0 & 1) as usize;
+    }
+
+    fn on_wl_keyboard_enter(&mut self, event: WlKeyboardEnterEvent<'_>, _connection: &WaylandConnection) {
+        self.bytes += event.into_owned().keys.len();
+    }
+}
+
+fn message(buf: &mut Vec<u8>, id: u32, opcode: u16, args: &[u8]) {
+    let len = (8 + args.len()) as u32;
+    buf.extend_from_slice(&id.to_ne_bytes());
+    buf.extend_from_slice(&(len << 16 | opcode as u32).to_ne_bytes());
+    buf.extend_from_slice(args);
+}
+
+fn array(buf: &mut Vec<u8>, data: &[u8]) {
+    buf.extend_from_slice(&(data.len() as u32).to_ne_bytes());
+    buf.extend_from_slice(data);
+    while buf.len() % 4 != 0 {
+        buf.push(0);
+    }
+}
+
+fn string(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(&(s.len() as u32 + 1).to_ne_bytes());
+    buf.extend_from_slice(s.as_bytes());
+    buf.push(0);
+    while buf.len() % 4 != 0 {
+        buf.push(0);
+    }
+}
+
+fn batch(registry: WlRegistry, pointer: WlPointer, keyboard: WlKeyboard) -> Vec<u8> {
+    let mut buf = Vec::new();
+    let mut args = Vec::new();
+
+    for i in 0..GROUPS {
+        args.clear();
+        args.extend_from_slice(&i.to_ne_bytes());
+        string(&mut args, "zwp_linux_dmabuf_v1");
+        args.extend_from_slice(&4u32.to_ne_bytes());
+        message(&mut buf, registry.id, 0, &args);
+
+        args.clear();
+        args.extend_from_slice(&i.to_ne_bytes());
+        args.extend_from_slice(&(i * 256).to_ne_bytes());
+        args.extend_from_slice(&(i * 512).to_ne_bytes());
+        message(&mut buf, pointer.id, 2, &args);
+
+        args.clear();
+        args.extend_from_slice(&i.to_ne_bytes());
+        args.extend_from_slice(&10u32.to_ne_bytes());
+        array(&mut args, &[30, 0, 0, 0, 42, 0, 0, 0]);
+        message(&mut buf, keyboard.id, 1, &args);
+    }
+
+    buf
+}
+
+fn bench<T: EventHandler>(name: &str, connection: &WaylandConnection, server: &mut UnixStream, batch: &[u8], handler: &mut T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    for _ in 0..ROUNDS {
+        server.write_all(batch).expect("failed to write events");
+        connection.recv();
+        connection.dispatch_events(handler);
+    }
+
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    let events = ROUNDS * EVENTS_PER_ROUND;
+
+    println!(
+        "{:<12} {:>8.1} ns/event {:>8.2} allocations/event",
+        name,
+        elapsed.as_nanos() as f64 / events as f64,
+        allocations as f64 / events as f64
+    );
+    allocations
+}
+
+fn main() {
+    let (client, mut server) = UnixStream::pair().expect("failed to create socket pair");
+    let connection = WaylandConnection::from_stream(client);
+
+    let registry = connection.get_display().get_registry(&connection);
+    let seat: WlSeat = registry.bind(&connection, 1, 9);
+    let pointer = seat.get_pointer(&connection);
+    let keyboard = seat.get_keyboard(&connection);
+    connection.send();
+
+    let batch = batch(registry, pointer, keyboard);
+
+    let mut borrowing = Borrowing::default();
+    let allocations = bench("borrowed", &connection, &mut server, &batch, &mut borrowing);
+    assert_eq!(allocations, 0, "borrowed dispatch allocated on the heap");
+
+    let mut owning = Owning::default();
+    bench("into_owned", &connection, &mut server, &batch, &mut owning);
+
+    assert_eq!(borrowing.bytes, owning.bytes);
+}
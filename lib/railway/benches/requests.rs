@@ -0,0 +1,104 @@
+//! Request encoding benchmark.
+//! Every request is written straight into the send buffer, encoding must
+//! not touch the heap. A counting allocator checks it for each request kind.
+
+use railway::connection::WaylandConnection;
+use railway::types::*;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::Read;
+use std::os::unix::net::UnixStream;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 100_000;
+// flush often enough to never overflow the send buffer
+const BATCH: usize = 64;
+
+const TITLES: [&str; 4] = [
+    "railway - frame 1",
+    "railway - frame 2",
+    "railway - frame 3",
+    "railway - frame 4",
+];
+
+fn bench<F: FnMut(&WaylandConnection, usize)>(name: &str, connection: &WaylandConnection, mut request: F) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+
+    for i in 0..ITERATIONS {
+        request(connection, i);
+        if i % BATCH == BATCH - 1 {
+            connection.send();
+        }
+    }
+    connection.send();
+
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+    println!(
+        "{:<24} {:>8.1} ns/request {:>8} allocations",
+        name,
+        elapsed.as_nanos() as f64 / ITERATIONS as f64,
+        allocations
+    );
+    assert_eq!(allocations, 0, "{} request allocated on the heap", name);
+}
+
+fn main() {
+    let (client, mut server) = UnixStream::pair().expect("failed to create socket pair");
+
+    // drain everything the client sends so the socket never blocks
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 16_384];
+        while server.read(&mut buf).map_or(false, |n| n > 0) {}
+    });
+
+    let connection = WaylandConnection::from_stream(client);
+    let registry = WlRegistry { id: 2 };
+    let toplevel = XdgToplevel { id: 3 };
+    let source = WlDataSource { id: 4 };
+    let surface = WlSurface { id: 5 };
+
+    bench("xdg_toplevel.set_title", &connection, |c, i| {
+        toplevel.set_title(c, TITLES[i % TITLES.len()]);
+    });
+
+    bench("xdg_toplevel.set_app_id", &connection, |c, _| {
+        toplevel.set_app_id(c, "org.railway.bench");
+    });
+
+    bench("wl_data_source.offer", &connection, |c, _| {
+        source.offer(c, "text/plain;charset=utf-8");
+    });
+
+    bench("wl_surface.damage+commit", &connection, |c, _| {
+        surface.damage(c, 0, 0, i32::MAX, i32::MAX);
+        surface.commit(c);
+    });
+
+    bench("wl_registry.bind", &connection, |c, i| {
+        let output: WlOutput = registry.bind(c, i as u32, 4);
+        // hand the id straight back to keep the object store from growing
+        c.delete_object(output.id);
+    });
+}
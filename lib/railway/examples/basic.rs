@@ -1,4 +1,5 @@
 use railway::connection::WaylandConnection;
+use railway::helpers::{Activation, Decoration, GlobalList};
 use railway::types::enums::*;
 use railway::types::events::*;
 use railway::types::handler::EventHandler;
@@ -9,8 +10,7 @@ use railway::renderer::*;
 
 pub struct State {
     // Wayland Globals
-    display: WlDisplay,
-    registry: WlRegistry,
+    globals: GlobalList,
     compositor: WlCompositor,
     shm: WlShm,
     xdg_wm: XdgWmBase,
@@ -24,6 +24,7 @@ pub struct State {
     // buffer_passive: WlBuffer,
     xdg_surface: XdgSurface,
     xdg_toplevel: XdgToplevel,
+    decoration: Option<Decoration>,
 
     // Other resources
     mempool: SharedMemory,
@@ -34,17 +35,15 @@ pub struct State {
 
 impl State {
     pub fn new(w: &WaylandConnection) -> Self {
-        let display = w.get_display();
-        let registry = display.get_registry(&w);
+        let globals = GlobalList::new(w);
 
         let mut state = Self {
-            display,
-            registry,
-            compositor: WlCompositor { id: 0 },
-            shm: WlShm { id: 0 },
+            compositor: globals.bind_one(w, 1..=6).expect("compositor was not initialized"),
+            shm: globals.bind_one(w, 1..=1).expect("shm was not initialized"),
 
-            xdg_wm: XdgWmBase { id: 0 },
-            zwp_linux_dmabuf: ZwpLinuxDmabufV1 { id: 0 },
+            xdg_wm: globals.bind_one(w, 1..=5).expect("xdg_wm_base was not initialized"),
+            zwp_linux_dmabuf: globals.bind_one(w, 4..=4).expect("zwp_linux_dmabuf was not initialized"),
+            globals,
 
             wl_shm_pool: WlShmPool { id: 0 },
             wl_surface: WlSurface { id: 0 },
@@ -53,34 +52,13 @@ impl State {
             //buffer_passive: WlBuffer { id: 0 },
             xdg_surface: XdgSurface { id: 0 },
             xdg_toplevel: XdgToplevel { id: 0 },
+            decoration: None,
 
             mempool: SharedMemory::new(1920, 1080),
             offset: 0.0,
             last_frame: 0,
         };
 
-        state.display.sync(w);
-
-        w.send();
-        w.recv();
-        w.dispatch_events(&mut state);
-
-        if state.compositor.id == 0 {
-            panic!("compositor was not initialized")
-        }
-
-        if state.shm.id == 0 {
-            panic!("shm was not initialized")
-        }
-
-        if state.xdg_wm.id == 0 {
-            panic!("xdg_wm_base was not initialized")
-        }
-
-        if state.zwp_linux_dmabuf.id == 0 {
-            panic!("zwp_linux_dmabuf was not initialized")
-        }
-
         let feedback = state.zwp_linux_dmabuf.get_default_feedback(w);
 
         state.wl_shm_pool = state
@@ -100,9 +78,17 @@ impl State {
         state.xdg_surface = state.xdg_wm.get_xdg_surface(w, state.wl_surface);
 
         state.xdg_toplevel = state.xdg_surface.get_toplevel(w);
+        state.decoration = Some(Decoration::server_side(
+            w,
+            state.globals.bind_one(w, 1..=2).ok(),
+            state.xdg_toplevel,
+        ));
         state
             .xdg_toplevel
-            .set_title(w, "Example client".to_string());
+            .set_title(w, "Example client");
+        if let Ok(activation) = state.globals.bind_one(w, 1..=1) {
+            Activation::new(activation).activate_from_env(w, state.wl_surface);
+        }
         state.wl_surface.commit(w);
         state.wl_surface.frame(w);
         //     framecb.set_listener(w, &Self::FrameCallbackListener);
@@ -136,7 +122,7 @@ impl State {
 }
 
 impl EventHandler for State {
-    fn on_wl_display_error(&mut self, event: WlDisplayErrorEvent, _connection: &WaylandConnection) {
+    fn on_wl_display_error(&mut self, event: WlDisplayErrorEvent<'_>, _connection: &WaylandConnection) {
         panic!("display error: code {}, {}", event.code, event.message);
     }
 
@@ -156,29 +142,12 @@ impl EventHandler for State {
         connection.delete_object(event.id);
     }
 
-    fn on_wl_registry_global(&mut self, e: WlRegistryGlobalEvent, c: &WaylandConnection) {
-        // println!("registry listener: {:?}", e);
-        if let Some(obj) = Object::from_str(&e.interface) {
-            match obj {
-                Object::WlCompositor => {
-                    let id = self.registry.bind(c, e.name, e.interface, e.version);
-                    self.compositor = WlCompositor { id: id.id };
-                }
-                Object::WlShm => {
-                    let id = self.registry.bind(c, e.name, e.interface, e.version);
-                    self.shm = WlShm { id: id.id };
-                }
-                Object::XdgWmBase => {
-                    let id = self.registry.bind(c, e.name, e.interface, e.version);
-                    self.xdg_wm = XdgWmBase { id: id.id };
-                }
-                Object::ZwpLinuxDmabufV1 => {
-                    let id = self.registry.bind(c, e.name, e.interface, e.version);
-                    self.zwp_linux_dmabuf = ZwpLinuxDmabufV1 { id: id.id };
-                }
-                _ => (),
-            }
-        }
+    fn on_wl_registry_global(&mut self, e: WlRegistryGlobalEvent<'_>, c: &WaylandConnection) {
+        self.globals.handle_global(e, c, &mut ());
+    }
+
+    fn on_wl_registry_global_remove(&mut self, e: WlRegistryGlobalRemoveEvent, c: &WaylandConnection) {
+        self.globals.handle_global_remove(e, c, &mut ());
     }
 
     fn on_wl_buffer_release(&mut self, _e: WlBufferReleaseEvent, _c: &WaylandConnection) {
@@ -189,11 +158,18 @@ impl EventHandler for State {
         // TODO adjust effective window size?
         self.xdg_surface.ack_configure(c, event.serial);
         self.draw();
-        self.wl_surface.attach(c, self.buffer, 0, 0);
+        self.wl_surface.attach(c, Some(self.buffer), 0, 0);
         self.wl_surface.damage(c, 0, 0, i32::MAX, i32::MAX);
         self.wl_surface.commit(c);
     }
 
+    fn on_zxdg_toplevel_decoration_v1_configure(&mut self, e: ZxdgToplevelDecorationV1ConfigureEvent, _c: &WaylandConnection) {
+        if let Some(decoration) = &mut self.decoration {
+            decoration.handle_configure(e);
+            println!("decoration mode: {:?}", decoration.mode());
+        }
+    }
+
     fn on_xdg_wm_base_ping(&mut self, event: XdgWmBasePingEvent, c: &WaylandConnection) {
         self.xdg_wm.pong(c, event.serial);
     }
@@ -213,7 +189,7 @@ impl EventHandler for State {
         if self.buffer_locked {
             panic!("Buffer is still locked!");
         }
-        self.wl_surface.attach(connection, self.buffer, 0, 0);
+        self.wl_surface.attach(connection, Some(self.buffer), 0, 0);
         self.wl_surface.damage(connection, 0, 0, i32::MAX, i32::MAX);
         self.wl_surface.commit(connection);
 
@@ -45,6 +45,16 @@ pub struct IOVec<'a> {
 }
 
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TimeSpec {
+    /// seconds
+    pub tv_sec: i64,
+
+    /// nanoseconds
+    pub tv_nsec: i64,
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct CmsgHdr {
@@ -86,6 +96,10 @@ extern "C" {
 
     pub fn close(fd: i32) -> i32;
 
+    pub fn pipe(fds: *mut i32) -> i32;
+
+    pub fn clock_gettime(clock_id: i32, tp: *mut TimeSpec) -> i32;
+
     #[cfg_attr(
         target_os = "freebsd",
         link_name = "__error"
@@ -5,6 +5,14 @@ use crate::types::events::*;
 use crate::types::handler::EventHandler;
 use crate::types::object::Object;
 
+/// Prints dispatched events in debug builds
+macro_rules! trace_event {
+    ($event:expr) => {
+        #[cfg(debug_assertions)]
+        println!("dispatch: event {:?}", $event);
+    };
+}
+
 impl WaylandConnectionPrivate {
 
     pub(crate) fn dispatch_event<T: EventHandler>(&mut self, c: &WaylandConnection, state: &mut T) {
@@ -15,10 +23,16 @@ impl WaylandConnectionPrivate {
         let hdr = self.get_header();
         let id =  hdr.obj_id;
         let op = hdr.opcode;
-        let obj = self.objects[id as usize];
+        let obj = self.get_object(id);
 
         match obj {
-            Object::Null => panic!("object of type null was sent by the server"),
+            Object::Null => {
+                // the object was destroyed while its events were in flight,
+                // close the descriptors of the event and skip the message body
+                let fds = event_fd_count(self.destroyed_object(id), op);
+                self.close_fds(fds);
+                self.recv_pos += hdr.len as usize - 8;
+            },
             Object::ZwpLinuxDmabufV1 => {
                 match op {
                     0 =>{
@@ -26,7 +40,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             format: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_dmabuf_v1_format(event, c);
                     },
                     1 =>{
@@ -36,7 +50,7 @@ impl WaylandConnectionPrivate {
                             modifier_hi: self.get_uint(),
                             modifier_lo: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_dmabuf_v1_modifier(event, c);
                     },
                     _ => (),
@@ -48,16 +62,16 @@ impl WaylandConnectionPrivate {
                         let event = ZwpLinuxBufferParamsV1CreatedEvent {
                             source_id: id,
                             // new_id
-                            buffer: self.get_uint(),
+                            buffer: self.get_new_id(Object::WlBuffer),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_buffer_params_v1_created(event, c);
                     },
                     1 =>{
                         let event = ZwpLinuxBufferParamsV1FailedEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_buffer_params_v1_failed(event, c);
                     },
                     _ => (),
@@ -69,7 +83,7 @@ impl WaylandConnectionPrivate {
                         let event = ZwpLinuxDmabufFeedbackV1DoneEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_dmabuf_feedback_v1_done(event, c);
                     },
                     1 =>{
@@ -78,7 +92,7 @@ impl WaylandConnectionPrivate {
                             fd: self.get_fd(),
                             size: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_dmabuf_feedback_v1_format_table(event, c);
                     },
                     2 =>{
@@ -86,14 +100,14 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             device: self.get_vec(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_dmabuf_feedback_v1_main_device(event, c);
                     },
                     3 =>{
                         let event = ZwpLinuxDmabufFeedbackV1TrancheDoneEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_dmabuf_feedback_v1_tranche_done(event, c);
                     },
                     4 =>{
@@ -101,7 +115,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             device: self.get_vec(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_dmabuf_feedback_v1_tranche_target_device(event, c);
                     },
                     5 =>{
@@ -109,7 +123,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             indices: self.get_vec(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_dmabuf_feedback_v1_tranche_formats(event, c);
                     },
                     6 =>{
@@ -117,7 +131,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             flags: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_zwp_linux_dmabuf_feedback_v1_tranche_flags(event, c);
                     },
                     _ => (),
@@ -130,7 +144,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             serial: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_xdg_wm_base_ping(event, c);
                     },
                     _ => (),
@@ -144,7 +158,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             serial: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_xdg_surface_configure(event, c);
                     },
                     _ => (),
@@ -159,14 +173,14 @@ impl WaylandConnectionPrivate {
                             height: self.get_int(),
                             states: self.get_vec(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_xdg_toplevel_configure(event, c);
                     },
                     1 =>{
                         let event = XdgToplevelCloseEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_xdg_toplevel_close(event, c);
                     },
                     2 =>{
@@ -175,7 +189,7 @@ impl WaylandConnectionPrivate {
                             width: self.get_int(),
                             height: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_xdg_toplevel_configure_bounds(event, c);
                     },
                     3 =>{
@@ -183,7 +197,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             capabilities: self.get_vec(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_xdg_toplevel_wm_capabilities(event, c);
                     },
                     _ => (),
@@ -199,14 +213,14 @@ impl WaylandConnectionPrivate {
                             width: self.get_int(),
                             height: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_xdg_popup_configure(event, c);
                     },
                     1 =>{
                         let event = XdgPopupPopupDoneEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_xdg_popup_popup_done(event, c);
                     },
                     2 =>{
@@ -214,7 +228,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             token: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_xdg_popup_repositioned(event, c);
                     },
                     _ => (),
@@ -227,7 +241,7 @@ impl WaylandConnectionPrivate {
                                 source_id: id,
                                 data: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_display_sync_done(event, c);
                     },
                     _ => ()
@@ -243,7 +257,7 @@ impl WaylandConnectionPrivate {
                             code: self.get_uint(),
                             message: self.get_str(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_display_error(event, c);
                     },
                     1 =>{
@@ -251,7 +265,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             id: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_display_delete_id(event, c);
                     },
                     _ => (),
@@ -266,7 +280,7 @@ impl WaylandConnectionPrivate {
                             interface: self.get_str(),
                             version: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_registry_global(event, c);
                     },
                     1 =>{
@@ -274,7 +288,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             name: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_registry_global_remove(event, c);
                     },
                     _ => (),
@@ -289,7 +303,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             format: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_shm_format(event, c);
                     },
                     _ => (),
@@ -301,7 +315,7 @@ impl WaylandConnectionPrivate {
                         let event = WlBufferReleaseEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_buffer_release(event, c);
                     },
                     _ => (),
@@ -314,7 +328,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             mime_type: self.get_str(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_offer_offer(event, c);
                     },
                     1 =>{
@@ -322,7 +336,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             source_actions: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_offer_source_actions(event, c);
                     },
                     2 =>{
@@ -330,7 +344,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             dnd_action: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_offer_action(event, c);
                     },
                     _ => (),
@@ -341,9 +355,9 @@ impl WaylandConnectionPrivate {
                     0 =>{
                         let event = WlDataSourceTargetEvent {
                             source_id: id,
-                            mime_type: self.get_str(),
+                            mime_type: self.get_nullable_str(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_source_target(event, c);
                     },
                     1 =>{
@@ -352,28 +366,28 @@ impl WaylandConnectionPrivate {
                             mime_type: self.get_str(),
                             fd: self.get_fd(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_source_send(event, c);
                     },
                     2 =>{
                         let event = WlDataSourceCancelledEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_source_cancelled(event, c);
                     },
                     3 =>{
                         let event = WlDataSourceDndDropPerformedEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_source_dnd_drop_performed(event, c);
                     },
                     4 =>{
                         let event = WlDataSourceDndFinishedEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_source_dnd_finished(event, c);
                     },
                     5 =>{
@@ -381,7 +395,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             dnd_action: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_source_action(event, c);
                     },
                     _ => (),
@@ -393,9 +407,9 @@ impl WaylandConnectionPrivate {
                         let event = WlDataDeviceDataOfferEvent {
                             source_id: id,
                             // new_id
-                            id: self.get_uint(),
+                            id: self.get_new_id(Object::WlDataOffer),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_device_data_offer(event, c);
                     },
                     1 =>{
@@ -405,16 +419,16 @@ impl WaylandConnectionPrivate {
                             surface: self.get_uint(),
                             x: Fixed::new(self.get_uint()),
                             y: Fixed::new(self.get_uint()),
-                            id: self.get_uint(),
+                            id: self.get_nullable_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_device_enter(event, c);
                     },
                     2 =>{
                         let event = WlDataDeviceLeaveEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_device_leave(event, c);
                     },
                     3 =>{
@@ -424,22 +438,22 @@ impl WaylandConnectionPrivate {
                             x: Fixed::new(self.get_uint()),
                             y: Fixed::new(self.get_uint()),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_device_motion(event, c);
                     },
                     4 =>{
                         let event = WlDataDeviceDropEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_device_drop(event, c);
                     },
                     5 =>{
                         let event = WlDataDeviceSelectionEvent {
                             source_id: id,
-                            id: self.get_uint(),
+                            id: self.get_nullable_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_data_device_selection(event, c);
                     },
                     _ => (),
@@ -454,7 +468,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             serial: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_shell_surface_ping(event, c);
                     },
                     1 =>{
@@ -464,14 +478,14 @@ impl WaylandConnectionPrivate {
                             width: self.get_int(),
                             height: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_shell_surface_configure(event, c);
                     },
                     2 =>{
                         let event = WlShellSurfacePopupDoneEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_shell_surface_popup_done(event, c);
                     },
                     _ => (),
@@ -484,7 +498,7 @@ impl WaylandConnectionPrivate {
                                 source_id: id,
                                 data: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_surface_frame_done(event, c);
                     },
                     _ => ()
@@ -498,7 +512,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             output: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_surface_enter(event, c);
                     },
                     1 =>{
@@ -506,7 +520,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             output: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_surface_leave(event, c);
                     },
                     2 =>{
@@ -514,7 +528,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             factor: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_surface_preferred_buffer_scale(event, c);
                     },
                     3 =>{
@@ -522,7 +536,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             transform: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_surface_preferred_buffer_transform(event, c);
                     },
                     _ => (),
@@ -535,7 +549,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             capabilities: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_seat_capabilities(event, c);
                     },
                     1 =>{
@@ -543,7 +557,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             name: self.get_str(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_seat_name(event, c);
                     },
                     _ => (),
@@ -559,7 +573,7 @@ impl WaylandConnectionPrivate {
                             surface_x: Fixed::new(self.get_uint()),
                             surface_y: Fixed::new(self.get_uint()),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_enter(event, c);
                     },
                     1 =>{
@@ -568,7 +582,7 @@ impl WaylandConnectionPrivate {
                             serial: self.get_uint(),
                             surface: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_leave(event, c);
                     },
                     2 =>{
@@ -578,7 +592,7 @@ impl WaylandConnectionPrivate {
                             surface_x: Fixed::new(self.get_uint()),
                             surface_y: Fixed::new(self.get_uint()),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_motion(event, c);
                     },
                     3 =>{
@@ -589,7 +603,7 @@ impl WaylandConnectionPrivate {
                             button: self.get_uint(),
                             state: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_button(event, c);
                     },
                     4 =>{
@@ -599,14 +613,14 @@ impl WaylandConnectionPrivate {
                             axis: self.get_uint().into(),
                             value: Fixed::new(self.get_uint()),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_axis(event, c);
                     },
                     5 =>{
                         let event = WlPointerFrameEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_frame(event, c);
                     },
                     6 =>{
@@ -614,7 +628,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             axis_source: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_axis_source(event, c);
                     },
                     7 =>{
@@ -623,7 +637,7 @@ impl WaylandConnectionPrivate {
                             time: self.get_uint(),
                             axis: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_axis_stop(event, c);
                     },
                     8 =>{
@@ -632,7 +646,7 @@ impl WaylandConnectionPrivate {
                             axis: self.get_uint().into(),
                             discrete: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_axis_discrete(event, c);
                     },
                     9 =>{
@@ -641,7 +655,7 @@ impl WaylandConnectionPrivate {
                             axis: self.get_uint().into(),
                             value120: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_axis_value120(event, c);
                     },
                     10 =>{
@@ -650,7 +664,7 @@ impl WaylandConnectionPrivate {
                             axis: self.get_uint().into(),
                             direction: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_pointer_axis_relative_direction(event, c);
                     },
                     _ => (),
@@ -665,7 +679,7 @@ impl WaylandConnectionPrivate {
                             fd: self.get_fd(),
                             size: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_keyboard_keymap(event, c);
                     },
                     1 =>{
@@ -675,7 +689,7 @@ impl WaylandConnectionPrivate {
                             surface: self.get_uint(),
                             keys: self.get_vec(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_keyboard_enter(event, c);
                     },
                     2 =>{
@@ -684,7 +698,7 @@ impl WaylandConnectionPrivate {
                             serial: self.get_uint(),
                             surface: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_keyboard_leave(event, c);
                     },
                     3 =>{
@@ -695,7 +709,7 @@ impl WaylandConnectionPrivate {
                             key: self.get_uint(),
                             state: self.get_uint().into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_keyboard_key(event, c);
                     },
                     4 =>{
@@ -707,7 +721,7 @@ impl WaylandConnectionPrivate {
                             mods_locked: self.get_uint(),
                             group: self.get_uint(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_keyboard_modifiers(event, c);
                     },
                     5 =>{
@@ -716,7 +730,7 @@ impl WaylandConnectionPrivate {
                             rate: self.get_int(),
                             delay: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_keyboard_repeat_info(event, c);
                     },
                     _ => (),
@@ -734,7 +748,7 @@ impl WaylandConnectionPrivate {
                             x: Fixed::new(self.get_uint()),
                             y: Fixed::new(self.get_uint()),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_touch_down(event, c);
                     },
                     1 =>{
@@ -744,7 +758,7 @@ impl WaylandConnectionPrivate {
                             time: self.get_uint(),
                             id: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_touch_up(event, c);
                     },
                     2 =>{
@@ -755,21 +769,21 @@ impl WaylandConnectionPrivate {
                             x: Fixed::new(self.get_uint()),
                             y: Fixed::new(self.get_uint()),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_touch_motion(event, c);
                     },
                     3 =>{
                         let event = WlTouchFrameEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_touch_frame(event, c);
                     },
                     4 =>{
                         let event = WlTouchCancelEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_touch_cancel(event, c);
                     },
                     5 =>{
@@ -779,7 +793,7 @@ impl WaylandConnectionPrivate {
                             major: Fixed::new(self.get_uint()),
                             minor: Fixed::new(self.get_uint()),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_touch_shape(event, c);
                     },
                     6 =>{
@@ -788,7 +802,7 @@ impl WaylandConnectionPrivate {
                             id: self.get_int(),
                             orientation: Fixed::new(self.get_uint()),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_touch_orientation(event, c);
                     },
                     _ => (),
@@ -808,7 +822,7 @@ impl WaylandConnectionPrivate {
                             model: self.get_str(),
                             transform: (self.get_int() as u32).into(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_output_geometry(event, c);
                     },
                     1 =>{
@@ -819,14 +833,14 @@ impl WaylandConnectionPrivate {
                             height: self.get_int(),
                             refresh: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_output_mode(event, c);
                     },
                     2 =>{
                         let event = WlOutputDoneEvent {
                             source_id: id,
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_output_done(event, c);
                     },
                     3 =>{
@@ -834,7 +848,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             factor: self.get_int(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_output_scale(event, c);
                     },
                     4 =>{
@@ -842,7 +856,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             name: self.get_str(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_output_name(event, c);
                     },
                     5 =>{
@@ -850,7 +864,7 @@ impl WaylandConnectionPrivate {
                             source_id: id,
                             description: self.get_str(),
                         };
-                        println!("dispatch: event {:?}", event);
+                        trace_event!(event);
                         state.on_wl_output_description(event, c);
                     },
                     _ => (),
@@ -859,6 +873,1258 @@ impl WaylandConnectionPrivate {
             Object::WlRegion => panic!("event from object with no events"),
             Object::WlSubcompositor => panic!("event from object with no events"),
             Object::WlSubsurface => panic!("event from object with no events"),
-        }
+            Object::ZxdgDecorationManagerV1 => panic!("event from object with no events"),
+            Object::ZxdgToplevelDecorationV1 => {
+                match op {
+                    0 =>{
+                        let event = ZxdgToplevelDecorationV1ConfigureEvent {
+                            source_id: id,
+                            mode: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zxdg_toplevel_decoration_v1_configure(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::WpViewporter => panic!("event from object with no events"),
+            Object::WpViewport => panic!("event from object with no events"),
+            Object::WpFractionalScaleManagerV1 => panic!("event from object with no events"),
+            Object::WpFractionalScaleV1 => {
+                match op {
+                    0 =>{
+                        let event = WpFractionalScaleV1PreferredScaleEvent {
+                            source_id: id,
+                            scale: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_wp_fractional_scale_v1_preferred_scale(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::WpPresentation => {
+                match op {
+                    0 =>{
+                        let event = WpPresentationClockIdEvent {
+                            source_id: id,
+                            clk_id: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_wp_presentation_clock_id(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::WpPresentationFeedback => {
+                match op {
+                    0 =>{
+                        let event = WpPresentationFeedbackSyncOutputEvent {
+                            source_id: id,
+                            output: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_wp_presentation_feedback_sync_output(event, c);
+                    },
+                    1 =>{
+                        let event = WpPresentationFeedbackPresentedEvent {
+                            source_id: id,
+                            tv_sec_hi: self.get_uint(),
+                            tv_sec_lo: self.get_uint(),
+                            tv_nsec: self.get_uint(),
+                            refresh: self.get_uint(),
+                            seq_hi: self.get_uint(),
+                            seq_lo: self.get_uint(),
+                            flags: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_wp_presentation_feedback_presented(event, c);
+                    },
+                    2 =>{
+                        let event = WpPresentationFeedbackDiscardedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_wp_presentation_feedback_discarded(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpLinuxExplicitSynchronizationV1 => panic!("event from object with no events"),
+            Object::ZwpLinuxSurfaceSynchronizationV1 => panic!("event from object with no events"),
+            Object::ZwpLinuxBufferReleaseV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpLinuxBufferReleaseV1FencedReleaseEvent {
+                            source_id: id,
+                            fence: self.get_fd(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_linux_buffer_release_v1_fenced_release(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpLinuxBufferReleaseV1ImmediateReleaseEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_linux_buffer_release_v1_immediate_release(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::WpLinuxDrmSyncobjManagerV1 => panic!("event from object with no events"),
+            Object::WpLinuxDrmSyncobjTimelineV1 => panic!("event from object with no events"),
+            Object::WpLinuxDrmSyncobjSurfaceV1 => panic!("event from object with no events"),
+            Object::XdgActivationV1 => panic!("event from object with no events"),
+            Object::XdgActivationTokenV1 => {
+                match op {
+                    0 =>{
+                        let event = XdgActivationTokenV1DoneEvent {
+                            source_id: id,
+                            token: self.get_str(),
+                        };
+                        trace_event!(event);
+                        state.on_xdg_activation_token_v1_done(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::WpCursorShapeManagerV1 => panic!("event from object with no events"),
+            Object::WpCursorShapeDeviceV1 => panic!("event from object with no events"),
+            Object::ZwpTextInputV3 => {
+                match op {
+                    0 =>{
+                        let event = ZwpTextInputV3EnterEvent {
+                            source_id: id,
+                            surface: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_text_input_v3_enter(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpTextInputV3LeaveEvent {
+                            source_id: id,
+                            surface: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_text_input_v3_leave(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpTextInputV3PreeditStringEvent {
+                            source_id: id,
+                            text: self.get_nullable_str(),
+                            cursor_begin: self.get_int(),
+                            cursor_end: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_text_input_v3_preedit_string(event, c);
+                    },
+                    3 =>{
+                        let event = ZwpTextInputV3CommitStringEvent {
+                            source_id: id,
+                            text: self.get_nullable_str(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_text_input_v3_commit_string(event, c);
+                    },
+                    4 =>{
+                        let event = ZwpTextInputV3DeleteSurroundingTextEvent {
+                            source_id: id,
+                            before_length: self.get_uint(),
+                            after_length: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_text_input_v3_delete_surrounding_text(event, c);
+                    },
+                    5 =>{
+                        let event = ZwpTextInputV3DoneEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_text_input_v3_done(event, c);
+                    },
+                    6 =>{
+                        let event = ZwpTextInputV3ActionEvent {
+                            source_id: id,
+                            action: self.get_uint().into(),
+                            serial: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_text_input_v3_action(event, c);
+                    },
+                    7 =>{
+                        let event = ZwpTextInputV3LanguageEvent {
+                            source_id: id,
+                            language: self.get_str(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_text_input_v3_language(event, c);
+                    },
+                    8 =>{
+                        let event = ZwpTextInputV3PreeditHintEvent {
+                            source_id: id,
+                            start: self.get_uint(),
+                            end: self.get_uint(),
+                            hint: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_text_input_v3_preedit_hint(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpTextInputManagerV3 => panic!("event from object with no events"),
+            Object::ZwpPrimarySelectionDeviceManagerV1 => panic!("event from object with no events"),
+            Object::ZwpPrimarySelectionDeviceV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpPrimarySelectionDeviceV1DataOfferEvent {
+                            source_id: id,
+                            // new_id
+                            offer: self.get_new_id(Object::ZwpPrimarySelectionOfferV1),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_primary_selection_device_v1_data_offer(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpPrimarySelectionDeviceV1SelectionEvent {
+                            source_id: id,
+                            id: self.get_nullable_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_primary_selection_device_v1_selection(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpPrimarySelectionOfferV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpPrimarySelectionOfferV1OfferEvent {
+                            source_id: id,
+                            mime_type: self.get_str(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_primary_selection_offer_v1_offer(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpPrimarySelectionSourceV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpPrimarySelectionSourceV1SendEvent {
+                            source_id: id,
+                            mime_type: self.get_str(),
+                            fd: self.get_fd(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_primary_selection_source_v1_send(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpPrimarySelectionSourceV1CancelledEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_primary_selection_source_v1_cancelled(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpPointerConstraintsV1 => panic!("event from object with no events"),
+            Object::ZwpLockedPointerV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpLockedPointerV1LockedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_locked_pointer_v1_locked(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpLockedPointerV1UnlockedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_locked_pointer_v1_unlocked(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpConfinedPointerV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpConfinedPointerV1ConfinedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_confined_pointer_v1_confined(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpConfinedPointerV1UnconfinedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_confined_pointer_v1_unconfined(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpRelativePointerManagerV1 => panic!("event from object with no events"),
+            Object::ZwpRelativePointerV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpRelativePointerV1RelativeMotionEvent {
+                            source_id: id,
+                            utime_hi: self.get_uint(),
+                            utime_lo: self.get_uint(),
+                            dx: Fixed::new(self.get_uint()),
+                            dy: Fixed::new(self.get_uint()),
+                            dx_unaccel: Fixed::new(self.get_uint()),
+                            dy_unaccel: Fixed::new(self.get_uint()),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_relative_pointer_v1_relative_motion(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpIdleInhibitManagerV1 => panic!("event from object with no events"),
+            Object::ZwpIdleInhibitorV1 => panic!("event from object with no events"),
+            Object::ExtIdleNotifierV1 => panic!("event from object with no events"),
+            Object::ExtIdleNotificationV1 => {
+                match op {
+                    0 =>{
+                        let event = ExtIdleNotificationV1IdledEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_ext_idle_notification_v1_idled(event, c);
+                    },
+                    1 =>{
+                        let event = ExtIdleNotificationV1ResumedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_ext_idle_notification_v1_resumed(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwlrLayerShellV1 => panic!("event from object with no events"),
+            Object::ZwlrLayerSurfaceV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwlrLayerSurfaceV1ConfigureEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            width: self.get_uint(),
+                            height: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwlr_layer_surface_v1_configure(event, c);
+                    },
+                    1 =>{
+                        let event = ZwlrLayerSurfaceV1ClosedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwlr_layer_surface_v1_closed(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ExtSessionLockManagerV1 => panic!("event from object with no events"),
+            Object::ExtSessionLockV1 => {
+                match op {
+                    0 =>{
+                        let event = ExtSessionLockV1LockedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_ext_session_lock_v1_locked(event, c);
+                    },
+                    1 =>{
+                        let event = ExtSessionLockV1FinishedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_ext_session_lock_v1_finished(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ExtSessionLockSurfaceV1 => {
+                match op {
+                    0 =>{
+                        let event = ExtSessionLockSurfaceV1ConfigureEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            width: self.get_uint(),
+                            height: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_session_lock_surface_v1_configure(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ExtImageCaptureSourceV1 => panic!("event from object with no events"),
+            Object::ExtOutputImageCaptureSourceManagerV1 => panic!("event from object with no events"),
+            Object::ExtImageCopyCaptureManagerV1 => panic!("event from object with no events"),
+            Object::ExtImageCopyCaptureSessionV1 => {
+                match op {
+                    0 =>{
+                        let event = ExtImageCopyCaptureSessionV1BufferSizeEvent {
+                            source_id: id,
+                            width: self.get_uint(),
+                            height: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_session_v1_buffer_size(event, c);
+                    },
+                    1 =>{
+                        let event = ExtImageCopyCaptureSessionV1ShmFormatEvent {
+                            source_id: id,
+                            format: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_session_v1_shm_format(event, c);
+                    },
+                    2 =>{
+                        let event = ExtImageCopyCaptureSessionV1DmabufDeviceEvent {
+                            source_id: id,
+                            device: self.get_vec(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_session_v1_dmabuf_device(event, c);
+                    },
+                    3 =>{
+                        let event = ExtImageCopyCaptureSessionV1DmabufFormatEvent {
+                            source_id: id,
+                            format: self.get_uint(),
+                            modifiers: self.get_vec(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_session_v1_dmabuf_format(event, c);
+                    },
+                    4 =>{
+                        let event = ExtImageCopyCaptureSessionV1DoneEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_session_v1_done(event, c);
+                    },
+                    5 =>{
+                        let event = ExtImageCopyCaptureSessionV1StoppedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_session_v1_stopped(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ExtImageCopyCaptureFrameV1 => {
+                match op {
+                    0 =>{
+                        let event = ExtImageCopyCaptureFrameV1TransformEvent {
+                            source_id: id,
+                            transform: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_frame_v1_transform(event, c);
+                    },
+                    1 =>{
+                        let event = ExtImageCopyCaptureFrameV1DamageEvent {
+                            source_id: id,
+                            x: self.get_int(),
+                            y: self.get_int(),
+                            width: self.get_int(),
+                            height: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_frame_v1_damage(event, c);
+                    },
+                    2 =>{
+                        let event = ExtImageCopyCaptureFrameV1PresentationTimeEvent {
+                            source_id: id,
+                            tv_sec_hi: self.get_uint(),
+                            tv_sec_lo: self.get_uint(),
+                            tv_nsec: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_frame_v1_presentation_time(event, c);
+                    },
+                    3 =>{
+                        let event = ExtImageCopyCaptureFrameV1ReadyEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_frame_v1_ready(event, c);
+                    },
+                    4 =>{
+                        let event = ExtImageCopyCaptureFrameV1FailedEvent {
+                            source_id: id,
+                            reason: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_frame_v1_failed(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ExtImageCopyCaptureCursorSessionV1 => {
+                match op {
+                    0 =>{
+                        let event = ExtImageCopyCaptureCursorSessionV1EnterEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_cursor_session_v1_enter(event, c);
+                    },
+                    1 =>{
+                        let event = ExtImageCopyCaptureCursorSessionV1LeaveEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_cursor_session_v1_leave(event, c);
+                    },
+                    2 =>{
+                        let event = ExtImageCopyCaptureCursorSessionV1PositionEvent {
+                            source_id: id,
+                            x: self.get_int(),
+                            y: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_cursor_session_v1_position(event, c);
+                    },
+                    3 =>{
+                        let event = ExtImageCopyCaptureCursorSessionV1HotspotEvent {
+                            source_id: id,
+                            x: self.get_int(),
+                            y: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_ext_image_copy_capture_cursor_session_v1_hotspot(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwlrScreencopyManagerV1 => panic!("event from object with no events"),
+            Object::ZwlrScreencopyFrameV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwlrScreencopyFrameV1BufferEvent {
+                            source_id: id,
+                            format: self.get_uint().into(),
+                            width: self.get_uint(),
+                            height: self.get_uint(),
+                            stride: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwlr_screencopy_frame_v1_buffer(event, c);
+                    },
+                    1 =>{
+                        let event = ZwlrScreencopyFrameV1FlagsEvent {
+                            source_id: id,
+                            flags: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zwlr_screencopy_frame_v1_flags(event, c);
+                    },
+                    2 =>{
+                        let event = ZwlrScreencopyFrameV1ReadyEvent {
+                            source_id: id,
+                            tv_sec_hi: self.get_uint(),
+                            tv_sec_lo: self.get_uint(),
+                            tv_nsec: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwlr_screencopy_frame_v1_ready(event, c);
+                    },
+                    3 =>{
+                        let event = ZwlrScreencopyFrameV1FailedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwlr_screencopy_frame_v1_failed(event, c);
+                    },
+                    4 =>{
+                        let event = ZwlrScreencopyFrameV1DamageEvent {
+                            source_id: id,
+                            x: self.get_uint(),
+                            y: self.get_uint(),
+                            width: self.get_uint(),
+                            height: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwlr_screencopy_frame_v1_damage(event, c);
+                    },
+                    5 =>{
+                        let event = ZwlrScreencopyFrameV1LinuxDmabufEvent {
+                            source_id: id,
+                            format: self.get_uint(),
+                            width: self.get_uint(),
+                            height: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwlr_screencopy_frame_v1_linux_dmabuf(event, c);
+                    },
+                    6 =>{
+                        let event = ZwlrScreencopyFrameV1BufferDoneEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwlr_screencopy_frame_v1_buffer_done(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpTabletManagerV2 => panic!("event from object with no events"),
+            Object::ZwpTabletSeatV2 => {
+                match op {
+                    0 =>{
+                        let event = ZwpTabletSeatV2TabletAddedEvent {
+                            source_id: id,
+                            // new_id
+                            id: self.get_new_id(Object::ZwpTabletV2),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_seat_v2_tablet_added(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpTabletSeatV2ToolAddedEvent {
+                            source_id: id,
+                            // new_id
+                            id: self.get_new_id(Object::ZwpTabletToolV2),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_seat_v2_tool_added(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpTabletSeatV2PadAddedEvent {
+                            source_id: id,
+                            // new_id
+                            id: self.get_new_id(Object::ZwpTabletPadV2),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_seat_v2_pad_added(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpTabletToolV2 => {
+                match op {
+                    0 =>{
+                        let event = ZwpTabletToolV2TypeEvent {
+                            source_id: id,
+                            tool_type: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_type(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpTabletToolV2HardwareSerialEvent {
+                            source_id: id,
+                            hardware_serial_hi: self.get_uint(),
+                            hardware_serial_lo: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_hardware_serial(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpTabletToolV2HardwareIdWacomEvent {
+                            source_id: id,
+                            hardware_id_hi: self.get_uint(),
+                            hardware_id_lo: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_hardware_id_wacom(event, c);
+                    },
+                    3 =>{
+                        let event = ZwpTabletToolV2CapabilityEvent {
+                            source_id: id,
+                            capability: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_capability(event, c);
+                    },
+                    4 =>{
+                        let event = ZwpTabletToolV2DoneEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_done(event, c);
+                    },
+                    5 =>{
+                        let event = ZwpTabletToolV2RemovedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_removed(event, c);
+                    },
+                    6 =>{
+                        let event = ZwpTabletToolV2ProximityInEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            tablet: self.get_uint(),
+                            surface: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_proximity_in(event, c);
+                    },
+                    7 =>{
+                        let event = ZwpTabletToolV2ProximityOutEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_proximity_out(event, c);
+                    },
+                    8 =>{
+                        let event = ZwpTabletToolV2DownEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_down(event, c);
+                    },
+                    9 =>{
+                        let event = ZwpTabletToolV2UpEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_up(event, c);
+                    },
+                    10 =>{
+                        let event = ZwpTabletToolV2MotionEvent {
+                            source_id: id,
+                            x: Fixed::new(self.get_uint()),
+                            y: Fixed::new(self.get_uint()),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_motion(event, c);
+                    },
+                    11 =>{
+                        let event = ZwpTabletToolV2PressureEvent {
+                            source_id: id,
+                            pressure: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_pressure(event, c);
+                    },
+                    12 =>{
+                        let event = ZwpTabletToolV2DistanceEvent {
+                            source_id: id,
+                            distance: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_distance(event, c);
+                    },
+                    13 =>{
+                        let event = ZwpTabletToolV2TiltEvent {
+                            source_id: id,
+                            tilt_x: Fixed::new(self.get_uint()),
+                            tilt_y: Fixed::new(self.get_uint()),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_tilt(event, c);
+                    },
+                    14 =>{
+                        let event = ZwpTabletToolV2RotationEvent {
+                            source_id: id,
+                            degrees: Fixed::new(self.get_uint()),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_rotation(event, c);
+                    },
+                    15 =>{
+                        let event = ZwpTabletToolV2SliderEvent {
+                            source_id: id,
+                            position: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_slider(event, c);
+                    },
+                    16 =>{
+                        let event = ZwpTabletToolV2WheelEvent {
+                            source_id: id,
+                            degrees: Fixed::new(self.get_uint()),
+                            clicks: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_wheel(event, c);
+                    },
+                    17 =>{
+                        let event = ZwpTabletToolV2ButtonEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            button: self.get_uint(),
+                            state: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_button(event, c);
+                    },
+                    18 =>{
+                        let event = ZwpTabletToolV2FrameEvent {
+                            source_id: id,
+                            time: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_tool_v2_frame(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpTabletV2 => {
+                match op {
+                    0 =>{
+                        let event = ZwpTabletV2NameEvent {
+                            source_id: id,
+                            name: self.get_str(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_v2_name(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpTabletV2IdEvent {
+                            source_id: id,
+                            vid: self.get_uint(),
+                            pid: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_v2_id(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpTabletV2PathEvent {
+                            source_id: id,
+                            path: self.get_str(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_v2_path(event, c);
+                    },
+                    3 =>{
+                        let event = ZwpTabletV2DoneEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_v2_done(event, c);
+                    },
+                    4 =>{
+                        let event = ZwpTabletV2RemovedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_v2_removed(event, c);
+                    },
+                    5 =>{
+                        let event = ZwpTabletV2BustypeEvent {
+                            source_id: id,
+                            bustype: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_v2_bustype(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpTabletPadRingV2 => {
+                match op {
+                    0 =>{
+                        let event = ZwpTabletPadRingV2SourceEvent {
+                            source_id: id,
+                            source: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_ring_v2_source(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpTabletPadRingV2AngleEvent {
+                            source_id: id,
+                            degrees: Fixed::new(self.get_uint()),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_ring_v2_angle(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpTabletPadRingV2StopEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_ring_v2_stop(event, c);
+                    },
+                    3 =>{
+                        let event = ZwpTabletPadRingV2FrameEvent {
+                            source_id: id,
+                            time: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_ring_v2_frame(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpTabletPadStripV2 => {
+                match op {
+                    0 =>{
+                        let event = ZwpTabletPadStripV2SourceEvent {
+                            source_id: id,
+                            source: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_strip_v2_source(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpTabletPadStripV2PositionEvent {
+                            source_id: id,
+                            position: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_strip_v2_position(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpTabletPadStripV2StopEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_strip_v2_stop(event, c);
+                    },
+                    3 =>{
+                        let event = ZwpTabletPadStripV2FrameEvent {
+                            source_id: id,
+                            time: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_strip_v2_frame(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpTabletPadGroupV2 => {
+                match op {
+                    0 =>{
+                        let event = ZwpTabletPadGroupV2ButtonsEvent {
+                            source_id: id,
+                            buttons: self.get_vec(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_group_v2_buttons(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpTabletPadGroupV2RingEvent {
+                            source_id: id,
+                            // new_id
+                            ring: self.get_new_id(Object::ZwpTabletPadRingV2),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_group_v2_ring(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpTabletPadGroupV2StripEvent {
+                            source_id: id,
+                            // new_id
+                            strip: self.get_new_id(Object::ZwpTabletPadStripV2),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_group_v2_strip(event, c);
+                    },
+                    3 =>{
+                        let event = ZwpTabletPadGroupV2ModesEvent {
+                            source_id: id,
+                            modes: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_group_v2_modes(event, c);
+                    },
+                    4 =>{
+                        let event = ZwpTabletPadGroupV2DoneEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_group_v2_done(event, c);
+                    },
+                    5 =>{
+                        let event = ZwpTabletPadGroupV2ModeSwitchEvent {
+                            source_id: id,
+                            time: self.get_uint(),
+                            serial: self.get_uint(),
+                            mode: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_group_v2_mode_switch(event, c);
+                    },
+                    6 =>{
+                        let event = ZwpTabletPadGroupV2DialEvent {
+                            source_id: id,
+                            // new_id
+                            dial: self.get_new_id(Object::ZwpTabletPadDialV2),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_group_v2_dial(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpTabletPadV2 => {
+                match op {
+                    0 =>{
+                        let event = ZwpTabletPadV2GroupEvent {
+                            source_id: id,
+                            // new_id
+                            pad_group: self.get_new_id(Object::ZwpTabletPadGroupV2),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_v2_group(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpTabletPadV2PathEvent {
+                            source_id: id,
+                            path: self.get_str(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_v2_path(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpTabletPadV2ButtonsEvent {
+                            source_id: id,
+                            buttons: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_v2_buttons(event, c);
+                    },
+                    3 =>{
+                        let event = ZwpTabletPadV2DoneEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_v2_done(event, c);
+                    },
+                    4 =>{
+                        let event = ZwpTabletPadV2ButtonEvent {
+                            source_id: id,
+                            time: self.get_uint(),
+                            button: self.get_uint(),
+                            state: self.get_uint().into(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_v2_button(event, c);
+                    },
+                    5 =>{
+                        let event = ZwpTabletPadV2EnterEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            tablet: self.get_uint(),
+                            surface: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_v2_enter(event, c);
+                    },
+                    6 =>{
+                        let event = ZwpTabletPadV2LeaveEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            surface: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_v2_leave(event, c);
+                    },
+                    7 =>{
+                        let event = ZwpTabletPadV2RemovedEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_v2_removed(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpTabletPadDialV2 => {
+                match op {
+                    0 =>{
+                        let event = ZwpTabletPadDialV2DeltaEvent {
+                            source_id: id,
+                            value120: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_dial_v2_delta(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpTabletPadDialV2FrameEvent {
+                            source_id: id,
+                            time: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_tablet_pad_dial_v2_frame(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpPointerGesturesV1 => panic!("event from object with no events"),
+            Object::ZwpPointerGestureSwipeV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpPointerGestureSwipeV1BeginEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            time: self.get_uint(),
+                            surface: self.get_uint(),
+                            fingers: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_pointer_gesture_swipe_v1_begin(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpPointerGestureSwipeV1UpdateEvent {
+                            source_id: id,
+                            time: self.get_uint(),
+                            dx: Fixed::new(self.get_uint()),
+                            dy: Fixed::new(self.get_uint()),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_pointer_gesture_swipe_v1_update(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpPointerGestureSwipeV1EndEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            time: self.get_uint(),
+                            cancelled: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_pointer_gesture_swipe_v1_end(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpPointerGesturePinchV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpPointerGesturePinchV1BeginEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            time: self.get_uint(),
+                            surface: self.get_uint(),
+                            fingers: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_pointer_gesture_pinch_v1_begin(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpPointerGesturePinchV1UpdateEvent {
+                            source_id: id,
+                            time: self.get_uint(),
+                            dx: Fixed::new(self.get_uint()),
+                            dy: Fixed::new(self.get_uint()),
+                            scale: Fixed::new(self.get_uint()),
+                            rotation: Fixed::new(self.get_uint()),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_pointer_gesture_pinch_v1_update(event, c);
+                    },
+                    2 =>{
+                        let event = ZwpPointerGesturePinchV1EndEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            time: self.get_uint(),
+                            cancelled: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_pointer_gesture_pinch_v1_end(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZwpPointerGestureHoldV1 => {
+                match op {
+                    0 =>{
+                        let event = ZwpPointerGestureHoldV1BeginEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            time: self.get_uint(),
+                            surface: self.get_uint(),
+                            fingers: self.get_uint(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_pointer_gesture_hold_v1_begin(event, c);
+                    },
+                    1 =>{
+                        let event = ZwpPointerGestureHoldV1EndEvent {
+                            source_id: id,
+                            serial: self.get_uint(),
+                            time: self.get_uint(),
+                            cancelled: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_zwp_pointer_gesture_hold_v1_end(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::ZxdgOutputManagerV1 => panic!("event from object with no events"),
+            Object::ZxdgOutputV1 => {
+                match op {
+                    0 =>{
+                        let event = ZxdgOutputV1LogicalPositionEvent {
+                            source_id: id,
+                            x: self.get_int(),
+                            y: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_zxdg_output_v1_logical_position(event, c);
+                    },
+                    1 =>{
+                        let event = ZxdgOutputV1LogicalSizeEvent {
+                            source_id: id,
+                            width: self.get_int(),
+                            height: self.get_int(),
+                        };
+                        trace_event!(event);
+                        state.on_zxdg_output_v1_logical_size(event, c);
+                    },
+                    2 =>{
+                        let event = ZxdgOutputV1DoneEvent {
+                            source_id: id,
+                        };
+                        trace_event!(event);
+                        state.on_zxdg_output_v1_done(event, c);
+                    },
+                    3 =>{
+                        let event = ZxdgOutputV1NameEvent {
+                            source_id: id,
+                            name: self.get_str(),
+                        };
+                        trace_event!(event);
+                        state.on_zxdg_output_v1_name(event, c);
+                    },
+                    4 =>{
+                        let event = ZxdgOutputV1DescriptionEvent {
+                            source_id: id,
+                            description: self.get_str(),
+                        };
+                        trace_event!(event);
+                        state.on_zxdg_output_v1_description(event, c);
+                    },
+                    _ => (),
+                }
+            },
+            Object::WpSinglePixelBufferManagerV1 => panic!("event from object with no events"),
+            Object::WpContentTypeManagerV1 => panic!("event from object with no events"),
+            Object::WpContentTypeV1 => panic!("event from object with no events"),
+            Object::WpTearingControlManagerV1 => panic!("event from object with no events"),
+            Object::WpTearingControlV1 => panic!("event from object with no events"),
+        }
+    }
+}
+
+/// Number of file descriptors carried by an event
+fn event_fd_count(obj: Object, opcode: u16) -> usize {
+    match (obj, opcode) {
+        (Object::WlDataSource, 1) => 1,
+        (Object::WlKeyboard, 0) => 1,
+        (Object::ZwpLinuxDmabufFeedbackV1, 1) => 1,
+        (Object::ZwpLinuxBufferReleaseV1, 0) => 1,
+        (Object::ZwpPrimarySelectionSourceV1, 0) => 1,
+        _ => 0,
     }
 }
@@ -1,6 +1,8 @@
 pub mod events;
 pub mod requests;
 pub mod c_ffi;
+#[cfg(test)]
+pub(crate) mod testing;
 
 use crate::connection::c_ffi::*;
 
@@ -15,6 +17,7 @@ use std::os::unix::net::UnixStream;
 use std::os::fd::RawFd;
 use std::mem::size_of;
 use std::env;
+use std::borrow::Cow;
 
 use std:: {
     os::unix::prelude::AsRawFd,
@@ -53,6 +56,11 @@ impl WaylandConnection {
 
         let socket = UnixStream::connect(socket_path).unwrap();
         // socket.set_read_timeout(Some(Duration::new(7, 0))).expect("set timeout on socket failed");
+        Self::from_stream(socket)
+    }
+
+    /// Creates a connection over an already connected socket
+    pub fn from_stream(socket: UnixStream) -> Self {
         let mut objects = Vec::with_capacity(1024);
         objects.insert(0, Object::Null);
         objects.insert(1, Object::WlDisplay);
@@ -65,10 +73,13 @@ impl WaylandConnection {
                 cmsg_send_buf: Buffer::new(),
                 objects,
                 used_ids: Vec::with_capacity(10),
+                server_objects: Vec::new(),
+                destroyed: Vec::new(),
                 socket,
 
                 recv_pos: 0,
                 fd_pos: 0,
+                dispatching: false,
 
                 _recv_avg: 0,
                 _recv_max: 0,
@@ -78,8 +89,12 @@ impl WaylandConnection {
         }
     }
 
+    /// Dispatches all received events to the `state` callbacks.
+    /// Strings and arrays in events borrow from the receive buffer,
+    /// calling `recv` from inside the callbacks panics.
     pub fn dispatch_events<T: EventHandler>(&self, state: &mut T) {
         let data = unsafe {&mut *self.data.get()};
+        let _dispatching = Dispatching::start(self);
         while data.recv_pos < data.recv_buf.len() {
             data.dispatch_event(self,state);
         }
@@ -125,12 +140,14 @@ impl WaylandConnection {
 
     pub fn recv(&self) {
         let data = unsafe {&mut *self.data.get()};
+        if data.dispatching {
+            panic!("recv: called during dispatch, events still borrow the receive buffer");
+        }
 
         data.recv_buf.clear();
-        data.fd_buf.clear();
         data.cmsg_recv_buf.clear();
         data.recv_pos = 0;
-        data.fd_pos = 0;
+        data.drain_fds();
 
         let mut recv_iov = data.recv_buf.as_recv_iovec();
         let cmgs_iov = data.cmsg_recv_buf.as_recv_iovec();
@@ -162,8 +179,10 @@ impl WaylandConnection {
         }
 
         debug_assert!(bytes_read % 4 == 0, "Word padding is broken");
+        let controllen = msg.msg_controllen as usize;
 
         data.recv_buf.set_len(bytes_read as usize);
+        data.cmsg_recv_buf.set_len(controllen);
         data.process_cmsgs();
         // println!("recv descriptors: {} bytes",data.fd_buf.len() );
     }
@@ -190,6 +209,18 @@ impl WaylandConnection {
 
     pub fn delete_object(&self, id: u32) {
         let data = unsafe {&mut *self.data.get()};
+        let obj = data.get_object(id);
+        data.forget_destroyed(id);
+        if !matches!(obj, Object::Null) {
+            data.destroyed.push((id, obj));
+        }
+        if id >= SERVER_ID_START {
+            // server ids are allocated by the compositor, nothing to reuse
+            if let Some(obj) = data.server_objects.get_mut((id - SERVER_ID_START) as usize) {
+                *obj = Object::Null;
+            }
+            return;
+        }
         data.objects[id as usize] = Object::Null;
         data.used_ids.push(id);
     }
@@ -198,10 +229,41 @@ impl WaylandConnection {
 
 
 
+/// Sets the dispatching flag and restores the previous value once dispatch
+/// ends, also when a callback panics. A dispatch nested in a callback keeps
+/// the flag of the outer one set.
+struct Dispatching<'a> {
+    connection: &'a WaylandConnection,
+    outer: bool,
+}
+
+impl<'a> Dispatching<'a> {
+    fn start(connection: &'a WaylandConnection) -> Self {
+        let data = unsafe {&mut *connection.data.get()};
+        let outer = std::mem::replace(&mut data.dispatching, true);
+        Dispatching { connection, outer }
+    }
+}
+
+impl Drop for Dispatching<'_> {
+    fn drop(&mut self) {
+        let data = unsafe {&mut *self.connection.data.get()};
+        data.dispatching = self.outer;
+    }
+}
+
+/// First object id in the range allocated by the server
+const SERVER_ID_START: u32 = 0xff000000;
+
 #[derive(Debug)]
 struct WaylandConnectionPrivate {
     objects: Vec<Object>,
     used_ids: Vec<u32>,
+    /// objects created by the server, indexed from `SERVER_ID_START`
+    server_objects: Vec<Object>,
+    /// former types of deleted ids, events still in flight for them are
+    /// skipped and the descriptors they carry closed
+    destroyed: Vec<(u32, Object)>,
 
     recv_buf: Buffer<16_384>,
     send_buf: Buffer<16_384>,
@@ -212,6 +274,8 @@ struct WaylandConnectionPrivate {
 
     recv_pos: usize,
     fd_pos: usize,
+    /// set while events borrowing `recv_buf` are handed to callbacks
+    dispatching: bool,
 
     socket: UnixStream,
 
@@ -227,6 +291,7 @@ impl WaylandConnectionPrivate {
     fn allocate_id(&mut self, obj: Object) -> u32 {
         if let Some(id) = self.used_ids.pop() {
             self.objects[id as usize] = obj;
+            self.forget_destroyed(id);
             id
         } else {
             self.objects.push(obj);
@@ -235,6 +300,30 @@ impl WaylandConnectionPrivate {
         }
     }
 
+    /// Object type registered for the id, `Null` for unknown server ids
+    fn get_object(&self, id: u32) -> Object {
+        if id >= SERVER_ID_START {
+            let index = (id - SERVER_ID_START) as usize;
+            return self.server_objects.get(index).copied().unwrap_or(Object::Null);
+        }
+        self.objects[id as usize]
+    }
+
+    /// Read object id created by the server and register it in the store.
+    /// The server may reuse an id once the client has destroyed the object.
+    fn get_new_id(&mut self, obj: Object) -> u32 {
+        let id = self.get_uint();
+        assert!(id >= SERVER_ID_START, "new_id from the server is in the client range");
+
+        let index = (id - SERVER_ID_START) as usize;
+        if index >= self.server_objects.len() {
+            self.server_objects.resize(index + 1, Object::Null);
+        }
+        self.server_objects[index] = obj;
+        self.forget_destroyed(id);
+        id
+    }
+
     /// Write wayland message header into the buffer
     fn write_header(&mut self, hdr: MessageHeader, pos: usize) {
         let hdr_bytes = hdr.into_bytes();
@@ -244,38 +333,39 @@ impl WaylandConnectionPrivate {
     }
 
     /// Write wayland string into the buffer
-    fn write_string(&mut self, s: String) {
-        let mut len_bytes = s.len();
+    /// Starts with 32-bit string length including the null terminator,
+    /// followed by the string contents and padding to a 32-bit boundary.
+    fn write_string(&mut self, s: &str) {
         // add 1 byte for null-byte terminator
-        len_bytes += 1;
-        let padded_len:u32 = align32(len_bytes) as u32;
+        let len_bytes = s.len() + 1;
 
-        // First word of the string is it's size
-        self.send_buf.extend(&padded_len.to_ne_bytes());
-        self.send_buf.extend(&s.into_bytes());
+        self.send_buf.extend(&(len_bytes as u32).to_ne_bytes());
+        self.send_buf.extend(s.as_bytes());
+        self.write_padding(len_bytes - 1, align32(len_bytes) as usize);
+    }
 
-        // fill the remaining bytes with nulls
-        while len_bytes <= padded_len as usize {
-            self.send_buf.push(0u8);
-            len_bytes += 1;
+    /// Write nullable wayland string into the buffer
+    /// A null string is encoded as a zero length with no contents.
+    fn write_nullable_string(&mut self, s: Option<&str>) {
+        match s {
+            Some(s) => self.write_string(s),
+            None => self.write_uint(0),
         }
-        debug_assert_eq!(self.send_buf.len() % 4, 0);
     }
 
     /// Write wayland array into the buffer
     /// Starts with 32-bit array size in bytes,
     /// followed by the array contents verbatim,
     /// and finally padding to a 32-bit boundary.
-    fn write_array(&mut self, v: Vec<u8>) {
-        let mut len = v.len();
-        let padded_len:u32 = align32(len) as u32;
-
-        // First word of the string is it's size
-        self.send_buf.extend(&padded_len.to_ne_bytes());
-        self.send_buf.extend(&v);
+    fn write_array(&mut self, v: &[u8]) {
+        self.send_buf.extend(&(v.len() as u32).to_ne_bytes());
+        self.send_buf.extend(v);
+        self.write_padding(v.len(), align32(v.len()) as usize);
+    }
 
-        // fill the remaining bytes with nulls
-        while len <= padded_len as usize {
+    /// Fill the buffer with nulls from `len` up to `padded_len` bytes
+    fn write_padding(&mut self, mut len: usize, padded_len: usize) {
+        while len < padded_len {
             self.send_buf.push(0u8);
             len += 1;
         }
@@ -290,6 +380,10 @@ impl WaylandConnectionPrivate {
         self.send_buf.extend(&i.to_ne_bytes());
     }
 
+    fn write_fixed(&mut self, f: Fixed) {
+        self.send_buf.extend(&f.0.to_ne_bytes());
+    }
+
     fn write_fd(&mut self, fd: RawFd) {
 
         let data = self.cmsg_send_buf.as_storage();
@@ -299,7 +393,8 @@ impl WaylandConnectionPrivate {
         unsafe {
             let mut ptr = data.as_mut_ptr().add(self.cmsg_send_buf.len());
             let hdr = ptr as *mut CmsgHdr;
-            (*hdr).cmsg_len = (hdrlen + payloadlen) as u32;
+            // the length covers a single descriptor, padding is not part of it
+            (*hdr).cmsg_len = (hdrlen + size_of::<RawFd>()) as u32;
             (*hdr).cmsg_type = SCM_RIGHTS;
             (*hdr).cmsg_level = SOL_SOCKET;
             ptr = ptr.add(hdrlen);
@@ -322,13 +417,46 @@ impl WaylandConnectionPrivate {
         }
     }
 
+    /// Type the id had before it was deleted, `Null` if it was never known
+    fn destroyed_object(&self, id: u32) -> Object {
+        self.destroyed.iter()
+            .find(|(destroyed, _)| *destroyed == id)
+            .map_or(Object::Null, |(_, obj)| *obj)
+    }
+
+    fn forget_destroyed(&mut self, id: u32) {
+        self.destroyed.retain(|(destroyed, _)| *destroyed != id);
+    }
+
+    /// Close the next `count` descriptors, they belong to a skipped event
+    fn close_fds(&mut self, count: usize) {
+        for _ in 0..count {
+            if self.fd_pos + size_of::<RawFd>() > self.fd_buf.len() {
+                break;
+            }
+            let fd = self.get_fd();
+            unsafe { close(fd) };
+        }
+    }
+
+    /// Take the next descriptor received alongside the messages
     fn get_fd(&mut self) -> RawFd {
-        let data = &self.recv_buf.as_slice()[self.fd_pos..self.fd_pos + size_of::<i32>()];
-        unsafe {
-            let ptr = data.as_ptr() as *const i32;
-            self.fd_pos += 4;
-            return *ptr;
+        let end = self.fd_pos + size_of::<RawFd>();
+        if end > self.fd_buf.len() {
+            panic!("event carries a file descriptor, but none was received");
         }
+        let data = &self.fd_buf.as_slice()[self.fd_pos..end];
+        self.fd_pos = end;
+        RawFd::from_ne_bytes(data.try_into().unwrap())
+    }
+
+    /// Drop the descriptors already taken by events and keep the rest,
+    /// a descriptor may arrive before the message it belongs to
+    fn drain_fds(&mut self) {
+        let len = self.fd_buf.len();
+        self.fd_buf.as_storage().copy_within(self.fd_pos..len, 0);
+        self.fd_buf.set_len(len - self.fd_pos);
+        self.fd_pos = 0;
     }
 
     fn get_int(&mut self) -> i32 {
@@ -341,36 +469,54 @@ impl WaylandConnectionPrivate {
         }
     }
 
-    fn get_str(&mut self) -> String {
-        debug_assert!(self.recv_buf.len() > self.recv_pos);
-        let len = self.get_uint() as usize;
-        let data = &self.recv_buf.as_slice()[self.recv_pos..self.recv_pos + len];
+    /// Read wayland string borrowed from the receive buffer.
+    /// The buffer is only overwritten by the next `recv`, which refuses to
+    /// run during dispatch, events holding the string are dropped before
+    /// that at the end of their callback.
+    fn get_str<'a>(&mut self) -> Cow<'a, str> {
+        let bytes = self.get_bytes();
+        if bytes.is_empty() {
+            return Cow::Borrowed("");
+        }
+
+        let cstr = CStr::from_bytes_with_nul(bytes).expect("string is not null terminated");
+        Cow::Borrowed(cstr.to_str().expect("invalid UTF"))
+    }
 
-        if len == 0 {
-            return String::new();
+    /// Read nullable wayland string, zero length represents null
+    fn get_nullable_str<'a>(&mut self) -> Option<Cow<'a, str>> {
+        let pos = self.recv_pos;
+        if self.get_uint() == 0 {
+            return None;
         }
+        self.recv_pos = pos;
+        Some(self.get_str())
+    }
 
-        let len_padded = align32(len) as usize;
-        let cstr = unsafe { CStr::from_bytes_with_nul_unchecked(data)};
+    /// Read nullable object id, the 0 ID represents null
+    fn get_nullable_uint(&mut self) -> Option<u32> {
+        match self.get_uint() {
+            0 => None,
+            id => Some(id),
+        }
+    }
 
-        self.recv_pos += len_padded;
-        return cstr.to_str().expect("invalid UTF").to_owned()
+    /// Read wayland array borrowed from the receive buffer
+    fn get_vec<'a>(&mut self) -> Cow<'a, [u8]> {
+        Cow::Borrowed(self.get_bytes())
     }
 
-    fn get_vec(&mut self) -> Vec<u8> {
+    /// Read length prefixed contents of a string or an array and skip
+    /// the padding after it.
+    fn get_bytes<'a>(&mut self) -> &'a [u8] {
         debug_assert!(self.recv_buf.len() > self.recv_pos);
         let len = self.get_uint() as usize;
         let data = &self.recv_buf.as_slice()[self.recv_pos..self.recv_pos + len];
-        if len == 0 {
-            return Vec::new();
-        }
-        let len_padded = align32(len) as usize;
+        self.recv_pos += align32(len) as usize;
 
-        let result:Vec<u8> = data.into();
-
-        self.recv_pos += len_padded;
-
-        result
+        // SAFETY: recv_buf is inline storage of the connection and is only
+        // rewritten by `recv`, which panics while events are dispatched.
+        unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) }
     }
 
     fn get_header(&mut self) -> MessageHeader {
@@ -384,8 +530,9 @@ impl WaylandConnectionPrivate {
         return result
     }
 
+    /// Queue the descriptors from the control messages of the last `recv`
     fn process_cmsgs(&mut self) {
-        let data = self.recv_buf.as_storage();
+        let data = self.cmsg_recv_buf.as_slice();
         let mut pos = 0;
 
         let hdr_size = alignptr(size_of::<CmsgHdr>());
@@ -393,14 +540,14 @@ impl WaylandConnectionPrivate {
         loop {
             // recv() function guarantees that non-truncated data was receieved
             // We assume that the payload is >= 4 bytes
-            if pos + hdr_size + size_of::<i32>() > self.cmsg_recv_buf.len() {
+            if pos + hdr_size + size_of::<i32>() > data.len() {
                 break;
             }
-            let hdr = unsafe { &*(data.as_ptr().add(pos) as *mut CmsgHdr) };
+            let hdr = unsafe { std::ptr::read_unaligned(data.as_ptr().add(pos) as *const CmsgHdr) };
 
             if hdr.cmsg_level == SOL_SOCKET && hdr.cmsg_type == SCM_RIGHTS {
                 let begin = pos + hdr_size;
-                let end = pos + hdr.cmsg_len as usize;
+                let end = std::cmp::min(pos + hdr.cmsg_len as usize, data.len());
                 self.fd_buf.extend(&data[begin..end]);
 
                 pos += alignptr(hdr.cmsg_len as usize);
@@ -413,6 +560,302 @@ impl WaylandConnectionPrivate {
 }
 
 
+#[cfg(test)]
+mod tests {
+    use super::{testing, WaylandConnection};
+    use super::c_ffi::{close, pipe};
+    use crate::types::MessageHeader;
+    use crate::types::events::{WlDisplayDeleteIdEvent, ZwpPrimarySelectionOfferV1OfferEvent, ZwpPrimarySelectionSourceV1SendEvent};
+    use crate::types::events::{ZwpLinuxBufferReleaseV1FencedReleaseEvent, ZwpLinuxBufferReleaseV1ImmediateReleaseEvent};
+    use crate::types::handler::EventHandler;
+    use crate::types::object::Object;
+    use std::fs::File;
+    use std::io::{Read, Write};
+    use std::os::fd::{AsRawFd, FromRawFd, RawFd};
+    use std::os::unix::net::UnixStream;
+
+    #[test]
+    fn string_and_array_encoding() {
+        let (connection, _peer) = testing::connection();
+        let data = unsafe { &mut *connection.data.get() };
+
+        data.write_string("abc");
+        data.write_string("abcd");
+        data.write_nullable_string(None);
+        data.write_array(&[1, 2, 3, 4]);
+        data.write_array(&[5]);
+
+        let expected: &[u8] = &[
+            4, 0, 0, 0, b'a', b'b', b'c', 0,
+            5, 0, 0, 0, b'a', b'b', b'c', b'd', 0, 0, 0, 0,
+            0, 0, 0, 0,
+            4, 0, 0, 0, 1, 2, 3, 4,
+            1, 0, 0, 0, 5, 0, 0, 0,
+        ];
+        assert_eq!(data.send_buf.as_slice(), expected);
+    }
+
+    struct Reentrant;
+
+    impl EventHandler for Reentrant {
+        fn on_wl_display_delete_id(&mut self, _event: WlDisplayDeleteIdEvent, connection: &WaylandConnection) {
+            connection.recv();
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "recv: called during dispatch")]
+    fn recv_during_dispatch() {
+        let (connection, _peer) = testing::connection();
+        let data = unsafe { &mut *connection.data.get() };
+
+        // wl_display.delete_id(3)
+        for word in [1u32, 12 << 16 | 1, 3] {
+            data.recv_buf.extend(&word.to_ne_bytes());
+        }
+        connection.dispatch_events(&mut Reentrant);
+    }
+
+    /// Dispatches the remaining events from a callback, then receives
+    struct Nested {
+        depth: usize,
+    }
+
+    impl EventHandler for Nested {
+        fn on_wl_display_delete_id(&mut self, _event: WlDisplayDeleteIdEvent, connection: &WaylandConnection) {
+            self.depth += 1;
+            if self.depth == 1 {
+                connection.dispatch_events(self);
+                connection.recv();
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "recv: called during dispatch")]
+    fn recv_after_nested_dispatch() {
+        let (connection, _peer) = testing::connection();
+        let data = unsafe { &mut *connection.data.get() };
+
+        // wl_display.delete_id(3), wl_display.delete_id(4)
+        for word in [1u32, 12 << 16 | 1, 3, 1, 12 << 16 | 1, 4] {
+            data.recv_buf.extend(&word.to_ne_bytes());
+        }
+        connection.dispatch_events(&mut Nested { depth: 0 });
+    }
+
+    #[derive(Default)]
+    struct Offers {
+        mime_types: Vec<String>,
+        deleted: Vec<u32>,
+    }
+
+    impl EventHandler for Offers {
+        fn on_zwp_primary_selection_offer_v1_offer(&mut self, event: ZwpPrimarySelectionOfferV1OfferEvent<'_>, _connection: &WaylandConnection) {
+            self.mime_types.push(event.mime_type.into_owned());
+        }
+
+        fn on_wl_display_delete_id(&mut self, event: WlDisplayDeleteIdEvent, _connection: &WaylandConnection) {
+            self.deleted.push(event.id);
+        }
+    }
+
+    #[test]
+    fn events_of_destroyed_server_objects() {
+        let (connection, _peer) = testing::connection();
+        let data = unsafe { &mut *connection.data.get() };
+
+        let offer = super::SERVER_ID_START;
+        data.server_objects.push(Object::ZwpPrimarySelectionOfferV1);
+
+        // zwp_primary_selection_offer_v1.offer("a") for the offer and for an
+        // id never announced, followed by wl_display.delete_id(3), dispatched
+        // before and after the offer is destroyed
+        let mut words = Vec::new();
+        for id in [offer, offer + 1] {
+            words.extend([id, 16 << 16, 2, u32::from_ne_bytes(*b"a\0\0\0")]);
+        }
+        words.extend([1u32, 12 << 16 | 1, 3]);
+
+        for word in &words {
+            data.recv_buf.extend(&word.to_ne_bytes());
+        }
+        let mut offers = Offers::default();
+        connection.dispatch_events(&mut offers);
+        assert_eq!(offers.mime_types, ["a"]);
+        assert_eq!(offers.deleted, [3]);
+
+        connection.delete_object(offer);
+        data.recv_buf.clear();
+        data.recv_pos = 0;
+        for word in &words {
+            data.recv_buf.extend(&word.to_ne_bytes());
+        }
+        let mut offers = Offers::default();
+        connection.dispatch_events(&mut offers);
+        assert!(offers.mime_types.is_empty());
+        assert_eq!(offers.deleted, [3]);
+    }
+
+    #[derive(Default)]
+    struct Sends(Vec<(String, RawFd)>);
+
+    impl EventHandler for Sends {
+        fn on_zwp_primary_selection_source_v1_send(&mut self, event: ZwpPrimarySelectionSourceV1SendEvent<'_>, _connection: &WaylandConnection) {
+            self.0.push((event.mime_type.into_owned(), event.fd));
+        }
+    }
+
+    #[test]
+    fn fd_reception() {
+        let (connection, peer_socket) = testing::connection();
+        let peer = WaylandConnection::from_stream(peer_socket);
+        let source = unsafe { &mut *connection.data.get() }.allocate_id(Object::ZwpPrimarySelectionSourceV1);
+
+        // zwp_primary_selection_source_v1.send(mime_type, fd) twice in one batch
+        let peer_data = unsafe { &mut *peer.data.get() };
+        let mut readers = Vec::new();
+        let mut sent = Vec::new();
+        for mime_type in ["text/plain", "image/png"] {
+            let mut fds = [0; 2];
+            assert_eq!(unsafe { pipe(fds.as_mut_ptr()) }, 0);
+            readers.push(unsafe { File::from_raw_fd(fds[0]) });
+
+            let start = peer_data.send_buf.len();
+            peer_data.send_buf.set_len(start + 8);
+            peer_data.write_string(mime_type);
+            let len = (peer_data.send_buf.len() - start) as u16;
+            peer_data.write_header(MessageHeader { obj_id: source, opcode: 0, len }, start);
+            peer_data.write_fd(fds[1]);
+            sent.push(fds[1]);
+        }
+        peer.send();
+        // the received descriptors are duplicates, reading ends once they are closed
+        for fd in sent {
+            unsafe { close(fd) };
+        }
+
+        connection.recv();
+        let mut sends = Sends::default();
+        connection.dispatch_events(&mut sends);
+        assert_eq!(sends.0.len(), 2);
+
+        for ((mime_type, fd), mut reader) in sends.0.into_iter().zip(readers) {
+            let mut writer = unsafe { File::from_raw_fd(fd) };
+            writer.write_all(mime_type.as_bytes()).unwrap();
+            drop(writer);
+
+            let mut content = String::new();
+            reader.read_to_string(&mut content).unwrap();
+            assert_eq!(content, mime_type);
+        }
+    }
+
+    #[test]
+    fn fds_of_destroyed_objects() {
+        let (connection, peer_socket) = testing::connection();
+        let peer = WaylandConnection::from_stream(peer_socket);
+        let data = unsafe { &mut *connection.data.get() };
+        let destroyed = data.allocate_id(Object::ZwpPrimarySelectionSourceV1);
+        let live = data.allocate_id(Object::ZwpPrimarySelectionSourceV1);
+        connection.delete_object(destroyed);
+
+        // zwp_primary_selection_source_v1.send(mime_type, fd) for both sources,
+        // a socket pair stands in for the pipe to tell when an end is closed
+        let peer_data = unsafe { &mut *peer.data.get() };
+        let mut ours = Vec::new();
+        let mut sent = Vec::new();
+        for source in [destroyed, live] {
+            let (mine, theirs) = UnixStream::pair().unwrap();
+            mine.set_nonblocking(true).unwrap();
+            let start = peer_data.send_buf.len();
+            peer_data.send_buf.set_len(start + 8);
+            peer_data.write_string("text/plain");
+            let len = (peer_data.send_buf.len() - start) as u16;
+            peer_data.write_header(MessageHeader { obj_id: source, opcode: 0, len }, start);
+            peer_data.write_fd(theirs.as_raw_fd());
+            ours.push(mine);
+            sent.push(theirs);
+        }
+        peer.send();
+        drop(sent);
+
+        connection.recv();
+        let mut sends = Sends::default();
+        connection.dispatch_events(&mut sends);
+        assert_eq!(sends.0.len(), 1);
+
+        // the live source got the second descriptor
+        let mut received = unsafe { UnixStream::from_raw_fd(sends.0[0].1) };
+        received.write_all(b"live").unwrap();
+        let mut buf = [0u8; 4];
+        ours[1].read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"live");
+
+        // the descriptor of the skipped event was closed
+        assert_eq!(ours[0].read(&mut buf).unwrap(), 0);
+    }
+
+    #[derive(Default)]
+    struct Releases(Vec<(u32, Option<RawFd>)>);
+
+    impl EventHandler for Releases {
+        fn on_zwp_linux_buffer_release_v1_fenced_release(&mut self, event: ZwpLinuxBufferReleaseV1FencedReleaseEvent, _connection: &WaylandConnection) {
+            self.0.push((event.source_id, Some(event.fence)));
+        }
+
+        fn on_zwp_linux_buffer_release_v1_immediate_release(&mut self, event: ZwpLinuxBufferReleaseV1ImmediateReleaseEvent, _connection: &WaylandConnection) {
+            self.0.push((event.source_id, None));
+        }
+    }
+
+    #[test]
+    fn fenced_release() {
+        let (connection, peer_socket) = testing::connection();
+        let peer = WaylandConnection::from_stream(peer_socket);
+        let data = unsafe { &mut *connection.data.get() };
+        let releases: Vec<u32> = (0..3).map(|_| data.allocate_id(Object::ZwpLinuxBufferReleaseV1)).collect();
+
+        // immediate_release for the first buffer, fenced_release for the others,
+        // a pipe stands in for the sync file
+        let peer_data = unsafe { &mut *peer.data.get() };
+        let mut fences = Vec::new();
+        for (i, &release) in releases.iter().enumerate() {
+            let opcode = if i == 0 { 1 } else { 0 };
+            let start = peer_data.send_buf.len();
+            peer_data.send_buf.set_len(start + 8);
+            peer_data.write_header(MessageHeader { obj_id: release, opcode, len: 8 }, start);
+            if opcode == 0 {
+                let mut fds = [0; 2];
+                assert_eq!(unsafe { pipe(fds.as_mut_ptr()) }, 0);
+                peer_data.write_fd(fds[0]);
+                fences.push(fds);
+            }
+        }
+        peer.send();
+
+        connection.recv();
+        let mut events = Releases::default();
+        connection.dispatch_events(&mut events);
+        assert_eq!(events.0.len(), 3);
+        assert_eq!(events.0[0], (releases[0], None));
+        assert_eq!(events.0[1].0, releases[1]);
+        assert_eq!(events.0[2].0, releases[2]);
+
+        for ((_, fence), fds) in events.0[1..].iter().zip(fences) {
+            let mut writer = unsafe { File::from_raw_fd(fds[1]) };
+            writer.write_all(b"signaled").unwrap();
+            drop(writer);
+            unsafe { close(fds[0]) };
+
+            let mut content = String::new();
+            let mut reader = unsafe { File::from_raw_fd(fence.unwrap()) };
+            reader.read_to_string(&mut content).unwrap();
+            assert_eq!(content, "signaled");
+        }
+    }
+}
+
 // Fixed to Double
 // fn f2d(f: Fixed) -> f64 {
 //     let int_part = f >> 8;
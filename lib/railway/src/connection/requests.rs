@@ -6,7 +6,7 @@ use crate::types::Request::*;
 
 impl WaylandConnection {
 
-pub fn enqueue(&self, req: Request) -> u32 {
+pub fn enqueue(&self, req: Request<'_>) -> u32 {
         let data =  unsafe { &mut *self.data.get() };
         // Used as a return value, not all requests generate a new_id,
         // according to the spec 0 ID is reserved to represent a null or non-existent object.
@@ -339,7 +339,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
                 data.write_uint(new_id);
-                data.write_uint(parent);
+                data.write_uint(parent.unwrap_or(0));
                 data.write_uint(positioner);
                 let hdr = MessageHeader {
                     obj_id: sendto,
@@ -390,7 +390,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
             XdgToplevelSetParent{sendto,parent} => {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
-                data.write_uint(parent);
+                data.write_uint(parent.unwrap_or(0));
                 let hdr = MessageHeader {
                     obj_id: sendto,
                     opcode: 1u16,
@@ -516,7 +516,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
             XdgToplevelSetFullscreen{sendto,output} => {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
-                data.write_uint(output);
+                data.write_uint(output.unwrap_or(0));
                 let hdr = MessageHeader {
                     obj_id: sendto,
                     opcode: 11u16,
@@ -610,9 +610,8 @@ pub fn enqueue(&self, req: Request) -> u32 {
                 data.write_header(hdr, hdr_pos);
             },
             // wl_registry:bind
-            WlRegistryBind{sendto,name,if_name,if_version} => {
-                let kind = Object::from_str(&if_name).unwrap();
-                new_id = data.allocate_id(kind);
+            WlRegistryBind{sendto,name,if_name,if_version,object} => {
+                new_id = data.allocate_id(object);
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
                 data.write_uint(name);
@@ -724,7 +723,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
                 data.write_uint(serial);
-                data.write_string(mime_type);
+                data.write_nullable_string(mime_type);
                 let hdr = MessageHeader {
                     obj_id: sendto,
                     opcode: 0u16,
@@ -819,9 +818,9 @@ pub fn enqueue(&self, req: Request) -> u32 {
             WlDataDeviceStartDrag{sendto,source,origin,icon,serial} => {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
-                data.write_uint(source);
+                data.write_uint(source.unwrap_or(0));
                 data.write_uint(origin);
-                data.write_uint(icon);
+                data.write_uint(icon.unwrap_or(0));
                 data.write_uint(serial);
                 let hdr = MessageHeader {
                     obj_id: sendto,
@@ -834,7 +833,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
             WlDataDeviceSetSelection{sendto,source,serial} => {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
-                data.write_uint(source);
+                data.write_uint(source.unwrap_or(0));
                 data.write_uint(serial);
                 let hdr = MessageHeader {
                     obj_id: sendto,
@@ -966,7 +965,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
                 data.send_buf.set_len(hdr_pos + 8);
                 data.write_uint(method);
                 data.write_uint(framerate);
-                data.write_uint(output);
+                data.write_uint(output.unwrap_or(0));
                 let hdr = MessageHeader {
                     obj_id: sendto,
                     opcode: 5u16,
@@ -995,7 +994,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
             WlShellSurfaceSetMaximized{sendto,output} => {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
-                data.write_uint(output);
+                data.write_uint(output.unwrap_or(0));
                 let hdr = MessageHeader {
                     obj_id: sendto,
                     opcode: 7u16,
@@ -1042,7 +1041,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
             WlSurfaceAttach{sendto,buffer,x,y} => {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
-                data.write_uint(buffer);
+                data.write_uint(buffer.unwrap_or(0));
                 data.write_int(x);
                 data.write_int(y);
                 let hdr = MessageHeader {
@@ -1084,7 +1083,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
             WlSurfaceSetOpaqueRegion{sendto,region} => {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
-                data.write_uint(region);
+                data.write_uint(region.unwrap_or(0));
                 let hdr = MessageHeader {
                     obj_id: sendto,
                     opcode: 4u16,
@@ -1096,7 +1095,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
             WlSurfaceSetInputRegion{sendto,region} => {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
-                data.write_uint(region);
+                data.write_uint(region.unwrap_or(0));
                 let hdr = MessageHeader {
                     obj_id: sendto,
                     opcode: 5u16,
@@ -1222,7 +1221,7 @@ pub fn enqueue(&self, req: Request) -> u32 {
                 let hdr_pos = data.send_buf.len();
                 data.send_buf.set_len(hdr_pos + 8);
                 data.write_uint(serial);
-                data.write_uint(surface);
+                data.write_uint(surface.unwrap_or(0));
                 data.write_int(hotspot_x);
                 data.write_int(hotspot_y);
                 let hdr = MessageHeader {
@@ -1413,6 +1412,1909 @@ pub fn enqueue(&self, req: Request) -> u32 {
                 };
                 data.write_header(hdr, hdr_pos);
             },
+            // zxdg_decoration_manager_v1:destroy
+            ZxdgDecorationManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zxdg_decoration_manager_v1:get_toplevel_decoration
+            ZxdgDecorationManagerV1GetToplevelDecoration{sendto,toplevel} => {
+                new_id = data.allocate_id(Object::ZxdgToplevelDecorationV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(toplevel);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zxdg_toplevel_decoration_v1:destroy
+            ZxdgToplevelDecorationV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zxdg_toplevel_decoration_v1:set_mode
+            ZxdgToplevelDecorationV1SetMode{sendto,mode} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(mode);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zxdg_toplevel_decoration_v1:unset_mode
+            ZxdgToplevelDecorationV1UnsetMode{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_viewporter:destroy
+            WpViewporterDestroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_viewporter:get_viewport
+            WpViewporterGetViewport{sendto,surface} => {
+                new_id = data.allocate_id(Object::WpViewport);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_viewport:destroy
+            WpViewportDestroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_viewport:set_source
+            WpViewportSetSource{sendto,x,y,width,height} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_fixed(x);
+                data.write_fixed(y);
+                data.write_fixed(width);
+                data.write_fixed(height);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_viewport:set_destination
+            WpViewportSetDestination{sendto,width,height} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_int(width);
+                data.write_int(height);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_fractional_scale_manager_v1:destroy
+            WpFractionalScaleManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_fractional_scale_manager_v1:get_fractional_scale
+            WpFractionalScaleManagerV1GetFractionalScale{sendto,surface} => {
+                new_id = data.allocate_id(Object::WpFractionalScaleV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_fractional_scale_v1:destroy
+            WpFractionalScaleV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_presentation:destroy
+            WpPresentationDestroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_presentation:feedback
+            WpPresentationFeedback{sendto,surface} => {
+                new_id = data.allocate_id(Object::WpPresentationFeedback);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(surface);
+                data.write_uint(new_id);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_linux_explicit_synchronization_v1:destroy
+            ZwpLinuxExplicitSynchronizationV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_linux_explicit_synchronization_v1:get_synchronization
+            ZwpLinuxExplicitSynchronizationV1GetSynchronization{sendto,surface} => {
+                new_id = data.allocate_id(Object::ZwpLinuxSurfaceSynchronizationV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_linux_surface_synchronization_v1:destroy
+            ZwpLinuxSurfaceSynchronizationV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_linux_surface_synchronization_v1:set_acquire_fence
+            ZwpLinuxSurfaceSynchronizationV1SetAcquireFence{sendto,fd} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_fd(fd);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_linux_surface_synchronization_v1:get_release
+            ZwpLinuxSurfaceSynchronizationV1GetRelease{sendto} => {
+                new_id = data.allocate_id(Object::ZwpLinuxBufferReleaseV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_linux_drm_syncobj_manager_v1:destroy
+            WpLinuxDrmSyncobjManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_linux_drm_syncobj_manager_v1:get_surface
+            WpLinuxDrmSyncobjManagerV1GetSurface{sendto,surface} => {
+                new_id = data.allocate_id(Object::WpLinuxDrmSyncobjSurfaceV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_linux_drm_syncobj_manager_v1:import_timeline
+            WpLinuxDrmSyncobjManagerV1ImportTimeline{sendto,fd} => {
+                new_id = data.allocate_id(Object::WpLinuxDrmSyncobjTimelineV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_fd(fd);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_linux_drm_syncobj_timeline_v1:destroy
+            WpLinuxDrmSyncobjTimelineV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_linux_drm_syncobj_surface_v1:destroy
+            WpLinuxDrmSyncobjSurfaceV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_linux_drm_syncobj_surface_v1:set_acquire_point
+            WpLinuxDrmSyncobjSurfaceV1SetAcquirePoint{sendto,timeline,point_hi,point_lo} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(timeline);
+                data.write_uint(point_hi);
+                data.write_uint(point_lo);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_linux_drm_syncobj_surface_v1:set_release_point
+            WpLinuxDrmSyncobjSurfaceV1SetReleasePoint{sendto,timeline,point_hi,point_lo} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(timeline);
+                data.write_uint(point_hi);
+                data.write_uint(point_lo);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // xdg_activation_v1:destroy
+            XdgActivationV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // xdg_activation_v1:get_activation_token
+            XdgActivationV1GetActivationToken{sendto} => {
+                new_id = data.allocate_id(Object::XdgActivationTokenV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // xdg_activation_v1:activate
+            XdgActivationV1Activate{sendto,token,surface} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_string(token);
+                data.write_uint(surface);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // xdg_activation_token_v1:set_serial
+            XdgActivationTokenV1SetSerial{sendto,serial,seat} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(serial);
+                data.write_uint(seat);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // xdg_activation_token_v1:set_app_id
+            XdgActivationTokenV1SetAppId{sendto,app_id} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_string(app_id);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // xdg_activation_token_v1:set_surface
+            XdgActivationTokenV1SetSurface{sendto,surface} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(surface);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // xdg_activation_token_v1:commit
+            XdgActivationTokenV1Commit{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 3u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // xdg_activation_token_v1:destroy
+            XdgActivationTokenV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 4u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_cursor_shape_manager_v1:destroy
+            WpCursorShapeManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_cursor_shape_manager_v1:get_pointer
+            WpCursorShapeManagerV1GetPointer{sendto,pointer} => {
+                new_id = data.allocate_id(Object::WpCursorShapeDeviceV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(pointer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_cursor_shape_manager_v1:get_tablet_tool_v2
+            WpCursorShapeManagerV1GetTabletToolV2{sendto,tablet_tool} => {
+                new_id = data.allocate_id(Object::WpCursorShapeDeviceV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(tablet_tool);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_cursor_shape_device_v1:destroy
+            WpCursorShapeDeviceV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_cursor_shape_device_v1:set_shape
+            WpCursorShapeDeviceV1SetShape{sendto,serial,shape} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(serial);
+                data.write_uint(shape);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:destroy
+            ZwpTextInputV3Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:enable
+            ZwpTextInputV3Enable{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:disable
+            ZwpTextInputV3Disable{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:set_surrounding_text
+            ZwpTextInputV3SetSurroundingText{sendto,text,cursor,anchor} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_string(text);
+                data.write_int(cursor);
+                data.write_int(anchor);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 3u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:set_text_change_cause
+            ZwpTextInputV3SetTextChangeCause{sendto,cause} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(cause);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 4u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:set_content_type
+            ZwpTextInputV3SetContentType{sendto,hint,purpose} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(hint);
+                data.write_uint(purpose);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 5u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:set_cursor_rectangle
+            ZwpTextInputV3SetCursorRectangle{sendto,x,y,width,height} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_int(x);
+                data.write_int(y);
+                data.write_int(width);
+                data.write_int(height);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 6u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:commit
+            ZwpTextInputV3Commit{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 7u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:set_available_actions
+            ZwpTextInputV3SetAvailableActions{sendto,available_actions} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_array(available_actions);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 8u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:show_input_panel
+            ZwpTextInputV3ShowInputPanel{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 9u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_v3:hide_input_panel
+            ZwpTextInputV3HideInputPanel{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 10u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_manager_v3:destroy
+            ZwpTextInputManagerV3Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_text_input_manager_v3:get_text_input
+            ZwpTextInputManagerV3GetTextInput{sendto,seat} => {
+                new_id = data.allocate_id(Object::ZwpTextInputV3);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(seat);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_primary_selection_device_manager_v1:create_source
+            ZwpPrimarySelectionDeviceManagerV1CreateSource{sendto} => {
+                new_id = data.allocate_id(Object::ZwpPrimarySelectionSourceV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_primary_selection_device_manager_v1:get_device
+            ZwpPrimarySelectionDeviceManagerV1GetDevice{sendto,seat} => {
+                new_id = data.allocate_id(Object::ZwpPrimarySelectionDeviceV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(seat);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_primary_selection_device_manager_v1:destroy
+            ZwpPrimarySelectionDeviceManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_primary_selection_device_v1:set_selection
+            ZwpPrimarySelectionDeviceV1SetSelection{sendto,source,serial} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(source.unwrap_or(0));
+                data.write_uint(serial);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_primary_selection_device_v1:destroy
+            ZwpPrimarySelectionDeviceV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_primary_selection_offer_v1:receive
+            ZwpPrimarySelectionOfferV1Receive{sendto,mime_type,fd} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_string(mime_type);
+                data.write_fd(fd);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_primary_selection_offer_v1:destroy
+            ZwpPrimarySelectionOfferV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_primary_selection_source_v1:offer
+            ZwpPrimarySelectionSourceV1Offer{sendto,mime_type} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_string(mime_type);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_primary_selection_source_v1:destroy
+            ZwpPrimarySelectionSourceV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_constraints_v1:destroy
+            ZwpPointerConstraintsV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_constraints_v1:lock_pointer
+            ZwpPointerConstraintsV1LockPointer{sendto,surface,pointer,region,lifetime} => {
+                new_id = data.allocate_id(Object::ZwpLockedPointerV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                data.write_uint(pointer);
+                data.write_uint(region.unwrap_or(0));
+                data.write_uint(lifetime);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_constraints_v1:confine_pointer
+            ZwpPointerConstraintsV1ConfinePointer{sendto,surface,pointer,region,lifetime} => {
+                new_id = data.allocate_id(Object::ZwpConfinedPointerV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                data.write_uint(pointer);
+                data.write_uint(region.unwrap_or(0));
+                data.write_uint(lifetime);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_locked_pointer_v1:destroy
+            ZwpLockedPointerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_locked_pointer_v1:set_cursor_position_hint
+            ZwpLockedPointerV1SetCursorPositionHint{sendto,surface_x,surface_y} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_fixed(surface_x);
+                data.write_fixed(surface_y);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_locked_pointer_v1:set_region
+            ZwpLockedPointerV1SetRegion{sendto,region} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(region.unwrap_or(0));
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_confined_pointer_v1:destroy
+            ZwpConfinedPointerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_confined_pointer_v1:set_region
+            ZwpConfinedPointerV1SetRegion{sendto,region} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(region.unwrap_or(0));
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_relative_pointer_manager_v1:destroy
+            ZwpRelativePointerManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_relative_pointer_manager_v1:get_relative_pointer
+            ZwpRelativePointerManagerV1GetRelativePointer{sendto,pointer} => {
+                new_id = data.allocate_id(Object::ZwpRelativePointerV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(pointer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_relative_pointer_v1:destroy
+            ZwpRelativePointerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_idle_inhibit_manager_v1:destroy
+            ZwpIdleInhibitManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_idle_inhibit_manager_v1:create_inhibitor
+            ZwpIdleInhibitManagerV1CreateInhibitor{sendto,surface} => {
+                new_id = data.allocate_id(Object::ZwpIdleInhibitorV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_idle_inhibitor_v1:destroy
+            ZwpIdleInhibitorV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_idle_notifier_v1:destroy
+            ExtIdleNotifierV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_idle_notifier_v1:get_idle_notification
+            ExtIdleNotifierV1GetIdleNotification{sendto,timeout,seat} => {
+                new_id = data.allocate_id(Object::ExtIdleNotificationV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(timeout);
+                data.write_uint(seat);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_idle_notifier_v1:get_input_idle_notification
+            ExtIdleNotifierV1GetInputIdleNotification{sendto,timeout,seat} => {
+                new_id = data.allocate_id(Object::ExtIdleNotificationV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(timeout);
+                data.write_uint(seat);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_idle_notification_v1:destroy
+            ExtIdleNotificationV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_shell_v1:get_layer_surface
+            ZwlrLayerShellV1GetLayerSurface{sendto,surface,output,layer,namespace} => {
+                new_id = data.allocate_id(Object::ZwlrLayerSurfaceV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                data.write_uint(output.unwrap_or(0));
+                data.write_uint(layer);
+                data.write_string(namespace);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_shell_v1:destroy
+            ZwlrLayerShellV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:set_size
+            ZwlrLayerSurfaceV1SetSize{sendto,width,height} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(width);
+                data.write_uint(height);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:set_anchor
+            ZwlrLayerSurfaceV1SetAnchor{sendto,anchor} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(anchor);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:set_exclusive_zone
+            ZwlrLayerSurfaceV1SetExclusiveZone{sendto,zone} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_int(zone);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:set_margin
+            ZwlrLayerSurfaceV1SetMargin{sendto,top,right,bottom,left} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_int(top);
+                data.write_int(right);
+                data.write_int(bottom);
+                data.write_int(left);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 3u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:set_keyboard_interactivity
+            ZwlrLayerSurfaceV1SetKeyboardInteractivity{sendto,keyboard_interactivity} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(keyboard_interactivity);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 4u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:get_popup
+            ZwlrLayerSurfaceV1GetPopup{sendto,popup} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(popup);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 5u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:ack_configure
+            ZwlrLayerSurfaceV1AckConfigure{sendto,serial} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(serial);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 6u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:destroy
+            ZwlrLayerSurfaceV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 7u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:set_layer
+            ZwlrLayerSurfaceV1SetLayer{sendto,layer} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(layer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 8u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_layer_surface_v1:set_exclusive_edge
+            ZwlrLayerSurfaceV1SetExclusiveEdge{sendto,edge} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(edge);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 9u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_session_lock_manager_v1:destroy
+            ExtSessionLockManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_session_lock_manager_v1:lock
+            ExtSessionLockManagerV1Lock{sendto} => {
+                new_id = data.allocate_id(Object::ExtSessionLockV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_session_lock_v1:destroy
+            ExtSessionLockV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_session_lock_v1:get_lock_surface
+            ExtSessionLockV1GetLockSurface{sendto,surface,output} => {
+                new_id = data.allocate_id(Object::ExtSessionLockSurfaceV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                data.write_uint(output);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_session_lock_v1:unlock_and_destroy
+            ExtSessionLockV1UnlockAndDestroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_session_lock_surface_v1:destroy
+            ExtSessionLockSurfaceV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_session_lock_surface_v1:ack_configure
+            ExtSessionLockSurfaceV1AckConfigure{sendto,serial} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(serial);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_capture_source_v1:destroy
+            ExtImageCaptureSourceV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_output_image_capture_source_manager_v1:create_source
+            ExtOutputImageCaptureSourceManagerV1CreateSource{sendto,output} => {
+                new_id = data.allocate_id(Object::ExtImageCaptureSourceV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(output);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_output_image_capture_source_manager_v1:destroy
+            ExtOutputImageCaptureSourceManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_manager_v1:create_session
+            ExtImageCopyCaptureManagerV1CreateSession{sendto,source,options} => {
+                new_id = data.allocate_id(Object::ExtImageCopyCaptureSessionV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(source);
+                data.write_uint(options);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_manager_v1:create_pointer_cursor_session
+            ExtImageCopyCaptureManagerV1CreatePointerCursorSession{sendto,source,pointer} => {
+                new_id = data.allocate_id(Object::ExtImageCopyCaptureCursorSessionV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(source);
+                data.write_uint(pointer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_manager_v1:destroy
+            ExtImageCopyCaptureManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_session_v1:create_frame
+            ExtImageCopyCaptureSessionV1CreateFrame{sendto} => {
+                new_id = data.allocate_id(Object::ExtImageCopyCaptureFrameV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_session_v1:destroy
+            ExtImageCopyCaptureSessionV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_frame_v1:destroy
+            ExtImageCopyCaptureFrameV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_frame_v1:attach_buffer
+            ExtImageCopyCaptureFrameV1AttachBuffer{sendto,buffer} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(buffer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_frame_v1:damage_buffer
+            ExtImageCopyCaptureFrameV1DamageBuffer{sendto,x,y,width,height} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_int(x);
+                data.write_int(y);
+                data.write_int(width);
+                data.write_int(height);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_frame_v1:capture
+            ExtImageCopyCaptureFrameV1Capture{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 3u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_cursor_session_v1:destroy
+            ExtImageCopyCaptureCursorSessionV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // ext_image_copy_capture_cursor_session_v1:get_capture_session
+            ExtImageCopyCaptureCursorSessionV1GetCaptureSession{sendto} => {
+                new_id = data.allocate_id(Object::ExtImageCopyCaptureSessionV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_screencopy_manager_v1:capture_output
+            ZwlrScreencopyManagerV1CaptureOutput{sendto,overlay_cursor,output} => {
+                new_id = data.allocate_id(Object::ZwlrScreencopyFrameV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_int(overlay_cursor);
+                data.write_uint(output);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_screencopy_manager_v1:capture_output_region
+            ZwlrScreencopyManagerV1CaptureOutputRegion{sendto,overlay_cursor,output,x,y,width,height} => {
+                new_id = data.allocate_id(Object::ZwlrScreencopyFrameV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_int(overlay_cursor);
+                data.write_uint(output);
+                data.write_int(x);
+                data.write_int(y);
+                data.write_int(width);
+                data.write_int(height);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_screencopy_manager_v1:destroy
+            ZwlrScreencopyManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_screencopy_frame_v1:copy
+            ZwlrScreencopyFrameV1Copy{sendto,buffer} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(buffer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_screencopy_frame_v1:destroy
+            ZwlrScreencopyFrameV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwlr_screencopy_frame_v1:copy_with_damage
+            ZwlrScreencopyFrameV1CopyWithDamage{sendto,buffer} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(buffer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_manager_v2:get_tablet_seat
+            ZwpTabletManagerV2GetTabletSeat{sendto,seat} => {
+                new_id = data.allocate_id(Object::ZwpTabletSeatV2);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(seat);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_manager_v2:destroy
+            ZwpTabletManagerV2Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_seat_v2:destroy
+            ZwpTabletSeatV2Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_tool_v2:set_cursor
+            ZwpTabletToolV2SetCursor{sendto,serial,surface,hotspot_x,hotspot_y} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(serial);
+                data.write_uint(surface.unwrap_or(0));
+                data.write_int(hotspot_x);
+                data.write_int(hotspot_y);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_tool_v2:destroy
+            ZwpTabletToolV2Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_v2:destroy
+            ZwpTabletV2Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_pad_ring_v2:set_feedback
+            ZwpTabletPadRingV2SetFeedback{sendto,description,serial} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_string(description);
+                data.write_uint(serial);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_pad_ring_v2:destroy
+            ZwpTabletPadRingV2Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_pad_strip_v2:set_feedback
+            ZwpTabletPadStripV2SetFeedback{sendto,description,serial} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_string(description);
+                data.write_uint(serial);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_pad_strip_v2:destroy
+            ZwpTabletPadStripV2Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_pad_group_v2:destroy
+            ZwpTabletPadGroupV2Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_pad_v2:set_feedback
+            ZwpTabletPadV2SetFeedback{sendto,button,description,serial} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(button);
+                data.write_string(description);
+                data.write_uint(serial);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_pad_v2:destroy
+            ZwpTabletPadV2Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_pad_dial_v2:set_feedback
+            ZwpTabletPadDialV2SetFeedback{sendto,description,serial} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_string(description);
+                data.write_uint(serial);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_tablet_pad_dial_v2:destroy
+            ZwpTabletPadDialV2Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_gestures_v1:get_swipe_gesture
+            ZwpPointerGesturesV1GetSwipeGesture{sendto,pointer} => {
+                new_id = data.allocate_id(Object::ZwpPointerGestureSwipeV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(pointer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_gestures_v1:get_pinch_gesture
+            ZwpPointerGesturesV1GetPinchGesture{sendto,pointer} => {
+                new_id = data.allocate_id(Object::ZwpPointerGesturePinchV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(pointer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_gestures_v1:release
+            ZwpPointerGesturesV1Release{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 2u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_gestures_v1:get_hold_gesture
+            ZwpPointerGesturesV1GetHoldGesture{sendto,pointer} => {
+                new_id = data.allocate_id(Object::ZwpPointerGestureHoldV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(pointer);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 3u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_gesture_swipe_v1:destroy
+            ZwpPointerGestureSwipeV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_gesture_pinch_v1:destroy
+            ZwpPointerGesturePinchV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zwp_pointer_gesture_hold_v1:destroy
+            ZwpPointerGestureHoldV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zxdg_output_manager_v1:destroy
+            ZxdgOutputManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zxdg_output_manager_v1:get_xdg_output
+            ZxdgOutputManagerV1GetXdgOutput{sendto,output} => {
+                new_id = data.allocate_id(Object::ZxdgOutputV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(output);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // zxdg_output_v1:destroy
+            ZxdgOutputV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_single_pixel_buffer_manager_v1:destroy
+            WpSinglePixelBufferManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_single_pixel_buffer_manager_v1:create_u32_rgba_buffer
+            WpSinglePixelBufferManagerV1CreateU32RgbaBuffer{sendto,r,g,b,a} => {
+                new_id = data.allocate_id(Object::WlBuffer);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(r);
+                data.write_uint(g);
+                data.write_uint(b);
+                data.write_uint(a);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_content_type_manager_v1:destroy
+            WpContentTypeManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_content_type_manager_v1:get_surface_content_type
+            WpContentTypeManagerV1GetSurfaceContentType{sendto,surface} => {
+                new_id = data.allocate_id(Object::WpContentTypeV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_content_type_v1:destroy
+            WpContentTypeV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_content_type_v1:set_content_type
+            WpContentTypeV1SetContentType{sendto,content_type} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(content_type);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_tearing_control_manager_v1:destroy
+            WpTearingControlManagerV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_tearing_control_manager_v1:get_tearing_control
+            WpTearingControlManagerV1GetTearingControl{sendto,surface} => {
+                new_id = data.allocate_id(Object::WpTearingControlV1);
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(new_id);
+                data.write_uint(surface);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_tearing_control_v1:set_presentation_hint
+            WpTearingControlV1SetPresentationHint{sendto,hint} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                data.write_uint(hint);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 0u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
+            // wp_tearing_control_v1:destroy
+            WpTearingControlV1Destroy{sendto} => {
+                let hdr_pos = data.send_buf.len();
+                data.send_buf.set_len(hdr_pos + 8);
+                let hdr = MessageHeader {
+                    obj_id: sendto,
+                    opcode: 1u16,
+                    len: (data.send_buf.len() - hdr_pos) as u16
+                };
+                data.write_header(hdr, hdr_pos);
+            },
         }
         return new_id
     }
@@ -0,0 +1,29 @@
+//! Fixtures shared by the unit tests
+
+use crate::connection::WaylandConnection;
+use crate::types::MessageHeader;
+use std::io::Read;
+use std::os::unix::net::UnixStream;
+
+/// Connection over a socket pair; the peer stands in for the compositor
+pub(crate) fn connection() -> (WaylandConnection, UnixStream) {
+    let (socket, peer) = UnixStream::pair().unwrap();
+    (WaylandConnection::from_stream(socket), peer)
+}
+
+/// Sends the queued requests and returns their object ids and opcodes as seen by the peer
+pub(crate) fn sent_requests(connection: &WaylandConnection, peer: &mut UnixStream) -> Vec<(u32, u16)> {
+    connection.send();
+    let mut buf = [0u8; 4096];
+    let len = peer.read(&mut buf).unwrap();
+    let words: Vec<u32> = buf[..len].chunks(4).map(|w| u32::from_ne_bytes(w.try_into().unwrap())).collect();
+
+    let mut result = Vec::new();
+    let mut pos = 0;
+    while pos < words.len() {
+        let header = MessageHeader::from_words(words[pos], words[pos + 1]);
+        result.push((header.obj_id, header.opcode));
+        pos += header.len as usize / 4;
+    }
+    result
+}
@@ -0,0 +1,72 @@
+use crate::connection::WaylandConnection;
+use crate::types::{WlSeat, WlSurface, XdgActivationTokenV1, XdgActivationV1};
+
+use std::env;
+
+/// Environment variable a launcher uses to pass the activation token
+/// to the spawned application
+pub const ACTIVATION_TOKEN_ENV: &str = "XDG_ACTIVATION_TOKEN";
+
+/// Surface activation with `xdg_activation_v1` tokens
+pub struct Activation {
+    activation: XdgActivationV1,
+}
+
+impl Activation {
+
+    pub fn new(activation: XdgActivationV1) -> Self {
+        Activation { activation }
+    }
+
+    pub fn activation(&self) -> XdgActivationV1 {
+        self.activation
+    }
+
+    /// Activates `surface` with the token the application was launched with
+    /// and returns the token, `None` if no token was set.
+    ///
+    /// The variable is left in the environment. It should not be passed on
+    /// to child processes, removing it is up to the caller since changing
+    /// the environment is only sound while no other thread reads it.
+    pub fn activate_from_env(&self, connection: &WaylandConnection, surface: WlSurface) -> Option<String> {
+        let token = env::var(ACTIVATION_TOKEN_ENV).ok()?;
+        self.activate(connection, &token, surface);
+        Some(token)
+    }
+
+    /// Requests the activation of `surface` with a token from another client
+    pub fn activate(&self, connection: &WaylandConnection, token: &str, surface: WlSurface) {
+        self.activation.activate(connection, token, surface);
+    }
+
+    /// Requests a new token to hand over to another client.
+    ///
+    /// The compositor is more likely to honor the token when it carries the
+    /// serial of the input event that triggered the request and the focused
+    /// surface. The token arrives in `xdg_activation_token_v1.done`, after
+    /// which the token object should be destroyed.
+    pub fn request_token(
+        &self,
+        connection: &WaylandConnection,
+        serial: Option<(u32, WlSeat)>,
+        app_id: Option<&str>,
+        surface: Option<WlSurface>,
+    ) -> XdgActivationTokenV1 {
+        let token = self.activation.get_activation_token(connection);
+        if let Some((serial, seat)) = serial {
+            token.set_serial(connection, serial, seat);
+        }
+        if let Some(app_id) = app_id {
+            token.set_app_id(connection, app_id);
+        }
+        if let Some(surface) = surface {
+            token.set_surface(connection, surface);
+        }
+        token.commit(connection);
+        token
+    }
+
+    pub fn destroy(self, connection: &WaylandConnection) {
+        self.activation.destroy(connection);
+    }
+}
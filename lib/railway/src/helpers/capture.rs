@@ -0,0 +1,574 @@
+use crate::connection::c_ffi::close;
+use crate::connection::WaylandConnection;
+use crate::types::enums::*;
+use crate::types::events::*;
+use crate::types::mempool::{ftruncate, mmap, munmap, shm_open, MAP_FAILED, MAP_SHARED, O_CREAT, O_RDWR, PROT_READ, SHM_ANON};
+use crate::types::{WlBuffer, WlOutput, WlShm, WlShmPool};
+use crate::types::{ExtImageCaptureSourceV1, ExtImageCopyCaptureFrameV1, ExtImageCopyCaptureSessionV1};
+use crate::types::{ExtImageCopyCaptureManagerV1, ExtOutputImageCaptureSourceManagerV1};
+use crate::types::{ZwlrScreencopyFrameV1, ZwlrScreencopyManagerV1};
+
+use core::ffi::c_void;
+use std::io;
+use std::os::fd::RawFd;
+
+/// Shm formats the capture buffer can be allocated in, all 4 bytes per pixel
+const SUPPORTED_FORMATS: [WlShmFormat; 4] = [
+    WlShmFormat::XRGB8888,
+    WlShmFormat::ARGB8888,
+    WlShmFormat::XBGR8888,
+    WlShmFormat::ABGR8888,
+];
+
+const PIXEL_SIZE: u32 = 4;
+
+/// Region of a captured frame that changed since the previous frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Damage {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+/// Pixels of a captured frame
+#[derive(Debug, Clone)]
+pub struct CapturedFrame {
+    pub format: WlShmFormat,
+    pub width: u32,
+    pub height: u32,
+    /// bytes per row in `data`
+    pub stride: u32,
+    pub data: Vec<u8>,
+    /// changed regions in buffer coordinates, the whole frame for the first capture
+    pub damage: Vec<Damage>,
+    /// rows are stored bottom to top
+    pub y_invert: bool,
+}
+
+/// Shared memory buffer the compositor copies frames into
+struct CaptureBuffer {
+    pool: WlShmPool,
+    buffer: WlBuffer,
+    fd: RawFd,
+    data: *const c_void,
+    size: usize,
+    format: WlShmFormat,
+    width: u32,
+    height: u32,
+    stride: u32,
+}
+
+impl CaptureBuffer {
+
+    fn new(connection: &WaylandConnection, shm: WlShm, format: WlShmFormat, width: u32, height: u32, stride: u32) -> io::Result<Self> {
+        let size = stride as usize * height as usize;
+        let (fd, data) = unsafe {
+            let fd = shm_open(SHM_ANON, O_RDWR | O_CREAT, 0o600);
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if ftruncate(fd, size as i64) < 0 {
+                let err = io::Error::last_os_error();
+                close(fd);
+                return Err(err);
+            }
+            let data = mmap(std::ptr::null(), size, PROT_READ, MAP_SHARED, fd, 0);
+            if data == MAP_FAILED {
+                let err = io::Error::last_os_error();
+                close(fd);
+                return Err(err);
+            }
+            (fd, data)
+        };
+
+        let pool = shm.create_pool(connection, fd, size as i32);
+        let buffer = pool.create_buffer(connection, 0, width as i32, height as i32, stride as i32, format as u32);
+        Ok(CaptureBuffer { pool, buffer, fd, data, size, format, width, height, stride })
+    }
+
+    fn matches(&self, format: WlShmFormat, width: u32, height: u32, stride: u32) -> bool {
+        self.format == format && self.width == width && self.height == height && self.stride == stride
+    }
+
+    fn read(&self) -> Vec<u8> {
+        // SAFETY: the mapping stays valid until `destroy` and the compositor
+        // no longer writes to it once the frame is ready
+        unsafe { std::slice::from_raw_parts(self.data as *const u8, self.size) }.to_vec()
+    }
+
+    fn destroy(self, connection: &WaylandConnection) {
+        self.buffer.destroy(connection);
+        self.pool.destroy(connection);
+        unsafe {
+            munmap(self.data, self.size);
+            close(self.fd);
+        }
+    }
+}
+
+enum Backend {
+    ImageCopy {
+        source: ExtImageCaptureSourceV1,
+        session: ExtImageCopyCaptureSessionV1,
+        frame: Option<ExtImageCopyCaptureFrameV1>,
+    },
+    Screencopy {
+        manager: ZwlrScreencopyManagerV1,
+        output: WlOutput,
+        overlay_cursor: bool,
+        frame: Option<ZwlrScreencopyFrameV1>,
+    },
+}
+
+/// Output capture into a shm buffer.
+///
+/// Uses `ext_image_copy_capture_v1` when available and falls back to
+/// `zwlr_screencopy_v1`, which has to be bound with at least version 3.
+/// The compositor advertises the buffer size and formats first, the
+/// buffer is allocated from them and the frame is copied into it. Every
+/// `ready` event yields a `CapturedFrame`, `capture` requests the next one.
+pub struct ScreenCapture {
+    shm: WlShm,
+    backend: Backend,
+    format: Option<WlShmFormat>,
+    width: u32,
+    height: u32,
+    stride: u32,
+    /// constraints announced by the session, applied on `done`
+    pending_format: Option<WlShmFormat>,
+    pending_size: Option<(u32, u32)>,
+    buffer: Option<CaptureBuffer>,
+    /// buffer contents are unknown to the compositor, damage it fully
+    buffer_damaged: bool,
+    damage: Vec<Damage>,
+    y_invert: bool,
+    /// a frame should be captured once the buffer constraints are known
+    wanted: bool,
+    stopped: bool,
+}
+
+impl ScreenCapture {
+
+    /// Starts an `ext_image_copy_capture_v1` session for `output`
+    pub fn output(
+        connection: &WaylandConnection,
+        shm: WlShm,
+        source_manager: ExtOutputImageCaptureSourceManagerV1,
+        manager: ExtImageCopyCaptureManagerV1,
+        output: WlOutput,
+        paint_cursors: bool,
+    ) -> Self {
+        let mut options = ExtImageCopyCaptureManagerV1Options::new();
+        if paint_cursors {
+            options.insert(ExtImageCopyCaptureManagerV1Options::PAINTCURSORS);
+        }
+        let source = source_manager.create_source(connection, output);
+        let session = manager.create_session(connection, source, options.into());
+        Self::with_backend(shm, Backend::ImageCopy { source, session, frame: None })
+    }
+
+    /// Captures `output` with `zwlr_screencopy_manager_v1`, for compositors
+    /// without `ext_image_copy_capture_v1`
+    pub fn screencopy(
+        connection: &WaylandConnection,
+        shm: WlShm,
+        manager: ZwlrScreencopyManagerV1,
+        output: WlOutput,
+        overlay_cursor: bool,
+    ) -> Self {
+        let frame = manager.capture_output(connection, overlay_cursor as i32, output);
+        Self::with_backend(shm, Backend::Screencopy { manager, output, overlay_cursor, frame: Some(frame) })
+    }
+
+    fn with_backend(shm: WlShm, backend: Backend) -> Self {
+        ScreenCapture {
+            shm,
+            backend,
+            format: None,
+            width: 0,
+            height: 0,
+            stride: 0,
+            pending_format: None,
+            pending_size: None,
+            buffer: None,
+            buffer_damaged: true,
+            damage: Vec::new(),
+            y_invert: false,
+            wanted: true,
+            stopped: false,
+        }
+    }
+
+    /// Whether the compositor ended the capture session, it should be destroyed then
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Requests the next frame, returns false while a frame is still pending.
+    /// With `ext_image_copy_capture_v1` the frame is sent once the output
+    /// content changes.
+    pub fn capture(&mut self, connection: &WaylandConnection) -> bool {
+        if self.wanted || self.stopped {
+            return false;
+        }
+        self.wanted = true;
+        match &mut self.backend {
+            Backend::ImageCopy { .. } => self.start_frame(connection),
+            Backend::Screencopy { manager, output, overlay_cursor, frame } => {
+                // buffer constraints are announced again for the new frame
+                self.format = None;
+                *frame = Some(manager.capture_output(connection, *overlay_cursor as i32, *output));
+            }
+        }
+        true
+    }
+
+    pub fn handle_buffer_size(&mut self, event: ExtImageCopyCaptureSessionV1BufferSizeEvent) -> bool {
+        if !self.is_session(event.source_id) {
+            return false;
+        }
+        self.pending_size = Some((event.width, event.height));
+        true
+    }
+
+    /// Keeps the first supported format, the compositor lists them in order of preference
+    pub fn handle_shm_format(&mut self, event: ExtImageCopyCaptureSessionV1ShmFormatEvent) -> bool {
+        if !self.is_session(event.source_id) {
+            return false;
+        }
+        if self.pending_format.is_none() && SUPPORTED_FORMATS.contains(&event.format) {
+            self.pending_format = Some(event.format);
+        }
+        true
+    }
+
+    /// Allocates a buffer matching the advertised constraints and starts
+    /// capturing if a frame was requested. Fails if the buffer can't be
+    /// allocated, the advertised size is too large or none of the advertised
+    /// formats is supported.
+    pub fn handle_session_done(&mut self, connection: &WaylandConnection, event: ExtImageCopyCaptureSessionV1DoneEvent) -> io::Result<bool> {
+        if !self.is_session(event.source_id) {
+            return Ok(false);
+        }
+        self.apply_constraints()?;
+        self.allocate(connection)?;
+        if self.wanted && !self.frame_pending() {
+            self.start_frame(connection);
+        }
+        Ok(true)
+    }
+
+    pub fn handle_session_stopped(&mut self, event: ExtImageCopyCaptureSessionV1StoppedEvent) -> bool {
+        if !self.is_session(event.source_id) {
+            return false;
+        }
+        self.stopped = true;
+        self.wanted = false;
+        true
+    }
+
+    pub fn handle_frame_damage(&mut self, event: ExtImageCopyCaptureFrameV1DamageEvent) -> bool {
+        if !self.is_frame(event.source_id) {
+            return false;
+        }
+        self.damage.push(Damage {
+            x: event.x,
+            y: event.y,
+            width: event.width,
+            height: event.height,
+        });
+        true
+    }
+
+    /// Returns the captured frame, `None` if the event belongs to another capture
+    pub fn handle_frame_ready(&mut self, connection: &WaylandConnection, event: ExtImageCopyCaptureFrameV1ReadyEvent) -> Option<CapturedFrame> {
+        if !self.is_frame(event.source_id) {
+            return None;
+        }
+        Some(self.finish_frame(connection))
+    }
+
+    /// Drops the pending frame. On a buffer constraints mismatch the frame
+    /// is captured again after the session announced the new constraints.
+    pub fn handle_frame_failed(&mut self, connection: &WaylandConnection, event: ExtImageCopyCaptureFrameV1FailedEvent) -> bool {
+        if !self.is_frame(event.source_id) {
+            return false;
+        }
+        self.drop_frame(connection);
+        self.wanted = event.reason == ExtImageCopyCaptureFrameV1FailureReason::BufferConstraints;
+        true
+    }
+
+    pub fn handle_screencopy_buffer(&mut self, event: ZwlrScreencopyFrameV1BufferEvent) -> bool {
+        if !self.is_frame(event.source_id) {
+            return false;
+        }
+        if self.format.is_none() && SUPPORTED_FORMATS.contains(&event.format) {
+            self.format = Some(event.format);
+            self.width = event.width;
+            self.height = event.height;
+            self.stride = event.stride;
+        }
+        true
+    }
+
+    pub fn handle_screencopy_flags(&mut self, event: ZwlrScreencopyFrameV1FlagsEvent) -> bool {
+        if !self.is_frame(event.source_id) {
+            return false;
+        }
+        self.y_invert = event.flags.contains(ZwlrScreencopyFrameV1Flags::YINVERT);
+        true
+    }
+
+    /// Allocates a buffer matching the advertised constraints and copies
+    /// the frame into it. Fails if the buffer can't be allocated or none
+    /// of the advertised formats is supported.
+    pub fn handle_screencopy_buffer_done(&mut self, connection: &WaylandConnection, event: ZwlrScreencopyFrameV1BufferDoneEvent) -> io::Result<bool> {
+        if !self.is_frame(event.source_id) {
+            return Ok(false);
+        }
+        self.allocate(connection)?;
+        if let (Backend::Screencopy { frame: Some(frame), .. }, Some(buffer)) = (&self.backend, &self.buffer) {
+            frame.copy_with_damage(connection, buffer.buffer);
+        }
+        Ok(true)
+    }
+
+    pub fn handle_screencopy_damage(&mut self, event: ZwlrScreencopyFrameV1DamageEvent) -> bool {
+        if !self.is_frame(event.source_id) {
+            return false;
+        }
+        self.damage.push(Damage {
+            x: event.x as i32,
+            y: event.y as i32,
+            width: event.width as i32,
+            height: event.height as i32,
+        });
+        true
+    }
+
+    /// Returns the captured frame, `None` if the event belongs to another capture
+    pub fn handle_screencopy_ready(&mut self, connection: &WaylandConnection, event: ZwlrScreencopyFrameV1ReadyEvent) -> Option<CapturedFrame> {
+        if !self.is_frame(event.source_id) {
+            return None;
+        }
+        Some(self.finish_frame(connection))
+    }
+
+    pub fn handle_screencopy_failed(&mut self, connection: &WaylandConnection, event: ZwlrScreencopyFrameV1FailedEvent) -> bool {
+        if !self.is_frame(event.source_id) {
+            return false;
+        }
+        self.drop_frame(connection);
+        self.wanted = false;
+        true
+    }
+
+    /// Destroys the capture session and frees the buffer
+    pub fn destroy(mut self, connection: &WaylandConnection) {
+        self.drop_frame(connection);
+        if let Backend::ImageCopy { source, session, .. } = self.backend {
+            session.destroy(connection);
+            source.destroy(connection);
+        }
+        if let Some(buffer) = self.buffer {
+            buffer.destroy(connection);
+        }
+    }
+
+    fn is_session(&self, id: u32) -> bool {
+        matches!(self.backend, Backend::ImageCopy { session, .. } if session.id == id)
+    }
+
+    fn is_frame(&self, id: u32) -> bool {
+        match self.backend {
+            Backend::ImageCopy { frame: Some(frame), .. } => frame.id == id,
+            Backend::Screencopy { frame: Some(frame), .. } => frame.id == id,
+            _ => false,
+        }
+    }
+
+    fn frame_pending(&self) -> bool {
+        matches!(
+            self.backend,
+            Backend::ImageCopy { frame: Some(_), .. } | Backend::Screencopy { frame: Some(_), .. }
+        )
+    }
+
+    /// Takes over the constraints announced since the previous session `done`,
+    /// the size stays if the compositor didn't repeat it. Fails if the row
+    /// stride of the announced width doesn't fit in 32 bits.
+    fn apply_constraints(&mut self) -> io::Result<()> {
+        self.format = self.pending_format.take();
+        if let Some((width, height)) = self.pending_size.take() {
+            let stride = width.checked_mul(PIXEL_SIZE).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "capture buffer width too large")
+            })?;
+            self.width = width;
+            self.height = height;
+            self.stride = stride;
+        }
+        Ok(())
+    }
+
+    /// Replaces the buffer if it doesn't match the current constraints
+    fn allocate(&mut self, connection: &WaylandConnection) -> io::Result<()> {
+        let format = self.format.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::Unsupported, "no supported shm format advertised for capture")
+        })?;
+        if let Some(buffer) = &self.buffer {
+            if buffer.matches(format, self.width, self.height, self.stride) {
+                return Ok(());
+            }
+        }
+        if let Some(buffer) = self.buffer.take() {
+            buffer.destroy(connection);
+        }
+        self.buffer = Some(CaptureBuffer::new(connection, self.shm, format, self.width, self.height, self.stride)?);
+        self.buffer_damaged = true;
+        Ok(())
+    }
+
+    fn start_frame(&mut self, connection: &WaylandConnection) {
+        let (Backend::ImageCopy { session, frame, .. }, Some(buffer)) = (&mut self.backend, &self.buffer) else {
+            return;
+        };
+        let new_frame = session.create_frame(connection);
+        new_frame.attach_buffer(connection, buffer.buffer);
+        if self.buffer_damaged {
+            new_frame.damage_buffer(connection, 0, 0, buffer.width as i32, buffer.height as i32);
+            self.buffer_damaged = false;
+        }
+        new_frame.capture(connection);
+        *frame = Some(new_frame);
+    }
+
+    fn finish_frame(&mut self, connection: &WaylandConnection) -> CapturedFrame {
+        let damage = std::mem::take(&mut self.damage);
+        self.drop_frame(connection);
+        self.wanted = false;
+
+        let buffer = self.buffer.as_ref().expect("capture frame ready without a buffer");
+        CapturedFrame {
+            format: buffer.format,
+            width: buffer.width,
+            height: buffer.height,
+            stride: buffer.stride,
+            data: buffer.read(),
+            damage,
+            y_invert: std::mem::take(&mut self.y_invert),
+        }
+    }
+
+    fn drop_frame(&mut self, connection: &WaylandConnection) {
+        self.damage.clear();
+        match &mut self.backend {
+            Backend::ImageCopy { frame, .. } => {
+                if let Some(frame) = frame.take() {
+                    frame.destroy(connection);
+                }
+            }
+            Backend::Screencopy { frame, .. } => {
+                if let Some(frame) = frame.take() {
+                    frame.destroy(connection);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::testing;
+    use crate::types::Interface;
+
+    const SESSION: u32 = 4;
+
+    fn session_capture() -> ScreenCapture {
+        ScreenCapture::with_backend(WlShm::from_id(2), Backend::ImageCopy {
+            source: ExtImageCaptureSourceV1::from_id(3),
+            session: ExtImageCopyCaptureSessionV1::from_id(SESSION),
+            frame: None,
+        })
+    }
+
+    fn shm_format(capture: &mut ScreenCapture, format: WlShmFormat) {
+        assert!(capture.handle_shm_format(ExtImageCopyCaptureSessionV1ShmFormatEvent { source_id: SESSION, format }));
+    }
+
+    fn buffer_size(capture: &mut ScreenCapture, width: u32, height: u32) {
+        assert!(capture.handle_buffer_size(ExtImageCopyCaptureSessionV1BufferSizeEvent { source_id: SESSION, width, height }));
+    }
+
+    #[test]
+    fn format_before_size() {
+        let mut capture = session_capture();
+        shm_format(&mut capture, WlShmFormat::C8);
+        shm_format(&mut capture, WlShmFormat::XRGB8888);
+        shm_format(&mut capture, WlShmFormat::ARGB8888);
+        buffer_size(&mut capture, 1920, 1080);
+        capture.apply_constraints().unwrap();
+
+        assert_eq!(capture.format, Some(WlShmFormat::XRGB8888));
+        assert_eq!((capture.width, capture.height, capture.stride), (1920, 1080, 7680));
+    }
+
+    #[test]
+    fn constraints_apply_on_done() {
+        let mut capture = session_capture();
+        buffer_size(&mut capture, 1920, 1080);
+        shm_format(&mut capture, WlShmFormat::ARGB8888);
+        capture.apply_constraints().unwrap();
+
+        // a new batch is collected without touching the current constraints
+        shm_format(&mut capture, WlShmFormat::XBGR8888);
+        buffer_size(&mut capture, 800, 600);
+        assert_eq!(capture.format, Some(WlShmFormat::ARGB8888));
+        assert_eq!((capture.width, capture.height), (1920, 1080));
+
+        capture.apply_constraints().unwrap();
+        assert_eq!(capture.format, Some(WlShmFormat::XBGR8888));
+        assert_eq!((capture.width, capture.height, capture.stride), (800, 600, 3200));
+        assert_eq!((capture.pending_format, capture.pending_size), (None, None));
+    }
+
+    #[test]
+    fn no_supported_format() {
+        let (connection, _peer) = testing::connection();
+        let mut capture = session_capture();
+        buffer_size(&mut capture, 640, 480);
+        shm_format(&mut capture, WlShmFormat::RGB332);
+
+        let done = ExtImageCopyCaptureSessionV1DoneEvent { source_id: SESSION };
+        let err = capture.handle_session_done(&connection, done).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+        assert!(capture.buffer.is_none());
+    }
+
+    #[test]
+    fn stride_overflow() {
+        let (connection, _peer) = testing::connection();
+        let mut capture = session_capture();
+        shm_format(&mut capture, WlShmFormat::XRGB8888);
+        buffer_size(&mut capture, 1920, 1080);
+        capture.apply_constraints().unwrap();
+
+        buffer_size(&mut capture, u32::MAX / PIXEL_SIZE + 1, 1);
+        shm_format(&mut capture, WlShmFormat::XRGB8888);
+        let done = ExtImageCopyCaptureSessionV1DoneEvent { source_id: SESSION };
+        let err = capture.handle_session_done(&connection, done).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!((capture.width, capture.height, capture.stride), (1920, 1080, 7680));
+        assert!(capture.buffer.is_none());
+    }
+
+    #[test]
+    fn other_session() {
+        let mut capture = session_capture();
+        let event = ExtImageCopyCaptureSessionV1BufferSizeEvent { source_id: SESSION + 1, width: 1, height: 1 };
+        assert!(!capture.handle_buffer_size(event));
+        assert_eq!(capture.pending_size, None);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::connection::WaylandConnection;
+use crate::types::enums::WpCursorShapeDeviceV1Shape;
+use crate::types::events::{WlPointerEnterEvent, WlPointerLeaveEvent};
+use crate::types::{WlPointer, WpCursorShapeDeviceV1, WpCursorShapeManagerV1};
+
+/// Pointer cursor drawn by the compositor from a named shape.
+///
+/// `wp_cursor_shape_device_v1.set_shape` needs the serial of the latest
+/// pointer enter, so the pointer enter and leave events have to be passed
+/// to `handle_enter` and `handle_leave`.
+pub struct CursorShape {
+    device: WpCursorShapeDeviceV1,
+    pointer: WlPointer,
+    shape: WpCursorShapeDeviceV1Shape,
+    serial: Option<u32>,
+}
+
+impl CursorShape {
+
+    pub fn new(connection: &WaylandConnection, manager: WpCursorShapeManagerV1, pointer: WlPointer, shape: WpCursorShapeDeviceV1Shape) -> Self {
+        CursorShape {
+            device: manager.get_pointer(connection, pointer),
+            pointer,
+            shape,
+            serial: None,
+        }
+    }
+
+    pub fn device(&self) -> WpCursorShapeDeviceV1 {
+        self.device
+    }
+
+    pub fn shape(&self) -> WpCursorShapeDeviceV1Shape {
+        self.shape
+    }
+
+    /// Applies the current shape when the pointer enters one of our surfaces.
+    /// Returns false if the event came from another pointer.
+    pub fn handle_enter(&mut self, connection: &WaylandConnection, event: &WlPointerEnterEvent) -> bool {
+        if event.source_id != self.pointer.id {
+            return false;
+        }
+        self.serial = Some(event.serial);
+        self.device.set_shape(connection, event.serial, self.shape as u32);
+        true
+    }
+
+    /// Forgets the enter serial, the shape can't be changed until the next enter
+    pub fn handle_leave(&mut self, event: &WlPointerLeaveEvent) -> bool {
+        if event.source_id != self.pointer.id {
+            return false;
+        }
+        self.serial = None;
+        true
+    }
+
+    /// Changes the cursor shape, it's applied right away when the pointer is
+    /// over one of our surfaces and on the next enter otherwise
+    pub fn set_shape(&mut self, connection: &WaylandConnection, shape: WpCursorShapeDeviceV1Shape) {
+        if self.shape == shape {
+            return;
+        }
+        self.shape = shape;
+        if let Some(serial) = self.serial {
+            self.device.set_shape(connection, serial, shape as u32);
+        }
+    }
+
+    pub fn destroy(self, connection: &WaylandConnection) {
+        self.device.destroy(connection);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::connection::WaylandConnection;
+use crate::types::enums::ZxdgToplevelDecorationV1Mode;
+use crate::types::events::ZxdgToplevelDecorationV1ConfigureEvent;
+use crate::types::{XdgToplevel, ZxdgDecorationManagerV1, ZxdgToplevelDecorationV1};
+
+/// Decoration mode negotiation for a toplevel.
+///
+/// Server-side decorations are requested when the compositor supports
+/// `zxdg_decoration_manager_v1`, the compositor answers with the mode it
+/// picked in a configure event that has to be forwarded to
+/// `handle_configure`. Without the manager the client always draws its
+/// own frame.
+pub struct Decoration {
+    decoration: Option<ZxdgToplevelDecorationV1>,
+    mode: ZxdgToplevelDecorationV1Mode,
+}
+
+impl Decoration {
+
+    /// Requests server-side decorations for `toplevel`.
+    /// The decoration object has to be created before the first commit of
+    /// the toplevel surface.
+    pub fn server_side(connection: &WaylandConnection, manager: Option<ZxdgDecorationManagerV1>, toplevel: XdgToplevel) -> Self {
+        let decoration = manager.map(|manager| {
+            let decoration = manager.get_toplevel_decoration(connection, toplevel);
+            decoration.set_mode(connection, ZxdgToplevelDecorationV1Mode::ServerSide as u32);
+            decoration
+        });
+
+        Decoration {
+            decoration,
+            mode: ZxdgToplevelDecorationV1Mode::ClientSide,
+        }
+    }
+
+    pub fn decoration(&self) -> Option<ZxdgToplevelDecorationV1> {
+        self.decoration
+    }
+
+    /// Records the mode chosen by the compositor.
+    /// Returns false if the event belongs to another decoration object.
+    pub fn handle_configure(&mut self, event: ZxdgToplevelDecorationV1ConfigureEvent) -> bool {
+        match self.decoration {
+            Some(decoration) if decoration.id == event.source_id => {
+                self.mode = event.mode;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Decoration mode currently in effect,
+    /// client-side until the compositor configures the decoration
+    pub fn mode(&self) -> ZxdgToplevelDecorationV1Mode {
+        self.mode
+    }
+
+    /// Whether the client has to draw its own window frame
+    pub fn client_side(&self) -> bool {
+        self.mode != ZxdgToplevelDecorationV1Mode::ServerSide
+    }
+
+    /// Destroys the decoration object, it must be destroyed before the toplevel
+    pub fn destroy(self, connection: &WaylandConnection) {
+        if let Some(decoration) = self.decoration {
+            decoration.destroy(connection);
+        }
+    }
+}
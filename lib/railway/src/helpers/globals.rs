@@ -0,0 +1,265 @@
+use crate::connection::WaylandConnection;
+use crate::types::events::*;
+use crate::types::handler::EventHandler;
+use crate::types::{Interface, WlOutput, WlRegistry, WlSeat, WlDisplaySyncCallback};
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Global object advertised by the compositor
+#[derive(Debug, Clone)]
+pub struct Global {
+    /// numeric name of the global
+    pub name: u32,
+    /// interface implemented by the global
+    pub interface: String,
+    /// interface version advertised by the compositor
+    pub version: u32,
+}
+
+/// Failure to bind a required global
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlobalError {
+    /// The compositor does not advertise the interface
+    Missing {
+        interface: &'static str,
+    },
+    /// The compositor advertises the interface with a lower version than required
+    TooOld {
+        interface: &'static str,
+        version: u32,
+        required: u32,
+    },
+}
+
+impl fmt::Display for GlobalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GlobalError::Missing { interface } => {
+                write!(f, "compositor does not support {}", interface)
+            }
+            GlobalError::TooOld { interface, version, required } => {
+                write!(f, "compositor supports {} version {}, version {} is required", interface, version, required)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GlobalError {}
+
+/// Notifications about outputs and seats added or removed after the
+/// initial roundtrip.
+#[allow(unused)]
+pub trait GlobalHandler {
+    /// A new output was bound
+    fn on_output_added(&mut self, output: WlOutput, connection: &WaylandConnection) {}
+
+    /// An output is going away, the proxy is released right after this call
+    fn on_output_removed(&mut self, output: WlOutput, connection: &WaylandConnection) {}
+
+    /// A new seat was bound
+    fn on_seat_added(&mut self, seat: WlSeat, connection: &WaylandConnection) {}
+
+    /// A seat is going away, the proxy is released right after this call
+    fn on_seat_removed(&mut self, seat: WlSeat, connection: &WaylandConnection) {}
+}
+
+/// No-op handler for clients that only read `outputs()` and `seats()`
+impl GlobalHandler for () {}
+
+#[derive(Debug, Clone, Copy)]
+struct Bound<T> {
+    name: u32,
+    version: u32,
+    proxy: T,
+}
+
+/// Registry wrapper that keeps track of the advertised globals.
+///
+/// Outputs and seats are bound as soon as they are advertised, every other
+/// interface is bound on request with `bind_one` or `bind_all`.
+/// Registry events received after `new` must be forwarded to
+/// `handle_global` and `handle_global_remove`.
+pub struct GlobalList {
+    registry: WlRegistry,
+    globals: Vec<Global>,
+    outputs: Vec<Bound<WlOutput>>,
+    seats: Vec<Bound<WlSeat>>,
+}
+
+impl GlobalList {
+
+    /// Creates the registry and collects the initial burst of globals
+    /// with a roundtrip.
+    ///
+    /// Must be called before any other object is created, events other
+    /// than the registry ones received during the roundtrip are dropped.
+    /// Outputs and seats are bound during the roundtrip, but the requests
+    /// are only flushed by the next `send`, so their events go to the
+    /// caller's handler.
+    pub fn new(connection: &WaylandConnection) -> Self {
+        let display = connection.get_display();
+        let mut list = GlobalList {
+            registry: display.get_registry(connection),
+            globals: Vec::new(),
+            outputs: Vec::new(),
+            seats: Vec::new(),
+        };
+
+        let mut roundtrip = Roundtrip {
+            list: &mut list,
+            callback: display.sync(connection),
+            done: false,
+        };
+        connection.send();
+        while !roundtrip.done {
+            connection.recv();
+            connection.dispatch_events(&mut roundtrip);
+        }
+
+        list
+    }
+
+    pub fn registry(&self) -> WlRegistry {
+        self.registry
+    }
+
+    /// All globals currently advertised by the compositor
+    pub fn globals(&self) -> &[Global] {
+        &self.globals
+    }
+
+    /// Currently known outputs
+    pub fn outputs(&self) -> impl Iterator<Item = WlOutput> + '_ {
+        self.outputs.iter().map(|o| o.proxy)
+    }
+
+    /// Currently known seats
+    pub fn seats(&self) -> impl Iterator<Item = WlSeat> + '_ {
+        self.seats.iter().map(|s| s.proxy)
+    }
+
+    /// Binds the first global implementing `T`.
+    ///
+    /// The global is bound with the highest version allowed by both the
+    /// compositor and `version`, an error is returned if the compositor
+    /// does not reach the lower bound of `version`.
+    pub fn bind_one<T: Interface>(&self, connection: &WaylandConnection, version: RangeInclusive<u32>) -> Result<T, GlobalError> {
+        let global = self.globals.iter()
+            .find(|g| g.interface == T::NAME)
+            .ok_or(GlobalError::Missing { interface: T::NAME })?;
+
+        if global.version < *version.start() {
+            return Err(GlobalError::TooOld {
+                interface: T::NAME,
+                version: global.version,
+                required: *version.start(),
+            });
+        }
+
+        let version = std::cmp::min(global.version, *version.end());
+        Ok(self.registry.bind::<T>(connection, global.name, version))
+    }
+
+    /// Binds every global implementing `T` with the highest supported version
+    pub fn bind_all<T: Interface>(&self, connection: &WaylandConnection) -> Vec<T> {
+        self.globals.iter()
+            .filter(|g| g.interface == T::NAME)
+            .map(|g| self.registry.bind::<T>(connection, g.name, g.version))
+            .collect()
+    }
+
+    /// Records a global advertised after the initial roundtrip
+    pub fn handle_global<H: GlobalHandler>(&mut self, event: WlRegistryGlobalEvent<'_>, connection: &WaylandConnection, handler: &mut H) {
+        if let Some(output) = self.add_global(event, connection) {
+            match output {
+                Added::Output(output) => handler.on_output_added(output, connection),
+                Added::Seat(seat) => handler.on_seat_added(seat, connection),
+            }
+        }
+    }
+
+    /// Forgets a removed global, releasing the output or seat bound for it
+    pub fn handle_global_remove<H: GlobalHandler>(&mut self, event: WlRegistryGlobalRemoveEvent, connection: &WaylandConnection, handler: &mut H) {
+        self.globals.retain(|g| g.name != event.name);
+
+        if let Some(pos) = self.outputs.iter().position(|o| o.name == event.name) {
+            let output = self.outputs.remove(pos);
+            handler.on_output_removed(output.proxy, connection);
+            // wl_output.release is available since version 3
+            if output.version >= 3 {
+                output.proxy.release(connection);
+            }
+        }
+
+        if let Some(pos) = self.seats.iter().position(|s| s.name == event.name) {
+            let seat = self.seats.remove(pos);
+            handler.on_seat_removed(seat.proxy, connection);
+            // wl_seat.release is available since version 5
+            if seat.version >= 5 {
+                seat.proxy.release(connection);
+            }
+        }
+    }
+
+    fn add_global(&mut self, event: WlRegistryGlobalEvent<'_>, connection: &WaylandConnection) -> Option<Added> {
+        let global = Global {
+            name: event.name,
+            interface: event.interface.into_owned(),
+            version: event.version,
+        };
+
+        let added = if global.interface == WlOutput::NAME {
+            let version = std::cmp::min(global.version, WlOutput::VERSION);
+            let proxy = self.registry.bind::<WlOutput>(connection, global.name, version);
+            self.outputs.push(Bound { name: global.name, version, proxy });
+            Some(Added::Output(proxy))
+        } else if global.interface == WlSeat::NAME {
+            let version = std::cmp::min(global.version, WlSeat::VERSION);
+            let proxy = self.registry.bind::<WlSeat>(connection, global.name, version);
+            self.seats.push(Bound { name: global.name, version, proxy });
+            Some(Added::Seat(proxy))
+        } else {
+            None
+        };
+
+        self.globals.push(global);
+        added
+    }
+}
+
+enum Added {
+    Output(WlOutput),
+    Seat(WlSeat),
+}
+
+/// Event handler used while waiting for the initial burst of globals
+struct Roundtrip<'a> {
+    list: &'a mut GlobalList,
+    callback: WlDisplaySyncCallback,
+    done: bool,
+}
+
+impl EventHandler for Roundtrip<'_> {
+    fn on_wl_display_error(&mut self, event: WlDisplayErrorEvent<'_>, _connection: &WaylandConnection) {
+        panic!("display error: code {}, {}", event.code, event.message);
+    }
+
+    fn on_wl_display_delete_id(&mut self, event: WlDisplayDeleteIdEvent, connection: &WaylandConnection) {
+        connection.delete_object(event.id);
+    }
+
+    fn on_wl_display_sync_done(&mut self, event: WlDisplaySyncDoneEvent, _connection: &WaylandConnection) {
+        if event.source_id == self.callback.id {
+            self.done = true;
+        }
+    }
+
+    fn on_wl_registry_global(&mut self, event: WlRegistryGlobalEvent<'_>, connection: &WaylandConnection) {
+        self.list.add_global(event, connection);
+    }
+
+    fn on_wl_registry_global_remove(&mut self, event: WlRegistryGlobalRemoveEvent, connection: &WaylandConnection) {
+        self.list.handle_global_remove(event, connection, &mut ());
+    }
+}
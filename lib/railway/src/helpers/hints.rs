@@ -0,0 +1,64 @@
+use crate::connection::WaylandConnection;
+use crate::types::enums::{WpContentTypeV1Type, WpTearingControlV1PresentationHint};
+use crate::types::{WlSurface, WpContentTypeManagerV1, WpContentTypeV1};
+use crate::types::{WpTearingControlManagerV1, WpTearingControlV1};
+
+/// Content type and presentation hints of a surface.
+///
+/// Both hints are optional, without the corresponding manager the setter
+/// does nothing. They are double-buffered and applied on the next
+/// `wl_surface.commit`.
+pub struct SurfaceHints {
+    content_type: Option<WpContentTypeV1>,
+    tearing_control: Option<WpTearingControlV1>,
+}
+
+impl SurfaceHints {
+
+    /// A surface may have only one content type and one tearing control object
+    pub fn new(
+        connection: &WaylandConnection,
+        surface: WlSurface,
+        content_type_manager: Option<WpContentTypeManagerV1>,
+        tearing_control_manager: Option<WpTearingControlManagerV1>,
+    ) -> Self {
+        SurfaceHints {
+            content_type: content_type_manager.map(|manager| manager.get_surface_content_type(connection, surface)),
+            tearing_control: tearing_control_manager.map(|manager| manager.get_tearing_control(connection, surface)),
+        }
+    }
+
+    pub fn content_type(&self) -> Option<WpContentTypeV1> {
+        self.content_type
+    }
+
+    pub fn tearing_control(&self) -> Option<WpTearingControlV1> {
+        self.tearing_control
+    }
+
+    /// Tells the compositor what kind of content the surface shows,
+    /// e.g. to pick a display mode for video or games
+    pub fn set_content_type(&self, connection: &WaylandConnection, content_type: WpContentTypeV1Type) {
+        if let Some(object) = self.content_type {
+            object.set_content_type(connection, content_type as u32);
+        }
+    }
+
+    /// `Async` allows the compositor to present the surface immediately
+    /// at the cost of tearing, `Vsync` waits for the next vertical blank
+    pub fn set_presentation_hint(&self, connection: &WaylandConnection, hint: WpTearingControlV1PresentationHint) {
+        if let Some(object) = self.tearing_control {
+            object.set_presentation_hint(connection, hint as u32);
+        }
+    }
+
+    /// Destroys both objects, the hints are reset on the next commit
+    pub fn destroy(self, connection: &WaylandConnection) {
+        if let Some(content_type) = self.content_type {
+            content_type.destroy(connection);
+        }
+        if let Some(tearing_control) = self.tearing_control {
+            tearing_control.destroy(connection);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::connection::WaylandConnection;
+use crate::types::enums::{ZwlrLayerShellV1Layer, ZwlrLayerSurfaceV1Anchor, ZwlrLayerSurfaceV1KeyboardInteractivity};
+use crate::types::events::{ZwlrLayerSurfaceV1ClosedEvent, ZwlrLayerSurfaceV1ConfigureEvent};
+use crate::types::{WlOutput, WlSurface, XdgPopup, ZwlrLayerShellV1, ZwlrLayerSurfaceV1};
+
+/// Surface placed on a shell layer, anchored to the edges of an output.
+///
+/// After the surface state is set up, `commit` has to be called once
+/// without a buffer. The compositor answers with a configure event that
+/// `handle_configure` acknowledges, only then a buffer of the configured
+/// size may be attached and committed. Later configures follow the same
+/// pattern.
+pub struct LayerSurface {
+    surface: WlSurface,
+    layer_surface: ZwlrLayerSurfaceV1,
+    size: (u32, u32),
+    configured: bool,
+    closed: bool,
+}
+
+impl LayerSurface {
+
+    /// Assigns the layer surface role to `surface`. `output` picks the
+    /// output to show the surface on, `None` lets the compositor decide.
+    /// `namespace` tells the compositor what the surface is for, e.g. "panel".
+    pub fn new(
+        connection: &WaylandConnection,
+        shell: ZwlrLayerShellV1,
+        surface: WlSurface,
+        output: Option<WlOutput>,
+        layer: ZwlrLayerShellV1Layer,
+        namespace: &str,
+    ) -> Self {
+        LayerSurface {
+            surface,
+            layer_surface: shell.get_layer_surface(connection, surface, output, layer as u32, namespace),
+            size: (0, 0),
+            configured: false,
+            closed: false,
+        }
+    }
+
+    pub fn surface(&self) -> WlSurface {
+        self.surface
+    }
+
+    pub fn layer_surface(&self) -> ZwlrLayerSurfaceV1 {
+        self.layer_surface
+    }
+
+    /// Size from the latest configure, a zero dimension means
+    /// the client picks it
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Whether a buffer may be attached yet
+    pub fn is_configured(&self) -> bool {
+        self.configured
+    }
+
+    /// Whether the compositor stopped showing the surface,
+    /// it should be destroyed then
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Size of the surface in surface-local coordinates, 0 stretches the
+    /// surface between the anchored edges. Applied on the next commit.
+    pub fn set_size(&self, connection: &WaylandConnection, width: u32, height: u32) {
+        self.layer_surface.set_size(connection, width, height);
+    }
+
+    /// Edges the surface is anchored to. Applied on the next commit.
+    pub fn set_anchor(&self, connection: &WaylandConnection, anchor: ZwlrLayerSurfaceV1Anchor) {
+        self.layer_surface.set_anchor(connection, anchor.into());
+    }
+
+    /// Space along the anchored edge reserved for the surface, 0 to be moved
+    /// out of the way of other exclusive zones, -1 to ignore them.
+    /// Applied on the next commit.
+    pub fn set_exclusive_zone(&self, connection: &WaylandConnection, zone: i32) {
+        self.layer_surface.set_exclusive_zone(connection, zone);
+    }
+
+    /// Distance from the anchored edges. Applied on the next commit.
+    pub fn set_margin(&self, connection: &WaylandConnection, top: i32, right: i32, bottom: i32, left: i32) {
+        self.layer_surface.set_margin(connection, top, right, bottom, left);
+    }
+
+    /// Applied on the next commit
+    pub fn set_keyboard_interactivity(&self, connection: &WaylandConnection, interactivity: ZwlrLayerSurfaceV1KeyboardInteractivity) {
+        self.layer_surface.set_keyboard_interactivity(connection, interactivity as u32);
+    }
+
+    /// Makes `popup` a child of this surface, the popup has to be created
+    /// with a null parent and this must be called before its first commit
+    pub fn set_popup(&self, connection: &WaylandConnection, popup: XdgPopup) {
+        self.layer_surface.get_popup(connection, popup);
+    }
+
+    /// Commits the surface state, the first commit must not have a buffer
+    pub fn commit(&self, connection: &WaylandConnection) {
+        self.surface.commit(connection);
+    }
+
+    /// Acknowledges the configure and returns the size to draw at,
+    /// `None` if the event belongs to another surface
+    pub fn handle_configure(&mut self, connection: &WaylandConnection, event: ZwlrLayerSurfaceV1ConfigureEvent) -> Option<(u32, u32)> {
+        if event.source_id != self.layer_surface.id {
+            return None;
+        }
+        self.layer_surface.ack_configure(connection, event.serial);
+        self.size = (event.width, event.height);
+        self.configured = true;
+        Some(self.size)
+    }
+
+    pub fn handle_closed(&mut self, event: ZwlrLayerSurfaceV1ClosedEvent) -> bool {
+        if event.source_id != self.layer_surface.id {
+            return false;
+        }
+        self.closed = true;
+        true
+    }
+
+    /// Destroys the layer surface, the `wl_surface` stays with the caller
+    pub fn destroy(self, connection: &WaylandConnection) {
+        self.layer_surface.destroy(connection);
+    }
+}
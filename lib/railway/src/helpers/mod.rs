@@ -0,0 +1,55 @@
+/// Registry and global objects tracking
+pub mod globals;
+/// Server-side window decorations
+pub mod decoration;
+/// Surface cropping and scaling
+pub mod viewport;
+/// Fractional HiDPI scaling
+pub mod scale;
+/// Frame presentation timing
+pub mod presentation;
+/// Explicit synchronization with DRM syncobj timelines
+pub mod syncobj;
+/// Focus handover with activation tokens
+pub mod activation;
+/// Compositor drawn cursor shapes
+pub mod cursor;
+/// Input method text input state
+pub mod text_input;
+/// Middle-click primary selection
+pub mod selection;
+/// Pointer lock with relative motion
+pub mod pointer_lock;
+/// Panels, docks and overlays on shell layers
+pub mod layer_shell;
+/// Screen locking
+pub mod session_lock;
+/// Output screenshots and recording
+pub mod capture;
+/// Drawing tablet tools grouped by frame
+pub mod tablet;
+/// Output properties with logical geometry
+pub mod output;
+/// Solid color surfaces without shm buffers
+pub mod solid_color;
+/// Content type and tearing hints
+pub mod hints;
+
+pub use globals::*;
+pub use decoration::*;
+pub use viewport::*;
+pub use scale::*;
+pub use presentation::*;
+pub use syncobj::*;
+pub use activation::*;
+pub use cursor::*;
+pub use text_input::*;
+pub use selection::*;
+pub use pointer_lock::*;
+pub use layer_shell::*;
+pub use session_lock::*;
+pub use capture::*;
+pub use tablet::*;
+pub use output::*;
+pub use solid_color::*;
+pub use hints::*;
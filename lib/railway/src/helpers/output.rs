@@ -0,0 +1,330 @@
+use crate::connection::WaylandConnection;
+use crate::types::enums::{WlOutputMode, WlOutputSubpixel, WlOutputTransform};
+use crate::types::events::*;
+use crate::types::{WlOutput, ZxdgOutputManagerV1, ZxdgOutputV1};
+
+/// Output properties reported by `wl_output` and `zxdg_output_v1`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputInfo {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub make: String,
+    pub model: String,
+    /// position in the global compositor space, prefer `logical_position`
+    pub position: (i32, i32),
+    /// physical size in millimeters
+    pub physical_size: (i32, i32),
+    pub subpixel: WlOutputSubpixel,
+    pub transform: WlOutputTransform,
+    /// size of the current mode in physical pixels
+    pub mode_size: (i32, i32),
+    /// refresh rate of the current mode in mHz
+    pub refresh: i32,
+    pub scale: i32,
+    /// position in the global compositor space after scaling and transform
+    pub logical_position: Option<(i32, i32)>,
+    /// size in the global compositor space after scaling and transform
+    pub logical_size: Option<(i32, i32)>,
+}
+
+impl Default for OutputInfo {
+    fn default() -> Self {
+        OutputInfo {
+            name: None,
+            description: None,
+            make: String::new(),
+            model: String::new(),
+            position: (0, 0),
+            physical_size: (0, 0),
+            subpixel: WlOutputSubpixel::Unknown,
+            transform: WlOutputTransform::TransformNormal,
+            mode_size: (0, 0),
+            refresh: 0,
+            scale: 1,
+            logical_position: None,
+            logical_size: None,
+        }
+    }
+}
+
+/// Output with its `wl_output` and `zxdg_output_v1` state merged.
+///
+/// Properties arrive in batches and are applied atomically on `done`.
+/// Since version 3 of `zxdg_output_manager_v1` the xdg output properties
+/// are applied by `wl_output.done`, older versions send their own `done`.
+pub struct Output {
+    output: WlOutput,
+    xdg_output: Option<ZxdgOutputV1>,
+    info: OutputInfo,
+    pending: OutputInfo,
+    /// `wl_output` sent a name, only done since version 4
+    wl_name: bool,
+    /// `wl_output` sent a description, only done since version 4
+    wl_description: bool,
+}
+
+impl Output {
+
+    /// Tracks `output`, the logical geometry is only known with a `manager`
+    pub fn new(connection: &WaylandConnection, output: WlOutput, manager: Option<ZxdgOutputManagerV1>) -> Self {
+        Output {
+            output,
+            xdg_output: manager.map(|manager| manager.get_xdg_output(connection, output)),
+            info: OutputInfo::default(),
+            pending: OutputInfo::default(),
+            wl_name: false,
+            wl_description: false,
+        }
+    }
+
+    pub fn output(&self) -> WlOutput {
+        self.output
+    }
+
+    pub fn xdg_output(&self) -> Option<ZxdgOutputV1> {
+        self.xdg_output
+    }
+
+    /// Properties as of the latest `done`
+    pub fn info(&self) -> &OutputInfo {
+        &self.info
+    }
+
+    pub fn handle_geometry(&mut self, event: WlOutputGeometryEvent<'_>) -> bool {
+        if event.source_id != self.output.id {
+            return false;
+        }
+        self.pending.position = (event.x, event.y);
+        self.pending.physical_size = (event.physical_width, event.physical_height);
+        self.pending.subpixel = event.subpixel;
+        self.pending.make = event.make.into_owned();
+        self.pending.model = event.model.into_owned();
+        self.pending.transform = event.transform;
+        true
+    }
+
+    /// Records the current mode, other modes are ignored
+    pub fn handle_mode(&mut self, event: WlOutputModeEvent) -> bool {
+        if event.source_id != self.output.id {
+            return false;
+        }
+        if event.flags.contains(WlOutputMode::CURRENT) {
+            self.pending.mode_size = (event.width, event.height);
+            self.pending.refresh = event.refresh;
+        }
+        true
+    }
+
+    pub fn handle_scale(&mut self, event: WlOutputScaleEvent) -> bool {
+        if event.source_id != self.output.id {
+            return false;
+        }
+        self.pending.scale = event.factor;
+        true
+    }
+
+    pub fn handle_name(&mut self, event: WlOutputNameEvent<'_>) -> bool {
+        if event.source_id != self.output.id {
+            return false;
+        }
+        self.pending.name = Some(event.name.into_owned());
+        self.wl_name = true;
+        true
+    }
+
+    pub fn handle_description(&mut self, event: WlOutputDescriptionEvent<'_>) -> bool {
+        if event.source_id != self.output.id {
+            return false;
+        }
+        self.pending.description = Some(event.description.into_owned());
+        self.wl_description = true;
+        true
+    }
+
+    /// Applies the pending properties
+    pub fn handle_done(&mut self, event: WlOutputDoneEvent) -> bool {
+        if event.source_id != self.output.id {
+            return false;
+        }
+        self.info = self.pending.clone();
+        true
+    }
+
+    pub fn handle_logical_position(&mut self, event: ZxdgOutputV1LogicalPositionEvent) -> bool {
+        if !self.is_xdg_output(event.source_id) {
+            return false;
+        }
+        self.pending.logical_position = Some((event.x, event.y));
+        true
+    }
+
+    pub fn handle_logical_size(&mut self, event: ZxdgOutputV1LogicalSizeEvent) -> bool {
+        if !self.is_xdg_output(event.source_id) {
+            return false;
+        }
+        self.pending.logical_size = Some((event.width, event.height));
+        true
+    }
+
+    /// Ignored once `wl_output` sent a name, i.e. since version 4
+    pub fn handle_xdg_name(&mut self, event: ZxdgOutputV1NameEvent<'_>) -> bool {
+        if !self.is_xdg_output(event.source_id) {
+            return false;
+        }
+        if !self.wl_name {
+            self.pending.name = Some(event.name.into_owned());
+        }
+        true
+    }
+
+    /// Ignored once `wl_output` sent a description, i.e. since version 4
+    pub fn handle_xdg_description(&mut self, event: ZxdgOutputV1DescriptionEvent<'_>) -> bool {
+        if !self.is_xdg_output(event.source_id) {
+            return false;
+        }
+        if !self.wl_description {
+            self.pending.description = Some(event.description.into_owned());
+        }
+        true
+    }
+
+    /// Applies the pending properties, only sent before version 3 of the xdg output
+    pub fn handle_xdg_done(&mut self, event: ZxdgOutputV1DoneEvent) -> bool {
+        if !self.is_xdg_output(event.source_id) {
+            return false;
+        }
+        self.info = self.pending.clone();
+        true
+    }
+
+    /// Destroys the xdg output, the `wl_output` stays with the caller
+    pub fn destroy(self, connection: &WaylandConnection) {
+        if let Some(xdg_output) = self.xdg_output {
+            xdg_output.destroy(connection);
+        }
+    }
+
+    fn is_xdg_output(&self, id: u32) -> bool {
+        self.xdg_output.is_some_and(|o| o.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::testing;
+    use crate::types::Interface;
+    use std::borrow::Cow;
+
+    const OUTPUT: u32 = 4;
+
+    fn wl_output_events(output: &mut Output) {
+        assert!(output.handle_geometry(WlOutputGeometryEvent {
+            source_id: OUTPUT,
+            x: 1920,
+            y: 0,
+            physical_width: 600,
+            physical_height: 340,
+            subpixel: WlOutputSubpixel::HorizontalRgb,
+            make: Cow::Borrowed("Foo"),
+            model: Cow::Borrowed("Bar 27"),
+            transform: WlOutputTransform::Transform90,
+        }));
+        assert!(output.handle_mode(WlOutputModeEvent { source_id: OUTPUT, flags: WlOutputMode::PREFERRED, width: 1280, height: 720, refresh: 60000 }));
+        assert!(output.handle_mode(WlOutputModeEvent { source_id: OUTPUT, flags: WlOutputMode::from(3), width: 3840, height: 2160, refresh: 59940 }));
+        assert!(output.handle_scale(WlOutputScaleEvent { source_id: OUTPUT, factor: 2 }));
+    }
+
+    fn xdg_output_events(output: &mut Output) {
+        let id = output.xdg_output().unwrap().id;
+        assert!(output.handle_logical_position(ZxdgOutputV1LogicalPositionEvent { source_id: id, x: 1920, y: 0 }));
+        assert!(output.handle_logical_size(ZxdgOutputV1LogicalSizeEvent { source_id: id, width: 1080, height: 1920 }));
+        assert!(output.handle_xdg_name(ZxdgOutputV1NameEvent { source_id: id, name: Cow::Borrowed("DP-2") }));
+        assert!(output.handle_xdg_description(ZxdgOutputV1DescriptionEvent { source_id: id, description: Cow::Borrowed("xdg description") }));
+    }
+
+    fn expected() -> OutputInfo {
+        OutputInfo {
+            name: Some("DP-2".into()),
+            description: Some("xdg description".into()),
+            make: "Foo".into(),
+            model: "Bar 27".into(),
+            position: (1920, 0),
+            physical_size: (600, 340),
+            subpixel: WlOutputSubpixel::HorizontalRgb,
+            transform: WlOutputTransform::Transform90,
+            mode_size: (3840, 2160),
+            refresh: 59940,
+            scale: 2,
+            logical_position: Some((1920, 0)),
+            logical_size: Some((1080, 1920)),
+        }
+    }
+
+    #[test]
+    fn current_versions() {
+        let (connection, _peer) = testing::connection();
+        let mut output = Output::new(&connection, WlOutput::from_id(OUTPUT), Some(ZxdgOutputManagerV1::from_id(2)));
+
+        // wl_output 4 names the output, xdg_output 3 has no done of its own
+        wl_output_events(&mut output);
+        assert!(output.handle_name(WlOutputNameEvent { source_id: OUTPUT, name: Cow::Borrowed("DP-1") }));
+        assert!(output.handle_description(WlOutputDescriptionEvent { source_id: OUTPUT, description: Cow::Borrowed("Foo Bar 27") }));
+        xdg_output_events(&mut output);
+        assert_eq!(output.info(), &OutputInfo::default());
+
+        assert!(output.handle_done(WlOutputDoneEvent { source_id: OUTPUT }));
+        assert_eq!(output.info(), &OutputInfo {
+            name: Some("DP-1".into()),
+            description: Some("Foo Bar 27".into()),
+            ..expected()
+        });
+    }
+
+    #[test]
+    fn older_versions() {
+        let (connection, _peer) = testing::connection();
+        let mut output = Output::new(&connection, WlOutput::from_id(OUTPUT), Some(ZxdgOutputManagerV1::from_id(2)));
+
+        // wl_output 3 without name, xdg_output 2 finishing with its own done
+        wl_output_events(&mut output);
+        assert!(output.handle_done(WlOutputDoneEvent { source_id: OUTPUT }));
+        xdg_output_events(&mut output);
+        let id = output.xdg_output().unwrap().id;
+        assert!(output.handle_xdg_done(ZxdgOutputV1DoneEvent { source_id: id }));
+        assert_eq!(output.info(), &expected());
+
+        // a later change keeps the other properties
+        assert!(output.handle_scale(WlOutputScaleEvent { source_id: OUTPUT, factor: 1 }));
+        assert!(output.handle_done(WlOutputDoneEvent { source_id: OUTPUT }));
+        assert_eq!(output.info(), &OutputInfo { scale: 1, ..expected() });
+
+        // so does a changed xdg description
+        let description = ZxdgOutputV1DescriptionEvent { source_id: id, description: Cow::Borrowed("changed") };
+        assert!(output.handle_xdg_description(description));
+        assert!(output.handle_xdg_done(ZxdgOutputV1DoneEvent { source_id: id }));
+        assert_eq!(output.info(), &OutputInfo {
+            scale: 1,
+            description: Some("changed".into()),
+            ..expected()
+        });
+    }
+
+    #[test]
+    fn without_xdg_output() {
+        let (connection, _peer) = testing::connection();
+        let mut output = Output::new(&connection, WlOutput::from_id(OUTPUT), None);
+
+        wl_output_events(&mut output);
+        assert!(!output.handle_logical_size(ZxdgOutputV1LogicalSizeEvent { source_id: 5, width: 1, height: 1 }));
+        assert!(!output.handle_scale(WlOutputScaleEvent { source_id: OUTPUT + 1, factor: 3 }));
+        assert!(output.handle_done(WlOutputDoneEvent { source_id: OUTPUT }));
+        assert_eq!(output.info(), &OutputInfo {
+            name: None,
+            description: None,
+            logical_position: None,
+            logical_size: None,
+            ..expected()
+        });
+    }
+}
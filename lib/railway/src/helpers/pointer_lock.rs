@@ -0,0 +1,126 @@
+use crate::connection::WaylandConnection;
+use crate::types::enums::ZwpPointerConstraintsV1Lifetime;
+use crate::types::events::*;
+use crate::types::{join, Fixed, WlPointer, WlRegion, WlSurface};
+use crate::types::{ZwpLockedPointerV1, ZwpPointerConstraintsV1};
+use crate::types::{ZwpRelativePointerManagerV1, ZwpRelativePointerV1};
+
+use std::time::Duration;
+
+/// Relative pointer motion with the split timestamp joined together
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelativeMotion {
+    /// timestamp with microsecond granularity, undefined base
+    pub time: Duration,
+    /// motion after pointer acceleration
+    pub dx: f64,
+    pub dy: f64,
+    /// motion before pointer acceleration
+    pub dx_unaccel: f64,
+    pub dy_unaccel: f64,
+}
+
+impl From<ZwpRelativePointerV1RelativeMotionEvent> for RelativeMotion {
+    fn from(event: ZwpRelativePointerV1RelativeMotionEvent) -> Self {
+        RelativeMotion {
+            time: Duration::from_micros(join(event.utime_hi, event.utime_lo)),
+            dx: event.dx.to_f64(),
+            dy: event.dy.to_f64(),
+            dx_unaccel: event.dx_unaccel.to_f64(),
+            dy_unaccel: event.dy_unaccel.to_f64(),
+        }
+    }
+}
+
+/// Pointer locked to a surface, reporting only relative motion.
+///
+/// The lock is not active right away, the compositor sends `locked` once
+/// it takes effect and `unlocked` when it's lifted. While locked, the
+/// `wl_pointer` sends no motion events and movement is only reported
+/// through `handle_relative_motion`.
+pub struct PointerLock {
+    locked_pointer: ZwpLockedPointerV1,
+    relative_pointer: ZwpRelativePointerV1,
+    lifetime: ZwpPointerConstraintsV1Lifetime,
+    active: bool,
+    unlocked: bool,
+}
+
+impl PointerLock {
+
+    /// Requests a lock of `pointer` on `surface`, `region` limits where the
+    /// pointer has to be for the lock to activate, `None` for the whole surface
+    pub fn new(
+        connection: &WaylandConnection,
+        constraints: ZwpPointerConstraintsV1,
+        relative_pointer_manager: ZwpRelativePointerManagerV1,
+        surface: WlSurface,
+        pointer: WlPointer,
+        region: Option<WlRegion>,
+        lifetime: ZwpPointerConstraintsV1Lifetime,
+    ) -> Self {
+        PointerLock {
+            locked_pointer: constraints.lock_pointer(connection, surface, pointer, region, lifetime as u32),
+            relative_pointer: relative_pointer_manager.get_relative_pointer(connection, pointer),
+            lifetime,
+            active: false,
+            unlocked: false,
+        }
+    }
+
+    pub fn locked_pointer(&self) -> ZwpLockedPointerV1 {
+        self.locked_pointer
+    }
+
+    pub fn relative_pointer(&self) -> ZwpRelativePointerV1 {
+        self.relative_pointer
+    }
+
+    /// True between the `locked` and `unlocked` events
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn handle_locked(&mut self, event: ZwpLockedPointerV1LockedEvent) -> bool {
+        if event.source_id != self.locked_pointer.id {
+            return false;
+        }
+        self.active = true;
+        true
+    }
+
+    /// Marks the lock inactive. A oneshot lock is defunct after this and
+    /// should be destroyed, a persistent one may be locked again later.
+    pub fn handle_unlocked(&mut self, event: ZwpLockedPointerV1UnlockedEvent) -> bool {
+        if event.source_id != self.locked_pointer.id {
+            return false;
+        }
+        self.active = false;
+        self.unlocked = true;
+        true
+    }
+
+    /// True once a oneshot lock was lifted
+    pub fn is_defunct(&self) -> bool {
+        self.unlocked && self.lifetime == ZwpPointerConstraintsV1Lifetime::Oneshot
+    }
+
+    /// Returns the motion, `None` if the event came from another relative pointer
+    pub fn handle_relative_motion(&self, event: ZwpRelativePointerV1RelativeMotionEvent) -> Option<RelativeMotion> {
+        if event.source_id != self.relative_pointer.id {
+            return None;
+        }
+        Some(event.into())
+    }
+
+    /// Where the compositor should put the cursor when the lock is lifted,
+    /// in surface-local coordinates. Applied on the next `wl_surface.commit`.
+    pub fn set_cursor_position_hint(&self, connection: &WaylandConnection, x: f64, y: f64) {
+        self.locked_pointer.set_cursor_position_hint(connection, Fixed::from_f64(x), Fixed::from_f64(y));
+    }
+
+    pub fn destroy(self, connection: &WaylandConnection) {
+        self.locked_pointer.destroy(connection);
+        self.relative_pointer.destroy(connection);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::connection::c_ffi::{clock_gettime, errno, TimeSpec};
+use crate::types::enums::WpPresentationFeedbackKind;
+use crate::types::events::{WpPresentationClockIdEvent, WpPresentationFeedbackPresentedEvent};
+use crate::types::join;
+
+use std::time::Duration;
+
+/// Clock used by the compositor for presentation timestamps,
+/// announced by `wp_presentation.clock_id`
+#[derive(Debug, Clone, Copy)]
+pub struct PresentationClock {
+    clock_id: u32,
+}
+
+impl PresentationClock {
+
+    pub fn new(event: WpPresentationClockIdEvent) -> Self {
+        PresentationClock {
+            clock_id: event.clk_id,
+        }
+    }
+
+    pub fn clock_id(&self) -> u32 {
+        self.clock_id
+    }
+
+    /// Current time on the presentation clock, comparable with
+    /// `PresentationTime::timestamp`
+    pub fn now(&self) -> Duration {
+        let mut ts = TimeSpec::default();
+        let ret = unsafe { clock_gettime(self.clock_id as i32, &mut ts) };
+        if ret < 0 {
+            panic!("clock_gettime: errno {}", errno());
+        }
+        Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32)
+    }
+}
+
+/// Presentation feedback of a single frame with the split wire fields
+/// joined together
+#[derive(Debug, Clone, Copy)]
+pub struct PresentationTime {
+    /// time the frame turned into light, on the presentation clock
+    pub timestamp: Duration,
+    /// duration of a refresh cycle, `None` if the output has no constant rate
+    pub refresh: Option<Duration>,
+    /// vertical retrace counter, `None` if the output has no such counter
+    pub sequence: Option<u64>,
+    /// how the frame was presented
+    pub flags: WpPresentationFeedbackKind,
+}
+
+impl PresentationTime {
+
+    /// Time elapsed between this and an earlier presentation
+    pub fn since(&self, earlier: &PresentationTime) -> Duration {
+        self.timestamp.saturating_sub(earlier.timestamp)
+    }
+
+    /// Expected timestamp of the presentation `frames` refresh cycles later
+    pub fn predict(&self, frames: u32) -> Option<Duration> {
+        self.refresh.map(|refresh| self.timestamp + refresh * frames)
+    }
+}
+
+impl From<WpPresentationFeedbackPresentedEvent> for PresentationTime {
+    fn from(event: WpPresentationFeedbackPresentedEvent) -> Self {
+        let tv_sec = join(event.tv_sec_hi, event.tv_sec_lo);
+        let seq = join(event.seq_hi, event.seq_lo);
+
+        PresentationTime {
+            timestamp: Duration::new(tv_sec, event.tv_nsec),
+            refresh: match event.refresh {
+                0 => None,
+                refresh => Some(Duration::from_nanos(refresh as u64)),
+            },
+            sequence: match seq {
+                0 => None,
+                seq => Some(seq),
+            },
+            flags: event.flags,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn presented(tv_sec: u64, tv_nsec: u32, refresh: u32, seq: u64, flags: WpPresentationFeedbackKind) -> WpPresentationFeedbackPresentedEvent {
+        WpPresentationFeedbackPresentedEvent {
+            source_id: 3,
+            tv_sec_hi: (tv_sec >> 32) as u32,
+            tv_sec_lo: tv_sec as u32,
+            tv_nsec,
+            refresh,
+            seq_hi: (seq >> 32) as u32,
+            seq_lo: seq as u32,
+            flags,
+        }
+    }
+
+    #[test]
+    fn joins_split_fields() {
+        let mut flags = WpPresentationFeedbackKind::VSYNC;
+        flags.insert(WpPresentationFeedbackKind::HWCLOCK);
+        let time = PresentationTime::from(presented(5 << 32 | 17, 250_000_000, 16_666_667, 3 << 32 | 9, flags));
+
+        assert_eq!(time.timestamp, Duration::new(5 << 32 | 17, 250_000_000));
+        assert_eq!(time.refresh, Some(Duration::from_nanos(16_666_667)));
+        assert_eq!(time.sequence, Some(3 << 32 | 9));
+        assert_eq!(time.flags, flags);
+    }
+
+    #[test]
+    fn unknown_refresh_and_sequence() {
+        let time = PresentationTime::from(presented(100, 999_999_999, 0, 0, WpPresentationFeedbackKind::new()));
+
+        assert_eq!(time.timestamp, Duration::new(100, 999_999_999));
+        assert_eq!(time.refresh, None);
+        assert_eq!(time.sequence, None);
+        assert_eq!(time.predict(2), None);
+        assert!(!time.flags.contains(WpPresentationFeedbackKind::VSYNC));
+    }
+
+    #[test]
+    fn prediction() {
+        let earlier = PresentationTime::from(presented(10, 0, 10_000_000, 1, WpPresentationFeedbackKind::VSYNC));
+        let later = PresentationTime::from(presented(10, 30_000_000, 10_000_000, 4, WpPresentationFeedbackKind::VSYNC));
+
+        assert_eq!(later.since(&earlier), Duration::from_millis(30));
+        assert_eq!(earlier.since(&later), Duration::ZERO);
+        assert_eq!(earlier.predict(3), Some(later.timestamp));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::connection::WaylandConnection;
+use crate::helpers::Viewport;
+use crate::types::events::WpFractionalScaleV1PreferredScaleEvent;
+use crate::types::{WlSurface, WpFractionalScaleManagerV1, WpFractionalScaleV1, WpViewporter};
+
+/// Denominator of the scale sent in `wp_fractional_scale_v1.preferred_scale`
+pub const SCALE_DENOMINATOR: u32 = 120;
+
+/// Fractional scale of a surface.
+///
+/// The compositor sends the preferred scale as a fraction of 120, the
+/// surface is rendered into a buffer of the scaled size and the viewport
+/// maps that buffer back to the logical surface size. The buffer scale of
+/// the surface must stay 1.
+pub struct FractionalScale {
+    fractional_scale: WpFractionalScaleV1,
+    viewport: Viewport,
+    numerator: u32,
+}
+
+impl FractionalScale {
+
+    pub fn new(connection: &WaylandConnection, manager: WpFractionalScaleManagerV1, viewporter: WpViewporter, surface: WlSurface) -> Self {
+        FractionalScale {
+            fractional_scale: manager.get_fractional_scale(connection, surface),
+            viewport: Viewport::new(connection, viewporter, surface),
+            numerator: SCALE_DENOMINATOR,
+        }
+    }
+
+    pub fn fractional_scale(&self) -> WpFractionalScaleV1 {
+        self.fractional_scale
+    }
+
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Records the scale preferred by the compositor.
+    /// Returns false if the event belongs to another surface.
+    pub fn handle_preferred_scale(&mut self, event: WpFractionalScaleV1PreferredScaleEvent) -> bool {
+        if event.source_id != self.fractional_scale.id {
+            return false;
+        }
+        self.numerator = event.scale;
+        true
+    }
+
+    /// Numerator of the preferred scale, the denominator is `SCALE_DENOMINATOR`
+    pub fn numerator(&self) -> u32 {
+        self.numerator
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.numerator as f64 / SCALE_DENOMINATOR as f64
+    }
+
+    /// Buffer size for the given logical surface size,
+    /// rounded half away from zero as required by the protocol
+    pub fn buffer_size(&self, width: i32, height: i32) -> (i32, i32) {
+        (self.scale_length(width), self.scale_length(height))
+    }
+
+    /// Sets the viewport destination to the logical surface size and
+    /// returns the size of the buffer to render into
+    pub fn apply(&self, connection: &WaylandConnection, width: i32, height: i32) -> (i32, i32) {
+        self.viewport.set_destination(connection, width, height);
+        self.buffer_size(width, height)
+    }
+
+    /// Destroys the fractional scale and viewport objects
+    pub fn destroy(self, connection: &WaylandConnection) {
+        self.fractional_scale.destroy(connection);
+        self.viewport.destroy(connection);
+    }
+
+    fn scale_length(&self, length: i32) -> i32 {
+        let scaled = length as i64 * self.numerator as i64;
+        let half = SCALE_DENOMINATOR as i64 / 2;
+        let rounded = if scaled < 0 { scaled - half } else { scaled + half };
+        (rounded / SCALE_DENOMINATOR as i64) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::testing;
+    use crate::types::Interface;
+
+    fn fractional_scale(connection: &WaylandConnection, numerator: u32) -> FractionalScale {
+        let mut scale = FractionalScale::new(connection, WpFractionalScaleManagerV1::from_id(2), WpViewporter::from_id(3), WlSurface::from_id(4));
+        let event = WpFractionalScaleV1PreferredScaleEvent { source_id: scale.fractional_scale().id, scale: numerator };
+        assert!(scale.handle_preferred_scale(event));
+        scale
+    }
+
+    #[test]
+    fn preferred_scale() {
+        let (connection, _peer) = testing::connection();
+        let mut scale = FractionalScale::new(&connection, WpFractionalScaleManagerV1::from_id(2), WpViewporter::from_id(3), WlSurface::from_id(4));
+        assert_eq!(scale.numerator(), SCALE_DENOMINATOR);
+        assert_eq!(scale.buffer_size(800, 600), (800, 600));
+
+        let other = WpFractionalScaleV1PreferredScaleEvent { source_id: scale.fractional_scale().id + 1, scale: 240 };
+        assert!(!scale.handle_preferred_scale(other));
+        assert_eq!(scale.scale(), 1.0);
+
+        let event = WpFractionalScaleV1PreferredScaleEvent { source_id: scale.fractional_scale().id, scale: 180 };
+        assert!(scale.handle_preferred_scale(event));
+        assert_eq!(scale.scale(), 1.5);
+    }
+
+    #[test]
+    fn buffer_size_rounding() {
+        let (connection, _peer) = testing::connection();
+
+        // 1.5: 101 * 1.5 = 151.5 rounds up
+        assert_eq!(fractional_scale(&connection, 180).buffer_size(101, 100), (152, 150));
+        // 1.25: 2.5 rounds away from zero, 3.75 to the nearest
+        assert_eq!(fractional_scale(&connection, 150).buffer_size(2, 3), (3, 4));
+        assert_eq!(fractional_scale(&connection, 150).buffer_size(-2, -3), (-3, -4));
+        // 1.0833..: 1.083 rounds down, 6.5 up
+        assert_eq!(fractional_scale(&connection, 130).buffer_size(1, 6), (1, 7));
+    }
+}
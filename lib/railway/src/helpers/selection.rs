@@ -0,0 +1,143 @@
+use crate::connection::WaylandConnection;
+use crate::types::events::*;
+use crate::types::{Interface, WlSeat};
+use crate::types::{ZwpPrimarySelectionDeviceManagerV1, ZwpPrimarySelectionDeviceV1};
+use crate::types::{ZwpPrimarySelectionOfferV1, ZwpPrimarySelectionSourceV1};
+
+use crate::connection::c_ffi::{close, errno, pipe};
+
+use std::fs::File;
+use std::io;
+use std::os::fd::FromRawFd;
+
+struct Offer {
+    offer: ZwpPrimarySelectionOfferV1,
+    mime_types: Vec<String>,
+}
+
+/// Primary selection of a seat, the text pasted with the middle button.
+///
+/// Offers are created by the compositor and announce their mime types
+/// right after creation, the `selection` event then picks the offer that
+/// holds the current primary selection. Offers that are not the current
+/// selection are destroyed.
+pub struct PrimarySelection {
+    manager: ZwpPrimarySelectionDeviceManagerV1,
+    device: ZwpPrimarySelectionDeviceV1,
+    offers: Vec<Offer>,
+    selection: Option<ZwpPrimarySelectionOfferV1>,
+}
+
+impl PrimarySelection {
+
+    pub fn new(connection: &WaylandConnection, manager: ZwpPrimarySelectionDeviceManagerV1, seat: WlSeat) -> Self {
+        PrimarySelection {
+            manager,
+            device: manager.get_device(connection, seat),
+            offers: Vec::new(),
+            selection: None,
+        }
+    }
+
+    pub fn device(&self) -> ZwpPrimarySelectionDeviceV1 {
+        self.device
+    }
+
+    pub fn handle_data_offer(&mut self, event: ZwpPrimarySelectionDeviceV1DataOfferEvent) -> bool {
+        if event.source_id != self.device.id {
+            return false;
+        }
+        self.offers.push(Offer {
+            offer: ZwpPrimarySelectionOfferV1::from_id(event.offer),
+            mime_types: Vec::new(),
+        });
+        true
+    }
+
+    pub fn handle_offer(&mut self, event: ZwpPrimarySelectionOfferV1OfferEvent<'_>) -> bool {
+        match self.offers.iter_mut().find(|o| o.offer.id == event.source_id) {
+            Some(offer) => {
+                offer.mime_types.push(event.mime_type.into_owned());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Switches to the new selection and destroys all other offers
+    pub fn handle_selection(&mut self, connection: &WaylandConnection, event: ZwpPrimarySelectionDeviceV1SelectionEvent) -> bool {
+        if event.source_id != self.device.id {
+            return false;
+        }
+
+        self.offers.retain(|o| {
+            if Some(o.offer.id) == event.id {
+                return true;
+            }
+            o.offer.destroy(connection);
+            connection.delete_object(o.offer.id);
+            false
+        });
+        self.selection = event.id.map(ZwpPrimarySelectionOfferV1::from_id);
+        true
+    }
+
+    /// Mime types of the current selection, empty if there is no selection
+    pub fn mime_types(&self) -> &[String] {
+        self.offers.iter()
+            .find(|o| Some(o.offer.id) == self.selection.map(|s| s.id))
+            .map_or(&[], |o| &o.mime_types)
+    }
+
+    /// Requests the current selection as `mime_type`.
+    ///
+    /// The request is flushed right away, the selection owner writes the
+    /// contents into the returned pipe and closes it when done. If the
+    /// selection may be owned by this client, read the pipe only after
+    /// the `send` event of the source was dispatched.
+    pub fn receive(&self, connection: &WaylandConnection, mime_type: &str) -> io::Result<Option<File>> {
+        let offer = match self.selection {
+            Some(offer) => offer,
+            None => return Ok(None),
+        };
+
+        let mut fds = [0; 2];
+        if unsafe { pipe(fds.as_mut_ptr()) } != 0 {
+            return Err(io::Error::from_raw_os_error(errno()));
+        }
+        offer.receive(connection, mime_type, fds[1]);
+        // the descriptor is duplicated into the message on send,
+        // only then the write end can be closed
+        connection.send();
+        unsafe { close(fds[1]) };
+
+        Ok(Some(unsafe { File::from_raw_fd(fds[0]) }))
+    }
+
+    /// Takes the primary selection with data in the given mime types.
+    ///
+    /// Contents are requested through the `send` event of the returned
+    /// source, the source must be destroyed once `cancelled` arrives.
+    pub fn set_selection(&self, connection: &WaylandConnection, mime_types: &[&str], serial: u32) -> ZwpPrimarySelectionSourceV1 {
+        let source = self.manager.create_source(connection);
+        for mime_type in mime_types {
+            source.offer(connection, mime_type);
+        }
+        self.device.set_selection(connection, Some(source), serial);
+        source
+    }
+
+    /// Clears the primary selection
+    pub fn clear_selection(&self, connection: &WaylandConnection, serial: u32) {
+        self.device.set_selection(connection, None, serial);
+    }
+
+    /// Destroys the device and all offers
+    pub fn destroy(self, connection: &WaylandConnection) {
+        for o in self.offers {
+            o.offer.destroy(connection);
+            connection.delete_object(o.offer.id);
+        }
+        self.device.destroy(connection);
+    }
+}
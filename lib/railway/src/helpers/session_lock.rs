@@ -0,0 +1,210 @@
+use crate::connection::WaylandConnection;
+use crate::types::events::*;
+use crate::types::{WlCompositor, WlOutput, WlSurface};
+use crate::types::{ExtSessionLockManagerV1, ExtSessionLockSurfaceV1, ExtSessionLockV1};
+
+/// Lock screen surface covering a single output
+#[derive(Clone, Copy)]
+pub struct LockSurface {
+    pub output: u32,
+    pub surface: WlSurface,
+    pub lock_surface: ExtSessionLockSurfaceV1,
+    /// exact size the buffer has to match, valid once configured
+    pub size: (u32, u32),
+    pub configured: bool,
+}
+
+/// Session lock with one lock surface per output.
+///
+/// Outputs that appear while the session is locked have to be passed to
+/// `add_output` and removed ones to `remove_output`, e.g. from the
+/// `GlobalHandler` callbacks. A lock surface may only be drawn to once
+/// its configure was handled and the buffer has to match the configured
+/// size exactly.
+pub struct SessionLock {
+    lock: ExtSessionLockV1,
+    compositor: WlCompositor,
+    surfaces: Vec<LockSurface>,
+    locked: bool,
+    /// `locked` was received, the lock must be released with `unlock_and_destroy`
+    /// even after `finished`
+    was_locked: bool,
+    finished: bool,
+}
+
+impl SessionLock {
+
+    /// Requests the session lock and creates lock surfaces for `outputs`
+    pub fn new(
+        connection: &WaylandConnection,
+        manager: ExtSessionLockManagerV1,
+        compositor: WlCompositor,
+        outputs: impl IntoIterator<Item = WlOutput>,
+    ) -> Self {
+        let mut lock = SessionLock {
+            lock: manager.lock(connection),
+            compositor,
+            surfaces: Vec::new(),
+            locked: false,
+            was_locked: false,
+            finished: false,
+        };
+        for output in outputs {
+            lock.add_output(connection, output);
+        }
+        lock
+    }
+
+    pub fn lock(&self) -> ExtSessionLockV1 {
+        self.lock
+    }
+
+    pub fn surfaces(&self) -> &[LockSurface] {
+        &self.surfaces
+    }
+
+    /// True once the compositor confirmed that the session is locked
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// True if the compositor refused or ended the lock,
+    /// the lock has to be destroyed then
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Creates a lock surface for a new output
+    pub fn add_output(&mut self, connection: &WaylandConnection, output: WlOutput) {
+        if self.finished || self.surfaces.iter().any(|s| s.output == output.id) {
+            return;
+        }
+        let surface = self.compositor.create_surface(connection);
+        self.surfaces.push(LockSurface {
+            output: output.id,
+            surface,
+            lock_surface: self.lock.get_lock_surface(connection, surface, output),
+            size: (0, 0),
+            configured: false,
+        });
+    }
+
+    /// Destroys the lock surface of a removed output
+    pub fn remove_output(&mut self, connection: &WaylandConnection, output: WlOutput) {
+        self.surfaces.retain(|s| {
+            if s.output != output.id {
+                return true;
+            }
+            s.lock_surface.destroy(connection);
+            s.surface.destroy(connection);
+            false
+        });
+    }
+
+    pub fn handle_locked(&mut self, event: ExtSessionLockV1LockedEvent) -> bool {
+        if event.source_id != self.lock.id {
+            return false;
+        }
+        self.locked = true;
+        self.was_locked = true;
+        true
+    }
+
+    pub fn handle_finished(&mut self, event: ExtSessionLockV1FinishedEvent) -> bool {
+        if event.source_id != self.lock.id {
+            return false;
+        }
+        self.locked = false;
+        self.finished = true;
+        true
+    }
+
+    /// Acknowledges the configure and returns the surface to redraw,
+    /// `None` if the event belongs to another lock surface
+    pub fn handle_configure(&mut self, connection: &WaylandConnection, event: ExtSessionLockSurfaceV1ConfigureEvent) -> Option<LockSurface> {
+        let surface = self.surfaces.iter_mut().find(|s| s.lock_surface.id == event.source_id)?;
+        surface.lock_surface.ack_configure(connection, event.serial);
+        surface.size = (event.width, event.height);
+        surface.configured = true;
+        Some(*surface)
+    }
+
+    /// Unlocks the session if it was locked and destroys all surfaces.
+    /// A lock that never got `locked` is only destroyed.
+    pub fn unlock(self, connection: &WaylandConnection) {
+        if self.was_locked {
+            self.lock.unlock_and_destroy(connection);
+        } else {
+            self.lock.destroy(connection);
+        }
+        for s in self.surfaces {
+            s.lock_surface.destroy(connection);
+            s.surface.destroy(connection);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::testing;
+    use crate::types::Interface;
+
+    fn session_lock(connection: &WaylandConnection) -> SessionLock {
+        SessionLock::new(connection, ExtSessionLockManagerV1::from_id(2), WlCompositor::from_id(3), [WlOutput::from_id(4)])
+    }
+
+    #[test]
+    fn unlock_after_finished() {
+        let (connection, mut peer) = testing::connection();
+        let mut lock = session_lock(&connection);
+        let id = lock.lock().id;
+
+        assert!(lock.handle_locked(ExtSessionLockV1LockedEvent { source_id: id }));
+        assert!(lock.is_locked());
+        assert!(lock.handle_finished(ExtSessionLockV1FinishedEvent { source_id: id }));
+        assert!(!lock.is_locked());
+        assert!(lock.is_finished());
+
+        lock.unlock(&connection);
+        // unlock_and_destroy
+        assert!(testing::sent_requests(&connection, &mut peer).contains(&(id, 2)));
+    }
+
+    #[test]
+    fn destroy_without_locked() {
+        let (connection, mut peer) = testing::connection();
+        let mut lock = session_lock(&connection);
+        let id = lock.lock().id;
+
+        assert!(lock.handle_finished(ExtSessionLockV1FinishedEvent { source_id: id }));
+        lock.unlock(&connection);
+        // destroy
+        let requests = testing::sent_requests(&connection, &mut peer);
+        assert!(requests.contains(&(id, 0)));
+        assert!(!requests.contains(&(id, 2)));
+    }
+
+    #[test]
+    fn configure() {
+        let (connection, _peer) = testing::connection();
+        let mut lock = session_lock(&connection);
+        let lock_surface = lock.surfaces()[0].lock_surface.id;
+
+        let event = ExtSessionLockSurfaceV1ConfigureEvent { source_id: lock_surface, serial: 5, width: 1920, height: 1080 };
+        let surface = lock.handle_configure(&connection, event).unwrap();
+        assert!(surface.configured);
+        assert_eq!((surface.output, surface.size), (4, (1920, 1080)));
+        assert!(lock.surfaces()[0].configured);
+
+        let event = ExtSessionLockSurfaceV1ConfigureEvent { source_id: lock_surface + 1, serial: 6, width: 1, height: 1 };
+        assert!(lock.handle_configure(&connection, event).is_none());
+
+        // outputs are not added twice, nor once the lock is finished
+        lock.add_output(&connection, WlOutput::from_id(4));
+        assert_eq!(lock.surfaces().len(), 1);
+        lock.handle_finished(ExtSessionLockV1FinishedEvent { source_id: lock.lock().id });
+        lock.add_output(&connection, WlOutput::from_id(5));
+        assert_eq!(lock.surfaces().len(), 1);
+    }
+}
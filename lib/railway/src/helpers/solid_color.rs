@@ -0,0 +1,84 @@
+use crate::connection::WaylandConnection;
+use crate::helpers::Viewport;
+use crate::types::{WlBuffer, WlSurface, WpSinglePixelBufferManagerV1, WpViewporter};
+
+/// Surface filled with a single color.
+///
+/// The content is a 1x1 buffer from `wp_single_pixel_buffer_manager_v1`
+/// stretched to the surface size by a viewport, so no shm memory has to
+/// be allocated or drawn. Color and size are applied on `commit`.
+pub struct SolidColor {
+    manager: WpSinglePixelBufferManagerV1,
+    viewport: Viewport,
+    buffer: Option<WlBuffer>,
+    replaced: Option<WlBuffer>,
+}
+
+impl SolidColor {
+
+    pub fn new(connection: &WaylandConnection, manager: WpSinglePixelBufferManagerV1, viewporter: WpViewporter, surface: WlSurface) -> Self {
+        SolidColor {
+            manager,
+            viewport: Viewport::new(connection, viewporter, surface),
+            buffer: None,
+            replaced: None,
+        }
+    }
+
+    pub fn surface(&self) -> WlSurface {
+        self.viewport.surface()
+    }
+
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Attaches a buffer of the given color, components are in 0..=1
+    /// without premultiplied alpha
+    pub fn set_color(&mut self, connection: &WaylandConnection, r: f64, g: f64, b: f64, a: f64) {
+        let a = a.clamp(0.0, 1.0);
+        let buffer = self.manager.create_u32_rgba_buffer(
+            connection,
+            channel(r * a),
+            channel(g * a),
+            channel(b * a),
+            channel(a),
+        );
+        self.surface().attach(connection, Some(buffer), 0, 0);
+        self.surface().damage_buffer(connection, 0, 0, 1, 1);
+
+        // the buffer shown until the next commit can only go after the commit
+        if let Some(pending) = self.buffer.replace(buffer) {
+            match self.replaced {
+                Some(_) => pending.destroy(connection),
+                None => self.replaced = Some(pending),
+            }
+        }
+    }
+
+    /// Surface size in surface-local coordinates
+    pub fn set_size(&self, connection: &WaylandConnection, width: i32, height: i32) {
+        self.viewport.set_destination(connection, width, height);
+    }
+
+    /// Commits the surface and destroys the buffer it replaced
+    pub fn commit(&mut self, connection: &WaylandConnection) {
+        self.surface().commit(connection);
+        if let Some(buffer) = self.replaced.take() {
+            buffer.destroy(connection);
+        }
+    }
+
+    /// Destroys the buffers and the viewport, the surface stays with the caller
+    pub fn destroy(self, connection: &WaylandConnection) {
+        for buffer in self.buffer.into_iter().chain(self.replaced) {
+            buffer.destroy(connection);
+        }
+        self.viewport.destroy(connection);
+    }
+}
+
+/// Maps a color component in 0..=1 to the full u32 range
+fn channel(value: f64) -> u32 {
+    (value.clamp(0.0, 1.0) * u32::MAX as f64).round() as u32
+}
@@ -0,0 +1,86 @@
+use crate::connection::WaylandConnection;
+use crate::types::split;
+use crate::types::{WpLinuxDrmSyncobjManagerV1, WpLinuxDrmSyncobjSurfaceV1, WpLinuxDrmSyncobjTimelineV1};
+
+use std::os::fd::RawFd;
+
+/// Acquire and release points set for a single surface commit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitPoints {
+    /// point the client signals once the buffer contents are ready
+    pub acquire: u64,
+    /// point the compositor signals once it no longer reads the buffer
+    pub release: u64,
+}
+
+/// DRM syncobj timeline shared with the compositor.
+///
+/// Every commit takes two new points on the timeline, one for acquire and
+/// one for release, so points only ever grow as the protocol requires.
+pub struct Timeline {
+    timeline: WpLinuxDrmSyncobjTimelineV1,
+    point: u64,
+}
+
+impl Timeline {
+
+    /// Imports the DRM syncobj timeline `fd` into the compositor.
+    ///
+    /// The descriptor is duplicated into the message by the next `send`,
+    /// it must stay open until then and remains owned by the caller.
+    pub fn import(connection: &WaylandConnection, manager: WpLinuxDrmSyncobjManagerV1, fd: RawFd) -> Self {
+        Timeline {
+            timeline: manager.import_timeline(connection, fd),
+            point: 0,
+        }
+    }
+
+    pub fn timeline(&self) -> WpLinuxDrmSyncobjTimelineV1 {
+        self.timeline
+    }
+
+    /// Last point handed out, 0 before the first commit
+    pub fn point(&self) -> u64 {
+        self.point
+    }
+
+    /// Takes the next pair of points and sets them on `surface`,
+    /// must be called before every `wl_surface.commit` with a new buffer
+    pub fn set_points(&mut self, connection: &WaylandConnection, surface: WpLinuxDrmSyncobjSurfaceV1) -> CommitPoints {
+        let points = CommitPoints {
+            acquire: self.point + 1,
+            release: self.point + 2,
+        };
+        self.point = points.release;
+
+        let (hi, lo) = split(points.acquire);
+        surface.set_acquire_point(connection, self.timeline, hi, lo);
+        let (hi, lo) = split(points.release);
+        surface.set_release_point(connection, self.timeline, hi, lo);
+
+        points
+    }
+
+    pub fn destroy(self, connection: &WaylandConnection) {
+        self.timeline.destroy(connection);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::testing;
+    use crate::types::Interface;
+
+    #[test]
+    fn points_grow_per_commit() {
+        let (connection, _peer) = testing::connection();
+        let mut timeline = Timeline::import(&connection, WpLinuxDrmSyncobjManagerV1::from_id(2), 0);
+        let surface = WpLinuxDrmSyncobjSurfaceV1::from_id(3);
+        assert_eq!(timeline.point(), 0);
+
+        assert_eq!(timeline.set_points(&connection, surface), CommitPoints { acquire: 1, release: 2 });
+        assert_eq!(timeline.set_points(&connection, surface), CommitPoints { acquire: 3, release: 4 });
+        assert_eq!(timeline.point(), 4);
+    }
+}
@@ -0,0 +1,458 @@
+use crate::connection::WaylandConnection;
+use crate::types::enums::{ZwpTabletToolV2ButtonState, ZwpTabletToolV2Capability, ZwpTabletToolV2Type};
+use crate::types::events::*;
+use crate::types::{join, Interface, WlSeat};
+use crate::types::{ZwpTabletManagerV2, ZwpTabletPadV2, ZwpTabletSeatV2, ZwpTabletToolV2, ZwpTabletV2};
+
+/// Largest value of the pressure and distance axes
+const AXIS_MAX: f64 = 65535.0;
+
+/// Description of a tool, complete once `done` was received
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TabletToolInfo {
+    pub tool_type: Option<ZwpTabletToolV2Type>,
+    /// serial unique to the physical tool, if the tablet reports one
+    pub hardware_serial: Option<u64>,
+    pub hardware_id_wacom: Option<u64>,
+    /// axes the tool reports besides the position
+    pub capabilities: Vec<ZwpTabletToolV2Capability>,
+}
+
+/// Tool entering the proximity of a tablet over one of our surfaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProximityIn {
+    pub serial: u32,
+    pub tablet: u32,
+    pub surface: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TabletToolButton {
+    pub serial: u32,
+    pub button: u32,
+    pub state: ZwpTabletToolV2ButtonState,
+}
+
+/// Tool state changes grouped by a `frame` event.
+///
+/// Axes that did not change in the frame are `None`. Pressure and distance
+/// are normalized to 0..=1, the slider to -1..=1.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TabletToolEvent {
+    pub tool: u32,
+    /// timestamp in milliseconds with an undefined base
+    pub time: u32,
+    pub proximity_in: Option<ProximityIn>,
+    pub proximity_out: bool,
+    /// serial of the tool touching the tablet
+    pub down: Option<u32>,
+    pub up: bool,
+    /// position in surface-local coordinates
+    pub position: Option<(f64, f64)>,
+    pub pressure: Option<f64>,
+    pub distance: Option<f64>,
+    /// tilt in degrees along the x and y axes
+    pub tilt: Option<(f64, f64)>,
+    /// rotation in degrees, clockwise from the tool's logical neutral position
+    pub rotation: Option<f64>,
+    pub slider: Option<f64>,
+    /// wheel rotation in degrees and discrete clicks
+    pub wheel: Option<(f64, i32)>,
+    pub buttons: Vec<TabletToolButton>,
+}
+
+/// Tool announced by the tablet seat
+pub struct TabletTool {
+    tool: ZwpTabletToolV2,
+    info: TabletToolInfo,
+    ready: bool,
+    pending: TabletToolEvent,
+}
+
+impl TabletTool {
+
+    pub fn tool(&self) -> ZwpTabletToolV2 {
+        self.tool
+    }
+
+    pub fn info(&self) -> &TabletToolInfo {
+        &self.info
+    }
+
+    /// Whether the description is complete
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+}
+
+/// Tablets, tools and pads of a seat.
+///
+/// All of them are created by the compositor and announced through the
+/// tablet seat. Tool axis events are collected per tool and returned as
+/// a single `TabletToolEvent` by `handle_tool_frame`. Pad groups, rings
+/// and strips are left to the caller.
+pub struct TabletSeat {
+    seat: ZwpTabletSeatV2,
+    tablets: Vec<ZwpTabletV2>,
+    tools: Vec<TabletTool>,
+    pads: Vec<ZwpTabletPadV2>,
+}
+
+impl TabletSeat {
+
+    pub fn new(connection: &WaylandConnection, manager: ZwpTabletManagerV2, seat: WlSeat) -> Self {
+        TabletSeat {
+            seat: manager.get_tablet_seat(connection, seat),
+            tablets: Vec::new(),
+            tools: Vec::new(),
+            pads: Vec::new(),
+        }
+    }
+
+    pub fn seat(&self) -> ZwpTabletSeatV2 {
+        self.seat
+    }
+
+    pub fn tablets(&self) -> &[ZwpTabletV2] {
+        &self.tablets
+    }
+
+    pub fn tools(&self) -> &[TabletTool] {
+        &self.tools
+    }
+
+    pub fn pads(&self) -> &[ZwpTabletPadV2] {
+        &self.pads
+    }
+
+    pub fn tool(&self, id: u32) -> Option<&TabletTool> {
+        self.tools.iter().find(|t| t.tool.id == id)
+    }
+
+    pub fn handle_tablet_added(&mut self, event: ZwpTabletSeatV2TabletAddedEvent) -> bool {
+        if event.source_id != self.seat.id {
+            return false;
+        }
+        self.tablets.push(ZwpTabletV2::from_id(event.id));
+        true
+    }
+
+    pub fn handle_tool_added(&mut self, event: ZwpTabletSeatV2ToolAddedEvent) -> bool {
+        if event.source_id != self.seat.id {
+            return false;
+        }
+        self.tools.push(TabletTool {
+            tool: ZwpTabletToolV2::from_id(event.id),
+            info: TabletToolInfo::default(),
+            ready: false,
+            pending: TabletToolEvent { tool: event.id, ..Default::default() },
+        });
+        true
+    }
+
+    pub fn handle_pad_added(&mut self, event: ZwpTabletSeatV2PadAddedEvent) -> bool {
+        if event.source_id != self.seat.id {
+            return false;
+        }
+        self.pads.push(ZwpTabletPadV2::from_id(event.id));
+        true
+    }
+
+    /// Destroys a tablet that was unplugged
+    pub fn handle_tablet_removed(&mut self, connection: &WaylandConnection, event: ZwpTabletV2RemovedEvent) -> bool {
+        let Some(index) = self.tablets.iter().position(|t| t.id == event.source_id) else {
+            return false;
+        };
+        let tablet = self.tablets.remove(index);
+        tablet.destroy(connection);
+        connection.delete_object(tablet.id);
+        true
+    }
+
+    /// Destroys a pad that was unplugged
+    pub fn handle_pad_removed(&mut self, connection: &WaylandConnection, event: ZwpTabletPadV2RemovedEvent) -> bool {
+        let Some(index) = self.pads.iter().position(|p| p.id == event.source_id) else {
+            return false;
+        };
+        let pad = self.pads.remove(index);
+        pad.destroy(connection);
+        connection.delete_object(pad.id);
+        true
+    }
+
+    /// Destroys a tool that will not be used anymore
+    pub fn handle_tool_removed(&mut self, connection: &WaylandConnection, event: ZwpTabletToolV2RemovedEvent) -> bool {
+        let Some(index) = self.tools.iter().position(|t| t.tool.id == event.source_id) else {
+            return false;
+        };
+        let tool = self.tools.remove(index);
+        tool.tool.destroy(connection);
+        connection.delete_object(tool.tool.id);
+        true
+    }
+
+    pub fn handle_tool_type(&mut self, event: ZwpTabletToolV2TypeEvent) -> bool {
+        let Some(tool) = self.tool_mut(event.source_id) else {
+            return false;
+        };
+        tool.info.tool_type = Some(event.tool_type);
+        true
+    }
+
+    pub fn handle_tool_hardware_serial(&mut self, event: ZwpTabletToolV2HardwareSerialEvent) -> bool {
+        let Some(tool) = self.tool_mut(event.source_id) else {
+            return false;
+        };
+        tool.info.hardware_serial = Some(join(event.hardware_serial_hi, event.hardware_serial_lo));
+        true
+    }
+
+    pub fn handle_tool_hardware_id_wacom(&mut self, event: ZwpTabletToolV2HardwareIdWacomEvent) -> bool {
+        let Some(tool) = self.tool_mut(event.source_id) else {
+            return false;
+        };
+        tool.info.hardware_id_wacom = Some(join(event.hardware_id_hi, event.hardware_id_lo));
+        true
+    }
+
+    pub fn handle_tool_capability(&mut self, event: ZwpTabletToolV2CapabilityEvent) -> bool {
+        let Some(tool) = self.tool_mut(event.source_id) else {
+            return false;
+        };
+        tool.info.capabilities.push(event.capability);
+        true
+    }
+
+    pub fn handle_tool_done(&mut self, event: ZwpTabletToolV2DoneEvent) -> bool {
+        let Some(tool) = self.tool_mut(event.source_id) else {
+            return false;
+        };
+        tool.ready = true;
+        true
+    }
+
+    pub fn handle_tool_proximity_in(&mut self, event: ZwpTabletToolV2ProximityInEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.proximity_in = Some(ProximityIn {
+            serial: event.serial,
+            tablet: event.tablet,
+            surface: event.surface,
+        });
+        true
+    }
+
+    pub fn handle_tool_proximity_out(&mut self, event: ZwpTabletToolV2ProximityOutEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.proximity_out = true;
+        true
+    }
+
+    pub fn handle_tool_down(&mut self, event: ZwpTabletToolV2DownEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.down = Some(event.serial);
+        true
+    }
+
+    pub fn handle_tool_up(&mut self, event: ZwpTabletToolV2UpEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.up = true;
+        true
+    }
+
+    pub fn handle_tool_motion(&mut self, event: ZwpTabletToolV2MotionEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.position = Some((event.x.to_f64(), event.y.to_f64()));
+        true
+    }
+
+    pub fn handle_tool_pressure(&mut self, event: ZwpTabletToolV2PressureEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.pressure = Some(event.pressure as f64 / AXIS_MAX);
+        true
+    }
+
+    pub fn handle_tool_distance(&mut self, event: ZwpTabletToolV2DistanceEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.distance = Some(event.distance as f64 / AXIS_MAX);
+        true
+    }
+
+    pub fn handle_tool_tilt(&mut self, event: ZwpTabletToolV2TiltEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.tilt = Some((event.tilt_x.to_f64(), event.tilt_y.to_f64()));
+        true
+    }
+
+    pub fn handle_tool_rotation(&mut self, event: ZwpTabletToolV2RotationEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.rotation = Some(event.degrees.to_f64());
+        true
+    }
+
+    pub fn handle_tool_slider(&mut self, event: ZwpTabletToolV2SliderEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.slider = Some(event.position as f64 / AXIS_MAX);
+        true
+    }
+
+    pub fn handle_tool_wheel(&mut self, event: ZwpTabletToolV2WheelEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.wheel = Some((event.degrees.to_f64(), event.clicks));
+        true
+    }
+
+    pub fn handle_tool_button(&mut self, event: ZwpTabletToolV2ButtonEvent) -> bool {
+        let Some(frame) = self.pending(event.source_id) else {
+            return false;
+        };
+        frame.buttons.push(TabletToolButton {
+            serial: event.serial,
+            button: event.button,
+            state: event.state,
+        });
+        true
+    }
+
+    /// Returns the changes collected since the previous frame,
+    /// `None` if the event came from an unknown tool
+    pub fn handle_tool_frame(&mut self, event: ZwpTabletToolV2FrameEvent) -> Option<TabletToolEvent> {
+        let frame = self.pending(event.source_id)?;
+        let mut update = std::mem::replace(frame, TabletToolEvent { tool: event.source_id, ..Default::default() });
+        update.time = event.time;
+        Some(update)
+    }
+
+    /// Destroys all tablets, tools and pads and the tablet seat
+    pub fn destroy(self, connection: &WaylandConnection) {
+        for tool in self.tools {
+            tool.tool.destroy(connection);
+            connection.delete_object(tool.tool.id);
+        }
+        for tablet in self.tablets {
+            tablet.destroy(connection);
+            connection.delete_object(tablet.id);
+        }
+        for pad in self.pads {
+            pad.destroy(connection);
+            connection.delete_object(pad.id);
+        }
+        self.seat.destroy(connection);
+    }
+
+    fn tool_mut(&mut self, id: u32) -> Option<&mut TabletTool> {
+        self.tools.iter_mut().find(|t| t.tool.id == id)
+    }
+
+    fn pending(&mut self, id: u32) -> Option<&mut TabletToolEvent> {
+        self.tool_mut(id).map(|t| &mut t.pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::testing;
+    use crate::types::Fixed;
+
+    const PEN: u32 = 0xff000000;
+    const ERASER: u32 = 0xff000001;
+
+    fn tablet_seat(connection: &WaylandConnection) -> TabletSeat {
+        let mut seat = TabletSeat::new(connection, ZwpTabletManagerV2::from_id(2), WlSeat::from_id(3));
+        for id in [PEN, ERASER] {
+            assert!(seat.handle_tool_added(ZwpTabletSeatV2ToolAddedEvent { source_id: seat.seat.id, id }));
+        }
+        seat
+    }
+
+    #[test]
+    fn frame_groups_tool_events() {
+        let (connection, _peer) = testing::connection();
+        let mut seat = tablet_seat(&connection);
+
+        seat.handle_tool_proximity_in(ZwpTabletToolV2ProximityInEvent { source_id: PEN, serial: 7, tablet: 0xff000002, surface: 4 });
+        seat.handle_tool_down(ZwpTabletToolV2DownEvent { source_id: PEN, serial: 8 });
+        seat.handle_tool_motion(ZwpTabletToolV2MotionEvent { source_id: PEN, x: Fixed::from_f64(10.5), y: Fixed::from_f64(20.0) });
+        seat.handle_tool_pressure(ZwpTabletToolV2PressureEvent { source_id: PEN, pressure: 65535 });
+        seat.handle_tool_distance(ZwpTabletToolV2DistanceEvent { source_id: PEN, distance: 0 });
+        seat.handle_tool_tilt(ZwpTabletToolV2TiltEvent { source_id: PEN, tilt_x: Fixed::from_f64(-30.0), tilt_y: Fixed::from_f64(15.25) });
+        seat.handle_tool_slider(ZwpTabletToolV2SliderEvent { source_id: PEN, position: -65535 });
+        seat.handle_tool_wheel(ZwpTabletToolV2WheelEvent { source_id: PEN, degrees: Fixed::from_f64(15.0), clicks: 1 });
+        seat.handle_tool_button(ZwpTabletToolV2ButtonEvent { source_id: PEN, serial: 9, button: 0x14b, state: ZwpTabletToolV2ButtonState::Pressed });
+        seat.handle_tool_motion(ZwpTabletToolV2MotionEvent { source_id: ERASER, x: Fixed::from_f64(1.0), y: Fixed::from_f64(2.0) });
+
+        let frame = seat.handle_tool_frame(ZwpTabletToolV2FrameEvent { source_id: PEN, time: 100 }).unwrap();
+        assert_eq!(frame, TabletToolEvent {
+            tool: PEN,
+            time: 100,
+            proximity_in: Some(ProximityIn { serial: 7, tablet: 0xff000002, surface: 4 }),
+            proximity_out: false,
+            down: Some(8),
+            up: false,
+            position: Some((10.5, 20.0)),
+            pressure: Some(1.0),
+            distance: Some(0.0),
+            tilt: Some((-30.0, 15.25)),
+            rotation: None,
+            slider: Some(-1.0),
+            wheel: Some((15.0, 1)),
+            buttons: vec![TabletToolButton { serial: 9, button: 0x14b, state: ZwpTabletToolV2ButtonState::Pressed }],
+        });
+
+        // the next frame starts empty, the other tool kept its own changes
+        let frame = seat.handle_tool_frame(ZwpTabletToolV2FrameEvent { source_id: PEN, time: 110 }).unwrap();
+        assert_eq!(frame, TabletToolEvent { tool: PEN, time: 110, ..Default::default() });
+        let frame = seat.handle_tool_frame(ZwpTabletToolV2FrameEvent { source_id: ERASER, time: 120 }).unwrap();
+        assert_eq!(frame.position, Some((1.0, 2.0)));
+        assert_eq!(frame.pressure, None);
+    }
+
+    #[test]
+    fn partial_axis_values() {
+        let (connection, _peer) = testing::connection();
+        let mut seat = tablet_seat(&connection);
+
+        seat.handle_tool_pressure(ZwpTabletToolV2PressureEvent { source_id: PEN, pressure: 13107 });
+        seat.handle_tool_slider(ZwpTabletToolV2SliderEvent { source_id: PEN, position: 32767 });
+        let frame = seat.handle_tool_frame(ZwpTabletToolV2FrameEvent { source_id: PEN, time: 0 }).unwrap();
+        assert_eq!(frame.pressure, Some(0.2));
+        assert!((frame.slider.unwrap() - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn removed_tool() {
+        let (connection, _peer) = testing::connection();
+        let mut seat = tablet_seat(&connection);
+
+        assert!(seat.handle_tool_removed(&connection, ZwpTabletToolV2RemovedEvent { source_id: PEN }));
+        assert_eq!(seat.tools().len(), 1);
+        assert!(seat.tool(PEN).is_none());
+
+        // events still in flight for the removed tool are not ours anymore
+        assert!(!seat.handle_tool_motion(ZwpTabletToolV2MotionEvent { source_id: PEN, x: Fixed::from_f64(0.0), y: Fixed::from_f64(0.0) }));
+        assert!(seat.handle_tool_frame(ZwpTabletToolV2FrameEvent { source_id: PEN, time: 0 }).is_none());
+        assert!(seat.handle_tool_frame(ZwpTabletToolV2FrameEvent { source_id: ERASER, time: 0 }).is_some());
+    }
+}
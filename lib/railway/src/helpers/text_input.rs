@@ -0,0 +1,212 @@
+use crate::connection::WaylandConnection;
+use crate::types::enums::{ZwpTextInputV3ContentHint, ZwpTextInputV3ContentPurpose};
+use crate::types::events::*;
+use crate::types::{WlSeat, ZwpTextInputManagerV3, ZwpTextInputV3};
+
+/// Text being composed by the input method, shown at the cursor
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Preedit {
+    pub text: String,
+    /// cursor position in bytes, -1 if the cursor should be hidden
+    pub cursor_begin: i32,
+    /// cursor end in bytes, equal to `cursor_begin` for a plain cursor
+    pub cursor_end: i32,
+}
+
+/// Bytes to delete around the cursor
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeleteSurrounding {
+    pub before_length: u32,
+    pub after_length: u32,
+}
+
+/// Changes delivered by the input method up to a `done` event.
+///
+/// They have to be applied in this order: replace the existing preedit
+/// with the cursor, delete the surrounding text, insert the commit string
+/// and move the cursor after it, then insert the new preedit.
+#[derive(Debug, Clone, Default)]
+pub struct TextInputUpdate {
+    /// new preedit, `None` removes the preedit
+    pub preedit: Option<Preedit>,
+    pub delete_surrounding: Option<DeleteSurrounding>,
+    pub commit: Option<String>,
+    /// false if the compositor has not seen all our commits yet, the
+    /// update still applies but the text input state must not be resent
+    pub in_sync: bool,
+}
+
+/// Double-buffered `zwp_text_input_v3` state.
+///
+/// Preedit, commit and delete events are collected until `done`, which
+/// yields them together as a `TextInputUpdate`. Commits are counted to
+/// tell whether a `done` answers the latest committed state.
+pub struct TextInput {
+    text_input: ZwpTextInputV3,
+    focus: Option<u32>,
+    pending: TextInputUpdate,
+    commits: u32,
+}
+
+impl TextInput {
+
+    pub fn new(connection: &WaylandConnection, manager: ZwpTextInputManagerV3, seat: WlSeat) -> Self {
+        TextInput {
+            text_input: manager.get_text_input(connection, seat),
+            focus: None,
+            pending: TextInputUpdate::default(),
+            commits: 0,
+        }
+    }
+
+    pub fn text_input(&self) -> ZwpTextInputV3 {
+        self.text_input
+    }
+
+    /// Surface with text input focus
+    pub fn focus(&self) -> Option<u32> {
+        self.focus
+    }
+
+    pub fn handle_enter(&mut self, event: ZwpTextInputV3EnterEvent) -> bool {
+        if event.source_id != self.text_input.id {
+            return false;
+        }
+        self.focus = Some(event.surface);
+        true
+    }
+
+    pub fn handle_leave(&mut self, event: ZwpTextInputV3LeaveEvent) -> bool {
+        if event.source_id != self.text_input.id {
+            return false;
+        }
+        self.focus = None;
+        true
+    }
+
+    pub fn handle_preedit_string(&mut self, event: ZwpTextInputV3PreeditStringEvent<'_>) -> bool {
+        if event.source_id != self.text_input.id {
+            return false;
+        }
+        self.pending.preedit = event.text.map(|text| Preedit {
+            text: text.into_owned(),
+            cursor_begin: event.cursor_begin,
+            cursor_end: event.cursor_end,
+        });
+        true
+    }
+
+    pub fn handle_commit_string(&mut self, event: ZwpTextInputV3CommitStringEvent<'_>) -> bool {
+        if event.source_id != self.text_input.id {
+            return false;
+        }
+        self.pending.commit = event.text.map(|text| text.into_owned());
+        true
+    }
+
+    pub fn handle_delete_surrounding_text(&mut self, event: ZwpTextInputV3DeleteSurroundingTextEvent) -> bool {
+        if event.source_id != self.text_input.id {
+            return false;
+        }
+        self.pending.delete_surrounding = Some(DeleteSurrounding {
+            before_length: event.before_length,
+            after_length: event.after_length,
+        });
+        true
+    }
+
+    /// Returns the changes collected since the previous `done`,
+    /// `None` if the event came from another text input
+    pub fn handle_done(&mut self, event: ZwpTextInputV3DoneEvent) -> Option<TextInputUpdate> {
+        if event.source_id != self.text_input.id {
+            return None;
+        }
+        let mut update = std::mem::take(&mut self.pending);
+        update.in_sync = event.serial == self.commits;
+        Some(update)
+    }
+
+    /// Starts text input on the focused surface, takes effect on `commit`
+    pub fn enable(&self, connection: &WaylandConnection) {
+        self.text_input.enable(connection);
+    }
+
+    /// Stops text input, takes effect on `commit`
+    pub fn disable(&self, connection: &WaylandConnection) {
+        self.text_input.disable(connection);
+    }
+
+    /// Text around the cursor, `cursor` and `anchor` are byte offsets in `text`
+    pub fn set_surrounding_text(&self, connection: &WaylandConnection, text: &str, cursor: i32, anchor: i32) {
+        self.text_input.set_surrounding_text(connection, text, cursor, anchor);
+    }
+
+    pub fn set_content_type(&self, connection: &WaylandConnection, hint: ZwpTextInputV3ContentHint, purpose: ZwpTextInputV3ContentPurpose) {
+        self.text_input.set_content_type(connection, hint.into(), purpose as u32);
+    }
+
+    /// Cursor area in surface-local coordinates, used to place the candidate window
+    pub fn set_cursor_rectangle(&self, connection: &WaylandConnection, x: i32, y: i32, width: i32, height: i32) {
+        self.text_input.set_cursor_rectangle(connection, x, y, width, height);
+    }
+
+    /// Applies the pending text input state
+    pub fn commit(&mut self, connection: &WaylandConnection) {
+        self.text_input.commit(connection);
+        self.commits = self.commits.wrapping_add(1);
+    }
+
+    pub fn destroy(self, connection: &WaylandConnection) {
+        self.text_input.destroy(connection);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::testing;
+    use crate::types::Interface;
+    use std::borrow::Cow;
+
+    #[test]
+    fn done_yields_pending_changes() {
+        let (connection, _peer) = testing::connection();
+        let mut input = TextInput::new(&connection, ZwpTextInputManagerV3::from_id(2), WlSeat::from_id(3));
+        let id = input.text_input().id;
+
+        input.handle_preedit_string(ZwpTextInputV3PreeditStringEvent { source_id: id, text: Some(Cow::Borrowed("ni")), cursor_begin: 2, cursor_end: 2 });
+        input.handle_delete_surrounding_text(ZwpTextInputV3DeleteSurroundingTextEvent { source_id: id, before_length: 1, after_length: 0 });
+        input.handle_commit_string(ZwpTextInputV3CommitStringEvent { source_id: id, text: Some(Cow::Borrowed("你")) });
+
+        let update = input.handle_done(ZwpTextInputV3DoneEvent { source_id: id, serial: 0 }).unwrap();
+        assert_eq!(update.preedit, Some(Preedit { text: "ni".into(), cursor_begin: 2, cursor_end: 2 }));
+        assert_eq!(update.delete_surrounding, Some(DeleteSurrounding { before_length: 1, after_length: 0 }));
+        assert_eq!(update.commit.as_deref(), Some("你"));
+        assert!(update.in_sync);
+
+        // nothing carries over into the next done, which removes the preedit
+        let update = input.handle_done(ZwpTextInputV3DoneEvent { source_id: id, serial: 0 }).unwrap();
+        assert_eq!(update.preedit, None);
+        assert_eq!(update.delete_surrounding, None);
+        assert_eq!(update.commit, None);
+
+        assert!(input.handle_done(ZwpTextInputV3DoneEvent { source_id: id + 1, serial: 0 }).is_none());
+    }
+
+    #[test]
+    fn serial_tracks_commits() {
+        let (connection, _peer) = testing::connection();
+        let mut input = TextInput::new(&connection, ZwpTextInputManagerV3::from_id(2), WlSeat::from_id(3));
+        let id = input.text_input().id;
+
+        input.enable(&connection);
+        input.commit(&connection);
+        input.commit(&connection);
+
+        // a done answering the first commit arrives after the second was sent
+        let update = input.handle_done(ZwpTextInputV3DoneEvent { source_id: id, serial: 1 }).unwrap();
+        assert!(!update.in_sync);
+        let update = input.handle_done(ZwpTextInputV3DoneEvent { source_id: id, serial: 2 }).unwrap();
+        assert!(update.in_sync);
+    }
+}
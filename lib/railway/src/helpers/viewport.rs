@@ -0,0 +1,64 @@
+use crate::connection::WaylandConnection;
+use crate::types::{Fixed, WlSurface, WpViewport, WpViewporter};
+
+/// Crop and scale state of a surface.
+///
+/// The source rectangle crops the attached buffer, the destination size
+/// scales the cropped content to surface-local coordinates. Both are
+/// double-buffered and applied on the next `wl_surface.commit`.
+pub struct Viewport {
+    viewport: WpViewport,
+    surface: WlSurface,
+}
+
+impl Viewport {
+
+    /// Creates the viewport, a surface may have only one viewport at a time
+    pub fn new(connection: &WaylandConnection, viewporter: WpViewporter, surface: WlSurface) -> Self {
+        Viewport {
+            viewport: viewporter.get_viewport(connection, surface),
+            surface,
+        }
+    }
+
+    pub fn viewport(&self) -> WpViewport {
+        self.viewport
+    }
+
+    pub fn surface(&self) -> WlSurface {
+        self.surface
+    }
+
+    /// Crops the buffer to the rectangle given in buffer coordinates
+    /// (after buffer transform and scale are applied)
+    pub fn set_source(&self, connection: &WaylandConnection, x: f64, y: f64, width: f64, height: f64) {
+        self.viewport.set_source(
+            connection,
+            Fixed::from_f64(x),
+            Fixed::from_f64(y),
+            Fixed::from_f64(width),
+            Fixed::from_f64(height),
+        );
+    }
+
+    /// Shows the whole buffer again
+    pub fn unset_source(&self, connection: &WaylandConnection) {
+        let unset = Fixed::from_f64(-1.0);
+        self.viewport.set_source(connection, unset, unset, unset, unset);
+    }
+
+    /// Scales the content to the given surface-local size
+    pub fn set_destination(&self, connection: &WaylandConnection, width: i32, height: i32) {
+        self.viewport.set_destination(connection, width, height);
+    }
+
+    /// Derives the surface size from the source rectangle or the buffer again
+    pub fn unset_destination(&self, connection: &WaylandConnection) {
+        self.viewport.set_destination(connection, -1, -1);
+    }
+
+    /// Destroys the viewport, crop and scale are removed on the next commit
+    pub fn destroy(self, connection: &WaylandConnection) {
+        self.viewport.destroy(connection);
+    }
+}
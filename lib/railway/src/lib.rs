@@ -2,6 +2,8 @@
 pub mod connection;
 pub mod types;
 pub mod renderer;
+/// Convenience wrappers around the protocol objects
+pub mod helpers;
 
 // https://wayland.app/protocols/linux-dmabuf-unstable-v1
 // TODO support WAYLAND_DEBUG env var
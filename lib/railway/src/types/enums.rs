@@ -54,6 +54,12 @@ impl From<u32> for ZwpLinuxBufferParamsV1Flags {
     }
 }
 
+impl From<ZwpLinuxBufferParamsV1Flags> for u32 {
+    fn from(value: ZwpLinuxBufferParamsV1Flags) -> Self {
+        value.value
+    }
+}
+
 impl ZwpLinuxBufferParamsV1Flags {
     /// contents are y-inverted
     pub const YINVERT:Self = ZwpLinuxBufferParamsV1Flags{ value: 1 };
@@ -117,6 +123,12 @@ impl From<u32> for ZwpLinuxDmabufFeedbackV1TrancheFlags {
     }
 }
 
+impl From<ZwpLinuxDmabufFeedbackV1TrancheFlags> for u32 {
+    fn from(value: ZwpLinuxDmabufFeedbackV1TrancheFlags) -> Self {
+        value.value
+    }
+}
+
 impl ZwpLinuxDmabufFeedbackV1TrancheFlags {
     /// direct scan-out tranche
     pub const SCANOUT:Self = ZwpLinuxDmabufFeedbackV1TrancheFlags{ value: 1 };
@@ -315,6 +327,12 @@ impl From<u32> for XdgPositionerConstraintAdjustment {
     }
 }
 
+impl From<XdgPositionerConstraintAdjustment> for u32 {
+    fn from(value: XdgPositionerConstraintAdjustment) -> Self {
+        value.value
+    }
+}
+
 impl XdgPositionerConstraintAdjustment {
     pub const NONE:Self = XdgPositionerConstraintAdjustment{ value: 0 };
     pub const SLIDEX:Self = XdgPositionerConstraintAdjustment{ value: 1 };
@@ -1067,6 +1085,12 @@ impl From<u32> for WlDataDeviceManagerDndAction {
     }
 }
 
+impl From<WlDataDeviceManagerDndAction> for u32 {
+    fn from(value: WlDataDeviceManagerDndAction) -> Self {
+        value.value
+    }
+}
+
 impl WlDataDeviceManagerDndAction {
     /// no action
     pub const NONE:Self = WlDataDeviceManagerDndAction{ value: 0 };
@@ -1156,6 +1180,12 @@ impl From<u32> for WlShellSurfaceResize {
     }
 }
 
+impl From<WlShellSurfaceResize> for u32 {
+    fn from(value: WlShellSurfaceResize) -> Self {
+        value.value
+    }
+}
+
 impl WlShellSurfaceResize {
     /// no edge
     pub const NONE:Self = WlShellSurfaceResize{ value: 0 };
@@ -1233,6 +1263,12 @@ impl From<u32> for WlShellSurfaceTransient {
     }
 }
 
+impl From<WlShellSurfaceTransient> for u32 {
+    fn from(value: WlShellSurfaceTransient) -> Self {
+        value.value
+    }
+}
+
 impl WlShellSurfaceTransient {
     /// do not set keyboard focus
     pub const INACTIVE:Self = WlShellSurfaceTransient{ value: 0x1 };
@@ -1359,6 +1395,12 @@ impl From<u32> for WlSeatCapability {
     }
 }
 
+impl From<WlSeatCapability> for u32 {
+    fn from(value: WlSeatCapability) -> Self {
+        value.value
+    }
+}
+
 impl WlSeatCapability {
     /// the seat has pointer devices
     pub const POINTER:Self = WlSeatCapability{ value: 1 };
@@ -1722,6 +1764,12 @@ impl From<u32> for WlOutputMode {
     }
 }
 
+impl From<WlOutputMode> for u32 {
+    fn from(value: WlOutputMode) -> Self {
+        value.value
+    }
+}
+
 impl WlOutputMode {
     /// indicates this is the current mode
     pub const CURRENT:Self = WlOutputMode{ value: 0x1 };
@@ -1813,3 +1861,1681 @@ impl From<u32> for WlSubsurfaceError {
         }
     }
 }
+
+/// zxdg_toplevel_decoration_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZxdgToplevelDecorationV1Error {
+    /// xdg_toplevel has a buffer attached before configure
+    UnconfiguredBuffer = 0u32,
+    /// xdg_toplevel already has a decoration object
+    AlreadyConstructed = 1u32,
+    /// xdg_toplevel destroyed before the decoration object
+    Orphaned = 2u32,
+    /// invalid mode
+    InvalidMode = 3u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZxdgToplevelDecorationV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::UnconfiguredBuffer,
+            1 => Self::AlreadyConstructed,
+            2 => Self::Orphaned,
+            3 => Self::InvalidMode,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zxdg_toplevel_decoration_v1:mode enum
+/// These values describe window decoration modes.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZxdgToplevelDecorationV1Mode {
+    /// no server-side window decoration
+    ClientSide = 1u32,
+    /// server-side window decoration
+    ServerSide = 2u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZxdgToplevelDecorationV1Mode {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::ClientSide,
+            2 => Self::ServerSide,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_viewporter:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpViewporterError {
+    /// the surface already has a viewport object associated
+    ViewportExists = 0u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpViewporterError {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::ViewportExists,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_viewport:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpViewportError {
+    /// negative or zero values in width or height
+    BadValue = 0u32,
+    /// destination size is not integer
+    BadSize = 1u32,
+    /// source rectangle extends outside of the content area
+    OutOfBuffer = 2u32,
+    /// the wl_surface was destroyed
+    NoSurface = 3u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpViewportError {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::BadValue,
+            1 => Self::BadSize,
+            2 => Self::OutOfBuffer,
+            3 => Self::NoSurface,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_fractional_scale_manager_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpFractionalScaleManagerV1Error {
+    /// the surface already has a fractional_scale object associated
+    FractionalScaleExists = 0u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpFractionalScaleManagerV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::FractionalScaleExists,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_presentation:error enum
+/// These fatal protocol errors may be emitted in response to
+/// illegal presentation requests.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpPresentationError {
+    /// invalid value in tv_nsec
+    InvalidTimestamp = 0u32,
+    /// invalid flag
+    InvalidFlag = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpPresentationError {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::InvalidTimestamp,
+            1 => Self::InvalidFlag,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_presentation_feedback:kind enum
+/// These flags provide information about how the presentation of
+/// the related content update was done. The intent is to help
+/// clients assess the reliability of the feedback and the visual
+/// quality with respect to possible tearing and timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WpPresentationFeedbackKind {
+    value: u32,
+}
+
+impl From<u32> for WpPresentationFeedbackKind {
+    fn from(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<WpPresentationFeedbackKind> for u32 {
+    fn from(value: WpPresentationFeedbackKind) -> Self {
+        value.value
+    }
+}
+
+impl WpPresentationFeedbackKind {
+    /// presentation was vsync'd
+    pub const VSYNC:Self = WpPresentationFeedbackKind{ value: 0x1 };
+    /// hardware provided the presentation timestamp
+    pub const HWCLOCK:Self = WpPresentationFeedbackKind{ value: 0x2 };
+    /// hardware signalled the start of the presentation
+    pub const HWCOMPLETION:Self = WpPresentationFeedbackKind{ value: 0x4 };
+    /// presentation was done zero-copy
+    pub const ZEROCOPY:Self = WpPresentationFeedbackKind{ value: 0x8 };
+
+    pub fn new() -> Self {
+        WpPresentationFeedbackKind { value: 0 }
+    }
+
+    pub fn contains(&self, flag: Self) -> bool {
+        self.value & flag.value != 0
+    }
+
+    pub fn insert(&mut self, flag: Self) {
+        self.value |= flag.value;
+    }
+
+    pub fn remove(&mut self, flag: Self) {
+        self.value &= !flag.value;
+    }
+}
+
+impl BitAnd for WpPresentationFeedbackKind {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitOr for WpPresentationFeedbackKind {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign for WpPresentationFeedbackKind {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.value |= rhs.value;
+    }
+}
+
+/// zwp_linux_explicit_synchronization_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpLinuxExplicitSynchronizationV1Error {
+    /// the surface already has a synchronization object associated
+    SynchronizationExists = 0u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpLinuxExplicitSynchronizationV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::SynchronizationExists,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_linux_surface_synchronization_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpLinuxSurfaceSynchronizationV1Error {
+    /// the fence specified by the client could not be imported
+    InvalidFence = 0u32,
+    /// multiple fences added for a single surface commit
+    DuplicateFence = 1u32,
+    /// multiple releases added for a single surface commit
+    DuplicateRelease = 2u32,
+    /// the associated wl_surface was destroyed
+    NoSurface = 3u32,
+    /// the buffer does not support explicit synchronization
+    UnsupportedBuffer = 4u32,
+    /// no buffer was attached
+    NoBuffer = 5u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpLinuxSurfaceSynchronizationV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::InvalidFence,
+            1 => Self::DuplicateFence,
+            2 => Self::DuplicateRelease,
+            3 => Self::NoSurface,
+            4 => Self::UnsupportedBuffer,
+            5 => Self::NoBuffer,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_linux_drm_syncobj_manager_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpLinuxDrmSyncobjManagerV1Error {
+    /// the surface already has a synchronization object associated
+    SurfaceExists = 0u32,
+    /// the timeline object could not be imported
+    InvalidTimeline = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpLinuxDrmSyncobjManagerV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::SurfaceExists,
+            1 => Self::InvalidTimeline,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_linux_drm_syncobj_surface_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpLinuxDrmSyncobjSurfaceV1Error {
+    /// the associated wl_surface was destroyed
+    NoSurface = 1u32,
+    /// the buffer does not support explicit synchronization
+    UnsupportedBuffer = 2u32,
+    /// no buffer was attached
+    NoBuffer = 3u32,
+    /// no acquire timeline point was set
+    NoAcquirePoint = 4u32,
+    /// no release timeline point was set
+    NoReleasePoint = 5u32,
+    /// acquire and release timeline points are in conflict
+    ConflictingPoints = 6u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpLinuxDrmSyncobjSurfaceV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::NoSurface,
+            2 => Self::UnsupportedBuffer,
+            3 => Self::NoBuffer,
+            4 => Self::NoAcquirePoint,
+            5 => Self::NoReleasePoint,
+            6 => Self::ConflictingPoints,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// xdg_activation_token_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XdgActivationTokenV1Error {
+    /// The token has already been used previously
+    AlreadyUsed = 0u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for XdgActivationTokenV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::AlreadyUsed,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_cursor_shape_device_v1:shape enum
+/// This enum describes cursor shapes.
+///
+/// The names are taken from the CSS W3C specification:
+/// https://w3c.github.io/csswg-drafts/css-ui/#cursor
+/// with a few additions.
+///
+/// Note that there are some groups of cursor shapes that are related:
+/// The first group is drag-and-drop cursors which are used to indicate
+/// the selected action during dnd operations. The second group is resize
+/// cursors which are used to indicate resizing and moving possibilities
+/// on window borders. It is recommended that the shapes in these groups
+/// should use visually compatible images and metaphors.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpCursorShapeDeviceV1Shape {
+    /// default cursor
+    Default = 1u32,
+    /// a context menu is available for the object under the cursor
+    ContextMenu = 2u32,
+    /// help is available for the object under the cursor
+    Help = 3u32,
+    /// pointer that indicates a link or another interactive element
+    Pointer = 4u32,
+    /// progress indicator
+    Progress = 5u32,
+    /// program is busy, user should wait
+    Wait = 6u32,
+    /// a cell or set of cells may be selected
+    Cell = 7u32,
+    /// simple crosshair
+    Crosshair = 8u32,
+    /// text may be selected
+    Text = 9u32,
+    /// vertical text may be selected
+    VerticalText = 10u32,
+    /// drag-and-drop: alias of/shortcut to something is to be created
+    Alias = 11u32,
+    /// drag-and-drop: something is to be copied
+    Copy = 12u32,
+    /// drag-and-drop: something is to be moved
+    Move = 13u32,
+    /// drag-and-drop: the dragged item cannot be dropped at the current cursor location
+    NoDrop = 14u32,
+    /// drag-and-drop: the requested action will not be carried out
+    NotAllowed = 15u32,
+    /// drag-and-drop: something can be grabbed
+    Grab = 16u32,
+    /// drag-and-drop: something is being grabbed
+    Grabbing = 17u32,
+    /// resizing: the east border is to be moved
+    EResize = 18u32,
+    /// resizing: the north border is to be moved
+    NResize = 19u32,
+    /// resizing: the north-east corner is to be moved
+    NeResize = 20u32,
+    /// resizing: the north-west corner is to be moved
+    NwResize = 21u32,
+    /// resizing: the south border is to be moved
+    SResize = 22u32,
+    /// resizing: the south-east corner is to be moved
+    SeResize = 23u32,
+    /// resizing: the south-west corner is to be moved
+    SwResize = 24u32,
+    /// resizing: the west border is to be moved
+    WResize = 25u32,
+    /// resizing: the east and west borders are to be moved
+    EwResize = 26u32,
+    /// resizing: the north and south borders are to be moved
+    NsResize = 27u32,
+    /// resizing: the north-east and south-west corners are to be moved
+    NeswResize = 28u32,
+    /// resizing: the north-west and south-east corners are to be moved
+    NwseResize = 29u32,
+    /// resizing: that the item/column can be resized horizontally
+    ColResize = 30u32,
+    /// resizing: that the item/row can be resized vertically
+    RowResize = 31u32,
+    /// something can be scrolled in any direction
+    AllScroll = 32u32,
+    /// something can be zoomed in
+    ZoomIn = 33u32,
+    /// something can be zoomed out
+    ZoomOut = 34u32,
+    /// drag-and-drop: the user will select which action will be carried out (non-css value)
+    DndAsk = 35u32,
+    /// resizing: something can be moved or resized in any direction (non-css value)
+    AllResize = 36u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpCursorShapeDeviceV1Shape {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Default,
+            2 => Self::ContextMenu,
+            3 => Self::Help,
+            4 => Self::Pointer,
+            5 => Self::Progress,
+            6 => Self::Wait,
+            7 => Self::Cell,
+            8 => Self::Crosshair,
+            9 => Self::Text,
+            10 => Self::VerticalText,
+            11 => Self::Alias,
+            12 => Self::Copy,
+            13 => Self::Move,
+            14 => Self::NoDrop,
+            15 => Self::NotAllowed,
+            16 => Self::Grab,
+            17 => Self::Grabbing,
+            18 => Self::EResize,
+            19 => Self::NResize,
+            20 => Self::NeResize,
+            21 => Self::NwResize,
+            22 => Self::SResize,
+            23 => Self::SeResize,
+            24 => Self::SwResize,
+            25 => Self::WResize,
+            26 => Self::EwResize,
+            27 => Self::NsResize,
+            28 => Self::NeswResize,
+            29 => Self::NwseResize,
+            30 => Self::ColResize,
+            31 => Self::RowResize,
+            32 => Self::AllScroll,
+            33 => Self::ZoomIn,
+            34 => Self::ZoomOut,
+            35 => Self::DndAsk,
+            36 => Self::AllResize,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_cursor_shape_device_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpCursorShapeDeviceV1Error {
+    /// the specified shape value is invalid
+    InvalidShape = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpCursorShapeDeviceV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::InvalidShape,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_text_input_v3:change_cause enum
+/// Reason for the change of surrounding text or cursor posision.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTextInputV3ChangeCause {
+    /// input method caused the change
+    InputMethod = 0u32,
+    /// something else than the input method caused the change
+    Other = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTextInputV3ChangeCause {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::InputMethod,
+            1 => Self::Other,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_text_input_v3:content_hint enum
+/// Content hint is a bitmask to allow to modify the behavior of the text
+/// input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZwpTextInputV3ContentHint {
+    value: u32,
+}
+
+impl From<u32> for ZwpTextInputV3ContentHint {
+    fn from(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<ZwpTextInputV3ContentHint> for u32 {
+    fn from(value: ZwpTextInputV3ContentHint) -> Self {
+        value.value
+    }
+}
+
+impl ZwpTextInputV3ContentHint {
+    /// no special behavior
+    pub const NONE:Self = ZwpTextInputV3ContentHint{ value: 0x0 };
+    /// suggest word completions
+    pub const COMPLETION:Self = ZwpTextInputV3ContentHint{ value: 0x1 };
+    /// suggest word corrections
+    pub const SPELLCHECK:Self = ZwpTextInputV3ContentHint{ value: 0x2 };
+    /// switch to uppercase letters at the start of a sentence
+    pub const AUTOCAPITALIZATION:Self = ZwpTextInputV3ContentHint{ value: 0x4 };
+    /// prefer lowercase letters
+    pub const LOWERCASE:Self = ZwpTextInputV3ContentHint{ value: 0x8 };
+    /// prefer uppercase letters
+    pub const UPPERCASE:Self = ZwpTextInputV3ContentHint{ value: 0x10 };
+    /// prefer casing for titles and headings (can be language dependent)
+    pub const TITLECASE:Self = ZwpTextInputV3ContentHint{ value: 0x20 };
+    /// characters should be hidden
+    pub const HIDDENTEXT:Self = ZwpTextInputV3ContentHint{ value: 0x40 };
+    /// typed text should not be stored
+    pub const SENSITIVEDATA:Self = ZwpTextInputV3ContentHint{ value: 0x80 };
+    /// just Latin characters should be entered
+    pub const LATIN:Self = ZwpTextInputV3ContentHint{ value: 0x100 };
+    /// the text input is multiline
+    pub const MULTILINE:Self = ZwpTextInputV3ContentHint{ value: 0x200 };
+    /// an on-screen way to fill in the input is already provided by the client
+    pub const ONSCREENINPUTPROVIDED:Self = ZwpTextInputV3ContentHint{ value: 0x400 };
+    /// prefer not offering emoji support
+    pub const NOEMOJI:Self = ZwpTextInputV3ContentHint{ value: 0x800 };
+    /// the text input will display preedit text in place
+    pub const PREEDITSHOWN:Self = ZwpTextInputV3ContentHint{ value: 0x1000 };
+
+    pub fn new() -> Self {
+        ZwpTextInputV3ContentHint { value: 0 }
+    }
+
+    pub fn contains(&self, flag: Self) -> bool {
+        self.value & flag.value != 0
+    }
+
+    pub fn insert(&mut self, flag: Self) {
+        self.value |= flag.value;
+    }
+
+    pub fn remove(&mut self, flag: Self) {
+        self.value &= !flag.value;
+    }
+}
+
+impl BitAnd for ZwpTextInputV3ContentHint {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitOr for ZwpTextInputV3ContentHint {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign for ZwpTextInputV3ContentHint {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.value |= rhs.value;
+    }
+}
+
+/// zwp_text_input_v3:content_purpose enum
+/// The content purpose allows to specify the primary purpose of a text
+/// input.
+///
+/// This allows an input method to show special purpose input panels with
+/// extra characters or to disallow some characters.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTextInputV3ContentPurpose {
+    /// default input, allowing all characters
+    Normal = 0u32,
+    /// allow only alphabetic characters
+    Alpha = 1u32,
+    /// allow only digits
+    Digits = 2u32,
+    /// input a number (including decimal separator and sign)
+    Number = 3u32,
+    /// input a phone number
+    Phone = 4u32,
+    /// input an URL
+    Url = 5u32,
+    /// input an email address
+    Email = 6u32,
+    /// input a name of a person
+    Name = 7u32,
+    /// input a password (combine with sensitive_data hint)
+    Password = 8u32,
+    /// input is a numeric password (combine with sensitive_data hint)
+    Pin = 9u32,
+    /// input a date
+    Date = 10u32,
+    /// input a time
+    Time = 11u32,
+    /// input a date and time
+    Datetime = 12u32,
+    /// input for a terminal
+    Terminal = 13u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTextInputV3ContentPurpose {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Normal,
+            1 => Self::Alpha,
+            2 => Self::Digits,
+            3 => Self::Number,
+            4 => Self::Phone,
+            5 => Self::Url,
+            6 => Self::Email,
+            7 => Self::Name,
+            8 => Self::Password,
+            9 => Self::Pin,
+            10 => Self::Date,
+            11 => Self::Time,
+            12 => Self::Datetime,
+            13 => Self::Terminal,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_text_input_v3:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTextInputV3Error {
+    /// an invalid or duplicate action was specified
+    InvalidAction = 0u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTextInputV3Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::InvalidAction,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_text_input_v3:action enum
+/// A possible action to perform on a text input.
+///
+/// The submit action is intended for input entries that expect some sort of
+/// activation after user interaction, e.g. the URL entry in a browser.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTextInputV3Action {
+    /// no action
+    None = 0u32,
+    /// the action is submitted
+    Submit = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTextInputV3Action {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Submit,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_text_input_v3:preedit_hint enum
+/// Style hints for the preedit string.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTextInputV3PreeditHint {
+    /// simple pre-edit text style, typically underlined
+    Whole = 1u32,
+    /// hint for a selected piece of text, e.g. per-character navigation and composition
+    Selection = 2u32,
+    /// predicted text, not typed by the user
+    Prediction = 3u32,
+    /// prefixed text not being currently edited, e.g. prior to a 'selection' section
+    Prefix = 4u32,
+    /// suffixed text not being currently edited, e.g. after a 'selection' section
+    Suffix = 5u32,
+    /// spelling error
+    SpellingError = 6u32,
+    /// wrong composition, e.g. user input that can not be transliterated
+    ComposeError = 7u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTextInputV3PreeditHint {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Whole,
+            2 => Self::Selection,
+            3 => Self::Prediction,
+            4 => Self::Prefix,
+            5 => Self::Suffix,
+            6 => Self::SpellingError,
+            7 => Self::ComposeError,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_pointer_constraints_v1:error enum
+/// These errors can be emitted in response to wp_pointer_constraints
+/// requests.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpPointerConstraintsV1Error {
+    /// pointer constraint already requested on that surface
+    AlreadyConstrained = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpPointerConstraintsV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::AlreadyConstrained,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_pointer_constraints_v1:lifetime enum
+/// These values represent different lifetime semantics. They are passed
+/// as arguments to the factory requests to specify how the constraint
+/// lifetimes should be managed.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpPointerConstraintsV1Lifetime {
+    /// the pointer constraint is defunct once deactivated
+    Oneshot = 1u32,
+    /// the pointer constraint may reactivate
+    Persistent = 2u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpPointerConstraintsV1Lifetime {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Oneshot,
+            2 => Self::Persistent,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwlr_layer_shell_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwlrLayerShellV1Error {
+    /// wl_surface has another role
+    Role = 0u32,
+    /// layer value is invalid
+    InvalidLayer = 1u32,
+    /// wl_surface has a buffer attached or committed
+    AlreadyConstructed = 2u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwlrLayerShellV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Role,
+            1 => Self::InvalidLayer,
+            2 => Self::AlreadyConstructed,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwlr_layer_shell_v1:layer enum
+/// These values indicate which layers a surface can be rendered in. They
+/// are ordered by z depth, bottom-most first. Traditional shell surfaces
+/// will typically be rendered between the bottom and top layers.
+/// Fullscreen shell surfaces are typically rendered at the top layer.
+/// Multiple surfaces can share a single layer, and ordering within a
+/// single layer is undefined.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwlrLayerShellV1Layer {
+    Background = 0u32,
+    Bottom = 1u32,
+    Top = 2u32,
+    Overlay = 3u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwlrLayerShellV1Layer {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Background,
+            1 => Self::Bottom,
+            2 => Self::Top,
+            3 => Self::Overlay,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwlr_layer_surface_v1:keyboard_interactivity enum
+/// Types of keyboard interaction possible for layer shell surfaces. The
+/// rationale for this is twofold: (1) some applications are not interested
+/// in keyboard events and not allowing them to be focused can improve the
+/// desktop experience; (2) some applications will want to take exclusive
+/// keyboard focus.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwlrLayerSurfaceV1KeyboardInteractivity {
+    /// no keyboard focus is possible
+    None = 0u32,
+    /// request exclusive keyboard focus
+    Exclusive = 1u32,
+    /// request regular keyboard focus semantics
+    OnDemand = 2u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwlrLayerSurfaceV1KeyboardInteractivity {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Exclusive,
+            2 => Self::OnDemand,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwlr_layer_surface_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwlrLayerSurfaceV1Error {
+    /// provided surface state is invalid
+    InvalidSurfaceState = 0u32,
+    /// size is invalid
+    InvalidSize = 1u32,
+    /// anchor bitfield is invalid
+    InvalidAnchor = 2u32,
+    /// keyboard interactivity is invalid
+    InvalidKeyboardInteractivity = 3u32,
+    /// exclusive edge is invalid given the surface anchors
+    InvalidExclusiveEdge = 4u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwlrLayerSurfaceV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::InvalidSurfaceState,
+            1 => Self::InvalidSize,
+            2 => Self::InvalidAnchor,
+            3 => Self::InvalidKeyboardInteractivity,
+            4 => Self::InvalidExclusiveEdge,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwlr_layer_surface_v1:anchor enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZwlrLayerSurfaceV1Anchor {
+    value: u32,
+}
+
+impl From<u32> for ZwlrLayerSurfaceV1Anchor {
+    fn from(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<ZwlrLayerSurfaceV1Anchor> for u32 {
+    fn from(value: ZwlrLayerSurfaceV1Anchor) -> Self {
+        value.value
+    }
+}
+
+impl ZwlrLayerSurfaceV1Anchor {
+    /// the top edge of the anchor rectangle
+    pub const TOP:Self = ZwlrLayerSurfaceV1Anchor{ value: 1 };
+    /// the bottom edge of the anchor rectangle
+    pub const BOTTOM:Self = ZwlrLayerSurfaceV1Anchor{ value: 2 };
+    /// the left edge of the anchor rectangle
+    pub const LEFT:Self = ZwlrLayerSurfaceV1Anchor{ value: 4 };
+    /// the right edge of the anchor rectangle
+    pub const RIGHT:Self = ZwlrLayerSurfaceV1Anchor{ value: 8 };
+
+    pub fn new() -> Self {
+        ZwlrLayerSurfaceV1Anchor { value: 0 }
+    }
+
+    pub fn contains(&self, flag: Self) -> bool {
+        self.value & flag.value != 0
+    }
+
+    pub fn insert(&mut self, flag: Self) {
+        self.value |= flag.value;
+    }
+
+    pub fn remove(&mut self, flag: Self) {
+        self.value &= !flag.value;
+    }
+}
+
+impl BitAnd for ZwlrLayerSurfaceV1Anchor {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitOr for ZwlrLayerSurfaceV1Anchor {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign for ZwlrLayerSurfaceV1Anchor {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.value |= rhs.value;
+    }
+}
+
+/// ext_session_lock_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtSessionLockV1Error {
+    /// attempted to destroy session lock while locked
+    InvalidDestroy = 0u32,
+    /// unlock requested but locked event was never sent
+    InvalidUnlock = 1u32,
+    /// given wl_surface already has a role
+    Role = 2u32,
+    /// given output already has a lock surface
+    DuplicateOutput = 3u32,
+    /// given wl_surface has a buffer attached or committed
+    AlreadyConstructed = 4u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ExtSessionLockV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::InvalidDestroy,
+            1 => Self::InvalidUnlock,
+            2 => Self::Role,
+            3 => Self::DuplicateOutput,
+            4 => Self::AlreadyConstructed,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// ext_session_lock_surface_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtSessionLockSurfaceV1Error {
+    /// surface committed before first ack_configure request
+    CommitBeforeFirstAck = 0u32,
+    /// surface committed with a null buffer
+    NullBuffer = 1u32,
+    /// failed to match ack'd width/height
+    DimensionsMismatch = 2u32,
+    /// serial provided in ack_configure is invalid
+    InvalidSerial = 3u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ExtSessionLockSurfaceV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::CommitBeforeFirstAck,
+            1 => Self::NullBuffer,
+            2 => Self::DimensionsMismatch,
+            3 => Self::InvalidSerial,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// ext_image_copy_capture_manager_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtImageCopyCaptureManagerV1Error {
+    /// invalid option flag
+    InvalidOption = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ExtImageCopyCaptureManagerV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::InvalidOption,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// ext_image_copy_capture_manager_v1:options enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtImageCopyCaptureManagerV1Options {
+    value: u32,
+}
+
+impl From<u32> for ExtImageCopyCaptureManagerV1Options {
+    fn from(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<ExtImageCopyCaptureManagerV1Options> for u32 {
+    fn from(value: ExtImageCopyCaptureManagerV1Options) -> Self {
+        value.value
+    }
+}
+
+impl ExtImageCopyCaptureManagerV1Options {
+    /// paint cursors onto captured frames
+    pub const PAINTCURSORS:Self = ExtImageCopyCaptureManagerV1Options{ value: 1 };
+
+    pub fn new() -> Self {
+        ExtImageCopyCaptureManagerV1Options { value: 0 }
+    }
+
+    pub fn contains(&self, flag: Self) -> bool {
+        self.value & flag.value != 0
+    }
+
+    pub fn insert(&mut self, flag: Self) {
+        self.value |= flag.value;
+    }
+
+    pub fn remove(&mut self, flag: Self) {
+        self.value &= !flag.value;
+    }
+}
+
+impl BitAnd for ExtImageCopyCaptureManagerV1Options {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitOr for ExtImageCopyCaptureManagerV1Options {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign for ExtImageCopyCaptureManagerV1Options {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.value |= rhs.value;
+    }
+}
+
+/// ext_image_copy_capture_session_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtImageCopyCaptureSessionV1Error {
+    /// create_frame sent before destroying previous frame
+    DuplicateFrame = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ExtImageCopyCaptureSessionV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::DuplicateFrame,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// ext_image_copy_capture_frame_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtImageCopyCaptureFrameV1Error {
+    /// capture sent without attach_buffer
+    NoBuffer = 1u32,
+    /// invalid buffer damage
+    InvalidBufferDamage = 2u32,
+    /// capture request has been sent
+    AlreadyCaptured = 3u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ExtImageCopyCaptureFrameV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::NoBuffer,
+            2 => Self::InvalidBufferDamage,
+            3 => Self::AlreadyCaptured,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// ext_image_copy_capture_frame_v1:failure_reason enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtImageCopyCaptureFrameV1FailureReason {
+    /// unknown runtime error
+    Unknown = 0u32,
+    /// buffer constraints mismatch
+    BufferConstraints = 1u32,
+    /// session is no longer available
+    Stopped = 2u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ExtImageCopyCaptureFrameV1FailureReason {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Unknown,
+            1 => Self::BufferConstraints,
+            2 => Self::Stopped,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// ext_image_copy_capture_cursor_session_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtImageCopyCaptureCursorSessionV1Error {
+    /// get_capture_session sent twice
+    DuplicateSession = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ExtImageCopyCaptureCursorSessionV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::DuplicateSession,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwlr_screencopy_frame_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwlrScreencopyFrameV1Error {
+    /// the object has already been used to copy a wl_buffer
+    AlreadyUsed = 0u32,
+    /// buffer attributes are invalid
+    InvalidBuffer = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwlrScreencopyFrameV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::AlreadyUsed,
+            1 => Self::InvalidBuffer,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwlr_screencopy_frame_v1:flags enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZwlrScreencopyFrameV1Flags {
+    value: u32,
+}
+
+impl From<u32> for ZwlrScreencopyFrameV1Flags {
+    fn from(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<ZwlrScreencopyFrameV1Flags> for u32 {
+    fn from(value: ZwlrScreencopyFrameV1Flags) -> Self {
+        value.value
+    }
+}
+
+impl ZwlrScreencopyFrameV1Flags {
+    /// contents are y-inverted
+    pub const YINVERT:Self = ZwlrScreencopyFrameV1Flags{ value: 1 };
+
+    pub fn new() -> Self {
+        ZwlrScreencopyFrameV1Flags { value: 0 }
+    }
+
+    pub fn contains(&self, flag: Self) -> bool {
+        self.value & flag.value != 0
+    }
+
+    pub fn insert(&mut self, flag: Self) {
+        self.value |= flag.value;
+    }
+
+    pub fn remove(&mut self, flag: Self) {
+        self.value &= !flag.value;
+    }
+}
+
+impl BitAnd for ZwlrScreencopyFrameV1Flags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitOr for ZwlrScreencopyFrameV1Flags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign for ZwlrScreencopyFrameV1Flags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.value |= rhs.value;
+    }
+}
+
+/// zwp_tablet_tool_v2:type enum
+/// Describes the physical type of a tool. The physical type of a tool
+/// generally defines its base usage.
+///
+/// The mouse tool represents a mouse-shaped tool that is not a relative
+/// device but bound to the tablet's surface, providing absolute
+/// coordinates.
+///
+/// The lens tool is a mouse-shaped tool with an attached lens to
+/// provide precision focus.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTabletToolV2Type {
+    /// Pen
+    Pen = 0x140u32,
+    /// Eraser
+    Eraser = 0x141u32,
+    /// Brush
+    Brush = 0x142u32,
+    /// Pencil
+    Pencil = 0x143u32,
+    /// Airbrush
+    Airbrush = 0x144u32,
+    /// Finger
+    Finger = 0x145u32,
+    /// Mouse
+    Mouse = 0x146u32,
+    /// Lens
+    Lens = 0x147u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTabletToolV2Type {
+    fn from(value: u32) -> Self {
+        match value {
+            0x140 => Self::Pen,
+            0x141 => Self::Eraser,
+            0x142 => Self::Brush,
+            0x143 => Self::Pencil,
+            0x144 => Self::Airbrush,
+            0x145 => Self::Finger,
+            0x146 => Self::Mouse,
+            0x147 => Self::Lens,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_tablet_tool_v2:capability enum
+/// Describes extra capabilities on a tablet.
+///
+/// Any tool must provide x and y values, extra axes are
+/// device-specific.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTabletToolV2Capability {
+    /// Tilt axes
+    Tilt = 1u32,
+    /// Pressure axis
+    Pressure = 2u32,
+    /// Distance axis
+    Distance = 3u32,
+    /// Z-rotation axis
+    Rotation = 4u32,
+    /// Slider axis
+    Slider = 5u32,
+    /// Wheel axis
+    Wheel = 6u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTabletToolV2Capability {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Tilt,
+            2 => Self::Pressure,
+            3 => Self::Distance,
+            4 => Self::Rotation,
+            5 => Self::Slider,
+            6 => Self::Wheel,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_tablet_tool_v2:button_state enum
+/// Describes the physical state of a button that produced the button event.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTabletToolV2ButtonState {
+    /// button is not pressed
+    Released = 0u32,
+    /// button is pressed
+    Pressed = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTabletToolV2ButtonState {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Released,
+            1 => Self::Pressed,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_tablet_tool_v2:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTabletToolV2Error {
+    /// given wl_surface has another role
+    Role = 0u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTabletToolV2Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Role,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_tablet_v2:bustype enum
+/// Describes the bus types this tablet is connected to.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTabletV2Bustype {
+    /// USB
+    Usb = 3u32,
+    /// Bluetooth
+    Bluetooth = 5u32,
+    /// Virtual
+    Virtual = 6u32,
+    /// Serial
+    Serial = 17u32,
+    /// I2C
+    I2c = 24u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTabletV2Bustype {
+    fn from(value: u32) -> Self {
+        match value {
+            3 => Self::Usb,
+            5 => Self::Bluetooth,
+            6 => Self::Virtual,
+            17 => Self::Serial,
+            24 => Self::I2c,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_tablet_pad_ring_v2:source enum
+/// Describes the source types for ring events. This indicates to the
+/// client how a ring event was physically generated; a client may
+/// adjust the user interface accordingly. For example, events
+/// from a "finger" source may trigger kinetic scrolling.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTabletPadRingV2Source {
+    /// finger
+    Finger = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTabletPadRingV2Source {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Finger,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_tablet_pad_strip_v2:source enum
+/// Describes the source types for strip events. This indicates to the
+/// client how a strip event was physically generated; a client may
+/// adjust the user interface accordingly. For example, events
+/// from a "finger" source may trigger kinetic scrolling.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTabletPadStripV2Source {
+    /// finger
+    Finger = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTabletPadStripV2Source {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => Self::Finger,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// zwp_tablet_pad_v2:button_state enum
+/// Describes the physical state of a button that caused the button
+/// event.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZwpTabletPadV2ButtonState {
+    /// the button is not pressed
+    Released = 0u32,
+    /// the button is pressed
+    Pressed = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for ZwpTabletPadV2ButtonState {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Released,
+            1 => Self::Pressed,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_content_type_manager_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpContentTypeManagerV1Error {
+    /// wl_surface already has a content type object
+    AlreadyConstructed = 0u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpContentTypeManagerV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::AlreadyConstructed,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_content_type_v1:type enum
+/// These values describe the available content types for a surface.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpContentTypeV1Type {
+    /// no content type applies
+    None = 0u32,
+    /// photo content type
+    Photo = 1u32,
+    /// video content type
+    Video = 2u32,
+    /// game content type
+    Game = 3u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpContentTypeV1Type {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Photo,
+            2 => Self::Video,
+            3 => Self::Game,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_tearing_control_manager_v1:error enum
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpTearingControlManagerV1Error {
+    /// the surface already has a tearing object associated
+    TearingControlExists = 0u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpTearingControlManagerV1Error {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::TearingControlExists,
+            _ => Self::Unexpected,
+        }
+    }
+}
+
+/// wp_tearing_control_v1:presentation_hint enum
+/// This enum provides information for if submitted frames from the client
+/// may be presented with tearing.
+#[repr(u32)]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpTearingControlV1PresentationHint {
+    /// tearing-free presentation
+    Vsync = 0u32,
+    /// asynchronous presentation
+    Async = 1u32,
+    /// Unexpected value was receieved on the wire
+    Unexpected,
+}
+
+impl From<u32> for WpTearingControlV1PresentationHint {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Vsync,
+            1 => Self::Async,
+            _ => Self::Unexpected,
+        }
+    }
+}
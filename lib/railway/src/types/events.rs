@@ -2,6 +2,8 @@
 
 use crate::types::Fixed;
 use crate::types::enums;
+
+use std::borrow::Cow;
 /// This event advertises one buffer format that the server supports.
 /// All the supported formats are advertised once when the client
 /// binds to this interface. A roundtrip after binding guarantees
@@ -147,12 +149,22 @@ pub struct ZwpLinuxDmabufFeedbackV1FormatTableEvent {
 /// allocations on a different device than the main device, then the client
 /// must force the buffer to have a linear layout.
 #[derive(Debug)]
-pub struct ZwpLinuxDmabufFeedbackV1MainDeviceEvent {
+pub struct ZwpLinuxDmabufFeedbackV1MainDeviceEvent<'a> {
     /// zwp_linux_dmabuf_feedback_v1:main_device event
     /// id of the object the event came from
     pub source_id: u32,
     /// device dev_t value
-    pub device: Vec<u8>,
+    pub device: Cow<'a, [u8]>,
+}
+
+impl ZwpLinuxDmabufFeedbackV1MainDeviceEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpLinuxDmabufFeedbackV1MainDeviceEvent<'static> {
+        ZwpLinuxDmabufFeedbackV1MainDeviceEvent {
+            source_id: self.source_id,
+            device: Cow::Owned(self.device.into_owned()),
+        }
+    }
 }
 
 /// This event splits tranche_target_device and tranche_formats events in
@@ -193,12 +205,22 @@ pub struct ZwpLinuxDmabufFeedbackV1TrancheDoneEvent {
 ///
 /// This event is tied to a preference tranche, see the tranche_done event.
 #[derive(Debug)]
-pub struct ZwpLinuxDmabufFeedbackV1TrancheTargetDeviceEvent {
+pub struct ZwpLinuxDmabufFeedbackV1TrancheTargetDeviceEvent<'a> {
     /// zwp_linux_dmabuf_feedback_v1:tranche_target_device event
     /// id of the object the event came from
     pub source_id: u32,
     /// device dev_t value
-    pub device: Vec<u8>,
+    pub device: Cow<'a, [u8]>,
+}
+
+impl ZwpLinuxDmabufFeedbackV1TrancheTargetDeviceEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpLinuxDmabufFeedbackV1TrancheTargetDeviceEvent<'static> {
+        ZwpLinuxDmabufFeedbackV1TrancheTargetDeviceEvent {
+            source_id: self.source_id,
+            device: Cow::Owned(self.device.into_owned()),
+        }
+    }
 }
 
 /// This event advertises the format + modifier combinations that the
@@ -226,12 +248,22 @@ pub struct ZwpLinuxDmabufFeedbackV1TrancheTargetDeviceEvent {
 /// For the definition of the format and modifier codes, see the
 /// wp_linux_buffer_params.create request.
 #[derive(Debug)]
-pub struct ZwpLinuxDmabufFeedbackV1TrancheFormatsEvent {
+pub struct ZwpLinuxDmabufFeedbackV1TrancheFormatsEvent<'a> {
     /// zwp_linux_dmabuf_feedback_v1:tranche_formats event
     /// id of the object the event came from
     pub source_id: u32,
     /// array of 16-bit indexes
-    pub indices: Vec<u8>,
+    pub indices: Cow<'a, [u8]>,
+}
+
+impl ZwpLinuxDmabufFeedbackV1TrancheFormatsEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpLinuxDmabufFeedbackV1TrancheFormatsEvent<'static> {
+        ZwpLinuxDmabufFeedbackV1TrancheFormatsEvent {
+            source_id: self.source_id,
+            indices: Cow::Owned(self.indices.into_owned()),
+        }
+    }
 }
 
 /// This event sets tranche-specific flags.
@@ -318,13 +350,25 @@ pub struct XdgSurfaceConfigureEvent {
 /// Clients must send an ack_configure in response to this event. See
 /// xdg_surface.configure and xdg_surface.ack_configure for details.
 #[derive(Debug)]
-pub struct XdgToplevelConfigureEvent {
+pub struct XdgToplevelConfigureEvent<'a> {
     /// xdg_toplevel:configure event
     /// id of the object the event came from
     pub source_id: u32,
     pub width: i32,
     pub height: i32,
-    pub states: Vec<u8>,
+    pub states: Cow<'a, [u8]>,
+}
+
+impl XdgToplevelConfigureEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> XdgToplevelConfigureEvent<'static> {
+        XdgToplevelConfigureEvent {
+            source_id: self.source_id,
+            width: self.width,
+            height: self.height,
+            states: Cow::Owned(self.states.into_owned()),
+        }
+    }
 }
 
 /// The close event is sent by the compositor when the user
@@ -387,12 +431,22 @@ pub struct XdgToplevelConfigureBoundsEvent {
 /// The capabilities are sent as an array of 32-bit unsigned integers in
 /// native endianness.
 #[derive(Debug)]
-pub struct XdgToplevelWmCapabilitiesEvent {
+pub struct XdgToplevelWmCapabilitiesEvent<'a> {
     /// xdg_toplevel:wm_capabilities event
     /// id of the object the event came from
     pub source_id: u32,
     /// array of 32-bit capabilities
-    pub capabilities: Vec<u8>,
+    pub capabilities: Cow<'a, [u8]>,
+}
+
+impl XdgToplevelWmCapabilitiesEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> XdgToplevelWmCapabilitiesEvent<'static> {
+        XdgToplevelWmCapabilitiesEvent {
+            source_id: self.source_id,
+            capabilities: Cow::Owned(self.capabilities.into_owned()),
+        }
+    }
 }
 
 /// This event asks the popup surface to configure itself given the
@@ -473,7 +527,7 @@ pub struct WlDisplaySyncDoneEvent{
 /// own set of error codes.  The message is a brief description
 /// of the error, for (debugging) convenience.
 #[derive(Debug)]
-pub struct WlDisplayErrorEvent {
+pub struct WlDisplayErrorEvent<'a> {
     /// wl_display:error event
     /// id of the object the event came from
     pub source_id: u32,
@@ -482,7 +536,19 @@ pub struct WlDisplayErrorEvent {
     /// error code
     pub code: u32,
     /// error description
-    pub message: String,
+    pub message: Cow<'a, str>,
+}
+
+impl WlDisplayErrorEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlDisplayErrorEvent<'static> {
+        WlDisplayErrorEvent {
+            source_id: self.source_id,
+            object_id: self.object_id,
+            code: self.code,
+            message: Cow::Owned(self.message.into_owned()),
+        }
+    }
 }
 
 /// This event is used internally by the object ID management
@@ -505,18 +571,30 @@ pub struct WlDisplayDeleteIdEvent {
 /// the given name is now available, and it implements the
 /// given version of the given interface.
 #[derive(Debug)]
-pub struct WlRegistryGlobalEvent {
+pub struct WlRegistryGlobalEvent<'a> {
     /// wl_registry:global event
     /// id of the object the event came from
     pub source_id: u32,
     /// numeric name of the global object
     pub name: u32,
     /// interface implemented by the object
-    pub interface: String,
+    pub interface: Cow<'a, str>,
     /// interface version
     pub version: u32,
 }
 
+impl WlRegistryGlobalEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlRegistryGlobalEvent<'static> {
+        WlRegistryGlobalEvent {
+            source_id: self.source_id,
+            name: self.name,
+            interface: Cow::Owned(self.interface.into_owned()),
+            version: self.version,
+        }
+    }
+}
+
 /// Notify the client of removed global objects.
 ///
 /// This event notifies the client that the global identified
@@ -580,12 +658,22 @@ pub struct WlBufferReleaseEvent {
 /// Sent immediately after creating the wl_data_offer object.  One
 /// event per offered mime type.
 #[derive(Debug)]
-pub struct WlDataOfferOfferEvent {
+pub struct WlDataOfferOfferEvent<'a> {
     /// wl_data_offer:offer event
     /// id of the object the event came from
     pub source_id: u32,
     /// offered mime type
-    pub mime_type: String,
+    pub mime_type: Cow<'a, str>,
+}
+
+impl WlDataOfferOfferEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlDataOfferOfferEvent<'static> {
+        WlDataOfferOfferEvent {
+            source_id: self.source_id,
+            mime_type: Cow::Owned(self.mime_type.into_owned()),
+        }
+    }
 }
 
 /// This event indicates the actions offered by the data source. It
@@ -650,28 +738,49 @@ pub struct WlDataOfferActionEvent {
 ///
 /// Used for feedback during drag-and-drop.
 #[derive(Debug)]
-pub struct WlDataSourceTargetEvent {
+pub struct WlDataSourceTargetEvent<'a> {
     /// wl_data_source:target event
     /// id of the object the event came from
     pub source_id: u32,
     /// mime type accepted by the target
-    pub mime_type: String,
+    pub mime_type: Option<Cow<'a, str>>,
+}
+
+impl WlDataSourceTargetEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlDataSourceTargetEvent<'static> {
+        WlDataSourceTargetEvent {
+            source_id: self.source_id,
+            mime_type: self.mime_type.map(|v| Cow::Owned(v.into_owned())),
+        }
+    }
 }
 
 /// Request for data from the client.  Send the data as the
 /// specified mime type over the passed file descriptor, then
 /// close it.
 #[derive(Debug)]
-pub struct WlDataSourceSendEvent {
+pub struct WlDataSourceSendEvent<'a> {
     /// wl_data_source:send event
     /// id of the object the event came from
     pub source_id: u32,
     /// mime type for the data
-    pub mime_type: String,
+    pub mime_type: Cow<'a, str>,
     /// file descriptor for the data
     pub fd: std::os::fd::RawFd,
 }
 
+impl WlDataSourceSendEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlDataSourceSendEvent<'static> {
+        WlDataSourceSendEvent {
+            source_id: self.source_id,
+            mime_type: Cow::Owned(self.mime_type.into_owned()),
+            fd: self.fd,
+        }
+    }
+}
+
 /// This data source is no longer valid. There are several reasons why
 /// this could happen:
 ///
@@ -797,7 +906,7 @@ pub struct WlDataDeviceEnterEvent {
     /// surface-local y coordinate
     pub y: Fixed,
     /// source data_offer object
-    pub id: u32,
+    pub id: Option<u32>,
 }
 
 /// This event is sent when the drag-and-drop pointer leaves the
@@ -865,7 +974,7 @@ pub struct WlDataDeviceSelectionEvent {
     /// id of the object the event came from
     pub source_id: u32,
     /// selection data_offer object
-    pub id: u32,
+    pub id: Option<u32>,
 }
 
 /// Ping a client to check if it is receiving events and sending
@@ -1041,12 +1150,22 @@ pub struct WlSeatCapabilitiesEvent {
 /// Compositors may re-use the same seat name if the wl_seat global is
 /// destroyed and re-created later.
 #[derive(Debug)]
-pub struct WlSeatNameEvent {
+pub struct WlSeatNameEvent<'a> {
     /// wl_seat:name event
     /// id of the object the event came from
     pub source_id: u32,
     /// seat identifier
-    pub name: String,
+    pub name: Cow<'a, str>,
+}
+
+impl WlSeatNameEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlSeatNameEvent<'static> {
+        WlSeatNameEvent {
+            source_id: self.source_id,
+            name: Cow::Owned(self.name.into_owned()),
+        }
+    }
 }
 
 /// Notification that this seat's pointer is focused on a certain
@@ -1404,7 +1523,7 @@ pub struct WlKeyboardKeymapEvent {
 /// The compositor must send the wl_keyboard.modifiers event after this
 /// event.
 #[derive(Debug)]
-pub struct WlKeyboardEnterEvent {
+pub struct WlKeyboardEnterEvent<'a> {
     /// wl_keyboard:enter event
     /// id of the object the event came from
     pub source_id: u32,
@@ -1413,7 +1532,19 @@ pub struct WlKeyboardEnterEvent {
     /// surface gaining keyboard focus
     pub surface: u32,
     /// the currently pressed keys
-    pub keys: Vec<u8>,
+    pub keys: Cow<'a, [u8]>,
+}
+
+impl WlKeyboardEnterEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlKeyboardEnterEvent<'static> {
+        WlKeyboardEnterEvent {
+            source_id: self.source_id,
+            serial: self.serial,
+            surface: self.surface,
+            keys: Cow::Owned(self.keys.into_owned()),
+        }
+    }
 }
 
 /// Notification that this seat's keyboard focus is no longer on
@@ -1686,7 +1817,7 @@ pub struct WlTouchOrientationEvent {
 /// should use xdg_output.logical_position. Instead of using make and model,
 /// clients should use name and description.
 #[derive(Debug)]
-pub struct WlOutputGeometryEvent {
+pub struct WlOutputGeometryEvent<'a> {
     /// wl_output:geometry event
     /// id of the object the event came from
     pub source_id: u32,
@@ -1701,13 +1832,30 @@ pub struct WlOutputGeometryEvent {
     /// subpixel orientation of the output
     pub subpixel: enums::WlOutputSubpixel,
     /// textual description of the manufacturer
-    pub make: String,
+    pub make: Cow<'a, str>,
     /// textual description of the model
-    pub model: String,
+    pub model: Cow<'a, str>,
     /// transform that maps framebuffer to output
     pub transform: enums::WlOutputTransform,
 }
 
+impl WlOutputGeometryEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlOutputGeometryEvent<'static> {
+        WlOutputGeometryEvent {
+            source_id: self.source_id,
+            x: self.x,
+            y: self.y,
+            physical_width: self.physical_width,
+            physical_height: self.physical_height,
+            subpixel: self.subpixel,
+            make: Cow::Owned(self.make.into_owned()),
+            model: Cow::Owned(self.model.into_owned()),
+            transform: self.transform,
+        }
+    }
+}
+
 /// The mode event describes an available mode for the output.
 ///
 /// The event is sent when binding to the output object and there
@@ -1826,12 +1974,22 @@ pub struct WlOutputScaleEvent {
 ///
 /// The name event will be followed by a done event.
 #[derive(Debug)]
-pub struct WlOutputNameEvent {
+pub struct WlOutputNameEvent<'a> {
     /// wl_output:name event
     /// id of the object the event came from
     pub source_id: u32,
     /// output name
-    pub name: String,
+    pub name: Cow<'a, str>,
+}
+
+impl WlOutputNameEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlOutputNameEvent<'static> {
+        WlOutputNameEvent {
+            source_id: self.source_id,
+            name: Cow::Owned(self.name.into_owned()),
+        }
+    }
 }
 
 /// Many compositors can produce human-readable descriptions of their
@@ -1849,11 +2007,2393 @@ pub struct WlOutputNameEvent {
 ///
 /// The description event will be followed by a done event.
 #[derive(Debug)]
-pub struct WlOutputDescriptionEvent {
+pub struct WlOutputDescriptionEvent<'a> {
     /// wl_output:description event
     /// id of the object the event came from
     pub source_id: u32,
     /// output description
-    pub description: String,
+    pub description: Cow<'a, str>,
+}
+
+impl WlOutputDescriptionEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> WlOutputDescriptionEvent<'static> {
+        WlOutputDescriptionEvent {
+            source_id: self.source_id,
+            description: Cow::Owned(self.description.into_owned()),
+        }
+    }
+}
+
+/// The configure event configures the effective decoration mode. The
+/// configured state should not be applied immediately. Clients must send an
+/// ack_configure in response to this event. See xdg_surface.configure and
+/// xdg_surface.ack_configure for details.
+///
+/// A configure event can be sent at any time. The specified mode must be
+/// obeyed by the client.
+#[derive(Debug)]
+pub struct ZxdgToplevelDecorationV1ConfigureEvent {
+    /// zxdg_toplevel_decoration_v1:configure event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the decoration mode
+    pub mode: enums::ZxdgToplevelDecorationV1Mode,
+}
+
+/// Notification of a new preferred scale for this surface that the
+/// compositor suggests that the client should use.
+///
+/// The sent scale is the numerator of a fraction with a denominator of 120.
+#[derive(Debug)]
+pub struct WpFractionalScaleV1PreferredScaleEvent {
+    /// wp_fractional_scale_v1:preferred_scale event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the new preferred scale
+    pub scale: u32,
+}
+
+/// This event tells the client in which clock domain the
+/// compositor interprets the timestamps used by the presentation
+/// extension. This clock is called the presentation clock.
+///
+/// The compositor sends this event when the client binds to the
+/// presentation interface. The presentation clock does not change
+/// during the lifetime of the client connection.
+///
+/// The clock identifier is platform dependent. On POSIX platforms, the
+/// identifier value is one of the clockid_t values accepted by
+/// clock_gettime(). clock_gettime() is defined by POSIX.1-2001.
+///
+/// Timestamps in this clock domain are expressed as tv_sec_hi,
+/// tv_sec_lo, tv_nsec triples, each component being an unsigned
+/// 32-bit value. Whole seconds are in tv_sec which is a 64-bit
+/// value combined from tv_sec_hi and tv_sec_lo, and the
+/// additional fractional part in tv_nsec as nanoseconds. Hence,
+/// for valid timestamps tv_nsec must be in [0, 999999999].
+///
+/// Note that clock_id applies only to the presentation clock,
+/// and implies nothing about e.g. the timestamps used in the
+/// Wayland core protocol input events.
+///
+/// Compositors should prefer a clock which does not jump and is
+/// not slewed e.g. by NTP. The absolute value of the clock is
+/// irrelevant. Precision of one millisecond or better is
+/// recommended. Clients must be able to query the current clock
+/// value directly, not by asking the compositor.
+#[derive(Debug)]
+pub struct WpPresentationClockIdEvent {
+    /// wp_presentation:clock_id event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// platform clock identifier
+    pub clk_id: u32,
+}
+
+/// As presentation can be synchronized to only one output at a
+/// time, this event tells which output it was. This event is only
+/// sent prior to the presented event.
+///
+/// As clients may bind to the same global wl_output multiple
+/// times, this event is sent for each bound instance that matches
+/// the synchronized output. If a client has not bound to the
+/// right wl_output global at all, this event is not sent.
+#[derive(Debug)]
+pub struct WpPresentationFeedbackSyncOutputEvent {
+    /// wp_presentation_feedback:sync_output event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// presentation output
+    pub output: u32,
+}
+
+/// The associated content update was displayed to the user at the
+/// indicated time (tv_sec_hi/lo, tv_nsec). For the interpretation of
+/// the timestamp, see presentation.clock_id event.
+///
+/// The timestamp corresponds to the time when the content update
+/// turned into light the first time on the surface's main output.
+/// Compositors may approximate this from the framebuffer flip
+/// completion events from the system, and the latency of the
+/// physical display path if known.
+///
+/// This event is preceded by all related sync_output events
+/// telling which output's refresh cycle the feedback corresponds
+/// to, i.e. the main output for the surface. Compositors are
+/// recommended to choose the output containing the largest part
+/// of the wl_surface, or keeping the output they previously
+/// chose. Having a stable presentation output association helps
+/// clients predict future output refreshes (vblank).
+///
+/// The 'refresh' argument gives the compositor's prediction of how
+/// many nanoseconds after tv_sec, tv_nsec the very next output
+/// refresh may occur. This is to further aid clients in
+/// predicting future refreshes, i.e., estimating the timestamps
+/// targeting the next few vblanks. If such prediction cannot
+/// usefully be done, the argument is zero.
+///
+/// For version 2 and later, if the output does not have a constant
+/// refresh rate, explicit video mode switches excluded, then the
+/// refresh argument must be either an appropriate rate picked by the
+/// compositor (e.g. fastest rate), or 0 if no such rate exists.
+/// For version 1, if the output does not have a constant refresh rate,
+/// the refresh argument must be zero.
+///
+/// The 64-bit value combined from seq_hi and seq_lo is the value
+/// of the output's vertical retrace counter when the content
+/// update was first scanned out to the display. This value must
+/// be compatible with the definition of MSC in
+/// GLX_OML_sync_control specification. Note, that if the display
+/// path has a non-zero latency, the time instant specified by
+/// this counter may differ from the timestamp's.
+///
+/// If the output does not have a concept of vertical retrace or a
+/// refresh cycle, or the output device is self-refreshing without
+/// a way to query the refresh count, then the arguments seq_hi
+/// and seq_lo must be zero.
+#[derive(Debug)]
+pub struct WpPresentationFeedbackPresentedEvent {
+    /// wp_presentation_feedback:presented event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// high 32 bits of the seconds part of the presentation timestamp
+    pub tv_sec_hi: u32,
+    /// low 32 bits of the seconds part of the presentation timestamp
+    pub tv_sec_lo: u32,
+    /// nanoseconds part of the presentation timestamp
+    pub tv_nsec: u32,
+    /// nanoseconds till next refresh
+    pub refresh: u32,
+    /// high 32 bits of refresh counter
+    pub seq_hi: u32,
+    /// low 32 bits of refresh counter
+    pub seq_lo: u32,
+    /// combination of 'kind' values
+    pub flags: enums::WpPresentationFeedbackKind,
+}
+
+/// The content update was never displayed to the user.
+#[derive(Debug)]
+pub struct WpPresentationFeedbackDiscardedEvent {
+    /// wp_presentation_feedback:discarded event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Sent when the compositor has finalised its usage of the associated
+/// buffer for the relevant commit, providing a dma_fence which will be
+/// signaled when all operations by the compositor on that buffer for that
+/// commit have finished.
+///
+/// Once the fence has signaled, and assuming the associated buffer is not
+/// pending release from other wl_surface.commit requests, no additional
+/// explicit or implicit synchronization is required to safely reuse or
+/// destroy the buffer.
+///
+/// This event destroys the zwp_linux_buffer_release_v1 object.
+#[derive(Debug)]
+pub struct ZwpLinuxBufferReleaseV1FencedReleaseEvent {
+    /// zwp_linux_buffer_release_v1:fenced_release event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// fence for last operation on buffer
+    pub fence: std::os::fd::RawFd,
+}
+
+/// Sent when the compositor has finalised its usage of the associated
+/// buffer for the relevant commit, and either performed no operations
+/// using it, or has a guarantee that all its operations on that buffer for
+/// that commit have finished.
+///
+/// Once this event is received, and assuming the associated buffer is not
+/// pending release from other wl_surface.commit requests, no additional
+/// explicit or implicit synchronization is required to safely reuse or
+/// destroy the buffer.
+///
+/// This event destroys the zwp_linux_buffer_release_v1 object.
+#[derive(Debug)]
+pub struct ZwpLinuxBufferReleaseV1ImmediateReleaseEvent {
+    /// zwp_linux_buffer_release_v1:immediate_release event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// The 'done' event contains the unique token of this activation request
+/// and notifies that the provider is done.
+#[derive(Debug)]
+pub struct XdgActivationTokenV1DoneEvent<'a> {
+    /// xdg_activation_token_v1:done event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the exported activation token
+    pub token: Cow<'a, str>,
+}
+
+impl XdgActivationTokenV1DoneEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> XdgActivationTokenV1DoneEvent<'static> {
+        XdgActivationTokenV1DoneEvent {
+            source_id: self.source_id,
+            token: Cow::Owned(self.token.into_owned()),
+        }
+    }
+}
+
+/// Notification that this seat's text-input focus is on a certain surface.
+///
+/// If client has created multiple text input objects, compositor must send
+/// this event to all of them.
+///
+/// When the seat has the keyboard capability the text-input focus follows
+/// the keyboard focus. This event sets the current surface for the
+/// text-input object.
+#[derive(Debug)]
+pub struct ZwpTextInputV3EnterEvent {
+    /// zwp_text_input_v3:enter event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub surface: u32,
+}
+
+/// Notification that this seat's text-input focus is no longer on a
+/// certain surface. The client should reset any preedit string previously
+/// set.
+///
+/// The leave notification clears the current surface. It is sent before
+/// the enter notification for the new focus. After leave event, compositor
+/// must ignore requests from any text input instances until next enter
+/// event.
+///
+/// When the seat has the keyboard capability the text-input focus follows
+/// the keyboard focus.
+#[derive(Debug)]
+pub struct ZwpTextInputV3LeaveEvent {
+    /// zwp_text_input_v3:leave event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub surface: u32,
+}
+
+/// Notify when a new composing text (pre-edit) should be set at the
+/// current cursor position. Any previously set composing text must be
+/// removed. Any previously existing selected text must be removed.
+///
+/// The argument text contains the pre-edit string buffer.
+///
+/// The parameters cursor_begin and cursor_end are counted in bytes
+/// relative to the beginning of the submitted text buffer. Cursor should
+/// be hidden when both are equal to -1.
+///
+/// They could be represented by the client as a line if both values are
+/// the same, or as a text highlight otherwise.
+///
+/// Values set with this event are double-buffered. They must be applied
+/// and reset to initial on the next zwp_text_input_v3.done event.
+///
+/// The initial value of text is an empty string, and cursor_begin,
+/// cursor_end and cursor_hidden are all 0.
+#[derive(Debug)]
+pub struct ZwpTextInputV3PreeditStringEvent<'a> {
+    /// zwp_text_input_v3:preedit_string event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub text: Option<Cow<'a, str>>,
+    pub cursor_begin: i32,
+    pub cursor_end: i32,
+}
+
+impl ZwpTextInputV3PreeditStringEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpTextInputV3PreeditStringEvent<'static> {
+        ZwpTextInputV3PreeditStringEvent {
+            source_id: self.source_id,
+            text: self.text.map(|v| Cow::Owned(v.into_owned())),
+            cursor_begin: self.cursor_begin,
+            cursor_end: self.cursor_end,
+        }
+    }
+}
+
+/// Notify when text should be inserted into the editor widget. The text to
+/// commit could be either just a single character after a key press or the
+/// result of some composing (pre-edit).
+///
+/// Values set with this event are double-buffered. They must be applied
+/// and reset to initial on the next zwp_text_input_v3.done event.
+///
+/// The initial value of text is an empty string.
+#[derive(Debug)]
+pub struct ZwpTextInputV3CommitStringEvent<'a> {
+    /// zwp_text_input_v3:commit_string event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub text: Option<Cow<'a, str>>,
+}
+
+impl ZwpTextInputV3CommitStringEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpTextInputV3CommitStringEvent<'static> {
+        ZwpTextInputV3CommitStringEvent {
+            source_id: self.source_id,
+            text: self.text.map(|v| Cow::Owned(v.into_owned())),
+        }
+    }
+}
+
+/// Notify when the text around the current cursor position should be
+/// deleted.
+///
+/// Before_length and after_length are the number of bytes before and after
+/// the current cursor index (excluding the selection) to delete.
+///
+/// If a preedit text is present, in effect before_length is counted from
+/// the beginning of it, and after_length from its end (see done event
+/// sequence).
+///
+/// Values set with this event are double-buffered. They must be applied
+/// and reset to initial on the next zwp_text_input_v3.done event.
+///
+/// The initial values of both before_length and after_length are 0.
+#[derive(Debug)]
+pub struct ZwpTextInputV3DeleteSurroundingTextEvent {
+    /// zwp_text_input_v3:delete_surrounding_text event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// length of text before current cursor position
+    pub before_length: u32,
+    /// length of text after current cursor position
+    pub after_length: u32,
+}
+
+/// Instruct the application to apply changes to state requested by the
+/// preedit_string, commit_string delete_surrounding_text, and action
+/// events.
+///
+/// The state relating to these events is double-buffered, and each one
+/// modifies the pending state. This event replaces the current state with
+/// the pending state.
+///
+/// The application must proceed by evaluating the changes in the following
+/// order:
+///
+/// 1. Replace existing preedit string with the cursor.
+/// 2. Delete requested surrounding text.
+/// 3. Insert commit string with the cursor at its end.
+/// 4. Calculate surrounding text to send.
+/// 5. Insert new preedit text in cursor position.
+/// 6. Place cursor inside preedit text.
+/// 7. Perform the requested action.
+///
+/// The serial number reflects the last state of the zwp_text_input_v3
+/// object known to the compositor. The value of the serial argument must
+/// be equal to the number of commit requests already issued on that object.
+///
+/// When the client receives a done event with a serial different than the
+/// number of past commit requests, it must proceed with evaluating and
+/// applying the changes as normal, except it should not change the current
+/// state of the zwp_text_input_v3 object. All pending state requests
+/// (set_surrounding_text, set_content_type and set_cursor_rectangle) on
+/// the zwp_text_input_v3 object should be sent and committed after
+/// receiving a zwp_text_input_v3.done event with a matching serial.
+#[derive(Debug)]
+pub struct ZwpTextInputV3DoneEvent {
+    /// zwp_text_input_v3:done event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+}
+
+/// An action was performed on this text input.
+///
+/// Values set with this event are double-buffered. They must be applied
+/// and reset to initial on the next zwp_text_input_v3.done event.
+///
+/// The initial value of action is none.
+#[derive(Debug)]
+pub struct ZwpTextInputV3ActionEvent {
+    /// zwp_text_input_v3:action event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// action performed
+    pub action: enums::ZwpTextInputV3Action,
+    /// serial number of the action event
+    pub serial: u32,
+}
+
+/// Notify the application of language used by the input method.
+///
+/// This event will be sent on creation if known and for all subsequent changes.
+///
+/// The language should be specified as an IETF BCP 47 tag.
+/// Setting an empty string will reset any known language back to the default unknown state.
+#[derive(Debug)]
+pub struct ZwpTextInputV3LanguageEvent<'a> {
+    /// zwp_text_input_v3:language event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// new language set by IME
+    pub language: Cow<'a, str>,
+}
+
+impl ZwpTextInputV3LanguageEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpTextInputV3LanguageEvent<'static> {
+        ZwpTextInputV3LanguageEvent {
+            source_id: self.source_id,
+            language: Cow::Owned(self.language.into_owned()),
+        }
+    }
+}
+
+/// Notify of contextual hints for the pre-edit string. This
+/// event is always sent together with a zwp_text_input_v3.preedit_string
+/// event.
+///
+/// The parameters start and end are counted in bytes relative to the
+/// beginning of the text buffer submitted through
+/// zwp_text_input_v3.preedit_string, and represent the substring in the
+/// pre-edit text affected by the hint.
+///
+/// Multiple events may be submitted if the preedit string has different
+/// sections. The extent of hints may overlap. The parts of the preedit
+/// string that are not covered by any zwp_text_input_v3.preedit_hint event,
+/// the text will be considered unhinted. This is also the case if no
+/// preedit_hint event is sent.
+///
+/// Clients should provide recognizable visuals to these hints. if they are
+/// unable to comply with this requisition, it may be preferable for them
+/// keep the preedit_shown content hint disabled.
+///
+/// Values set with this event are double-buffered. They must be applied
+/// and reset on the next zwp_text_input_v3.done event.
+#[derive(Debug)]
+pub struct ZwpTextInputV3PreeditHintEvent {
+    /// zwp_text_input_v3:preedit_hint event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// starting point of the affected substring
+    pub start: u32,
+    /// end point of the affected substring
+    pub end: u32,
+    /// hint to apply
+    pub hint: enums::ZwpTextInputV3PreeditHint,
+}
+
+/// Introduces a new wp_primary_selection_offer object that may be used
+/// to receive the current primary selection. Immediately following this
+/// event, the new wp_primary_selection_offer object will send
+/// wp_primary_selection_offer.offer events to describe the offered mime
+/// types.
+#[derive(Debug)]
+pub struct ZwpPrimarySelectionDeviceV1DataOfferEvent {
+    /// zwp_primary_selection_device_v1:data_offer event
+    /// id of the object the event came from
+    pub source_id: u32,
+    // new_id
+    pub offer: u32,
+}
+
+/// The wp_primary_selection_device.selection event is sent to notify the
+/// client of a new primary selection. This event is sent after the
+/// wp_primary_selection.data_offer event introducing this object, and after
+/// the offer has announced its mimetypes through
+/// wp_primary_selection_offer.offer.
+///
+/// The data_offer is valid until a new offer or NULL is received
+/// or until the client loses keyboard focus. The client must destroy the
+/// previous selection data_offer, if any, upon receiving this event.
+#[derive(Debug)]
+pub struct ZwpPrimarySelectionDeviceV1SelectionEvent {
+    /// zwp_primary_selection_device_v1:selection event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub id: Option<u32>,
+}
+
+/// Sent immediately after creating announcing the
+/// wp_primary_selection_offer through
+/// wp_primary_selection_device.data_offer. One event is sent per offered
+/// mime type.
+#[derive(Debug)]
+pub struct ZwpPrimarySelectionOfferV1OfferEvent<'a> {
+    /// zwp_primary_selection_offer_v1:offer event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub mime_type: Cow<'a, str>,
+}
+
+impl ZwpPrimarySelectionOfferV1OfferEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpPrimarySelectionOfferV1OfferEvent<'static> {
+        ZwpPrimarySelectionOfferV1OfferEvent {
+            source_id: self.source_id,
+            mime_type: Cow::Owned(self.mime_type.into_owned()),
+        }
+    }
+}
+
+/// Request for the current primary selection contents from the client.
+/// Send the specified mime type over the passed file descriptor, then
+/// close it.
+#[derive(Debug)]
+pub struct ZwpPrimarySelectionSourceV1SendEvent<'a> {
+    /// zwp_primary_selection_source_v1:send event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub mime_type: Cow<'a, str>,
+    pub fd: std::os::fd::RawFd,
+}
+
+impl ZwpPrimarySelectionSourceV1SendEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpPrimarySelectionSourceV1SendEvent<'static> {
+        ZwpPrimarySelectionSourceV1SendEvent {
+            source_id: self.source_id,
+            mime_type: Cow::Owned(self.mime_type.into_owned()),
+            fd: self.fd,
+        }
+    }
+}
+
+/// This primary selection source is no longer valid. The client should
+/// clean up and destroy this primary selection source.
+#[derive(Debug)]
+pub struct ZwpPrimarySelectionSourceV1CancelledEvent {
+    /// zwp_primary_selection_source_v1:cancelled event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Notification that the pointer lock of the seat's pointer is activated.
+#[derive(Debug)]
+pub struct ZwpLockedPointerV1LockedEvent {
+    /// zwp_locked_pointer_v1:locked event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Notification that the pointer lock of the seat's pointer is no longer
+/// active. If this is a oneshot pointer lock (see
+/// wp_pointer_constraints.lifetime) this object is now defunct and should
+/// be destroyed. If this is a persistent pointer lock (see
+/// wp_pointer_constraints.lifetime) this pointer lock may again
+/// reactivate in the future.
+#[derive(Debug)]
+pub struct ZwpLockedPointerV1UnlockedEvent {
+    /// zwp_locked_pointer_v1:unlocked event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Notification that the pointer confinement of the seat's pointer is
+/// activated.
+#[derive(Debug)]
+pub struct ZwpConfinedPointerV1ConfinedEvent {
+    /// zwp_confined_pointer_v1:confined event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Notification that the pointer confinement of the seat's pointer is no
+/// longer active. If this is a oneshot pointer confinement (see
+/// wp_pointer_constraints.lifetime) this object is now defunct and should
+/// be destroyed. If this is a persistent pointer confinement (see
+/// wp_pointer_constraints.lifetime) this pointer confinement may again
+/// reactivate in the future.
+#[derive(Debug)]
+pub struct ZwpConfinedPointerV1UnconfinedEvent {
+    /// zwp_confined_pointer_v1:unconfined event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Relative x/y pointer motion from the pointer of the seat associated with
+/// this object.
+///
+/// A relative motion is in the same dimension as regular wl_pointer motion
+/// events, except they do not represent an absolute position. For example,
+/// moving a pointer from (x, y) to (x', y') would have the equivalent
+/// relative motion (x' - x, y' - y). If a pointer motion caused the
+/// absolute pointer position to be clipped by for example the edge of the
+/// monitor, the relative motion is unaffected by the clipping and will
+/// represent the unclipped motion.
+///
+/// This event also contains non-accelerated motion deltas. The
+/// non-accelerated delta is, when applicable, the regular pointer motion
+/// delta as it was before having applied motion acceleration and other
+/// transformations such as normalization.
+///
+/// Note that the non-accelerated delta does not represent 'raw' events as
+/// they were read from some device. Pointer motion acceleration is device-
+/// and configuration-specific and non-accelerated deltas and accelerated
+/// deltas may have the same value on some devices.
+///
+/// Relative motions are not coupled to wl_pointer.motion events, and can be
+/// sent in combination with such events, but also independently. There may
+/// also be scenarios where wl_pointer.motion is sent, but there is no
+/// relative motion. The order of an absolute and relative motion event
+/// originating from the same physical motion is not guaranteed.
+///
+/// If the client needs button events or focus state, it can receive them
+/// from a wl_pointer object of the same seat that the wp_relative_pointer
+/// object is associated with.
+#[derive(Debug)]
+pub struct ZwpRelativePointerV1RelativeMotionEvent {
+    /// zwp_relative_pointer_v1:relative_motion event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// high 32 bits of a 64 bit timestamp with microsecond granularity
+    pub utime_hi: u32,
+    /// low 32 bits of a 64 bit timestamp with microsecond granularity
+    pub utime_lo: u32,
+    /// the x component of the motion vector
+    pub dx: Fixed,
+    /// the y component of the motion vector
+    pub dy: Fixed,
+    /// the x component of the unaccelerated motion vector
+    pub dx_unaccel: Fixed,
+    /// the y component of the unaccelerated motion vector
+    pub dy_unaccel: Fixed,
+}
+
+/// This event is sent when the notification object becomes idle.
+///
+/// It's a compositor protocol error to send this event twice without a
+/// resumed event in-between.
+#[derive(Debug)]
+pub struct ExtIdleNotificationV1IdledEvent {
+    /// ext_idle_notification_v1:idled event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// This event is sent when the notification object stops being idle.
+///
+/// It's a compositor protocol error to send this event twice without an
+/// idled event in-between. It's a compositor protocol error to send this
+/// event prior to any idled event.
+#[derive(Debug)]
+pub struct ExtIdleNotificationV1ResumedEvent {
+    /// ext_idle_notification_v1:resumed event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// The configure event asks the client to resize its surface.
+///
+/// Clients should arrange their surface for the new states, and then send
+/// an ack_configure request with the serial sent in this configure event at
+/// some point before committing the new surface.
+///
+/// The client is free to dismiss all but the last configure event it
+/// received.
+///
+/// The width and height arguments specify the size of the window in
+/// surface-local coordinates.
+///
+/// The size is a hint, in the sense that the client is free to ignore it if
+/// it doesn't resize, pick a smaller size (to satisfy aspect ratio or
+/// resize in steps of NxM pixels). If the client picks a smaller size and
+/// is anchored to two opposite anchors (e.g. 'top' and 'bottom'), the
+/// surface will be centered on this axis.
+///
+/// If the width or height arguments are zero, it means the client should
+/// decide its own window dimension.
+#[derive(Debug)]
+pub struct ZwlrLayerSurfaceV1ConfigureEvent {
+    /// zwlr_layer_surface_v1:configure event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// The closed event is sent by the compositor when the surface will no
+/// longer be shown. The output may have been destroyed or the user may
+/// have asked for it to be removed. Further changes to the surface will be
+/// ignored. The client should destroy the resource after receiving this
+/// event, and create a new surface if they so choose.
+#[derive(Debug)]
+pub struct ZwlrLayerSurfaceV1ClosedEvent {
+    /// zwlr_layer_surface_v1:closed event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// This client is now responsible for displaying graphics while the
+/// session is locked and deciding when to unlock the session.
+///
+/// The locked event must not be sent until a new "locked" frame has been
+/// presented on all outputs and no security sensitive normal/unlocked
+/// content is possibly visible.
+///
+/// If this event is sent, making the destroy request is a protocol error,
+/// the lock object must be destroyed using the unlock_and_destroy request.
+#[derive(Debug)]
+pub struct ExtSessionLockV1LockedEvent {
+    /// ext_session_lock_v1:locked event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// The compositor has decided that the session lock should be destroyed
+/// as it will no longer be used by the compositor. Exactly when this
+/// event is sent is compositor policy, but it must never be sent more
+/// than once for a given session lock object.
+///
+/// This might be sent because there is already another ext_session_lock_v1
+/// object held by a client, or the compositor has decided to deny the
+/// request to lock the session for some other reason. This might also
+/// be sent because the compositor implements some alternative, secure
+/// way to authenticate and unlock the session.
+///
+/// The finished event should be sent immediately on creation of this
+/// object if the compositor decides that the locked event will not
+/// be sent.
+///
+/// If the locked event is sent on creation of this object the finished
+/// event may still be sent at some later time in this object's
+/// lifetime. This is compositor policy.
+///
+/// Upon receiving this event, the client should make either the destroy
+/// request or the unlock_and_destroy request, depending on whether or
+/// not the locked event was received on this object.
+#[derive(Debug)]
+pub struct ExtSessionLockV1FinishedEvent {
+    /// ext_session_lock_v1:finished event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// This event is sent once on binding the interface and may be sent again
+/// at the compositor's discretion, for example if output geometry changes.
+///
+/// The width and height are in surface-local coordinates and are exact
+/// requirements. Failing to match these surface dimensions in the next
+/// commit after acking a configure is a protocol error.
+#[derive(Debug)]
+pub struct ExtSessionLockSurfaceV1ConfigureEvent {
+    /// ext_session_lock_surface_v1:configure event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// serial for use in ack_configure
+    pub serial: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Provides the dimensions of the source image in buffer pixel coordinates.
+///
+/// The client must attach buffers that match this size.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureSessionV1BufferSizeEvent {
+    /// ext_image_copy_capture_session_v1:buffer_size event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// buffer width
+    pub width: u32,
+    /// buffer height
+    pub height: u32,
+}
+
+/// Provides the format that must be used for shared-memory buffers.
+///
+/// This event may be emitted multiple times, in which case the client may
+/// choose any given format.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureSessionV1ShmFormatEvent {
+    /// ext_image_copy_capture_session_v1:shm_format event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// shm format
+    pub format: enums::WlShmFormat,
+}
+
+/// This event advertises the device buffers must be allocated on for
+/// dma-buf buffers.
+///
+/// In general the device is a DRM node. The DRM node type (primary vs.
+/// render) is unspecified. Clients must not rely on the compositor sending
+/// a particular node type. Clients cannot check two devices for equality
+/// by comparing the dev_t value.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureSessionV1DmabufDeviceEvent<'a> {
+    /// ext_image_copy_capture_session_v1:dmabuf_device event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// device dev_t value
+    pub device: Cow<'a, [u8]>,
+}
+
+impl ExtImageCopyCaptureSessionV1DmabufDeviceEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ExtImageCopyCaptureSessionV1DmabufDeviceEvent<'static> {
+        ExtImageCopyCaptureSessionV1DmabufDeviceEvent {
+            source_id: self.source_id,
+            device: Cow::Owned(self.device.into_owned()),
+        }
+    }
+}
+
+/// Provides the format that must be used for dma-buf buffers.
+///
+/// The client may choose any of the modifiers advertised in the array of
+/// 64-bit unsigned integers.
+///
+/// This event may be emitted multiple times, in which case the client may
+/// choose any given format.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureSessionV1DmabufFormatEvent<'a> {
+    /// ext_image_copy_capture_session_v1:dmabuf_format event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// drm format code
+    pub format: u32,
+    /// drm format modifiers
+    pub modifiers: Cow<'a, [u8]>,
+}
+
+impl ExtImageCopyCaptureSessionV1DmabufFormatEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ExtImageCopyCaptureSessionV1DmabufFormatEvent<'static> {
+        ExtImageCopyCaptureSessionV1DmabufFormatEvent {
+            source_id: self.source_id,
+            format: self.format,
+            modifiers: Cow::Owned(self.modifiers.into_owned()),
+        }
+    }
+}
+
+/// This event is sent once when all buffer constraint events have been
+/// sent.
+///
+/// The compositor must always end a batch of buffer constraint events with
+/// this event, regardless of whether it sends the initial constraints or
+/// an update.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureSessionV1DoneEvent {
+    /// ext_image_copy_capture_session_v1:done event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// This event indicates that the capture session has stopped and is no
+/// longer available. This can happen in a number of cases, e.g. when the
+/// underlying source is destroyed, if the user decides to end the image
+/// capture, or if an unrecoverable runtime error has occurred.
+///
+/// The client should destroy the session after receiving this event.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureSessionV1StoppedEvent {
+    /// ext_image_copy_capture_session_v1:stopped event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// This event is sent before the ready event and holds the transform that
+/// the compositor has applied to the buffer contents.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureFrameV1TransformEvent {
+    /// ext_image_copy_capture_frame_v1:transform event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub transform: enums::WlOutputTransform,
+}
+
+/// This event is sent before the ready event. It may be generated multiple
+/// times to describe a region.
+///
+/// The first captured frame in a session will always carry full damage.
+/// Subsequent frames' damaged regions describe which parts of the buffer
+/// have changed since the last ready event.
+///
+/// These coordinates originate in the upper left corner of the buffer.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureFrameV1DamageEvent {
+    /// ext_image_copy_capture_frame_v1:damage event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// damage x coordinate
+    pub x: i32,
+    /// damage y coordinate
+    pub y: i32,
+    /// damage width
+    pub width: i32,
+    /// damage height
+    pub height: i32,
+}
+
+/// This event indicates the time at which the frame is presented to the
+/// output in system monotonic time. This event is sent before the ready
+/// event.
+///
+/// The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,
+/// each component being an unsigned 32-bit value. Whole seconds are in
+/// tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,
+/// and the additional fractional part in tv_nsec as nanoseconds. Hence,
+/// for valid timestamps tv_nsec must be in [0, 999999999].
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureFrameV1PresentationTimeEvent {
+    /// ext_image_copy_capture_frame_v1:presentation_time event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// high 32 bits of the seconds part of the timestamp
+    pub tv_sec_hi: u32,
+    /// low 32 bits of the seconds part of the timestamp
+    pub tv_sec_lo: u32,
+    /// nanoseconds part of the timestamp
+    pub tv_nsec: u32,
+}
+
+/// Called as soon as the frame is copied, indicating it is available
+/// for reading.
+///
+/// The buffer may be re-used by the client after this event.
+///
+/// After receiving this event, the client must destroy the object.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureFrameV1ReadyEvent {
+    /// ext_image_copy_capture_frame_v1:ready event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// This event indicates that the attempted frame copy has failed.
+///
+/// After receiving this event, the client must destroy the object.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureFrameV1FailedEvent {
+    /// ext_image_copy_capture_frame_v1:failed event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub reason: enums::ExtImageCopyCaptureFrameV1FailureReason,
+}
+
+/// Sent when a cursor enters the captured area. It shall be generated
+/// before the "position" and "hotspot" events when and only when a cursor
+/// enters the area.
+///
+/// The cursor enters the captured area when the cursor image intersects
+/// with the captured area. Note, this is different from e.g.
+/// wl_pointer.enter.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureCursorSessionV1EnterEvent {
+    /// ext_image_copy_capture_cursor_session_v1:enter event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Sent when a cursor leaves the captured area. No "position" or "hotspot"
+/// event is generated for the cursor until the cursor enters the captured
+/// area again.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureCursorSessionV1LeaveEvent {
+    /// ext_image_copy_capture_cursor_session_v1:leave event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Cursors outside the image capture source do not get captured and no
+/// event will be generated for them.
+///
+/// The given position is the position of the cursor's hotspot and it is
+/// relative to the main buffer's top left corner in transformed buffer
+/// pixel coordinates. The coordinates may be negative or greater than the
+/// main buffer size.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureCursorSessionV1PositionEvent {
+    /// ext_image_copy_capture_cursor_session_v1:position event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// position x coordinates
+    pub x: i32,
+    /// position y coordinates
+    pub y: i32,
+}
+
+/// The hotspot describes the offset between the cursor image and the
+/// position of the input device.
+///
+/// The given coordinates are the hotspot's offset from the origin in
+/// buffer coordinates.
+///
+/// Clients should not apply the hotspot immediately: the hotspot becomes
+/// effective when the next ext_image_copy_capture_frame_v1.ready event is received.
+///
+/// Compositors may delay this event until the client captures a new frame.
+#[derive(Debug)]
+pub struct ExtImageCopyCaptureCursorSessionV1HotspotEvent {
+    /// ext_image_copy_capture_cursor_session_v1:hotspot event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// hotspot x coordinates
+    pub x: i32,
+    /// hotspot y coordinates
+    pub y: i32,
+}
+
+/// Provides information about wl_shm buffer parameters that need to be
+/// used for this frame. This event is sent once after the frame is created
+/// if wl_shm buffers are supported.
+#[derive(Debug)]
+pub struct ZwlrScreencopyFrameV1BufferEvent {
+    /// zwlr_screencopy_frame_v1:buffer event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// buffer format
+    pub format: enums::WlShmFormat,
+    /// buffer width
+    pub width: u32,
+    /// buffer height
+    pub height: u32,
+    /// buffer stride
+    pub stride: u32,
+}
+
+/// Provides flags about the frame. This event is sent once before the
+/// "ready" event.
+#[derive(Debug)]
+pub struct ZwlrScreencopyFrameV1FlagsEvent {
+    /// zwlr_screencopy_frame_v1:flags event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// frame flags
+    pub flags: enums::ZwlrScreencopyFrameV1Flags,
+}
+
+/// Called as soon as the frame is copied, indicating it is available
+/// for reading. This event includes the time at which the presentation took place.
+///
+/// The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,
+/// each component being an unsigned 32-bit value. Whole seconds are in
+/// tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,
+/// and the additional fractional part in tv_nsec as nanoseconds. Hence,
+/// for valid timestamps tv_nsec must be in [0, 999999999]. The seconds part
+/// may have an arbitrary offset at start.
+///
+/// After receiving this event, the client should destroy the object.
+#[derive(Debug)]
+pub struct ZwlrScreencopyFrameV1ReadyEvent {
+    /// zwlr_screencopy_frame_v1:ready event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// high 32 bits of the seconds part of the timestamp
+    pub tv_sec_hi: u32,
+    /// low 32 bits of the seconds part of the timestamp
+    pub tv_sec_lo: u32,
+    /// nanoseconds part of the timestamp
+    pub tv_nsec: u32,
+}
+
+/// This event indicates that the attempted frame copy has failed.
+///
+/// After receiving this event, the client should destroy the object.
+#[derive(Debug)]
+pub struct ZwlrScreencopyFrameV1FailedEvent {
+    /// zwlr_screencopy_frame_v1:failed event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// This event is sent right before the ready event when copy_with_damage is
+/// requested. It may be generated multiple times for each copy_with_damage
+/// request.
+///
+/// The arguments describe a box around an area that has changed since the
+/// last copy request that was derived from the current screencopy manager
+/// instance.
+///
+/// The union of all regions received between the call to copy_with_damage
+/// and a ready event is the total damage since the prior ready event.
+#[derive(Debug)]
+pub struct ZwlrScreencopyFrameV1DamageEvent {
+    /// zwlr_screencopy_frame_v1:damage event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// damaged x coordinates
+    pub x: u32,
+    /// damaged y coordinates
+    pub y: u32,
+    /// current width
+    pub width: u32,
+    /// current height
+    pub height: u32,
+}
+
+/// Provides information about linux-dmabuf buffer parameters that need to
+/// be used for this frame. This event is sent once after the frame is
+/// created if linux-dmabuf buffers are supported.
+#[derive(Debug)]
+pub struct ZwlrScreencopyFrameV1LinuxDmabufEvent {
+    /// zwlr_screencopy_frame_v1:linux_dmabuf event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// fourcc pixel format
+    pub format: u32,
+    /// buffer width
+    pub width: u32,
+    /// buffer height
+    pub height: u32,
+}
+
+/// This event is sent once after all buffer events have been sent.
+///
+/// The client should proceed to create a buffer of one of the supported
+/// types, and send a "copy" request.
+#[derive(Debug)]
+pub struct ZwlrScreencopyFrameV1BufferDoneEvent {
+    /// zwlr_screencopy_frame_v1:buffer_done event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// This event is sent whenever a new tablet becomes available on this
+/// seat. This event only provides the object id of the tablet, any
+/// static information about the tablet (device name, vid/pid, etc.) is
+/// sent through the zwp_tablet_v2 interface.
+#[derive(Debug)]
+pub struct ZwpTabletSeatV2TabletAddedEvent {
+    /// zwp_tablet_seat_v2:tablet_added event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the newly added graphics tablet
+    // new_id
+    pub id: u32,
+}
+
+/// This event is sent whenever a tool that has not previously been used
+/// with a tablet comes into use. This event only provides the object id
+/// of the tool; any static information about the tool (capabilities,
+/// type, etc.) is sent through the zwp_tablet_tool_v2 interface.
+#[derive(Debug)]
+pub struct ZwpTabletSeatV2ToolAddedEvent {
+    /// zwp_tablet_seat_v2:tool_added event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the newly added tablet tool
+    // new_id
+    pub id: u32,
+}
+
+/// This event is sent whenever a new pad is known to the system. Typically,
+/// pads are physically attached to tablets and a pad_added event is
+/// sent immediately after the zwp_tablet_seat_v2.tablet_added.
+/// However, some standalone pad devices logically attach to tablets at
+/// runtime, and the client must wait for zwp_tablet_pad_v2.enter to know
+/// the tablet a pad is attached to.
+///
+/// This event only provides the object id of the pad. All further
+/// features (buttons, strips, rings) are sent through the zwp_tablet_pad_v2
+/// interface.
+#[derive(Debug)]
+pub struct ZwpTabletSeatV2PadAddedEvent {
+    /// zwp_tablet_seat_v2:pad_added event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the newly added pad
+    // new_id
+    pub id: u32,
+}
+
+/// The tool type is the high-level type of the tool and usually decides
+/// the interaction expected from this tool.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_tool_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2TypeEvent {
+    /// zwp_tablet_tool_v2:type event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the physical tool type
+    pub tool_type: enums::ZwpTabletToolV2Type,
+}
+
+/// If the physical tool can be identified by a unique 64-bit serial
+/// number, this event notifies the client of this serial number.
+///
+/// If multiple tablets are available in the same seat and the tool is
+/// uniquely identifiable by the serial number, that tool may move
+/// between tablets.
+///
+/// Otherwise, if the tool has no serial number and this event is
+/// missing, the tool is tied to the tablet it first comes into
+/// proximity with. Even if the physical tool is used on multiple
+/// tablets, separate zwp_tablet_tool_v2 objects will be created, one per
+/// tablet.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_tool_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2HardwareSerialEvent {
+    /// zwp_tablet_tool_v2:hardware_serial event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the unique serial number of the tool, most significant bits
+    pub hardware_serial_hi: u32,
+    /// the unique serial number of the tool, least significant bits
+    pub hardware_serial_lo: u32,
+}
+
+/// This event notifies the client of a hardware id available on this tool.
+///
+/// The hardware id is a device-specific 64-bit id that provides extra
+/// information about the tool in use, beyond the wl_tool.type
+/// enumeration. The format of the id is specific to tablets made by
+/// Wacom Inc. For example, the hardware id of a Wacom Grip
+/// Pen (a stylus) is 0x802.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_tool_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2HardwareIdWacomEvent {
+    /// zwp_tablet_tool_v2:hardware_id_wacom event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the hardware id, most significant bits
+    pub hardware_id_hi: u32,
+    /// the hardware id, least significant bits
+    pub hardware_id_lo: u32,
+}
+
+/// This event notifies the client of any capabilities of this tool,
+/// beyond the main set of x/y axes and tip up/down detection.
+///
+/// One event is sent for each extra capability available on this tool.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_tool_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2CapabilityEvent {
+    /// zwp_tablet_tool_v2:capability event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the capability
+    pub capability: enums::ZwpTabletToolV2Capability,
+}
+
+/// This event signals the end of the initial burst of descriptive
+/// events. A client may consider the static description of the tool to
+/// be complete and finalize initialization of the tool.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2DoneEvent {
+    /// zwp_tablet_tool_v2:done event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// This event is sent when the tool is removed from the system and will
+/// send no further events. Should the physical tool come back into
+/// proximity later, a new zwp_tablet_tool_v2 object will be created.
+///
+/// It is compositor-dependent when a tool is removed. A compositor may
+/// remove a tool on proximity out, tablet removal or any other reason.
+/// A compositor may also keep a tool alive until shutdown.
+///
+/// If the tool is currently in proximity, a proximity_out event will be
+/// sent before the removed event. See zwp_tablet_tool_v2.proximity_out for
+/// the handling of any buttons logically down.
+///
+/// When this event is received, the client must zwp_tablet_tool_v2.destroy
+/// the object.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2RemovedEvent {
+    /// zwp_tablet_tool_v2:removed event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Notification that this tool is focused on a certain surface.
+///
+/// This event can be received when the tool has moved from one surface to
+/// another, or when the tool has come back into proximity above the
+/// surface.
+///
+/// If any button is logically down when the tool comes into proximity,
+/// the respective button event is sent after the proximity_in event but
+/// within the same frame as the proximity_in event.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2ProximityInEvent {
+    /// zwp_tablet_tool_v2:proximity_in event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+    /// The tablet the tool is in proximity of
+    pub tablet: u32,
+    /// The current surface the tablet tool is over
+    pub surface: u32,
+}
+
+/// Notification that this tool has either left proximity, or is no
+/// longer focused on a certain surface.
+///
+/// When the tablet tool leaves proximity of the tablet, button release
+/// events are sent for each button that was held down at the time of
+/// leaving proximity. These events are sent before the proximity_out
+/// event but within the same zwp_tablet_v2.frame.
+///
+/// If the tool stays within proximity of the tablet, but the focus
+/// changes from one surface to another, a button release event may not
+/// be sent until the button is actually released or the tool leaves the
+/// proximity of the tablet.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2ProximityOutEvent {
+    /// zwp_tablet_tool_v2:proximity_out event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Sent whenever the tablet tool comes in contact with the surface of the
+/// tablet.
+///
+/// If the tool is already in contact with the tablet when entering the
+/// input region, the client owning said region will receive a
+/// zwp_tablet_v2.proximity_in event, followed by a zwp_tablet_v2.down
+/// event and a zwp_tablet_v2.frame event.
+///
+/// Note that this event describes logical contact, not physical
+/// contact. On some devices, a compositor may not consider a tool in
+/// logical contact until a minimum physical pressure threshold is
+/// exceeded.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2DownEvent {
+    /// zwp_tablet_tool_v2:down event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+}
+
+/// Sent whenever the tablet tool stops making contact with the surface of
+/// the tablet, or when the tablet tool moves out of the input region
+/// and the compositor grab (if any) is dismissed.
+///
+/// If the tablet tool moves out of the input region while in contact
+/// with the surface of the tablet and the compositor does not have an
+/// ongoing grab on the surface, the client owning said region will
+/// receive a zwp_tablet_v2.up event, followed by a zwp_tablet_v2.proximity_out
+/// event and a zwp_tablet_v2.frame event. If the compositor has an ongoing
+/// grab on this device, this event sequence is sent whenever the grab
+/// is dismissed in the future.
+///
+/// Note that this event describes logical contact, not physical
+/// contact. On some devices, a compositor may not consider a tool out
+/// of logical contact until physical pressure falls below a specific
+/// threshold.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2UpEvent {
+    /// zwp_tablet_tool_v2:up event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Sent whenever a tablet tool moves.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2MotionEvent {
+    /// zwp_tablet_tool_v2:motion event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// surface-local x coordinate
+    pub x: Fixed,
+    /// surface-local y coordinate
+    pub y: Fixed,
+}
+
+/// Sent whenever the pressure axis on a tool changes. The value of this
+/// event is normalized to a value between 0 and 65535.
+///
+/// Note that pressure may be nonzero even when a tool is not in logical
+/// contact. See the down and up events for more details.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2PressureEvent {
+    /// zwp_tablet_tool_v2:pressure event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// The current pressure value
+    pub pressure: u32,
+}
+
+/// Sent whenever the distance axis on a tool changes. The value of this
+/// event is normalized to a value between 0 and 65535.
+///
+/// Note that distance may be nonzero even when a tool is not in logical
+/// contact. See the down and up events for more details.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2DistanceEvent {
+    /// zwp_tablet_tool_v2:distance event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// The current distance value
+    pub distance: u32,
+}
+
+/// Sent whenever one or both of the tilt axes on a tool change. Each tilt
+/// value is in degrees, relative to the z-axis of the tablet.
+/// The angle is positive when the top of a tool tilts along the
+/// positive x or y axis.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2TiltEvent {
+    /// zwp_tablet_tool_v2:tilt event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// The current value of the X tilt axis
+    pub tilt_x: Fixed,
+    /// The current value of the Y tilt axis
+    pub tilt_y: Fixed,
+}
+
+/// Sent whenever the z-rotation axis on the tool changes. The
+/// rotation value is in degrees clockwise from the tool's
+/// logical neutral position.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2RotationEvent {
+    /// zwp_tablet_tool_v2:rotation event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// The current rotation of the Z axis
+    pub degrees: Fixed,
+}
+
+/// Sent whenever the slider position on the tool changes. The
+/// value is normalized between -65535 and 65535, with 0 as the logical
+/// neutral position of the slider.
+///
+/// The slider is available on e.g. the Wacom Airbrush tool.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2SliderEvent {
+    /// zwp_tablet_tool_v2:slider event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// The current position of slider
+    pub position: i32,
+}
+
+/// Sent whenever the wheel on the tool emits an event. This event
+/// contains two values for the same axis change. The degrees value is
+/// in the same orientation as the wl_pointer.vertical_scroll axis. The
+/// clicks value is in discrete logical clicks of the mouse wheel. This
+/// value may be zero if the movement of the wheel was less
+/// than one logical click.
+///
+/// Clients should choose either value and avoid mixing degrees and
+/// clicks. The compositor may accumulate values smaller than a logical
+/// click and emulate click events when a certain threshold is met.
+/// Thus, zwp_tablet_tool_v2.wheel events with non-zero clicks values may
+/// have different degrees values.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2WheelEvent {
+    /// zwp_tablet_tool_v2:wheel event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// The wheel delta in degrees
+    pub degrees: Fixed,
+    /// The wheel delta in discrete clicks
+    pub clicks: i32,
+}
+
+/// Sent whenever a button on the tool is pressed or released.
+///
+/// If a button is held down when the tool moves in or out of proximity,
+/// button events are generated by the compositor. See
+/// zwp_tablet_tool_v2.proximity_in and zwp_tablet_tool_v2.proximity_out for
+/// details.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2ButtonEvent {
+    /// zwp_tablet_tool_v2:button event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+    /// The button whose state has changed
+    pub button: u32,
+    /// Whether the button was pressed or released
+    pub state: enums::ZwpTabletToolV2ButtonState,
+}
+
+/// Marks the end of a series of axis and/or button updates from the
+/// tablet. The Wayland protocol requires axis updates to be sent
+/// sequentially, however all events within a frame should be considered
+/// one hardware event.
+#[derive(Debug)]
+pub struct ZwpTabletToolV2FrameEvent {
+    /// zwp_tablet_tool_v2:frame event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// The time of the event with millisecond granularity
+    pub time: u32,
+}
+
+/// A descriptive name for the tablet device.
+///
+/// If the device has no descriptive name, this event is not sent.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletV2NameEvent<'a> {
+    /// zwp_tablet_v2:name event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the device name
+    pub name: Cow<'a, str>,
+}
+
+impl ZwpTabletV2NameEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpTabletV2NameEvent<'static> {
+        ZwpTabletV2NameEvent {
+            source_id: self.source_id,
+            name: Cow::Owned(self.name.into_owned()),
+        }
+    }
+}
+
+/// The vendor and product IDs for the tablet device.
+///
+/// The interpretation of the id depends on the zwp_tablet_v2.bustype.
+/// Prior to version v2 of this protocol, the id was implied to be a USB
+/// vendor and product ID. If no zwp_tablet_v2.bustype is sent, the ID
+/// is to be interpreted as USB vendor and product ID.
+///
+/// If the device has no vendor/product ID, this event is not sent.
+/// This can happen for virtual devices or non-USB devices, for instance.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletV2IdEvent {
+    /// zwp_tablet_v2:id event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// vendor id
+    pub vid: u32,
+    /// product id
+    pub pid: u32,
+}
+
+/// A system-specific device path that indicates which device is behind
+/// this zwp_tablet_v2. This information may be used to gather additional
+/// information about the device, e.g. through libwacom.
+///
+/// A device may have more than one device path. If so, multiple
+/// zwp_tablet_v2.path events are sent. A device may be emulated and not
+/// have a device path, and in that case this event will not be sent.
+///
+/// The format of the path is unspecified, it may be a device node, a
+/// sysfs path, or some other identifier. It is up to the client to
+/// identify the string provided.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletV2PathEvent<'a> {
+    /// zwp_tablet_v2:path event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// path to local device
+    pub path: Cow<'a, str>,
+}
+
+impl ZwpTabletV2PathEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpTabletV2PathEvent<'static> {
+        ZwpTabletV2PathEvent {
+            source_id: self.source_id,
+            path: Cow::Owned(self.path.into_owned()),
+        }
+    }
+}
+
+/// This event is sent immediately to signal the end of the initial
+/// burst of descriptive events. A client may consider the static
+/// description of the tablet to be complete and finalize initialization
+/// of the tablet.
+#[derive(Debug)]
+pub struct ZwpTabletV2DoneEvent {
+    /// zwp_tablet_v2:done event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Sent when the tablet has been removed from the system. When a tablet
+/// is removed, some tools may be removed.
+///
+/// When this event is received, the client must zwp_tablet_v2.destroy
+/// the object.
+#[derive(Debug)]
+pub struct ZwpTabletV2RemovedEvent {
+    /// zwp_tablet_v2:removed event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// The bustype argument is one of the BUS_ defines in the Linux kernel's
+/// linux/input.h
+///
+/// If the device has no known bustype or the bustype cannot be
+/// queried, this event is not sent.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletV2BustypeEvent {
+    /// zwp_tablet_v2:bustype event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// bus type
+    pub bustype: enums::ZwpTabletV2Bustype,
+}
+
+/// Source information for ring events.
+///
+/// This event does not occur on its own. It is sent before a
+/// zwp_tablet_pad_ring_v2.frame event and carries the source information
+/// for all events within that frame.
+///
+/// The source specifies how this event was generated. If the source is
+/// zwp_tablet_pad_ring_v2.source.finger, a zwp_tablet_pad_ring_v2.stop event
+/// will be sent when the user lifts the finger off the device.
+///
+/// This event is optional. If the source is unknown for an interaction,
+/// no event is sent.
+#[derive(Debug)]
+pub struct ZwpTabletPadRingV2SourceEvent {
+    /// zwp_tablet_pad_ring_v2:source event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the event source
+    pub source: enums::ZwpTabletPadRingV2Source,
+}
+
+/// Sent whenever the angle on a ring changes.
+///
+/// The angle is provided in degrees clockwise from the logical
+/// north of the ring in the pad's current rotation.
+#[derive(Debug)]
+pub struct ZwpTabletPadRingV2AngleEvent {
+    /// zwp_tablet_pad_ring_v2:angle event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the current angle in degrees
+    pub degrees: Fixed,
+}
+
+/// Stop notification for ring events.
+///
+/// For some zwp_tablet_pad_ring_v2.source types, a zwp_tablet_pad_ring_v2.stop
+/// event is sent to notify a client that the interaction with the ring
+/// has terminated. This enables the client to implement kinetic scrolling.
+/// See the zwp_tablet_pad_ring_v2.source documentation for information on
+/// when this event may be generated.
+///
+/// Any zwp_tablet_pad_ring_v2.angle events with the same source after this
+/// event should be considered as the start of a new interaction.
+#[derive(Debug)]
+pub struct ZwpTabletPadRingV2StopEvent {
+    /// zwp_tablet_pad_ring_v2:stop event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Indicates the end of a set of ring events that logically belong
+/// together. A client is expected to accumulate the data in all events
+/// within the frame before proceeding.
+///
+/// All zwp_tablet_pad_ring_v2 events before a zwp_tablet_pad_ring_v2.frame event belong
+/// logically together. For example, on termination of a finger interaction
+/// on a ring the compositor will send a zwp_tablet_pad_ring_v2.source event,
+/// a zwp_tablet_pad_ring_v2.stop event and a zwp_tablet_pad_ring_v2.frame event.
+///
+/// A zwp_tablet_pad_ring_v2.frame event is sent for every logical event
+/// group, even if the group only contains a single zwp_tablet_pad_ring_v2
+/// event. Specifically, a client may get a sequence: angle, frame,
+/// angle, frame, etc.
+#[derive(Debug)]
+pub struct ZwpTabletPadRingV2FrameEvent {
+    /// zwp_tablet_pad_ring_v2:frame event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+}
+
+/// Source information for strip events.
+///
+/// This event does not occur on its own. It is sent before a
+/// zwp_tablet_pad_strip_v2.frame event and carries the source information
+/// for all events within that frame.
+///
+/// The source specifies how this event was generated. If the source is
+/// zwp_tablet_pad_strip_v2.source.finger, a zwp_tablet_pad_strip_v2.stop event
+/// will be sent when the user lifts their finger off the device.
+///
+/// This event is optional. If the source is unknown for an interaction,
+/// no event is sent.
+#[derive(Debug)]
+pub struct ZwpTabletPadStripV2SourceEvent {
+    /// zwp_tablet_pad_strip_v2:source event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the event source
+    pub source: enums::ZwpTabletPadStripV2Source,
+}
+
+/// Sent whenever the position on a strip changes.
+///
+/// The position is normalized to a range of [0, 65535], the 0-value
+/// represents the top-most and/or left-most position of the strip in
+/// the pad's current rotation.
+#[derive(Debug)]
+pub struct ZwpTabletPadStripV2PositionEvent {
+    /// zwp_tablet_pad_strip_v2:position event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the current position
+    pub position: u32,
+}
+
+/// Stop notification for strip events.
+///
+/// For some zwp_tablet_pad_strip_v2.source types, a zwp_tablet_pad_strip_v2.stop
+/// event is sent to notify a client that the interaction with the strip
+/// has terminated. This enables the client to implement kinetic
+/// scrolling. See the zwp_tablet_pad_strip_v2.source documentation for
+/// information on when this event may be generated.
+///
+/// Any zwp_tablet_pad_strip_v2.position events with the same source after this
+/// event should be considered as the start of a new interaction.
+#[derive(Debug)]
+pub struct ZwpTabletPadStripV2StopEvent {
+    /// zwp_tablet_pad_strip_v2:stop event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Indicates the end of a set of events that represent one logical
+/// hardware strip event. A client is expected to accumulate the data
+/// in all events within the frame before proceeding.
+///
+/// All zwp_tablet_pad_strip_v2 events before a zwp_tablet_pad_strip_v2.frame event belong
+/// logically together. For example, on termination of a finger interaction
+/// on a strip the compositor will send a zwp_tablet_pad_strip_v2.source event,
+/// a zwp_tablet_pad_strip_v2.stop event and a zwp_tablet_pad_strip_v2.frame
+/// event.
+///
+/// A zwp_tablet_pad_strip_v2.frame event is sent for every logical event
+/// group, even if the group only contains a single zwp_tablet_pad_strip_v2
+/// event. Specifically, a client may get a sequence: position, frame,
+/// position, frame, etc.
+#[derive(Debug)]
+pub struct ZwpTabletPadStripV2FrameEvent {
+    /// zwp_tablet_pad_strip_v2:frame event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+}
+
+/// Sent on zwp_tablet_pad_group_v2 initialization to announce the available
+/// buttons in the group. Button indices start at 0, a button may only be
+/// in one group at a time.
+///
+/// This event is first sent in the initial burst of events before the
+/// zwp_tablet_pad_group_v2.done event.
+///
+/// Some buttons are reserved by the compositor. These buttons may not be
+/// assigned to any zwp_tablet_pad_group_v2. Compositors may broadcast this
+/// event in the case of changes to the mapping of these reserved buttons.
+/// If the compositor happens to reserve all buttons in a group, this event
+/// will be sent with an empty array.
+#[derive(Debug)]
+pub struct ZwpTabletPadGroupV2ButtonsEvent<'a> {
+    /// zwp_tablet_pad_group_v2:buttons event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// buttons in this group
+    pub buttons: Cow<'a, [u8]>,
+}
+
+impl ZwpTabletPadGroupV2ButtonsEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpTabletPadGroupV2ButtonsEvent<'static> {
+        ZwpTabletPadGroupV2ButtonsEvent {
+            source_id: self.source_id,
+            buttons: Cow::Owned(self.buttons.into_owned()),
+        }
+    }
+}
+
+/// Sent on zwp_tablet_pad_group_v2 initialization to announce available rings.
+/// One event is sent for each ring available on this pad group.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_pad_group_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletPadGroupV2RingEvent {
+    /// zwp_tablet_pad_group_v2:ring event
+    /// id of the object the event came from
+    pub source_id: u32,
+    // new_id
+    pub ring: u32,
+}
+
+/// Sent on zwp_tablet_pad_v2 initialization to announce available strips.
+/// One event is sent for each strip available on this pad group.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_pad_group_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletPadGroupV2StripEvent {
+    /// zwp_tablet_pad_group_v2:strip event
+    /// id of the object the event came from
+    pub source_id: u32,
+    // new_id
+    pub strip: u32,
+}
+
+/// Sent on zwp_tablet_pad_group_v2 initialization to announce that the pad
+/// group may switch between modes. A client may use a mode to store a
+/// specific configuration for buttons, rings and strips and use the
+/// zwp_tablet_pad_group_v2.mode_switch event to toggle between these
+/// configurations. Mode indices start at 0.
+///
+/// Switching modes is compositor-dependent. See the
+/// zwp_tablet_pad_group_v2.mode_switch event for more details.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_pad_group_v2.done event. This event is only sent when
+/// more than one mode is available.
+#[derive(Debug)]
+pub struct ZwpTabletPadGroupV2ModesEvent {
+    /// zwp_tablet_pad_group_v2:modes event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the number of modes
+    pub modes: u32,
+}
+
+/// This event is sent immediately to signal the end of the initial
+/// burst of descriptive events. A client may consider the static
+/// description of the tablet to be complete and finalize initialization
+/// of the tablet group.
+#[derive(Debug)]
+pub struct ZwpTabletPadGroupV2DoneEvent {
+    /// zwp_tablet_pad_group_v2:done event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Notification that the mode was switched.
+///
+/// A mode applies to all buttons, rings, strips and dials in a group
+/// simultaneously, but a client is not required to assign different actions
+/// for each mode. For example, a client may have mode-specific button
+/// mappings but map the ring to vertical scrolling in all modes. Mode
+/// indices start at 0.
+///
+/// Switching modes is compositor-dependent. The compositor may provide
+/// visual cues to the user about the mode, e.g. by toggling LEDs on
+/// the tablet device. Mode-switching may be software-controlled or
+/// controlled by one or more physical buttons. For example, on a Wacom
+/// Intuos Pro, the button inside the ring may be assigned to switch
+/// between modes.
+///
+/// The compositor will also send this event after zwp_tablet_pad_v2.enter on
+/// each group in order to notify of the current mode. Groups that only
+/// feature one mode will use mode=0 when emitting this event.
+///
+/// If a button action in the new mode differs from the action in the
+/// previous mode, the client should immediately issue a
+/// zwp_tablet_pad_v2.set_feedback request for each changed button.
+///
+/// If a ring, strip or dial action in the new mode differs from the action
+/// in the previous mode, the client should immediately issue a
+/// zwp_tablet_ring_v2.set_feedback, zwp_tablet_strip_v2.set_feedback or
+/// zwp_tablet_dial_v2.set_feedback request for each changed ring, strip or dial.
+#[derive(Debug)]
+pub struct ZwpTabletPadGroupV2ModeSwitchEvent {
+    /// zwp_tablet_pad_group_v2:mode_switch event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the time of the event with millisecond granularity
+    pub time: u32,
+    pub serial: u32,
+    /// the new mode of the pad
+    pub mode: u32,
+}
+
+/// Sent on zwp_tablet_pad_v2 initialization to announce available dials.
+/// One event is sent for each dial available on this pad group.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_pad_group_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletPadGroupV2DialEvent {
+    /// zwp_tablet_pad_group_v2:dial event
+    /// id of the object the event came from
+    pub source_id: u32,
+    // new_id
+    pub dial: u32,
+}
+
+/// Sent on zwp_tablet_pad_v2 initialization to announce available groups.
+/// One event is sent for each pad group available.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_pad_v2.done event. At least one group will be announced.
+#[derive(Debug)]
+pub struct ZwpTabletPadV2GroupEvent {
+    /// zwp_tablet_pad_v2:group event
+    /// id of the object the event came from
+    pub source_id: u32,
+    // new_id
+    pub pad_group: u32,
+}
+
+/// A system-specific device path that indicates which device is behind
+/// this zwp_tablet_pad_v2. This information may be used to gather additional
+/// information about the device, e.g. through libwacom.
+///
+/// The format of the path is unspecified, it may be a device node, a
+/// sysfs path, or some other identifier. It is up to the client to
+/// identify the string provided.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_pad_v2.done event.
+#[derive(Debug)]
+pub struct ZwpTabletPadV2PathEvent<'a> {
+    /// zwp_tablet_pad_v2:path event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// path to local device
+    pub path: Cow<'a, str>,
+}
+
+impl ZwpTabletPadV2PathEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZwpTabletPadV2PathEvent<'static> {
+        ZwpTabletPadV2PathEvent {
+            source_id: self.source_id,
+            path: Cow::Owned(self.path.into_owned()),
+        }
+    }
+}
+
+/// Sent on zwp_tablet_pad_v2 initialization to announce the available
+/// buttons.
+///
+/// This event is sent in the initial burst of events before the
+/// zwp_tablet_pad_v2.done event. This event is only sent when at least one
+/// button is available.
+#[derive(Debug)]
+pub struct ZwpTabletPadV2ButtonsEvent {
+    /// zwp_tablet_pad_v2:buttons event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the number of buttons
+    pub buttons: u32,
+}
+
+/// This event signals the end of the initial burst of descriptive
+/// events. A client may consider the static description of the pad to
+/// be complete and finalize initialization of the pad.
+#[derive(Debug)]
+pub struct ZwpTabletPadV2DoneEvent {
+    /// zwp_tablet_pad_v2:done event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Sent whenever the physical state of a button changes.
+#[derive(Debug)]
+pub struct ZwpTabletPadV2ButtonEvent {
+    /// zwp_tablet_pad_v2:button event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// the time of the event with millisecond granularity
+    pub time: u32,
+    /// the index of the button that changed state
+    pub button: u32,
+    pub state: enums::ZwpTabletPadV2ButtonState,
+}
+
+/// Notification that this pad is focused on the specified surface.
+#[derive(Debug)]
+pub struct ZwpTabletPadV2EnterEvent {
+    /// zwp_tablet_pad_v2:enter event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// serial number of the enter event
+    pub serial: u32,
+    /// the tablet the pad is attached to
+    pub tablet: u32,
+    /// surface the pad is focused on
+    pub surface: u32,
+}
+
+/// Notification that this pad is no longer focused on the specified
+/// surface.
+#[derive(Debug)]
+pub struct ZwpTabletPadV2LeaveEvent {
+    /// zwp_tablet_pad_v2:leave event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// serial number of the leave event
+    pub serial: u32,
+    /// surface the pad is no longer focused on
+    pub surface: u32,
+}
+
+/// Sent when the pad has been removed from the system. When a tablet
+/// is removed its pad(s) will be removed too.
+///
+/// When this event is received, the client must destroy all rings, strips
+/// and groups that were offered by this pad, and issue zwp_tablet_pad_v2.destroy
+/// the pad itself.
+#[derive(Debug)]
+pub struct ZwpTabletPadV2RemovedEvent {
+    /// zwp_tablet_pad_v2:removed event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Sent whenever the position on a dial changes.
+///
+/// This event carries the wheel delta as multiples or fractions
+/// of 120 with each multiple of 120 representing one logical wheel detent.
+/// For example, an axis_value120 of 30 is one quarter of
+/// a logical wheel step in the positive direction, a value120 of
+/// -240 are two logical wheel steps in the negative direction within the
+/// same hardware event. See the wl_pointer.axis_value120 for more details.
+///
+/// The value120 must not be zero.
+#[derive(Debug)]
+pub struct ZwpTabletPadDialV2DeltaEvent {
+    /// zwp_tablet_pad_dial_v2:delta event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// rotation distance as fraction of 120
+    pub value120: i32,
+}
+
+/// Indicates the end of a set of events that represent one logical
+/// hardware dial event. A client is expected to accumulate the data
+/// in all events within the frame before proceeding.
+///
+/// All zwp_tablet_pad_dial_v2 events before a zwp_tablet_pad_dial_v2.frame event belong
+/// logically together.
+///
+/// A zwp_tablet_pad_dial_v2.frame event is sent for every logical event
+/// group, even if the group only contains a single zwp_tablet_pad_dial_v2
+/// event. Specifically, a client may get a sequence: delta, frame,
+/// delta, frame, etc.
+#[derive(Debug)]
+pub struct ZwpTabletPadDialV2FrameEvent {
+    /// zwp_tablet_pad_dial_v2:frame event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+}
+
+/// This event is sent when a multi-finger swipe gesture is detected
+/// on the device.
+#[derive(Debug)]
+pub struct ZwpPointerGestureSwipeV1BeginEvent {
+    /// zwp_pointer_gesture_swipe_v1:begin event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+    pub surface: u32,
+    /// number of fingers
+    pub fingers: u32,
+}
+
+/// This event is sent when a multi-finger swipe gesture changes the
+/// position of the logical center.
+///
+/// The dx and dy coordinates are relative coordinates of the logical
+/// center of the gesture compared to the previous event.
+#[derive(Debug)]
+pub struct ZwpPointerGestureSwipeV1UpdateEvent {
+    /// zwp_pointer_gesture_swipe_v1:update event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+    /// delta x coordinate in surface coordinate space
+    pub dx: Fixed,
+    /// delta y coordinate in surface coordinate space
+    pub dy: Fixed,
+}
+
+/// This event is sent when a multi-finger swipe gesture ceases to
+/// be valid. This may happen when one or more fingers are lifted or
+/// the gesture is cancelled.
+///
+/// When a gesture is cancelled, the client should undo state changes
+/// caused by this gesture. What causes a gesture to be cancelled is
+/// implementation-dependent.
+#[derive(Debug)]
+pub struct ZwpPointerGestureSwipeV1EndEvent {
+    /// zwp_pointer_gesture_swipe_v1:end event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+    /// 1 if the gesture was cancelled, 0 otherwise
+    pub cancelled: i32,
+}
+
+/// This event is sent when a multi-finger pinch gesture is detected
+/// on the device.
+#[derive(Debug)]
+pub struct ZwpPointerGesturePinchV1BeginEvent {
+    /// zwp_pointer_gesture_pinch_v1:begin event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+    pub surface: u32,
+    /// number of fingers
+    pub fingers: u32,
+}
+
+/// This event is sent when a multi-finger pinch gesture changes the
+/// position of the logical center, the rotation or the relative scale.
+///
+/// The dx and dy coordinates are relative coordinates in the
+/// surface coordinate space of the logical center of the gesture.
+///
+/// The scale factor is an absolute scale compared to the
+/// pointer_gesture_pinch.begin event, e.g. a scale of 2 means the fingers
+/// are now twice as far apart as on pointer_gesture_pinch.begin.
+///
+/// The rotation is the relative angle in degrees clockwise compared to the previous
+/// pointer_gesture_pinch.begin or pointer_gesture_pinch.update event.
+#[derive(Debug)]
+pub struct ZwpPointerGesturePinchV1UpdateEvent {
+    /// zwp_pointer_gesture_pinch_v1:update event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+    /// delta x coordinate in surface coordinate space
+    pub dx: Fixed,
+    /// delta y coordinate in surface coordinate space
+    pub dy: Fixed,
+    /// scale relative to the initial finger position
+    pub scale: Fixed,
+    /// angle in degrees cw relative to the previous event
+    pub rotation: Fixed,
+}
+
+/// This event is sent when a multi-finger pinch gesture ceases to
+/// be valid. This may happen when one or more fingers are lifted or
+/// the gesture is cancelled.
+///
+/// When a gesture is cancelled, the client should undo state changes
+/// caused by this gesture. What causes a gesture to be cancelled is
+/// implementation-dependent.
+#[derive(Debug)]
+pub struct ZwpPointerGesturePinchV1EndEvent {
+    /// zwp_pointer_gesture_pinch_v1:end event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+    /// 1 if the gesture was cancelled, 0 otherwise
+    pub cancelled: i32,
+}
+
+/// This event is sent when a hold gesture is detected on the device.
+#[derive(Debug)]
+pub struct ZwpPointerGestureHoldV1BeginEvent {
+    /// zwp_pointer_gesture_hold_v1:begin event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+    pub surface: u32,
+    /// number of fingers
+    pub fingers: u32,
+}
+
+/// This event is sent when a hold gesture ceases to
+/// be valid. This may happen when the holding fingers are lifted or
+/// the gesture is cancelled, for example if the fingers move past an
+/// implementation-defined threshold, the finger count changes or the hold
+/// gesture changes into a different type of gesture.
+///
+/// When a gesture is cancelled, the client may need to undo state changes
+/// caused by this gesture. What causes a gesture to be cancelled is
+/// implementation-dependent.
+#[derive(Debug)]
+pub struct ZwpPointerGestureHoldV1EndEvent {
+    /// zwp_pointer_gesture_hold_v1:end event
+    /// id of the object the event came from
+    pub source_id: u32,
+    pub serial: u32,
+    /// timestamp with millisecond granularity
+    pub time: u32,
+    /// 1 if the gesture was cancelled, 0 otherwise
+    pub cancelled: i32,
+}
+
+/// The position event describes the location of the wl_output within
+/// the global compositor space.
+///
+/// The logical_position event is sent after creating an xdg_output
+/// (see xdg_output_manager.get_xdg_output) and whenever the location
+/// of the output changes within the global compositor space.
+#[derive(Debug)]
+pub struct ZxdgOutputV1LogicalPositionEvent {
+    /// zxdg_output_v1:logical_position event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// x position within the global compositor space
+    pub x: i32,
+    /// y position within the global compositor space
+    pub y: i32,
+}
+
+/// The logical_size event describes the size of the output in the
+/// global compositor space.
+///
+/// Most regular Wayland clients should not pay attention to the
+/// logical size and would rather rely on xdg_shell interfaces.
+///
+/// Some clients such as Xwayland, however, need this to configure
+/// their surfaces in the global compositor space as the compositor
+/// may apply a different scale from what is advertised by the output
+/// scaling property (to achieve fractional scaling, for example).
+///
+/// For example, for a wl_output mode 3840×2160 and a scale factor 2:
+///
+/// - A compositor not scaling the monitor viewport in its compositing space
+/// will advertise a logical size of 3840×2160,
+///
+/// - A compositor scaling the monitor viewport with scale factor 2 will
+/// advertise a logical size of 1920×1080,
+///
+/// - A compositor scaling the monitor viewport using a fractional scale of
+/// 1.5 will advertise a logical size of 2560×1440.
+///
+/// For example, for a wl_output mode 1920×1080 and a 90 degree rotation,
+/// the compositor will advertise a logical size of 1080x1920.
+///
+/// The logical_size event is sent after creating an xdg_output
+/// (see xdg_output_manager.get_xdg_output) and whenever the logical
+/// size of the output changes, either as a result of a change in the
+/// applied scale or because of a change in the corresponding output
+/// mode(see wl_output.mode) or transform (see wl_output.transform).
+#[derive(Debug)]
+pub struct ZxdgOutputV1LogicalSizeEvent {
+    /// zxdg_output_v1:logical_size event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// width in global compositor space
+    pub width: i32,
+    /// height in global compositor space
+    pub height: i32,
+}
+
+/// This event is sent after all other properties of an xdg_output
+/// have been sent.
+///
+/// This allows changes to the xdg_output properties to be seen as
+/// atomic, even if they happen via multiple events.
+///
+/// For objects version 3 onwards, this event is deprecated. Compositors
+/// are not required to send it anymore and must send wl_output.done
+/// instead.
+#[derive(Debug)]
+pub struct ZxdgOutputV1DoneEvent {
+    /// zxdg_output_v1:done event
+    /// id of the object the event came from
+    pub source_id: u32,
+}
+
+/// Many compositors will assign names to their outputs, show them to the
+/// user, allow them to be configured by name, etc. The client may wish to
+/// know this name as well to offer the user similar behaviors.
+///
+/// The naming convention is compositor defined, but limited to
+/// alphanumeric characters and dashes (-). Each name is unique among all
+/// wl_output globals, but if a wl_output global is destroyed the same name
+/// may be reused later. The names will also remain consistent across
+/// sessions with the same hardware and software configuration.
+///
+/// Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do
+/// not assume that the name is a reflection of an underlying DRM
+/// connector, X11 connection, etc.
+///
+/// The name event is sent after creating an xdg_output (see
+/// xdg_output_manager.get_xdg_output). This event is only sent once per
+/// xdg_output, and the name does not change over the lifetime of the
+/// wl_output global.
+///
+/// This event is deprecated, instead clients should use wl_output.name.
+/// Compositors must still support this event.
+#[derive(Debug)]
+pub struct ZxdgOutputV1NameEvent<'a> {
+    /// zxdg_output_v1:name event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// output name
+    pub name: Cow<'a, str>,
+}
+
+impl ZxdgOutputV1NameEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZxdgOutputV1NameEvent<'static> {
+        ZxdgOutputV1NameEvent {
+            source_id: self.source_id,
+            name: Cow::Owned(self.name.into_owned()),
+        }
+    }
+}
+
+/// Many compositors can produce human-readable descriptions of their
+/// outputs.  The client may wish to know this description as well, to
+/// communicate the user for various purposes.
+///
+/// The description is a UTF-8 string with no convention defined for its
+/// contents. Examples might include 'Foocorp 11" Display' or 'Virtual X11
+/// output via :1'.
+///
+/// The description event is sent after creating an xdg_output (see
+/// xdg_output_manager.get_xdg_output) and whenever the description
+/// changes. The description is optional, and may not be sent at all.
+///
+/// For objects of version 2 and lower, this event is only sent once per
+/// xdg_output, and the description does not change over the lifetime of
+/// the wl_output global.
+///
+/// This event is deprecated, instead clients should use
+/// wl_output.description. Compositors must still support this event.
+#[derive(Debug)]
+pub struct ZxdgOutputV1DescriptionEvent<'a> {
+    /// zxdg_output_v1:description event
+    /// id of the object the event came from
+    pub source_id: u32,
+    /// output description
+    pub description: Cow<'a, str>,
+}
+
+impl ZxdgOutputV1DescriptionEvent<'_> {
+    /// Detaches the event from the receive buffer
+    pub fn into_owned(self) -> ZxdgOutputV1DescriptionEvent<'static> {
+        ZxdgOutputV1DescriptionEvent {
+            source_id: self.source_id,
+            description: Cow::Owned(self.description.into_owned()),
+        }
+    }
 }
 
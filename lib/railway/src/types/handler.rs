@@ -15,13 +15,13 @@ pub trait EventHandler{
 
     fn on_zwp_linux_dmabuf_feedback_v1_format_table(&mut self, event: ZwpLinuxDmabufFeedbackV1FormatTableEvent, connection: &WaylandConnection) {}
 
-    fn on_zwp_linux_dmabuf_feedback_v1_main_device(&mut self, event: ZwpLinuxDmabufFeedbackV1MainDeviceEvent, connection: &WaylandConnection) {}
+    fn on_zwp_linux_dmabuf_feedback_v1_main_device(&mut self, event: ZwpLinuxDmabufFeedbackV1MainDeviceEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_zwp_linux_dmabuf_feedback_v1_tranche_done(&mut self, event: ZwpLinuxDmabufFeedbackV1TrancheDoneEvent, connection: &WaylandConnection) {}
 
-    fn on_zwp_linux_dmabuf_feedback_v1_tranche_target_device(&mut self, event: ZwpLinuxDmabufFeedbackV1TrancheTargetDeviceEvent, connection: &WaylandConnection) {}
+    fn on_zwp_linux_dmabuf_feedback_v1_tranche_target_device(&mut self, event: ZwpLinuxDmabufFeedbackV1TrancheTargetDeviceEvent<'_>, connection: &WaylandConnection) {}
 
-    fn on_zwp_linux_dmabuf_feedback_v1_tranche_formats(&mut self, event: ZwpLinuxDmabufFeedbackV1TrancheFormatsEvent, connection: &WaylandConnection) {}
+    fn on_zwp_linux_dmabuf_feedback_v1_tranche_formats(&mut self, event: ZwpLinuxDmabufFeedbackV1TrancheFormatsEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_zwp_linux_dmabuf_feedback_v1_tranche_flags(&mut self, event: ZwpLinuxDmabufFeedbackV1TrancheFlagsEvent, connection: &WaylandConnection) {}
 
@@ -29,13 +29,13 @@ pub trait EventHandler{
 
     fn on_xdg_surface_configure(&mut self, event: XdgSurfaceConfigureEvent, connection: &WaylandConnection) {}
 
-    fn on_xdg_toplevel_configure(&mut self, event: XdgToplevelConfigureEvent, connection: &WaylandConnection) {}
+    fn on_xdg_toplevel_configure(&mut self, event: XdgToplevelConfigureEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_xdg_toplevel_close(&mut self, event: XdgToplevelCloseEvent, connection: &WaylandConnection) {}
 
     fn on_xdg_toplevel_configure_bounds(&mut self, event: XdgToplevelConfigureBoundsEvent, connection: &WaylandConnection) {}
 
-    fn on_xdg_toplevel_wm_capabilities(&mut self, event: XdgToplevelWmCapabilitiesEvent, connection: &WaylandConnection) {}
+    fn on_xdg_toplevel_wm_capabilities(&mut self, event: XdgToplevelWmCapabilitiesEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_xdg_popup_configure(&mut self, event: XdgPopupConfigureEvent, connection: &WaylandConnection) {}
 
@@ -45,11 +45,11 @@ pub trait EventHandler{
 
     fn on_wl_display_sync_done(&mut self, event: WlDisplaySyncDoneEvent, connection: &WaylandConnection) {}
 
-    fn on_wl_display_error(&mut self, event: WlDisplayErrorEvent, connection: &WaylandConnection) {}
+    fn on_wl_display_error(&mut self, event: WlDisplayErrorEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_wl_display_delete_id(&mut self, event: WlDisplayDeleteIdEvent, connection: &WaylandConnection) {}
 
-    fn on_wl_registry_global(&mut self, event: WlRegistryGlobalEvent, connection: &WaylandConnection) {}
+    fn on_wl_registry_global(&mut self, event: WlRegistryGlobalEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_wl_registry_global_remove(&mut self, event: WlRegistryGlobalRemoveEvent, connection: &WaylandConnection) {}
 
@@ -57,15 +57,15 @@ pub trait EventHandler{
 
     fn on_wl_buffer_release(&mut self, event: WlBufferReleaseEvent, connection: &WaylandConnection) {}
 
-    fn on_wl_data_offer_offer(&mut self, event: WlDataOfferOfferEvent, connection: &WaylandConnection) {}
+    fn on_wl_data_offer_offer(&mut self, event: WlDataOfferOfferEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_wl_data_offer_source_actions(&mut self, event: WlDataOfferSourceActionsEvent, connection: &WaylandConnection) {}
 
     fn on_wl_data_offer_action(&mut self, event: WlDataOfferActionEvent, connection: &WaylandConnection) {}
 
-    fn on_wl_data_source_target(&mut self, event: WlDataSourceTargetEvent, connection: &WaylandConnection) {}
+    fn on_wl_data_source_target(&mut self, event: WlDataSourceTargetEvent<'_>, connection: &WaylandConnection) {}
 
-    fn on_wl_data_source_send(&mut self, event: WlDataSourceSendEvent, connection: &WaylandConnection) {}
+    fn on_wl_data_source_send(&mut self, event: WlDataSourceSendEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_wl_data_source_cancelled(&mut self, event: WlDataSourceCancelledEvent, connection: &WaylandConnection) {}
 
@@ -105,7 +105,7 @@ pub trait EventHandler{
 
     fn on_wl_seat_capabilities(&mut self, event: WlSeatCapabilitiesEvent, connection: &WaylandConnection) {}
 
-    fn on_wl_seat_name(&mut self, event: WlSeatNameEvent, connection: &WaylandConnection) {}
+    fn on_wl_seat_name(&mut self, event: WlSeatNameEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_wl_pointer_enter(&mut self, event: WlPointerEnterEvent, connection: &WaylandConnection) {}
 
@@ -131,7 +131,7 @@ pub trait EventHandler{
 
     fn on_wl_keyboard_keymap(&mut self, event: WlKeyboardKeymapEvent, connection: &WaylandConnection) {}
 
-    fn on_wl_keyboard_enter(&mut self, event: WlKeyboardEnterEvent, connection: &WaylandConnection) {}
+    fn on_wl_keyboard_enter(&mut self, event: WlKeyboardEnterEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_wl_keyboard_leave(&mut self, event: WlKeyboardLeaveEvent, connection: &WaylandConnection) {}
 
@@ -155,7 +155,7 @@ pub trait EventHandler{
 
     fn on_wl_touch_orientation(&mut self, event: WlTouchOrientationEvent, connection: &WaylandConnection) {}
 
-    fn on_wl_output_geometry(&mut self, event: WlOutputGeometryEvent, connection: &WaylandConnection) {}
+    fn on_wl_output_geometry(&mut self, event: WlOutputGeometryEvent<'_>, connection: &WaylandConnection) {}
 
     fn on_wl_output_mode(&mut self, event: WlOutputModeEvent, connection: &WaylandConnection) {}
 
@@ -163,8 +163,262 @@ pub trait EventHandler{
 
     fn on_wl_output_scale(&mut self, event: WlOutputScaleEvent, connection: &WaylandConnection) {}
 
-    fn on_wl_output_name(&mut self, event: WlOutputNameEvent, connection: &WaylandConnection) {}
+    fn on_wl_output_name(&mut self, event: WlOutputNameEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_wl_output_description(&mut self, event: WlOutputDescriptionEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zxdg_toplevel_decoration_v1_configure(&mut self, event: ZxdgToplevelDecorationV1ConfigureEvent, connection: &WaylandConnection) {}
+
+
+    fn on_wp_fractional_scale_v1_preferred_scale(&mut self, event: WpFractionalScaleV1PreferredScaleEvent, connection: &WaylandConnection) {}
+
+    fn on_wp_presentation_clock_id(&mut self, event: WpPresentationClockIdEvent, connection: &WaylandConnection) {}
+
+    fn on_wp_presentation_feedback_sync_output(&mut self, event: WpPresentationFeedbackSyncOutputEvent, connection: &WaylandConnection) {}
+
+    fn on_wp_presentation_feedback_presented(&mut self, event: WpPresentationFeedbackPresentedEvent, connection: &WaylandConnection) {}
+
+    fn on_wp_presentation_feedback_discarded(&mut self, event: WpPresentationFeedbackDiscardedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_linux_buffer_release_v1_fenced_release(&mut self, event: ZwpLinuxBufferReleaseV1FencedReleaseEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_linux_buffer_release_v1_immediate_release(&mut self, event: ZwpLinuxBufferReleaseV1ImmediateReleaseEvent, connection: &WaylandConnection) {}
+
+
+    fn on_xdg_activation_token_v1_done(&mut self, event: XdgActivationTokenV1DoneEvent<'_>, connection: &WaylandConnection) {}
+
+
+    fn on_zwp_text_input_v3_enter(&mut self, event: ZwpTextInputV3EnterEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_text_input_v3_leave(&mut self, event: ZwpTextInputV3LeaveEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_text_input_v3_preedit_string(&mut self, event: ZwpTextInputV3PreeditStringEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zwp_text_input_v3_commit_string(&mut self, event: ZwpTextInputV3CommitStringEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zwp_text_input_v3_delete_surrounding_text(&mut self, event: ZwpTextInputV3DeleteSurroundingTextEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_text_input_v3_done(&mut self, event: ZwpTextInputV3DoneEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_text_input_v3_action(&mut self, event: ZwpTextInputV3ActionEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_text_input_v3_language(&mut self, event: ZwpTextInputV3LanguageEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zwp_text_input_v3_preedit_hint(&mut self, event: ZwpTextInputV3PreeditHintEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_primary_selection_device_v1_data_offer(&mut self, event: ZwpPrimarySelectionDeviceV1DataOfferEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_primary_selection_device_v1_selection(&mut self, event: ZwpPrimarySelectionDeviceV1SelectionEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_primary_selection_offer_v1_offer(&mut self, event: ZwpPrimarySelectionOfferV1OfferEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zwp_primary_selection_source_v1_send(&mut self, event: ZwpPrimarySelectionSourceV1SendEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zwp_primary_selection_source_v1_cancelled(&mut self, event: ZwpPrimarySelectionSourceV1CancelledEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_locked_pointer_v1_locked(&mut self, event: ZwpLockedPointerV1LockedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_locked_pointer_v1_unlocked(&mut self, event: ZwpLockedPointerV1UnlockedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_confined_pointer_v1_confined(&mut self, event: ZwpConfinedPointerV1ConfinedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_confined_pointer_v1_unconfined(&mut self, event: ZwpConfinedPointerV1UnconfinedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_relative_pointer_v1_relative_motion(&mut self, event: ZwpRelativePointerV1RelativeMotionEvent, connection: &WaylandConnection) {}
+
+
+    fn on_ext_idle_notification_v1_idled(&mut self, event: ExtIdleNotificationV1IdledEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_idle_notification_v1_resumed(&mut self, event: ExtIdleNotificationV1ResumedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwlr_layer_surface_v1_configure(&mut self, event: ZwlrLayerSurfaceV1ConfigureEvent, connection: &WaylandConnection) {}
+
+    fn on_zwlr_layer_surface_v1_closed(&mut self, event: ZwlrLayerSurfaceV1ClosedEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_session_lock_v1_locked(&mut self, event: ExtSessionLockV1LockedEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_session_lock_v1_finished(&mut self, event: ExtSessionLockV1FinishedEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_session_lock_surface_v1_configure(&mut self, event: ExtSessionLockSurfaceV1ConfigureEvent, connection: &WaylandConnection) {}
+
+
+    fn on_ext_image_copy_capture_session_v1_buffer_size(&mut self, event: ExtImageCopyCaptureSessionV1BufferSizeEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_session_v1_shm_format(&mut self, event: ExtImageCopyCaptureSessionV1ShmFormatEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_session_v1_dmabuf_device(&mut self, event: ExtImageCopyCaptureSessionV1DmabufDeviceEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_session_v1_dmabuf_format(&mut self, event: ExtImageCopyCaptureSessionV1DmabufFormatEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_session_v1_done(&mut self, event: ExtImageCopyCaptureSessionV1DoneEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_session_v1_stopped(&mut self, event: ExtImageCopyCaptureSessionV1StoppedEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_frame_v1_transform(&mut self, event: ExtImageCopyCaptureFrameV1TransformEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_frame_v1_damage(&mut self, event: ExtImageCopyCaptureFrameV1DamageEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_frame_v1_presentation_time(&mut self, event: ExtImageCopyCaptureFrameV1PresentationTimeEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_frame_v1_ready(&mut self, event: ExtImageCopyCaptureFrameV1ReadyEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_frame_v1_failed(&mut self, event: ExtImageCopyCaptureFrameV1FailedEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_cursor_session_v1_enter(&mut self, event: ExtImageCopyCaptureCursorSessionV1EnterEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_cursor_session_v1_leave(&mut self, event: ExtImageCopyCaptureCursorSessionV1LeaveEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_cursor_session_v1_position(&mut self, event: ExtImageCopyCaptureCursorSessionV1PositionEvent, connection: &WaylandConnection) {}
+
+    fn on_ext_image_copy_capture_cursor_session_v1_hotspot(&mut self, event: ExtImageCopyCaptureCursorSessionV1HotspotEvent, connection: &WaylandConnection) {}
+
+    fn on_zwlr_screencopy_frame_v1_buffer(&mut self, event: ZwlrScreencopyFrameV1BufferEvent, connection: &WaylandConnection) {}
+
+    fn on_zwlr_screencopy_frame_v1_flags(&mut self, event: ZwlrScreencopyFrameV1FlagsEvent, connection: &WaylandConnection) {}
+
+    fn on_zwlr_screencopy_frame_v1_ready(&mut self, event: ZwlrScreencopyFrameV1ReadyEvent, connection: &WaylandConnection) {}
+
+    fn on_zwlr_screencopy_frame_v1_failed(&mut self, event: ZwlrScreencopyFrameV1FailedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwlr_screencopy_frame_v1_damage(&mut self, event: ZwlrScreencopyFrameV1DamageEvent, connection: &WaylandConnection) {}
+
+    fn on_zwlr_screencopy_frame_v1_linux_dmabuf(&mut self, event: ZwlrScreencopyFrameV1LinuxDmabufEvent, connection: &WaylandConnection) {}
+
+    fn on_zwlr_screencopy_frame_v1_buffer_done(&mut self, event: ZwlrScreencopyFrameV1BufferDoneEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_seat_v2_tablet_added(&mut self, event: ZwpTabletSeatV2TabletAddedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_seat_v2_tool_added(&mut self, event: ZwpTabletSeatV2ToolAddedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_seat_v2_pad_added(&mut self, event: ZwpTabletSeatV2PadAddedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_type(&mut self, event: ZwpTabletToolV2TypeEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_hardware_serial(&mut self, event: ZwpTabletToolV2HardwareSerialEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_hardware_id_wacom(&mut self, event: ZwpTabletToolV2HardwareIdWacomEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_capability(&mut self, event: ZwpTabletToolV2CapabilityEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_done(&mut self, event: ZwpTabletToolV2DoneEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_removed(&mut self, event: ZwpTabletToolV2RemovedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_proximity_in(&mut self, event: ZwpTabletToolV2ProximityInEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_proximity_out(&mut self, event: ZwpTabletToolV2ProximityOutEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_down(&mut self, event: ZwpTabletToolV2DownEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_up(&mut self, event: ZwpTabletToolV2UpEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_motion(&mut self, event: ZwpTabletToolV2MotionEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_pressure(&mut self, event: ZwpTabletToolV2PressureEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_distance(&mut self, event: ZwpTabletToolV2DistanceEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_tilt(&mut self, event: ZwpTabletToolV2TiltEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_rotation(&mut self, event: ZwpTabletToolV2RotationEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_slider(&mut self, event: ZwpTabletToolV2SliderEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_wheel(&mut self, event: ZwpTabletToolV2WheelEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_button(&mut self, event: ZwpTabletToolV2ButtonEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_tool_v2_frame(&mut self, event: ZwpTabletToolV2FrameEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_v2_name(&mut self, event: ZwpTabletV2NameEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_v2_id(&mut self, event: ZwpTabletV2IdEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_v2_path(&mut self, event: ZwpTabletV2PathEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_v2_done(&mut self, event: ZwpTabletV2DoneEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_v2_removed(&mut self, event: ZwpTabletV2RemovedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_v2_bustype(&mut self, event: ZwpTabletV2BustypeEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_ring_v2_source(&mut self, event: ZwpTabletPadRingV2SourceEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_ring_v2_angle(&mut self, event: ZwpTabletPadRingV2AngleEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_ring_v2_stop(&mut self, event: ZwpTabletPadRingV2StopEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_ring_v2_frame(&mut self, event: ZwpTabletPadRingV2FrameEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_strip_v2_source(&mut self, event: ZwpTabletPadStripV2SourceEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_strip_v2_position(&mut self, event: ZwpTabletPadStripV2PositionEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_strip_v2_stop(&mut self, event: ZwpTabletPadStripV2StopEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_strip_v2_frame(&mut self, event: ZwpTabletPadStripV2FrameEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_group_v2_buttons(&mut self, event: ZwpTabletPadGroupV2ButtonsEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_group_v2_ring(&mut self, event: ZwpTabletPadGroupV2RingEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_group_v2_strip(&mut self, event: ZwpTabletPadGroupV2StripEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_group_v2_modes(&mut self, event: ZwpTabletPadGroupV2ModesEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_group_v2_done(&mut self, event: ZwpTabletPadGroupV2DoneEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_group_v2_mode_switch(&mut self, event: ZwpTabletPadGroupV2ModeSwitchEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_group_v2_dial(&mut self, event: ZwpTabletPadGroupV2DialEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_v2_group(&mut self, event: ZwpTabletPadV2GroupEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_v2_path(&mut self, event: ZwpTabletPadV2PathEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_v2_buttons(&mut self, event: ZwpTabletPadV2ButtonsEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_v2_done(&mut self, event: ZwpTabletPadV2DoneEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_v2_button(&mut self, event: ZwpTabletPadV2ButtonEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_v2_enter(&mut self, event: ZwpTabletPadV2EnterEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_v2_leave(&mut self, event: ZwpTabletPadV2LeaveEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_v2_removed(&mut self, event: ZwpTabletPadV2RemovedEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_dial_v2_delta(&mut self, event: ZwpTabletPadDialV2DeltaEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_tablet_pad_dial_v2_frame(&mut self, event: ZwpTabletPadDialV2FrameEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_pointer_gesture_swipe_v1_begin(&mut self, event: ZwpPointerGestureSwipeV1BeginEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_pointer_gesture_swipe_v1_update(&mut self, event: ZwpPointerGestureSwipeV1UpdateEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_pointer_gesture_swipe_v1_end(&mut self, event: ZwpPointerGestureSwipeV1EndEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_pointer_gesture_pinch_v1_begin(&mut self, event: ZwpPointerGesturePinchV1BeginEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_pointer_gesture_pinch_v1_update(&mut self, event: ZwpPointerGesturePinchV1UpdateEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_pointer_gesture_pinch_v1_end(&mut self, event: ZwpPointerGesturePinchV1EndEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_pointer_gesture_hold_v1_begin(&mut self, event: ZwpPointerGestureHoldV1BeginEvent, connection: &WaylandConnection) {}
+
+    fn on_zwp_pointer_gesture_hold_v1_end(&mut self, event: ZwpPointerGestureHoldV1EndEvent, connection: &WaylandConnection) {}
+
+    fn on_zxdg_output_v1_logical_position(&mut self, event: ZxdgOutputV1LogicalPositionEvent, connection: &WaylandConnection) {}
+
+    fn on_zxdg_output_v1_logical_size(&mut self, event: ZxdgOutputV1LogicalSizeEvent, connection: &WaylandConnection) {}
+
+    fn on_zxdg_output_v1_done(&mut self, event: ZxdgOutputV1DoneEvent, connection: &WaylandConnection) {}
+
+    fn on_zxdg_output_v1_name(&mut self, event: ZxdgOutputV1NameEvent<'_>, connection: &WaylandConnection) {}
+
+    fn on_zxdg_output_v1_description(&mut self, event: ZxdgOutputV1DescriptionEvent<'_>, connection: &WaylandConnection) {}
+
+
 
-    fn on_wl_output_description(&mut self, event: WlOutputDescriptionEvent, connection: &WaylandConnection) {}
 
 }
@@ -0,0 +1,17 @@
+use crate::types::Object;
+
+/// Wayland interface implemented by every proxy type
+pub trait Interface: Copy {
+    /// Interface name as advertised by the registry
+    const NAME: &'static str;
+    /// Highest interface version supported by railway
+    const VERSION: u32;
+    /// Object type used to register new instances in the object store
+    const OBJECT: Object;
+
+    /// Wraps an object id into a typed proxy
+    fn from_id(id: u32) -> Self;
+
+    /// Object id of the proxy
+    fn id(&self) -> u32;
+}
@@ -138,5 +138,16 @@ extern "C" {
         offset: i64
     ) -> *const c_void;
 
+    pub fn munmap(
+        addr: *const c_void,
+        len: usize
+    ) -> i32;
+
+    pub fn shm_open(
+        path: *const u8,
+        flags: i32,
+        mode: u16,
+    ) -> i32;
+
 }
 
@@ -6,11 +6,13 @@ pub mod requests;
 pub mod id_store;
 pub mod mempool;
 pub mod handler;
+pub mod interface;
 
 pub use events::*;
 pub use enums::*;
 pub use object::*;
 pub use requests::*;
+pub use interface::*;
 
 /// Typless object
 #[derive(Clone, Copy)]
@@ -61,6 +63,25 @@ impl Fixed {
     pub fn new(value: u32) -> Fixed {
         Fixed(value)
     }
+
+    /// Converts to signed 24.8 fixed point, rounding to the nearest 1/256
+    pub fn from_f64(value: f64) -> Fixed {
+        Fixed((value * 256.0).round() as i32 as u32)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        (self.0 as i32) as f64 / 256.0
+    }
+}
+
+/// Joins the hi and lo words of a 64 bit value sent on the wire
+pub fn join(hi: u32, lo: u32) -> u64 {
+    (hi as u64) << 32 | lo as u64
+}
+
+/// Splits a 64 bit value into the hi and lo words sent on the wire
+pub fn split(value: u64) -> (u32, u32) {
+    ((value >> 32) as u32, value as u32)
 }
 // pub struct Id {
 //         id: u32,
@@ -70,12 +91,29 @@ impl Fixed {
 
 #[cfg(test)]
 mod tests {
-    use super::MessageHeader;
+    use super::{join, split, Fixed, MessageHeader};
 
     #[test]
     fn msg_header_test() {
         let hdr = MessageHeader::from_words(1310724, 8);
         assert!(hdr.len != 0);
     }
+
+    #[test]
+    fn fixed_conversion() {
+        assert_eq!(Fixed::from_f64(1.5).0, 384);
+        assert_eq!(Fixed::from_f64(-1.0).0, 0xFFFFFF00);
+        assert_eq!(Fixed(0xFFFFFF80).to_f64(), -0.5);
+    }
+
+    #[test]
+    fn split_words() {
+        assert_eq!(split(5), (0, 5));
+        assert_eq!(split(1 << 32 | 7), (1, 7));
+        assert_eq!(join(1, 7), 1 << 32 | 7);
+        assert_eq!(join(u32::MAX, u32::MAX), u64::MAX);
+        let (hi, lo) = split(0x0123_4567_89ab_cdef);
+        assert_eq!(join(hi, lo), 0x0123_4567_89ab_cdef);
+    }
 }
 
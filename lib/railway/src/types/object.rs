@@ -1,4 +1,5 @@
-use crate::types::Id;
+use crate::types::Interface;
+use crate::types::Fixed;
 use crate::connection::WaylandConnection;
 use crate::types::Request;
 
@@ -530,6 +531,867 @@ pub enum Object {
     /// unmapped.
     WlSubsurface,
 
+    /// This interface allows a compositor to announce support for server-side
+    /// decorations.
+    ///
+    /// A window decoration is a set of window controls as deemed appropriate by
+    /// the party managing them, such as user interface components used to move,
+    /// resize and change a window's state.
+    ///
+    /// A client can use this protocol to request being decorated by a supporting
+    /// compositor.
+    ///
+    /// If compositor and client do not negotiate the use of a server-side
+    /// decoration using this protocol, clients continue to self-decorate as they
+    /// see fit.
+    ///
+    /// Warning! The protocol described in this file is experimental and
+    /// backward incompatible changes may be made. Backward compatible changes
+    /// may be added together with the corresponding interface version bump.
+    /// Backward incompatible changes are done by bumping the version number in
+    /// the protocol and interface names and resetting the interface version.
+    /// Once the protocol is to be declared stable, the 'z' prefix and the
+    /// version number in the protocol and interface names are removed and the
+    /// interface version number is reset.
+    ZxdgDecorationManagerV1,
+
+    /// The decoration object allows the compositor to toggle server-side window
+    /// decorations for a toplevel surface. The client can request to switch to
+    /// another mode.
+    ///
+    /// The xdg_toplevel_decoration object must be destroyed before its
+    /// xdg_toplevel.
+    ZxdgToplevelDecorationV1,
+
+    /// The global interface exposing surface cropping and scaling
+    /// capabilities is used to instantiate an interface extension for a
+    /// wl_surface object. This extended interface will then allow
+    /// cropping and scaling the surface contents, effectively
+    /// disconnecting the direct relationship between the buffer and the
+    /// surface size.
+    WpViewporter,
+
+    /// An additional interface to a wl_surface object, which allows the
+    /// client to specify the cropping and scaling of the surface
+    /// contents.
+    ///
+    /// This interface works with two concepts: the source rectangle (src_x,
+    /// src_y, src_width, src_height), and the destination size (dst_width,
+    /// dst_height). The contents of the source rectangle are scaled to the
+    /// destination size, and content outside the source rectangle is ignored.
+    /// This state is double-buffered, see wl_surface.commit.
+    ///
+    /// The two parts of crop and scale state are independent: the source
+    /// rectangle, and the destination size. Initially both are unset, that
+    /// is, no scaling is applied. The whole of the current wl_buffer is
+    /// used as the source, and the surface size is as defined in
+    /// wl_surface.attach.
+    ///
+    /// If the destination size is set, it causes the surface size to become
+    /// dst_width, dst_height. The source (rectangle) is scaled to exactly
+    /// this size. This overrides whatever the attached wl_buffer size is,
+    /// unless the wl_buffer is NULL. If the wl_buffer is NULL, the surface
+    /// has no content and therefore no size. Otherwise, the size is always
+    /// at least 1x1 in surface local coordinates.
+    ///
+    /// If the source rectangle is set, it defines what area of the wl_buffer is
+    /// taken as the source. If the source rectangle is set and the destination
+    /// size is not set, then src_width and src_height must be integers, and the
+    /// surface size becomes the source rectangle size. This results in cropping
+    /// without scaling. If src_width or src_height are not integers and
+    /// destination size is not set, the bad_size protocol error is raised when
+    /// the surface state is applied.
+    ///
+    /// The coordinate transformations from buffer pixel coordinates up to
+    /// the surface-local coordinates happen in the following order:
+    /// 1. buffer_transform (wl_surface.set_buffer_transform)
+    /// 2. buffer_scale (wl_surface.set_buffer_scale)
+    /// 3. crop and scale (wp_viewport.set*)
+    /// This means, that the source rectangle coordinates of crop and scale
+    /// are given in the coordinates after the buffer transform and scale,
+    /// i.e. in the coordinates that would be the surface-local coordinates
+    /// if the crop and scale was not applied.
+    ///
+    /// If src_x or src_y are negative, the bad_value protocol error is raised.
+    /// Otherwise, if the source rectangle is partially or completely outside of
+    /// the non-NULL wl_buffer, then the out_of_buffer protocol error is raised
+    /// when the surface state is applied. A NULL wl_buffer does not raise the
+    /// out_of_buffer error.
+    ///
+    /// If the wl_surface associated with the wp_viewport is destroyed,
+    /// all wp_viewport requests except 'destroy' raise the protocol error
+    /// no_surface.
+    ///
+    /// If the wp_viewport object is destroyed, the crop and scale
+    /// state is removed from the wl_surface. The change will be applied
+    /// on the next wl_surface.commit.
+    WpViewport,
+
+    /// A global interface for requesting surfaces to use fractional scales.
+    WpFractionalScaleManagerV1,
+
+    /// An additional interface to a wl_surface object which allows the compositor
+    /// to inform the client of the preferred scale.
+    WpFractionalScaleV1,
+
+    /// The main feature of this interface is accurate presentation
+    /// timing feedback to ensure smooth video playback while maintaining
+    /// audio/video synchronization. Some features use the concept of a
+    /// presentation clock, which is defined in the
+    /// presentation.clock_id event.
+    ///
+    /// A content update for a wl_surface is submitted by a
+    /// wl_surface.commit request. Request 'feedback' associates with
+    /// the wl_surface.commit and provides feedback on the content
+    /// update, particularly the final realized presentation time.
+    ///
+    ///
+    ///
+    /// When the final realized presentation time is available, e.g.
+    /// after a framebuffer flip completes, the requested
+    /// presentation_feedback.presented events are sent. The final
+    /// presentation time can differ from the compositor's predicted
+    /// display update time and the update's target time, especially
+    /// when the compositor misses its target vertical blanking period.
+    WpPresentation,
+
+    /// A presentation_feedback object returns an indication that a
+    /// wl_surface content update has become visible to the user.
+    /// One object corresponds to one content update submission
+    /// (wl_surface.commit). There are two possible outcomes: the
+    /// content update is presented to the user, and a presentation
+    /// timestamp delivered; or, the user did not see the content
+    /// update because it was superseded or its surface destroyed,
+    /// and the content update is discarded.
+    ///
+    /// Once a presentation_feedback object has delivered a 'presented'
+    /// or 'discarded' event it is automatically destroyed.
+    WpPresentationFeedback,
+
+    /// This global is a factory interface, allowing clients to request
+    /// explicit synchronization for buffers on a per-surface basis.
+    ///
+    /// See zwp_linux_surface_synchronization_v1 for more information.
+    ///
+    /// This interface is derived from Chromium's
+    /// zcr_linux_explicit_synchronization_v1.
+    ///
+    /// Note: this protocol is superseded by linux-drm-syncobj.
+    ///
+    /// Warning! The protocol described in this file is experimental and
+    /// backward incompatible changes may be made. Backward compatible changes
+    /// may be added together with the corresponding interface version bump.
+    /// Backward incompatible changes are done by bumping the version number in
+    /// the protocol and interface names and resetting the interface version.
+    /// Once the protocol is to be declared stable, the 'z' prefix and the
+    /// version number in the protocol and interface names are removed and the
+    /// interface version number is reset.
+    ZwpLinuxExplicitSynchronizationV1,
+
+    /// This object implements per-surface explicit synchronization.
+    ///
+    /// Synchronization refers to co-ordination of pipelined operations performed
+    /// on buffers. Most GPU clients will schedule an asynchronous operation to
+    /// render to the buffer, then immediately send the buffer to the compositor
+    /// to be attached to a surface.
+    ///
+    /// In implicit synchronization, ensuring that the rendering operation is
+    /// complete before the compositor displays the buffer is an implementation
+    /// detail handled by either the kernel or userspace graphics driver.
+    ///
+    /// By contrast, in explicit synchronization, dma_fence objects mark when the
+    /// asynchronous operations are complete. When submitting a buffer, the
+    /// client provides an acquire fence which will be waited on before the
+    /// compositor accesses the buffer. The Wayland server, through a
+    /// zwp_linux_buffer_release_v1 object, will inform the client with an event
+    /// which may be accompanied by a release fence, when the compositor will no
+    /// longer access the buffer contents due to the specific commit that
+    /// requested the release event.
+    ///
+    /// Each surface can be associated with only one object of this interface at
+    /// any time.
+    ///
+    /// In version 1 of this interface, explicit synchronization is only
+    /// guaranteed to be supported for buffers created with any version of the
+    /// wp_linux_dmabuf buffer factory. Version 2 additionally guarantees
+    /// explicit synchronization support for opaque EGL buffers, which is a type
+    /// of platform specific buffers described in the EGL_WL_bind_wayland_display
+    /// extension. Compositors are free to support explicit synchronization for
+    /// additional buffer types.
+    ZwpLinuxSurfaceSynchronizationV1,
+
+    /// This object is instantiated in response to a
+    /// zwp_linux_surface_synchronization_v1.get_release request.
+    ///
+    /// It provides an alternative to wl_buffer.release events, providing a
+    /// unique release from a single wl_surface.commit request. The release event
+    /// also supports explicit synchronization, providing a fence FD for the
+    /// client to synchronize against.
+    ///
+    /// Exactly one event, either a fenced_release or an immediate_release, will
+    /// be emitted for the wl_surface.commit request. The compositor can choose
+    /// release by release which event it uses.
+    ///
+    /// This event does not replace wl_buffer.release events; servers are still
+    /// required to send those events.
+    ///
+    /// Once a buffer release object has delivered a 'fenced_release' or an
+    /// 'immediate_release' event it is automatically destroyed.
+    ZwpLinuxBufferReleaseV1,
+
+    /// This global is a factory interface, allowing clients to request
+    /// explicit synchronization for buffers on a per-surface basis.
+    ///
+    /// See wp_linux_drm_syncobj_surface_v1 for more information.
+    WpLinuxDrmSyncobjManagerV1,
+
+    /// This object represents an explicit synchronization object timeline
+    /// imported by the client to the compositor.
+    WpLinuxDrmSyncobjTimelineV1,
+
+    /// This object is an add-on interface for wl_surface to enable explicit
+    /// synchronization.
+    ///
+    /// Each surface can be associated with only one object of this interface at
+    /// any time.
+    ///
+    /// Explicit synchronization is guaranteed to be supported for buffers
+    /// created with any version of the linux-dmabuf protocol. Compositors are
+    /// free to support explicit synchronization for additional buffer types.
+    /// If at surface commit time the attached buffer does not support explicit
+    /// synchronization, an unsupported_buffer error is raised.
+    ///
+    /// As long as the wp_linux_drm_syncobj_surface_v1 object is alive, the
+    /// compositor may ignore implicit synchronization for buffers attached and
+    /// committed to the wl_surface. The delivery of wl_buffer.release events
+    /// for buffers attached to the surface becomes undefined.
+    ///
+    /// Clients must set both acquire and release points if and only if a
+    /// non-null buffer is attached in the same surface commit. See the
+    /// no_buffer, no_acquire_point and no_release_point protocol errors.
+    ///
+    /// If at surface commit time the acquire and release DRM syncobj timelines
+    /// are identical, the acquire point value must be strictly less than the
+    /// release point value, or else the conflicting_points protocol error is
+    /// raised.
+    WpLinuxDrmSyncobjSurfaceV1,
+
+    /// A global interface used for informing the compositor about applications
+    /// being activated or started, or for applications to request to be
+    /// activated.
+    XdgActivationV1,
+
+    /// An object for setting up a token and receiving a token handle that can
+    /// be passed as an activation token to another client.
+    ///
+    /// The object is created using the xdg_activation_v1.get_activation_token
+    /// request. This object should then be populated with the app_id, surface
+    /// and serial information and committed. The compositor shall then issue a
+    /// done event with the token. In case the request's parameters are invalid,
+    /// the compositor will provide an invalid token.
+    XdgActivationTokenV1,
+
+    /// This global offers an alternative, optional way to set cursor images. This
+    /// new way uses enumerated cursors instead of a wl_surface like
+    /// wl_pointer.set_cursor does.
+    ///
+    /// Warning! The protocol described in this file is currently in the testing
+    /// phase. Backward compatible changes may be added together with the
+    /// corresponding interface version bump. Backward incompatible changes can
+    /// only be done by creating a new major version of the extension.
+    WpCursorShapeManagerV1,
+
+    /// This interface allows clients to set the cursor shape.
+    WpCursorShapeDeviceV1,
+
+    /// The zwp_text_input_v3 interface represents text input and input methods
+    /// associated with a seat. It provides enter/leave events to follow the
+    /// text input focus for a seat.
+    ///
+    /// Requests are used to enable/disable the text-input object and set
+    /// state information like surrounding and selected text or the content type.
+    /// The information about the entered text is sent to the text-input object
+    /// via the preedit_string and commit_string events.
+    ///
+    /// Text is valid UTF-8 encoded, indices and lengths are in bytes. Indices
+    /// must not point to middle bytes inside a code point: they must either
+    /// point to the first byte of a code point or to the end of the buffer.
+    /// Lengths must be measured between two valid indices.
+    ///
+    /// Focus moving throughout surfaces will result in the emission of
+    /// zwp_text_input_v3.enter and zwp_text_input_v3.leave events. The focused
+    /// surface must commit zwp_text_input_v3.enable and
+    /// zwp_text_input_v3.disable requests as the keyboard focus moves across
+    /// editable and non-editable elements of the UI. Those two requests are not
+    /// expected to be paired with each other, the compositor must be able to
+    /// handle consecutive series of the same request.
+    ///
+    /// State is sent by the state requests (set_surrounding_text,
+    /// set_content_type and set_cursor_rectangle) and a commit request. After an
+    /// enter event or disable request all state information is invalidated and
+    /// needs to be resent by the client.
+    ZwpTextInputV3,
+
+    /// A factory for text-input objects. This object is a global singleton.
+    ZwpTextInputManagerV3,
+
+    /// The primary selection device manager is a singleton global object that
+    /// provides access to the primary selection. It allows to create
+    /// wp_primary_selection_source objects, as well as retrieving the per-seat
+    /// wp_primary_selection_device objects.
+    ZwpPrimarySelectionDeviceManagerV1,
+
+    ZwpPrimarySelectionDeviceV1,
+
+    /// A wp_primary_selection_offer represents an offer to transfer the contents
+    /// of the primary selection clipboard to the client. Similar to
+    /// wl_data_offer, the offer also describes the mime types that the data can
+    /// be converted to and provides the mechanisms for transferring the data
+    /// directly to the client.
+    ZwpPrimarySelectionOfferV1,
+
+    /// The source side of a wp_primary_selection_offer, it provides a way to
+    /// describe the offered data and respond to requests to transfer the
+    /// requested contents of the primary selection clipboard.
+    ZwpPrimarySelectionSourceV1,
+
+    /// The global interface exposing pointer constraining functionality. It
+    /// exposes two requests: lock_pointer for locking the pointer to its
+    /// position, and confine_pointer for locking the pointer to a region.
+    ///
+    /// The lock_pointer and confine_pointer requests create the objects
+    /// wp_locked_pointer and wp_confined_pointer respectively, and the client can
+    /// use these objects to interact with the lock.
+    ///
+    /// For any surface, only one lock or confinement may be active across all
+    /// wl_pointer objects of the same seat. If a lock or confinement is requested
+    /// when another lock or confinement is active or requested on the same surface
+    /// and with any of the wl_pointer objects of the same seat, an
+    /// 'already_constrained' error will be raised.
+    ZwpPointerConstraintsV1,
+
+    /// The wp_locked_pointer interface represents a locked pointer state.
+    ///
+    /// While the lock of this object is active, the wl_pointer objects of the
+    /// associated seat will not emit any wl_pointer.motion events.
+    ///
+    /// This object will send the event 'locked' when the lock is activated.
+    /// Whenever the lock is activated, it is guaranteed that the locked surface
+    /// will already have received pointer focus and that the pointer will be
+    /// within the region passed to the request creating this object.
+    ///
+    /// To unlock the pointer, send the destroy request. This will also destroy
+    /// the wp_locked_pointer object.
+    ///
+    /// If the compositor decides to unlock the pointer the unlocked event is
+    /// sent. See wp_locked_pointer.unlock for details.
+    ///
+    /// When unlocking, the compositor may warp the cursor position to the set
+    /// cursor position hint. If it does, it will not result in any relative
+    /// motion events emitted via wp_relative_pointer.
+    ///
+    /// If the surface the lock was requested on is destroyed and the lock is not
+    /// yet activated, the wp_locked_pointer object is now defunct and must be
+    /// destroyed.
+    ZwpLockedPointerV1,
+
+    /// The wp_confined_pointer interface represents a confined pointer state.
+    ///
+    /// This object will send the event 'confined' when the confinement is
+    /// activated. Whenever the confinement is activated, it is guaranteed that
+    /// the surface the pointer is confined to will already have received pointer
+    /// focus and that the pointer will be within the region passed to the request
+    /// creating this object. It is up to the compositor to decide whether this
+    /// requires some user interaction and if the pointer will warp to within the
+    /// passed region if outside.
+    ///
+    /// To unconfine the pointer, send the destroy request. This will also destroy
+    /// the wp_confined_pointer object.
+    ///
+    /// If the compositor decides to unconfine the pointer the unconfined event is
+    /// sent. The wp_confined_pointer object is at this point defunct and should
+    /// be destroyed.
+    ZwpConfinedPointerV1,
+
+    /// A global interface used for getting the relative pointer object for a
+    /// given pointer.
+    ZwpRelativePointerManagerV1,
+
+    /// A wp_relative_pointer object is an extension to the wl_pointer interface
+    /// used for emitting relative pointer events. It shares the same focus as
+    /// wl_pointer objects of the same seat and will only emit events when it has
+    /// focus.
+    ZwpRelativePointerV1,
+
+    /// This interface permits inhibiting the idle behavior such as screen
+    /// blanking, locking, and screensaving.  The client binds the idle manager
+    /// globally, then creates idle-inhibitor objects for each surface.
+    ///
+    /// Warning! The protocol described in this file is experimental and
+    /// backward incompatible changes may be made. Backward compatible changes
+    /// may be added together with the corresponding interface version bump.
+    /// Backward incompatible changes are done by bumping the version number in
+    /// the protocol and interface names and resetting the interface version.
+    /// Once the protocol is to be declared stable, the 'z' prefix and the
+    /// version number in the protocol and interface names are removed and the
+    /// interface version number is reset.
+    ZwpIdleInhibitManagerV1,
+
+    /// An idle inhibitor prevents the output that the associated surface is
+    /// visible on from being set to a state where it is not visually usable due
+    /// to lack of user interaction (e.g. blanked, dimmed, locked, set to power
+    /// save, etc.)  Any screensaver processes are also blocked from displaying.
+    ///
+    /// If the surface is destroyed, unmapped, becomes occluded, loses
+    /// visibility, or otherwise becomes not visually relevant for the user, the
+    /// idle inhibitor will not be honored by the compositor; if the surface
+    /// subsequently regains visibility the inhibitor takes effect once again.
+    /// Likewise, the inhibitor isn't honored if the system was already idled at
+    /// the time the inhibitor was established, although if the system later
+    /// de-idles and re-idles the inhibitor will take effect.
+    ZwpIdleInhibitorV1,
+
+    /// This interface allows clients to monitor user idle status.
+    ///
+    /// After binding to this global, clients can create ext_idle_notification_v1
+    /// objects to get notified when the user is idle for a given amount of time.
+    ExtIdleNotifierV1,
+
+    /// This interface is used by the compositor to send idle notification events
+    /// to clients.
+    ///
+    /// Initially the notification object is not idle. The notification object
+    /// becomes idle when no user activity has happened for at least the timeout
+    /// duration, starting from the creation of the notification object. User
+    /// activity may include input events or a presence sensor, but is
+    /// compositor-specific.
+    ///
+    /// How this notification responds to idle inhibitors depends on how
+    /// it was constructed. If constructed from the
+    /// get_idle_notification request, then if an idle inhibitor is
+    /// active (e.g. another client has created a zwp_idle_inhibitor_v1
+    /// on a visible surface), the compositor must not make the
+    /// notification object idle. However, if constructed from the
+    /// get_input_idle_notification request, then idle inhibitors are
+    /// ignored, and only input from the user, e.g. from a keyboard or
+    /// mouse, counts as activity.
+    ///
+    /// When the notification object becomes idle, an idled event is sent. When
+    /// user activity starts again, the notification object stops being idle,
+    /// a resumed event is sent and the timeout is restarted.
+    ExtIdleNotificationV1,
+
+    /// Clients can use this interface to assign the surface_layer role to
+    /// wl_surfaces. Such surfaces are assigned to a "layer" of the output and
+    /// rendered with a defined z-depth respective to each other. They may also be
+    /// anchored to the edges and corners of a screen and specify input handling
+    /// semantics. This interface should be suitable for the implementation of
+    /// many desktop shell components, and a broad number of other applications
+    /// that interact with the desktop.
+    ZwlrLayerShellV1,
+
+    /// An interface that may be implemented by a wl_surface, for surfaces that
+    /// are designed to be rendered as a layer of a stacked desktop-like
+    /// environment.
+    ///
+    /// Layer surface state (layer, size, anchor, exclusive zone,
+    /// margin, interactivity) is double-buffered, and will be applied at the
+    /// time wl_surface.commit of the corresponding wl_surface is called.
+    ///
+    /// Attaching a null buffer to a layer surface unmaps it.
+    ///
+    /// Unmapping a layer_surface means that the surface cannot be shown by the
+    /// compositor until it is explicitly mapped again. The layer_surface
+    /// returns to the state it had right after layer_shell.get_layer_surface.
+    /// The client can re-map the surface by performing a commit without any
+    /// buffer attached, waiting for a configure event and handling it as usual.
+    ZwlrLayerSurfaceV1,
+
+    /// This interface is used to request that the session be locked.
+    ExtSessionLockManagerV1,
+
+    /// In response to the creation of this object the compositor must send
+    /// either the locked or finished event.
+    ///
+    /// The locked event indicates that the session is locked. This means
+    /// that the compositor must stop rendering and providing input to normal
+    /// clients. Instead the compositor must blank all outputs with an opaque
+    /// color such that their normal content is fully hidden.
+    ///
+    /// The only surfaces that should be rendered while the session is locked
+    /// are the lock surfaces created through this interface and optionally,
+    /// at the compositor's discretion, special privileged surfaces such as
+    /// input methods or portions of desktop shell UIs.
+    ///
+    /// The locked event must not be sent until a new "locked" frame (either
+    /// from a session lock surface or the compositor blanking the output) has
+    /// been presented on all outputs and no security sensitive normal/unlocked
+    /// content is possibly visible.
+    ///
+    /// The finished event should be sent immediately on creation of this
+    /// object if the compositor decides that the locked event will not be sent.
+    ///
+    /// The compositor may wait for the client to create and render session lock
+    /// surfaces before sending the locked event to avoid displaying intermediate
+    /// blank frames. However, it must impose a reasonable time limit if
+    /// waiting and send the locked event as soon as the hard requirements
+    /// described above can be met if the time limit expires. Clients should
+    /// immediately create lock surfaces for all outputs on creation of this
+    /// object to make this possible.
+    ///
+    /// This behavior of the locked event is required in order to prevent
+    /// possible race conditions with clients that wish to suspend the system
+    /// or similar after locking the session. Without these semantics, clients
+    /// triggering a suspend after receiving the locked event would race with
+    /// the first "locked" frame being presented and normal/unlocked frames
+    /// might be briefly visible as the system is resumed if the suspend
+    /// operation wins the race.
+    ///
+    /// If the client dies while the session is locked, the compositor must not
+    /// unlock the session in response. It is acceptable for the session to be
+    /// permanently locked if this happens. The compositor may choose to continue
+    /// to display the lock surfaces the client had mapped before it died or
+    /// alternatively fall back to a solid color, this is compositor policy.
+    ///
+    /// Compositors may also allow a secure way to recover the session, the
+    /// details of this are compositor policy. Compositors may allow a new
+    /// client to create a ext_session_lock_v1 object and take responsibility
+    /// for unlocking the session, they may even start a new lock client
+    /// instance automatically.
+    ExtSessionLockV1,
+
+    /// The client may use lock surfaces to display a screensaver, render a
+    /// dialog to enter a password and unlock the session, or however else it
+    /// sees fit.
+    ///
+    /// On binding this interface the compositor will immediately send the
+    /// first configure event. After making the ack_configure request in
+    /// response to this event the client should attach and commit the first
+    /// buffer. Committing the surface before acking the first configure is a
+    /// protocol error. Committing the surface with a null buffer at any time
+    /// is a protocol error.
+    ///
+    /// The compositor is free to handle keyboard/pointer focus for lock
+    /// surfaces however it chooses. A reasonable way to do this would be to
+    /// give the first lock surface created keyboard focus and change keyboard
+    /// focus if the user clicks on other surfaces.
+    ExtSessionLockSurfaceV1,
+
+    /// The image capture source object is an opaque descriptor for a capturable
+    /// resource.  This resource may be any sort of entity from which an image
+    /// may be derived.
+    ///
+    /// Note, because ext_image_capture_source_v1 objects are created from multiple
+    /// independent factory interfaces, the ext_image_capture_source_v1 interface is
+    /// frozen at version 1.
+    ExtImageCaptureSourceV1,
+
+    /// A manager for creating image capture source objects for wl_output objects.
+    ExtOutputImageCaptureSourceManagerV1,
+
+    /// This object is a manager which offers requests to start capturing from a
+    /// source.
+    ExtImageCopyCaptureManagerV1,
+
+    /// This object represents an active image copy capture session.
+    ///
+    /// After a capture session is created, buffer constraint events will be
+    /// emitted from the compositor to tell the client which buffer types and
+    /// formats are supported for reading from the session. The compositor may
+    /// re-send buffer constraint events whenever they change.
+    ///
+    /// To advertise buffer constraints, the compositor must send in no
+    /// particular order: zero or more shm_format and dmabuf_format events, zero
+    /// or one dmabuf_device event, and exactly one buffer_size event. Then the
+    /// compositor must send a done event.
+    ///
+    /// When the client has received all the buffer constraints, it can create a
+    /// buffer accordingly, attach it to the capture session using the
+    /// attach_buffer request, set the buffer damage using the damage_buffer
+    /// request and then send the capture request.
+    ExtImageCopyCaptureSessionV1,
+
+    /// This object represents an image capture frame.
+    ///
+    /// The client should attach a buffer, damage the buffer, and then send a
+    /// capture request.
+    ///
+    /// If the capture is successful, the compositor must send the frame metadata
+    /// (transform, damage, presentation_time in any order) followed by the ready
+    /// event.
+    ///
+    /// If the capture fails, the compositor must send the failed event.
+    ExtImageCopyCaptureFrameV1,
+
+    /// This object represents a cursor capture session. It extends the base
+    /// capture session with cursor-specific metadata.
+    ExtImageCopyCaptureCursorSessionV1,
+
+    /// This object is a manager which offers requests to start capturing from a
+    /// source.
+    ZwlrScreencopyManagerV1,
+
+    /// This object represents a single frame.
+    ///
+    /// When created, a series of buffer events will be sent, each representing a
+    /// supported buffer type. The "buffer_done" event is sent afterwards to
+    /// indicate that all supported buffer types have been enumerated. The client
+    /// will then be able to send a "copy" request. If the capture is successful,
+    /// the compositor will send a "flags" event followed by a "ready" event.
+    ///
+    /// For objects version 2 or lower, wl_shm buffers are always supported, ie.
+    /// the "buffer" event is guaranteed to be sent.
+    ///
+    /// If the capture failed, the "failed" event is sent. This can happen anytime
+    /// before the "ready" event.
+    ///
+    /// Once either a "ready" or a "failed" event is received, the client should
+    /// destroy the frame.
+    ZwlrScreencopyFrameV1,
+
+    /// An object that provides access to the graphics tablets available on this
+    /// system. All tablets are associated with a seat, to get access to the
+    /// actual tablets, use zwp_tablet_manager_v2.get_tablet_seat.
+    ZwpTabletManagerV2,
+
+    /// An object that provides access to the graphics tablets available on this
+    /// seat. After binding to this interface, the compositor sends a set of
+    /// zwp_tablet_seat_v2.tablet_added and zwp_tablet_seat_v2.tool_added events.
+    ZwpTabletSeatV2,
+
+    /// An object that represents a physical tool that has been, or is
+    /// currently in use with a tablet in this seat. Each zwp_tablet_tool_v2
+    /// object stays valid until the client destroys it; the compositor
+    /// reuses the zwp_tablet_tool_v2 object to indicate that the object's
+    /// respective physical tool has come into proximity of a tablet again.
+    ///
+    /// A zwp_tablet_tool_v2 object's relation to a physical tool depends on the
+    /// tablet's ability to report serial numbers. If the tablet supports
+    /// this capability, then the object represents a specific physical tool
+    /// and can be identified even when used on multiple tablets.
+    ///
+    /// A tablet tool has a number of static characteristics, e.g. tool type,
+    /// hardware_serial and capabilities. These capabilities are sent in an
+    /// event sequence after the zwp_tablet_seat_v2.tool_added event before any
+    /// actual events from this tool. This initial event sequence is
+    /// terminated by a zwp_tablet_tool_v2.done event.
+    ///
+    /// Tablet tool events are grouped by zwp_tablet_tool_v2.frame events.
+    /// Any events received before a zwp_tablet_tool_v2.frame event should be
+    /// considered part of the same hardware state change.
+    ZwpTabletToolV2,
+
+    /// The zwp_tablet_v2 interface represents one graphics tablet device. The
+    /// tablet interface itself does not generate events; all events are
+    /// generated by zwp_tablet_tool_v2 objects when in proximity above a tablet.
+    ///
+    /// A tablet has a number of static characteristics, e.g. device name and
+    /// pid/vid. These capabilities are sent in an event sequence after the
+    /// zwp_tablet_seat_v2.tablet_added event. This initial event sequence is
+    /// terminated by a zwp_tablet_v2.done event.
+    ZwpTabletV2,
+
+    /// A circular interaction area, such as the touch ring on the Wacom Intuos
+    /// Pro series tablets.
+    ///
+    /// Events on a ring are logically grouped by the zwp_tablet_pad_ring_v2.frame
+    /// event.
+    ZwpTabletPadRingV2,
+
+    /// A linear interaction area, such as the strips found in Wacom Cintiq
+    /// models.
+    ///
+    /// Events on a strip are logically grouped by the zwp_tablet_pad_strip_v2.frame
+    /// event.
+    ZwpTabletPadStripV2,
+
+    /// A pad group describes a distinct (sub)set of buttons, rings and strips
+    /// present in the tablet. The criteria of this grouping is usually positional,
+    /// eg. if a tablet has buttons on the left and right side, 2 groups will be
+    /// presented. The physical arrangement of groups is undisclosed and may
+    /// change on the fly.
+    ///
+    /// Pad groups will announce their features during pad initialization. Between
+    /// the corresponding zwp_tablet_pad_v2.group event and zwp_tablet_pad_group_v2.done, the
+    /// pad group will announce the buttons, rings and strips contained in it,
+    /// plus the number of supported modes.
+    ///
+    /// Modes are a mechanism to allow multiple groups of actions for every element
+    /// in the pad group. The number of groups and available modes in each is
+    /// persistent across device plugs. The current mode is user-switchable, it
+    /// will be announced through the zwp_tablet_pad_group_v2.mode_switch event both
+    /// whenever it is switched, and after zwp_tablet_pad_v2.enter.
+    ///
+    /// The current mode logically applies to all elements in the pad group,
+    /// although it is at clients' discretion whether to actually perform different
+    /// actions, and/or issue the respective .set_feedback requests to notify the
+    /// compositor. See the zwp_tablet_pad_group_v2.mode_switch event for more details.
+    ZwpTabletPadGroupV2,
+
+    /// A pad device is a set of buttons, rings, strips and dials
+    /// usually physically present on the tablet device itself. Some
+    /// exceptions exist where the pad device is physically detached, e.g. the
+    /// Wacom ExpressKey Remote.
+    ///
+    /// Pad devices have no axes that control the cursor and are generally
+    /// auxiliary devices to the tool devices used on the tablet surface.
+    ///
+    /// A pad device has a number of static characteristics, e.g. the number
+    /// of rings. These capabilities are sent in an event sequence after the
+    /// zwp_tablet_seat_v2.pad_added event before any actual events from this pad.
+    /// This initial event sequence is terminated by a zwp_tablet_pad_v2.done
+    /// event.
+    ///
+    /// All pad features (buttons, rings, strips and dials) are logically divided into
+    /// groups and all pads have at least one group. The available groups are
+    /// notified through the zwp_tablet_pad_v2.group event; the compositor will
+    /// emit one event per group before emitting zwp_tablet_pad_v2.done.
+    ///
+    /// Groups may have multiple modes. Modes allow clients to map multiple
+    /// actions to a single pad feature. Only one mode can be active per group,
+    /// although different groups may have different active modes.
+    ZwpTabletPadV2,
+
+    /// A rotary control, e.g. a dial or a wheel.
+    ///
+    /// Events on a dial are logically grouped by the zwp_tablet_pad_dial_v2.frame
+    /// event.
+    ZwpTabletPadDialV2,
+
+    /// A global interface to provide semantic touchpad gestures for a given
+    /// pointer.
+    ///
+    /// Three gestures are currently supported: swipe, pinch, and hold.
+    /// Pinch and swipe gestures follow a three-stage cycle: begin, update,
+    /// end. Hold gestures follow a two-stage cycle: begin and end. All
+    /// gestures are identified by a unique id.
+    ///
+    /// Warning! The protocol described in this file is experimental and
+    /// backward incompatible changes may be made. Backward compatible changes
+    /// may be added together with the corresponding interface version bump.
+    /// Backward incompatible changes are done by bumping the version number in
+    /// the protocol and interface names and resetting the interface version.
+    /// Once the protocol is to be declared stable, the 'z' prefix and the
+    /// version number in the protocol and interface names are removed and the
+    /// interface version number is reset.
+    ZwpPointerGesturesV1,
+
+    /// A swipe gesture object notifies a client about a multi-finger swipe
+    /// gesture detected on an indirect input device such as a touchpad.
+    /// The gesture is usually initiated by multiple fingers moving in the
+    /// same direction but once initiated the direction may change.
+    /// The precise conditions of when such a gesture is detected are
+    /// implementation-dependent.
+    ///
+    /// A gesture consists of three stages: begin, update (optional) and end.
+    /// There cannot be multiple simultaneous hold, pinch or swipe gestures on a
+    /// same pointer/seat, how compositors prevent these situations is
+    /// implementation-dependent.
+    ///
+    /// A gesture may be cancelled by the compositor or the hardware.
+    /// Clients should not consider performing permanent or irreversible
+    /// actions until the end of a gesture has been received.
+    ZwpPointerGestureSwipeV1,
+
+    /// A pinch gesture object notifies a client about a multi-finger pinch
+    /// gesture detected on an indirect input device such as a touchpad.
+    /// The gesture is usually initiated by multiple fingers moving towards
+    /// each other or away from each other, or by two or more fingers rotating
+    /// around a logical center of gravity. The precise conditions of when
+    /// such a gesture is detected are implementation-dependent.
+    ///
+    /// A gesture consists of three stages: begin, update (optional) and end.
+    /// There cannot be multiple simultaneous hold, pinch or swipe gestures on a
+    /// same pointer/seat, how compositors prevent these situations is
+    /// implementation-dependent.
+    ///
+    /// A gesture may be cancelled by the compositor or the hardware.
+    /// Clients should not consider performing permanent or irreversible
+    /// actions until the end of a gesture has been received.
+    ZwpPointerGesturePinchV1,
+
+    /// A hold gesture object notifies a client about a single- or
+    /// multi-finger hold gesture detected on an indirect input device such as
+    /// a touchpad. The gesture is usually initiated by one or more fingers
+    /// being held down without significant movement. The precise conditions
+    /// of when such a gesture is detected are implementation-dependent.
+    ///
+    /// In particular, this gesture may be used to cancel kinetic scrolling.
+    ///
+    /// A hold gesture consists of two stages: begin and end. Unlike pinch and
+    /// swipe there is no update stage.
+    /// There cannot be multiple simultaneous hold, pinch or swipe gestures on a
+    /// same pointer/seat, how compositors prevent these situations is
+    /// implementation-dependent.
+    ///
+    /// A gesture may be cancelled by the compositor or the hardware.
+    /// Clients should not consider performing permanent or irreversible
+    /// actions until the end of a gesture has been received.
+    ZwpPointerGestureHoldV1,
+
+    /// A global factory interface for xdg_output objects.
+    ZxdgOutputManagerV1,
+
+    /// An xdg_output describes part of the compositor geometry.
+    ///
+    /// This typically corresponds to a monitor that displays part of the
+    /// compositor space.
+    ///
+    /// For objects version 3 onwards, after all xdg_output properties have been
+    /// sent (when the object is created and when properties are updated), a
+    /// wl_output.done event is sent. This allows changes to the output
+    /// properties to be seen as atomic, even if they happen via multiple events.
+    ZxdgOutputV1,
+
+    /// The wp_single_pixel_buffer_manager_v1 interface is a factory for
+    /// single-pixel buffers.
+    WpSinglePixelBufferManagerV1,
+
+    /// This interface allows a client to describe the kind of content a surface
+    /// will display, to allow the compositor to optimize its behavior for it.
+    ///
+    /// Warning! The protocol described in this file is currently in the testing
+    /// phase. Backward compatible changes may be added together with the
+    /// corresponding interface version bump. Backward incompatible changes can
+    /// only be done by creating a new major version of the extension.
+    WpContentTypeManagerV1,
+
+    /// The content type object allows the compositor to optimize for the kind
+    /// of content shown on the surface. A compositor may for example use it to
+    /// set relevant drm properties like "content type".
+    ///
+    /// The client may request to switch to another content type at any time.
+    /// When the associated surface gets destroyed, this object becomes inert and
+    /// the client should destroy it.
+    WpContentTypeV1,
+
+    /// For some use cases like games or drawing tablets it can make sense to
+    /// reduce latency by accepting tearing with the use of asynchronous page
+    /// flips. This global is a factory interface, allowing clients to inform
+    /// which type of presentation the content of their surfaces is suitable for.
+    ///
+    /// Graphics APIs like EGL or Vulkan, that manage the buffer queue and commits
+    /// of a wl_surface themselves, are likely to be using this extension
+    /// internally. If a client is using such an API for a wl_surface, it should
+    /// not directly use this extension on that surface, to avoid raising a
+    /// tearing_control_exists protocol error.
+    ///
+    /// Warning! The protocol described in this file is currently in the testing
+    /// phase. Backward compatible changes may be added together with the
+    /// corresponding interface version bump. Backward incompatible changes can
+    /// only be done by creating a new major version of the extension.
+    WpTearingControlManagerV1,
+
+    /// An additional interface to a wl_surface object, which allows the client
+    /// to hint to the compositor if the content on the surface is suitable for
+    /// presentation with tearing.
+    /// The default presentation hint is vsync. See presentation_hint for more
+    /// details.
+    ///
+    /// If the associated wl_surface is destroyed, this object becomes inert and
+    /// should be destroyed.
+    WpTearingControlV1,
+
 }
 
 impl Object {
@@ -566,6 +1428,72 @@ impl Object {
             "wl_region" => Some(WlRegion),
             "wl_subcompositor" => Some(WlSubcompositor),
             "wl_subsurface" => Some(WlSubsurface),
+            "zxdg_decoration_manager_v1" => Some(ZxdgDecorationManagerV1),
+            "zxdg_toplevel_decoration_v1" => Some(ZxdgToplevelDecorationV1),
+            "wp_viewporter" => Some(WpViewporter),
+            "wp_viewport" => Some(WpViewport),
+            "wp_fractional_scale_manager_v1" => Some(WpFractionalScaleManagerV1),
+            "wp_fractional_scale_v1" => Some(WpFractionalScaleV1),
+            "wp_presentation" => Some(WpPresentation),
+            "wp_presentation_feedback" => Some(WpPresentationFeedback),
+            "zwp_linux_explicit_synchronization_v1" => Some(ZwpLinuxExplicitSynchronizationV1),
+            "zwp_linux_surface_synchronization_v1" => Some(ZwpLinuxSurfaceSynchronizationV1),
+            "zwp_linux_buffer_release_v1" => Some(ZwpLinuxBufferReleaseV1),
+            "wp_linux_drm_syncobj_manager_v1" => Some(WpLinuxDrmSyncobjManagerV1),
+            "wp_linux_drm_syncobj_timeline_v1" => Some(WpLinuxDrmSyncobjTimelineV1),
+            "wp_linux_drm_syncobj_surface_v1" => Some(WpLinuxDrmSyncobjSurfaceV1),
+            "xdg_activation_v1" => Some(XdgActivationV1),
+            "xdg_activation_token_v1" => Some(XdgActivationTokenV1),
+            "wp_cursor_shape_manager_v1" => Some(WpCursorShapeManagerV1),
+            "wp_cursor_shape_device_v1" => Some(WpCursorShapeDeviceV1),
+            "zwp_text_input_v3" => Some(ZwpTextInputV3),
+            "zwp_text_input_manager_v3" => Some(ZwpTextInputManagerV3),
+            "zwp_primary_selection_device_manager_v1" => Some(ZwpPrimarySelectionDeviceManagerV1),
+            "zwp_primary_selection_device_v1" => Some(ZwpPrimarySelectionDeviceV1),
+            "zwp_primary_selection_offer_v1" => Some(ZwpPrimarySelectionOfferV1),
+            "zwp_primary_selection_source_v1" => Some(ZwpPrimarySelectionSourceV1),
+            "zwp_pointer_constraints_v1" => Some(ZwpPointerConstraintsV1),
+            "zwp_locked_pointer_v1" => Some(ZwpLockedPointerV1),
+            "zwp_confined_pointer_v1" => Some(ZwpConfinedPointerV1),
+            "zwp_relative_pointer_manager_v1" => Some(ZwpRelativePointerManagerV1),
+            "zwp_relative_pointer_v1" => Some(ZwpRelativePointerV1),
+            "zwp_idle_inhibit_manager_v1" => Some(ZwpIdleInhibitManagerV1),
+            "zwp_idle_inhibitor_v1" => Some(ZwpIdleInhibitorV1),
+            "ext_idle_notifier_v1" => Some(ExtIdleNotifierV1),
+            "ext_idle_notification_v1" => Some(ExtIdleNotificationV1),
+            "zwlr_layer_shell_v1" => Some(ZwlrLayerShellV1),
+            "zwlr_layer_surface_v1" => Some(ZwlrLayerSurfaceV1),
+            "ext_session_lock_manager_v1" => Some(ExtSessionLockManagerV1),
+            "ext_session_lock_v1" => Some(ExtSessionLockV1),
+            "ext_session_lock_surface_v1" => Some(ExtSessionLockSurfaceV1),
+            "ext_image_capture_source_v1" => Some(ExtImageCaptureSourceV1),
+            "ext_output_image_capture_source_manager_v1" => Some(ExtOutputImageCaptureSourceManagerV1),
+            "ext_image_copy_capture_manager_v1" => Some(ExtImageCopyCaptureManagerV1),
+            "ext_image_copy_capture_session_v1" => Some(ExtImageCopyCaptureSessionV1),
+            "ext_image_copy_capture_frame_v1" => Some(ExtImageCopyCaptureFrameV1),
+            "ext_image_copy_capture_cursor_session_v1" => Some(ExtImageCopyCaptureCursorSessionV1),
+            "zwlr_screencopy_manager_v1" => Some(ZwlrScreencopyManagerV1),
+            "zwlr_screencopy_frame_v1" => Some(ZwlrScreencopyFrameV1),
+            "zwp_tablet_manager_v2" => Some(ZwpTabletManagerV2),
+            "zwp_tablet_seat_v2" => Some(ZwpTabletSeatV2),
+            "zwp_tablet_tool_v2" => Some(ZwpTabletToolV2),
+            "zwp_tablet_v2" => Some(ZwpTabletV2),
+            "zwp_tablet_pad_ring_v2" => Some(ZwpTabletPadRingV2),
+            "zwp_tablet_pad_strip_v2" => Some(ZwpTabletPadStripV2),
+            "zwp_tablet_pad_group_v2" => Some(ZwpTabletPadGroupV2),
+            "zwp_tablet_pad_v2" => Some(ZwpTabletPadV2),
+            "zwp_tablet_pad_dial_v2" => Some(ZwpTabletPadDialV2),
+            "zwp_pointer_gestures_v1" => Some(ZwpPointerGesturesV1),
+            "zwp_pointer_gesture_swipe_v1" => Some(ZwpPointerGestureSwipeV1),
+            "zwp_pointer_gesture_pinch_v1" => Some(ZwpPointerGesturePinchV1),
+            "zwp_pointer_gesture_hold_v1" => Some(ZwpPointerGestureHoldV1),
+            "zxdg_output_manager_v1" => Some(ZxdgOutputManagerV1),
+            "zxdg_output_v1" => Some(ZxdgOutputV1),
+            "wp_single_pixel_buffer_manager_v1" => Some(WpSinglePixelBufferManagerV1),
+            "wp_content_type_manager_v1" => Some(WpContentTypeManagerV1),
+            "wp_content_type_v1" => Some(WpContentTypeV1),
+            "wp_tearing_control_manager_v1" => Some(WpTearingControlManagerV1),
+            "wp_tearing_control_v1" => Some(WpTearingControlV1),
             _ => None
         }
     }
@@ -646,6 +1574,20 @@ pub struct ZwpLinuxDmabufV1{
     pub id: u32,
 }
 
+impl Interface for ZwpLinuxDmabufV1 {
+    const NAME: &'static str = "zwp_linux_dmabuf_v1";
+    const VERSION: u32 = 4;
+    const OBJECT: Object = Object::ZwpLinuxDmabufV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpLinuxDmabufV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl ZwpLinuxDmabufV1 {
 
     /// `zwp_linux_dmabuf_v1:destroy` request
@@ -735,6 +1677,20 @@ pub struct ZwpLinuxBufferParamsV1{
     pub id: u32,
 }
 
+impl Interface for ZwpLinuxBufferParamsV1 {
+    const NAME: &'static str = "zwp_linux_buffer_params_v1";
+    const VERSION: u32 = 4;
+    const OBJECT: Object = Object::ZwpLinuxBufferParamsV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpLinuxBufferParamsV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl ZwpLinuxBufferParamsV1 {
 
     /// `zwp_linux_buffer_params_v1:destroy` request
@@ -926,6 +1882,20 @@ pub struct ZwpLinuxDmabufFeedbackV1{
     pub id: u32,
 }
 
+impl Interface for ZwpLinuxDmabufFeedbackV1 {
+    const NAME: &'static str = "zwp_linux_dmabuf_feedback_v1";
+    const VERSION: u32 = 4;
+    const OBJECT: Object = Object::ZwpLinuxDmabufFeedbackV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpLinuxDmabufFeedbackV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl ZwpLinuxDmabufFeedbackV1 {
 
     /// `zwp_linux_dmabuf_feedback_v1:destroy` request
@@ -952,6 +1922,20 @@ pub struct XdgWmBase{
     pub id: u32,
 }
 
+impl Interface for XdgWmBase {
+    const NAME: &'static str = "xdg_wm_base";
+    const VERSION: u32 = 5;
+    const OBJECT: Object = Object::XdgWmBase;
+
+    fn from_id(id: u32) -> Self {
+        XdgWmBase { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl XdgWmBase {
 
     /// `xdg_wm_base:destroy` request
@@ -1052,6 +2036,20 @@ pub struct XdgPositioner{
     pub id: u32,
 }
 
+impl Interface for XdgPositioner {
+    const NAME: &'static str = "xdg_positioner";
+    const VERSION: u32 = 5;
+    const OBJECT: Object = Object::XdgPositioner;
+
+    fn from_id(id: u32) -> Self {
+        XdgPositioner { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl XdgPositioner {
 
     /// `xdg_positioner:destroy` request
@@ -1290,6 +2288,20 @@ pub struct XdgSurface{
     pub id: u32,
 }
 
+impl Interface for XdgSurface {
+    const NAME: &'static str = "xdg_surface";
+    const VERSION: u32 = 5;
+    const OBJECT: Object = Object::XdgSurface;
+
+    fn from_id(id: u32) -> Self {
+        XdgSurface { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl XdgSurface {
 
     /// `xdg_surface:destroy` request
@@ -1332,12 +2344,12 @@ impl XdgSurface {
     ///
     /// See the documentation of xdg_popup for more details about what an
     /// xdg_popup is and how it is used.
-    pub fn r#get_popup(&self, connection: &WaylandConnection, parent: XdgSurface, positioner: XdgPositioner) -> XdgPopup {
+    pub fn r#get_popup(&self, connection: &WaylandConnection, parent: Option<XdgSurface>, positioner: XdgPositioner) -> XdgPopup {
 
         let _enq_id = connection.enqueue(
             Request::XdgSurfaceGetPopup {
                 sendto: self.id,
-                parent: parent.id,
+                parent: parent.map(|o| o.id),
                 positioner: positioner.id,
             }
         );
@@ -1465,6 +2477,20 @@ pub struct XdgToplevel{
     pub id: u32,
 }
 
+impl Interface for XdgToplevel {
+    const NAME: &'static str = "xdg_toplevel";
+    const VERSION: u32 = 5;
+    const OBJECT: Object = Object::XdgToplevel;
+
+    fn from_id(id: u32) -> Self {
+        XdgToplevel { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl XdgToplevel {
 
     /// `xdg_toplevel:destroy` request
@@ -1500,12 +2526,12 @@ impl XdgToplevel {
     /// The parent toplevel must not be one of the child toplevel's
     /// descendants, and the parent must be different from the child toplevel,
     /// otherwise the invalid_parent protocol error is raised.
-    pub fn r#set_parent(&self, connection: &WaylandConnection, parent: XdgToplevel)  {
+    pub fn r#set_parent(&self, connection: &WaylandConnection, parent: Option<XdgToplevel>)  {
 
         let _enq_id = connection.enqueue(
             Request::XdgToplevelSetParent {
                 sendto: self.id,
-                parent: parent.id,
+                parent: parent.map(|o| o.id),
             }
         );
     }
@@ -1518,7 +2544,7 @@ impl XdgToplevel {
     /// compositor.
     ///
     /// The string must be encoded in UTF-8.
-    pub fn r#set_title(&self, connection: &WaylandConnection, title: String)  {
+    pub fn r#set_title(&self, connection: &WaylandConnection, title: &str)  {
 
         let _enq_id = connection.enqueue(
             Request::XdgToplevelSetTitle {
@@ -1552,7 +2578,7 @@ impl XdgToplevel {
     /// names and .desktop files.
     ///
     /// [0] https://standards.freedesktop.org/desktop-entry-spec/
-    pub fn r#set_app_id(&self, connection: &WaylandConnection, app_id: String)  {
+    pub fn r#set_app_id(&self, connection: &WaylandConnection, app_id: &str)  {
 
         let _enq_id = connection.enqueue(
             Request::XdgToplevelSetAppId {
@@ -1836,12 +2862,12 @@ impl XdgToplevel {
     /// sure that other screen content not part of the same surface tree (made
     /// up of subsurfaces, popups or similarly coupled surfaces) are not
     /// visible below the fullscreened surface.
-    pub fn r#set_fullscreen(&self, connection: &WaylandConnection, output: WlOutput)  {
+    pub fn r#set_fullscreen(&self, connection: &WaylandConnection, output: Option<WlOutput>)  {
 
         let _enq_id = connection.enqueue(
             Request::XdgToplevelSetFullscreen {
                 sendto: self.id,
-                output: output.id,
+                output: output.map(|o| o.id),
             }
         );
     }
@@ -1922,6 +2948,20 @@ pub struct XdgPopup{
     pub id: u32,
 }
 
+impl Interface for XdgPopup {
+    const NAME: &'static str = "xdg_popup";
+    const VERSION: u32 = 5;
+    const OBJECT: Object = Object::XdgPopup;
+
+    fn from_id(id: u32) -> Self {
+        XdgPopup { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl XdgPopup {
 
     /// `xdg_popup:destroy` request
@@ -2038,6 +3078,20 @@ pub struct WlDisplay{
     pub id: u32,
 }
 
+impl Interface for WlDisplay {
+    const NAME: &'static str = "wl_display";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlDisplay;
+
+    fn from_id(id: u32) -> Self {
+        WlDisplay { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlDisplay {
 
     /// `wl_display:sync` request
@@ -2113,24 +3167,40 @@ pub struct WlRegistry{
     pub id: u32,
 }
 
+impl Interface for WlRegistry {
+    const NAME: &'static str = "wl_registry";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlRegistry;
+
+    fn from_id(id: u32) -> Self {
+        WlRegistry { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlRegistry {
 
     /// `wl_registry:bind` request
     /// Binds a new, client-created object to the server using the
     /// specified name as the identifier.
-    pub fn r#bind(&self, connection: &WaylandConnection, name: u32, if_name: String, if_version: u32) -> Id {
+    ///
+    /// The object is bound with the lower of the advertised `version` and
+    /// the highest version of `T` supported by railway.
+    pub fn r#bind<T: Interface>(&self, connection: &WaylandConnection, name: u32, version: u32) -> T {
 
         let _enq_id = connection.enqueue(
             Request::WlRegistryBind {
                 sendto: self.id,
                 name,
-                if_name,
-                if_version,
+                if_name: T::NAME,
+                if_version: std::cmp::min(version, T::VERSION),
+                object: T::OBJECT,
             }
         );
-        return Id{
-            id: _enq_id,
-        };
+        return T::from_id(_enq_id);
     }
 }
 
@@ -2143,6 +3213,20 @@ pub struct WlCompositor{
     pub id: u32,
 }
 
+impl Interface for WlCompositor {
+    const NAME: &'static str = "wl_compositor";
+    const VERSION: u32 = 6;
+    const OBJECT: Object = Object::WlCompositor;
+
+    fn from_id(id: u32) -> Self {
+        WlCompositor { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlCompositor {
 
     /// `wl_compositor:create_surface` request
@@ -2187,6 +3271,20 @@ pub struct WlShmPool{
     pub id: u32,
 }
 
+impl Interface for WlShmPool {
+    const NAME: &'static str = "wl_shm_pool";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlShmPool;
+
+    fn from_id(id: u32) -> Self {
+        WlShmPool { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlShmPool {
 
     /// `wl_shm_pool:create_buffer` request
@@ -2270,6 +3368,20 @@ pub struct WlShm{
     pub id: u32,
 }
 
+impl Interface for WlShm {
+    const NAME: &'static str = "wl_shm";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlShm;
+
+    fn from_id(id: u32) -> Self {
+        WlShm { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlShm {
 
     /// `wl_shm:create_pool` request
@@ -2312,6 +3424,20 @@ pub struct WlBuffer{
     pub id: u32,
 }
 
+impl Interface for WlBuffer {
+    const NAME: &'static str = "wl_buffer";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlBuffer;
+
+    fn from_id(id: u32) -> Self {
+        WlBuffer { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlBuffer {
 
     /// `wl_buffer:destroy` request
@@ -2341,6 +3467,20 @@ pub struct WlDataOffer{
     pub id: u32,
 }
 
+impl Interface for WlDataOffer {
+    const NAME: &'static str = "wl_data_offer";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::WlDataOffer;
+
+    fn from_id(id: u32) -> Self {
+        WlDataOffer { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlDataOffer {
 
     /// `wl_data_offer:accept` request
@@ -2358,7 +3498,7 @@ impl WlDataOffer {
     /// will be cancelled and the corresponding drag source will receive
     /// wl_data_source.cancelled. Clients may still use this event in
     /// conjunction with wl_data_source.action for feedback.
-    pub fn r#accept(&self, connection: &WaylandConnection, serial: u32, mime_type: String)  {
+    pub fn r#accept(&self, connection: &WaylandConnection, serial: u32, mime_type: Option<&str>)  {
 
         let _enq_id = connection.enqueue(
             Request::WlDataOfferAccept {
@@ -2385,7 +3525,7 @@ impl WlDataOffer {
     /// both before and after wl_data_device.drop. Drag-and-drop destination
     /// clients may preemptively fetch data or examine it more closely to
     /// determine acceptance.
-    pub fn r#receive(&self, connection: &WaylandConnection, mime_type: String, fd: RawFd)  {
+    pub fn r#receive(&self, connection: &WaylandConnection, mime_type: &str, fd: RawFd)  {
 
         let _enq_id = connection.enqueue(
             Request::WlDataOfferReceive {
@@ -2485,13 +3625,27 @@ pub struct WlDataSource{
     pub id: u32,
 }
 
+impl Interface for WlDataSource {
+    const NAME: &'static str = "wl_data_source";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::WlDataSource;
+
+    fn from_id(id: u32) -> Self {
+        WlDataSource { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlDataSource {
 
     /// `wl_data_source:offer` request
     /// This request adds a mime type to the set of mime types
     /// advertised to targets.  Can be called several times to offer
     /// multiple types.
-    pub fn r#offer(&self, connection: &WaylandConnection, mime_type: String)  {
+    pub fn r#offer(&self, connection: &WaylandConnection, mime_type: &str)  {
 
         let _enq_id = connection.enqueue(
             Request::WlDataSourceOffer {
@@ -2548,6 +3702,20 @@ pub struct WlDataDevice{
     pub id: u32,
 }
 
+impl Interface for WlDataDevice {
+    const NAME: &'static str = "wl_data_device";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::WlDataDevice;
+
+    fn from_id(id: u32) -> Self {
+        WlDataDevice { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlDataDevice {
 
     /// `wl_data_device:start_drag` request
@@ -2580,14 +3748,14 @@ impl WlDataDevice {
     /// The given source may not be used in any further set_selection or
     /// start_drag requests. Attempting to reuse a previously-used source
     /// may send a used_source error.
-    pub fn r#start_drag(&self, connection: &WaylandConnection, source: WlDataSource, origin: WlSurface, icon: WlSurface, serial: u32)  {
+    pub fn r#start_drag(&self, connection: &WaylandConnection, source: Option<WlDataSource>, origin: WlSurface, icon: Option<WlSurface>, serial: u32)  {
 
         let _enq_id = connection.enqueue(
             Request::WlDataDeviceStartDrag {
                 sendto: self.id,
-                source: source.id,
+                source: source.map(|o| o.id),
                 origin: origin.id,
-                icon: icon.id,
+                icon: icon.map(|o| o.id),
                 serial,
             }
         );
@@ -2602,12 +3770,12 @@ impl WlDataDevice {
     /// The given source may not be used in any further set_selection or
     /// start_drag requests. Attempting to reuse a previously-used source
     /// may send a used_source error.
-    pub fn r#set_selection(&self, connection: &WaylandConnection, source: WlDataSource, serial: u32)  {
+    pub fn r#set_selection(&self, connection: &WaylandConnection, source: Option<WlDataSource>, serial: u32)  {
 
         let _enq_id = connection.enqueue(
             Request::WlDataDeviceSetSelection {
                 sendto: self.id,
-                source: source.id,
+                source: source.map(|o| o.id),
                 serial,
             }
         );
@@ -2641,6 +3809,20 @@ pub struct WlDataDeviceManager{
     pub id: u32,
 }
 
+impl Interface for WlDataDeviceManager {
+    const NAME: &'static str = "wl_data_device_manager";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::WlDataDeviceManager;
+
+    fn from_id(id: u32) -> Self {
+        WlDataDeviceManager { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlDataDeviceManager {
 
     /// `wl_data_device_manager:create_data_source` request
@@ -2688,6 +3870,20 @@ pub struct WlShell{
     pub id: u32,
 }
 
+impl Interface for WlShell {
+    const NAME: &'static str = "wl_shell";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlShell;
+
+    fn from_id(id: u32) -> Self {
+        WlShell { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlShell {
 
     /// `wl_shell:get_shell_surface` request
@@ -2727,6 +3923,20 @@ pub struct WlShellSurface{
     pub id: u32,
 }
 
+impl Interface for WlShellSurface {
+    const NAME: &'static str = "wl_shell_surface";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlShellSurface;
+
+    fn from_id(id: u32) -> Self {
+        WlShellSurface { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlShellSurface {
 
     /// `wl_shell_surface:pong` request
@@ -2845,14 +4055,14 @@ impl WlShellSurface {
     /// The compositor must reply to this request with a configure event
     /// with the dimensions for the output on which the surface will
     /// be made fullscreen.
-    pub fn r#set_fullscreen(&self, connection: &WaylandConnection, method: u32, framerate: u32, output: WlOutput)  {
+    pub fn r#set_fullscreen(&self, connection: &WaylandConnection, method: u32, framerate: u32, output: Option<WlOutput>)  {
 
         let _enq_id = connection.enqueue(
             Request::WlShellSurfaceSetFullscreen {
                 sendto: self.id,
                 method,
                 framerate,
-                output: output.id,
+                output: output.map(|o| o.id),
             }
         );
     }
@@ -2911,12 +4121,12 @@ impl WlShellSurface {
     /// fullscreen shell surface.
     ///
     /// The details depend on the compositor implementation.
-    pub fn r#set_maximized(&self, connection: &WaylandConnection, output: WlOutput)  {
+    pub fn r#set_maximized(&self, connection: &WaylandConnection, output: Option<WlOutput>)  {
 
         let _enq_id = connection.enqueue(
             Request::WlShellSurfaceSetMaximized {
                 sendto: self.id,
-                output: output.id,
+                output: output.map(|o| o.id),
             }
         );
     }
@@ -2929,7 +4139,7 @@ impl WlShellSurface {
     /// compositor.
     ///
     /// The string must be encoded in UTF-8.
-    pub fn r#set_title(&self, connection: &WaylandConnection, title: String)  {
+    pub fn r#set_title(&self, connection: &WaylandConnection, title: &str)  {
 
         let _enq_id = connection.enqueue(
             Request::WlShellSurfaceSetTitle {
@@ -2946,7 +4156,7 @@ impl WlShellSurface {
     /// to which the surface belongs. A common convention is to use the
     /// file name (or the full path if it is a non-standard location) of
     /// the application's .desktop file as the class.
-    pub fn r#set_class(&self, connection: &WaylandConnection, class_: String)  {
+    pub fn r#set_class(&self, connection: &WaylandConnection, class_: &str)  {
 
         let _enq_id = connection.enqueue(
             Request::WlShellSurfaceSetClass {
@@ -3011,6 +4221,20 @@ pub struct WlSurface{
     pub id: u32,
 }
 
+impl Interface for WlSurface {
+    const NAME: &'static str = "wl_surface";
+    const VERSION: u32 = 6;
+    const OBJECT: Object = Object::WlSurface;
+
+    fn from_id(id: u32) -> Self {
+        WlSurface { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlSurface {
 
     /// `wl_surface:destroy` request
@@ -3082,12 +4306,12 @@ impl WlSurface {
     ///
     /// If wl_surface.attach is sent with a NULL wl_buffer, the
     /// following wl_surface.commit will remove the surface content.
-    pub fn r#attach(&self, connection: &WaylandConnection, buffer: WlBuffer, x: i32, y: i32)  {
+    pub fn r#attach(&self, connection: &WaylandConnection, buffer: Option<WlBuffer>, x: i32, y: i32)  {
 
         let _enq_id = connection.enqueue(
             Request::WlSurfaceAttach {
                 sendto: self.id,
-                buffer: buffer.id,
+                buffer: buffer.map(|o| o.id),
                 x,
                 y,
             }
@@ -3199,12 +4423,12 @@ impl WlSurface {
     /// opaque region has copy semantics, and the wl_region object can be
     /// destroyed immediately. A NULL wl_region causes the pending opaque
     /// region to be set to empty.
-    pub fn r#set_opaque_region(&self, connection: &WaylandConnection, region: WlRegion)  {
+    pub fn r#set_opaque_region(&self, connection: &WaylandConnection, region: Option<WlRegion>)  {
 
         let _enq_id = connection.enqueue(
             Request::WlSurfaceSetOpaqueRegion {
                 sendto: self.id,
-                region: region.id,
+                region: region.map(|o| o.id),
             }
         );
     }
@@ -3232,12 +4456,12 @@ impl WlSurface {
     /// has copy semantics, and the wl_region object can be destroyed
     /// immediately. A NULL wl_region causes the input region to be set
     /// to infinite.
-    pub fn r#set_input_region(&self, connection: &WaylandConnection, region: WlRegion)  {
+    pub fn r#set_input_region(&self, connection: &WaylandConnection, region: Option<WlRegion>)  {
 
         let _enq_id = connection.enqueue(
             Request::WlSurfaceSetInputRegion {
                 sendto: self.id,
-                region: region.id,
+                region: region.map(|o| o.id),
             }
         );
     }
@@ -3424,6 +4648,20 @@ pub struct WlSeat{
     pub id: u32,
 }
 
+impl Interface for WlSeat {
+    const NAME: &'static str = "wl_seat";
+    const VERSION: u32 = 9;
+    const OBJECT: Object = Object::WlSeat;
+
+    fn from_id(id: u32) -> Self {
+        WlSeat { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlSeat {
 
     /// `wl_seat:get_pointer` request
@@ -3516,6 +4754,20 @@ pub struct WlPointer{
     pub id: u32,
 }
 
+impl Interface for WlPointer {
+    const NAME: &'static str = "wl_pointer";
+    const VERSION: u32 = 9;
+    const OBJECT: Object = Object::WlPointer;
+
+    fn from_id(id: u32) -> Self {
+        WlPointer { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlPointer {
 
     /// `wl_pointer:set_cursor` request
@@ -3552,13 +4804,13 @@ impl WlPointer {
     /// The serial parameter must match the latest wl_pointer.enter
     /// serial number sent to the client. Otherwise the request will be
     /// ignored.
-    pub fn r#set_cursor(&self, connection: &WaylandConnection, serial: u32, surface: WlSurface, hotspot_x: i32, hotspot_y: i32)  {
+    pub fn r#set_cursor(&self, connection: &WaylandConnection, serial: u32, surface: Option<WlSurface>, hotspot_x: i32, hotspot_y: i32)  {
 
         let _enq_id = connection.enqueue(
             Request::WlPointerSetCursor {
                 sendto: self.id,
                 serial,
-                surface: surface.id,
+                surface: surface.map(|o| o.id),
                 hotspot_x,
                 hotspot_y,
             }
@@ -3589,6 +4841,20 @@ pub struct WlKeyboard{
     pub id: u32,
 }
 
+impl Interface for WlKeyboard {
+    const NAME: &'static str = "wl_keyboard";
+    const VERSION: u32 = 9;
+    const OBJECT: Object = Object::WlKeyboard;
+
+    fn from_id(id: u32) -> Self {
+        WlKeyboard { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlKeyboard {
 
     /// `wl_keyboard:release` request
@@ -3616,6 +4882,20 @@ pub struct WlTouch{
     pub id: u32,
 }
 
+impl Interface for WlTouch {
+    const NAME: &'static str = "wl_touch";
+    const VERSION: u32 = 9;
+    const OBJECT: Object = Object::WlTouch;
+
+    fn from_id(id: u32) -> Self {
+        WlTouch { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlTouch {
 
     /// `wl_touch:release` request
@@ -3641,6 +4921,20 @@ pub struct WlOutput{
     pub id: u32,
 }
 
+impl Interface for WlOutput {
+    const NAME: &'static str = "wl_output";
+    const VERSION: u32 = 4;
+    const OBJECT: Object = Object::WlOutput;
+
+    fn from_id(id: u32) -> Self {
+        WlOutput { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlOutput {
 
     /// `wl_output:release` request
@@ -3666,6 +4960,20 @@ pub struct WlRegion{
     pub id: u32,
 }
 
+impl Interface for WlRegion {
+    const NAME: &'static str = "wl_region";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlRegion;
+
+    fn from_id(id: u32) -> Self {
+        WlRegion { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlRegion {
 
     /// `wl_region:destroy` request
@@ -3735,6 +5043,20 @@ pub struct WlSubcompositor{
     pub id: u32,
 }
 
+impl Interface for WlSubcompositor {
+    const NAME: &'static str = "wl_subcompositor";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlSubcompositor;
+
+    fn from_id(id: u32) -> Self {
+        WlSubcompositor { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlSubcompositor {
 
     /// `wl_subcompositor:destroy` request
@@ -3838,6 +5160,20 @@ pub struct WlSubsurface{
     pub id: u32,
 }
 
+impl Interface for WlSubsurface {
+    const NAME: &'static str = "wl_subsurface";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WlSubsurface;
+
+    fn from_id(id: u32) -> Self {
+        WlSubsurface { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
 impl WlSubsurface {
 
     /// `wl_subsurface:destroy` request
@@ -3974,3 +5310,5159 @@ impl WlSubsurface {
     }
 }
 
+
+/// This interface allows a compositor to announce support for server-side
+/// decorations.
+///
+/// A window decoration is a set of window controls as deemed appropriate by
+/// the party managing them, such as user interface components used to move,
+/// resize and change a window's state.
+///
+/// A client can use this protocol to request being decorated by a supporting
+/// compositor.
+///
+/// If compositor and client do not negotiate the use of a server-side
+/// decoration using this protocol, clients continue to self-decorate as they
+/// see fit.
+///
+/// Warning! The protocol described in this file is experimental and
+/// backward incompatible changes may be made. Backward compatible changes
+/// may be added together with the corresponding interface version bump.
+/// Backward incompatible changes are done by bumping the version number in
+/// the protocol and interface names and resetting the interface version.
+/// Once the protocol is to be declared stable, the 'z' prefix and the
+/// version number in the protocol and interface names are removed and the
+/// interface version number is reset.
+#[derive(Clone, Copy)]
+pub struct ZxdgDecorationManagerV1{
+    pub id: u32,
+}
+
+impl Interface for ZxdgDecorationManagerV1 {
+    const NAME: &'static str = "zxdg_decoration_manager_v1";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZxdgDecorationManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        ZxdgDecorationManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZxdgDecorationManagerV1 {
+
+    /// `zxdg_decoration_manager_v1:destroy` request
+    /// Destroy the decoration manager. This doesn't destroy objects created
+    /// with the manager.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZxdgDecorationManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zxdg_decoration_manager_v1:get_toplevel_decoration` request
+    /// Create a new decoration object associated with the given toplevel.
+    ///
+    /// For objects of version 1, creating an xdg_toplevel_decoration from an
+    /// xdg_toplevel which has a buffer attached or committed is a client
+    /// error, and any attempts by a client to attach or manipulate a buffer
+    /// prior to the first xdg_toplevel_decoration.configure event must also be
+    /// treated as errors.
+    ///
+    /// For objects of version 2 or newer, creating an xdg_toplevel_decoration
+    /// from an xdg_toplevel which has a buffer attached or committed is
+    /// allowed. The initial decoration mode of the surface if a buffer is
+    /// already attached depends on whether a xdg_toplevel_decoration object
+    /// has been associated with the surface or not prior to this request.
+    ///
+    /// If an xdg_toplevel_decoration was associated with the surface, then
+    /// destroyed without a surface commit, the previous decoration mode is
+    /// retained.
+    ///
+    /// If no xdg_toplevel_decoration was associated with the surface prior to
+    /// this request, or if a surface commit has been performed after a previous
+    /// xdg_toplevel_decoration object associated with the surface was
+    /// destroyed, the decoration mode is assumed to be client-side.
+    pub fn r#get_toplevel_decoration(&self, connection: &WaylandConnection, toplevel: XdgToplevel) -> ZxdgToplevelDecorationV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZxdgDecorationManagerV1GetToplevelDecoration {
+                sendto: self.id,
+                toplevel: toplevel.id,
+            }
+        );
+        return ZxdgToplevelDecorationV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// The decoration object allows the compositor to toggle server-side window
+/// decorations for a toplevel surface. The client can request to switch to
+/// another mode.
+///
+/// The xdg_toplevel_decoration object must be destroyed before its
+/// xdg_toplevel.
+#[derive(Clone, Copy)]
+pub struct ZxdgToplevelDecorationV1{
+    pub id: u32,
+}
+
+impl Interface for ZxdgToplevelDecorationV1 {
+    const NAME: &'static str = "zxdg_toplevel_decoration_v1";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZxdgToplevelDecorationV1;
+
+    fn from_id(id: u32) -> Self {
+        ZxdgToplevelDecorationV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZxdgToplevelDecorationV1 {
+
+    /// `zxdg_toplevel_decoration_v1:destroy` request
+    /// Switch back to a mode without any server-side decorations at the next
+    /// commit, unless a new xdg_toplevel_decoration is created for the surface
+    /// first.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZxdgToplevelDecorationV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zxdg_toplevel_decoration_v1:set_mode` request
+    /// Set the toplevel surface decoration mode. This informs the compositor
+    /// that the client prefers the provided decoration mode.
+    ///
+    /// After requesting a decoration mode, the compositor will respond by
+    /// emitting an xdg_surface.configure event. The client should then update
+    /// its content, drawing it without decorations if the received mode is
+    /// server-side decorations. The client must also acknowledge the configure
+    /// when committing the new content (see xdg_surface.ack_configure).
+    ///
+    /// The compositor can decide not to use the client's mode and enforce a
+    /// different mode instead.
+    ///
+    /// Clients whose decoration mode depend on the xdg_toplevel state may send
+    /// a set_mode request in response to an xdg_surface.configure event and wait
+    /// for the next xdg_surface.configure event to prevent unwanted state.
+    /// Such clients are responsible for preventing configure loops and must
+    /// make sure not to send multiple successive set_mode requests with the
+    /// same decoration mode.
+    ///
+    /// If an invalid mode is supplied by the client, the invalid_mode protocol
+    /// error is raised by the compositor.
+    pub fn r#set_mode(&self, connection: &WaylandConnection, mode: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZxdgToplevelDecorationV1SetMode {
+                sendto: self.id,
+                mode,
+            }
+        );
+    }
+
+    /// `zxdg_toplevel_decoration_v1:unset_mode` request
+    /// Unset the toplevel surface decoration mode. This informs the compositor
+    /// that the client doesn't prefer a particular decoration mode.
+    ///
+    /// This request has the same semantics as set_mode.
+    pub fn r#unset_mode(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZxdgToplevelDecorationV1UnsetMode {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// The global interface exposing surface cropping and scaling
+/// capabilities is used to instantiate an interface extension for a
+/// wl_surface object. This extended interface will then allow
+/// cropping and scaling the surface contents, effectively
+/// disconnecting the direct relationship between the buffer and the
+/// surface size.
+#[derive(Clone, Copy)]
+pub struct WpViewporter{
+    pub id: u32,
+}
+
+impl Interface for WpViewporter {
+    const NAME: &'static str = "wp_viewporter";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpViewporter;
+
+    fn from_id(id: u32) -> Self {
+        WpViewporter { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpViewporter {
+
+    /// `wp_viewporter:destroy` request
+    /// Informs the server that the client will not be using this
+    /// protocol object anymore. This does not affect any other objects,
+    /// wp_viewport objects included.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpViewporterDestroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_viewporter:get_viewport` request
+    /// Instantiate an interface extension for the given wl_surface to
+    /// crop and scale its content. If the given wl_surface already has
+    /// a wp_viewport object associated, the viewport_exists
+    /// protocol error is raised.
+    pub fn r#get_viewport(&self, connection: &WaylandConnection, surface: WlSurface) -> WpViewport {
+
+        let _enq_id = connection.enqueue(
+            Request::WpViewporterGetViewport {
+                sendto: self.id,
+                surface: surface.id,
+            }
+        );
+        return WpViewport{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// An additional interface to a wl_surface object, which allows the
+/// client to specify the cropping and scaling of the surface
+/// contents.
+///
+/// This interface works with two concepts: the source rectangle (src_x,
+/// src_y, src_width, src_height), and the destination size (dst_width,
+/// dst_height). The contents of the source rectangle are scaled to the
+/// destination size, and content outside the source rectangle is ignored.
+/// This state is double-buffered, see wl_surface.commit.
+///
+/// The two parts of crop and scale state are independent: the source
+/// rectangle, and the destination size. Initially both are unset, that
+/// is, no scaling is applied. The whole of the current wl_buffer is
+/// used as the source, and the surface size is as defined in
+/// wl_surface.attach.
+///
+/// If the destination size is set, it causes the surface size to become
+/// dst_width, dst_height. The source (rectangle) is scaled to exactly
+/// this size. This overrides whatever the attached wl_buffer size is,
+/// unless the wl_buffer is NULL. If the wl_buffer is NULL, the surface
+/// has no content and therefore no size. Otherwise, the size is always
+/// at least 1x1 in surface local coordinates.
+///
+/// If the source rectangle is set, it defines what area of the wl_buffer is
+/// taken as the source. If the source rectangle is set and the destination
+/// size is not set, then src_width and src_height must be integers, and the
+/// surface size becomes the source rectangle size. This results in cropping
+/// without scaling. If src_width or src_height are not integers and
+/// destination size is not set, the bad_size protocol error is raised when
+/// the surface state is applied.
+///
+/// The coordinate transformations from buffer pixel coordinates up to
+/// the surface-local coordinates happen in the following order:
+/// 1. buffer_transform (wl_surface.set_buffer_transform)
+/// 2. buffer_scale (wl_surface.set_buffer_scale)
+/// 3. crop and scale (wp_viewport.set*)
+/// This means, that the source rectangle coordinates of crop and scale
+/// are given in the coordinates after the buffer transform and scale,
+/// i.e. in the coordinates that would be the surface-local coordinates
+/// if the crop and scale was not applied.
+///
+/// If src_x or src_y are negative, the bad_value protocol error is raised.
+/// Otherwise, if the source rectangle is partially or completely outside of
+/// the non-NULL wl_buffer, then the out_of_buffer protocol error is raised
+/// when the surface state is applied. A NULL wl_buffer does not raise the
+/// out_of_buffer error.
+///
+/// If the wl_surface associated with the wp_viewport is destroyed,
+/// all wp_viewport requests except 'destroy' raise the protocol error
+/// no_surface.
+///
+/// If the wp_viewport object is destroyed, the crop and scale
+/// state is removed from the wl_surface. The change will be applied
+/// on the next wl_surface.commit.
+#[derive(Clone, Copy)]
+pub struct WpViewport{
+    pub id: u32,
+}
+
+impl Interface for WpViewport {
+    const NAME: &'static str = "wp_viewport";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpViewport;
+
+    fn from_id(id: u32) -> Self {
+        WpViewport { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpViewport {
+
+    /// `wp_viewport:destroy` request
+    /// The associated wl_surface's crop and scale state is removed.
+    /// The change is applied on the next wl_surface.commit.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpViewportDestroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_viewport:set_source` request
+    /// Set the source rectangle of the associated wl_surface. See
+    /// wp_viewport for the description, and relation to the wl_buffer
+    /// size.
+    ///
+    /// If all of x, y, width and height are -1.0, the source rectangle is
+    /// unset instead. Any other set of values where width or height are zero
+    /// or negative, or x or y are negative, raise the bad_value protocol
+    /// error.
+    ///
+    /// The crop and scale state is double-buffered, see wl_surface.commit.
+    pub fn r#set_source(&self, connection: &WaylandConnection, x: Fixed, y: Fixed, width: Fixed, height: Fixed)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpViewportSetSource {
+                sendto: self.id,
+                x,
+                y,
+                width,
+                height,
+            }
+        );
+    }
+
+    /// `wp_viewport:set_destination` request
+    /// Set the destination size of the associated wl_surface. See
+    /// wp_viewport for the description, and relation to the wl_buffer
+    /// size.
+    ///
+    /// If width is -1 and height is -1, the destination size is unset
+    /// instead. Any other pair of values for width and height that
+    /// contains zero or negative values raises the bad_value protocol
+    /// error.
+    ///
+    /// The crop and scale state is double-buffered, see wl_surface.commit.
+    pub fn r#set_destination(&self, connection: &WaylandConnection, width: i32, height: i32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpViewportSetDestination {
+                sendto: self.id,
+                width,
+                height,
+            }
+        );
+    }
+}
+
+
+/// A global interface for requesting surfaces to use fractional scales.
+#[derive(Clone, Copy)]
+pub struct WpFractionalScaleManagerV1{
+    pub id: u32,
+}
+
+impl Interface for WpFractionalScaleManagerV1 {
+    const NAME: &'static str = "wp_fractional_scale_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpFractionalScaleManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        WpFractionalScaleManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpFractionalScaleManagerV1 {
+
+    /// `wp_fractional_scale_manager_v1:destroy` request
+    /// Informs the server that the client will not be using this protocol
+    /// object anymore. This does not affect any other objects,
+    /// wp_fractional_scale_v1 objects included.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpFractionalScaleManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_fractional_scale_manager_v1:get_fractional_scale` request
+    /// Create an add-on object for the the wl_surface to let the compositor
+    /// request fractional scales. If the given wl_surface already has a
+    /// wp_fractional_scale_v1 object associated, the fractional_scale_exists
+    /// protocol error is raised.
+    pub fn r#get_fractional_scale(&self, connection: &WaylandConnection, surface: WlSurface) -> WpFractionalScaleV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::WpFractionalScaleManagerV1GetFractionalScale {
+                sendto: self.id,
+                surface: surface.id,
+            }
+        );
+        return WpFractionalScaleV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// An additional interface to a wl_surface object which allows the compositor
+/// to inform the client of the preferred scale.
+#[derive(Clone, Copy)]
+pub struct WpFractionalScaleV1{
+    pub id: u32,
+}
+
+impl Interface for WpFractionalScaleV1 {
+    const NAME: &'static str = "wp_fractional_scale_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpFractionalScaleV1;
+
+    fn from_id(id: u32) -> Self {
+        WpFractionalScaleV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpFractionalScaleV1 {
+
+    /// `wp_fractional_scale_v1:destroy` request
+    /// Destroy the fractional scale object. When this object is destroyed,
+    /// preferred_scale events will no longer be sent.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpFractionalScaleV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// The main feature of this interface is accurate presentation
+/// timing feedback to ensure smooth video playback while maintaining
+/// audio/video synchronization. Some features use the concept of a
+/// presentation clock, which is defined in the
+/// presentation.clock_id event.
+///
+/// A content update for a wl_surface is submitted by a
+/// wl_surface.commit request. Request 'feedback' associates with
+/// the wl_surface.commit and provides feedback on the content
+/// update, particularly the final realized presentation time.
+///
+///
+///
+/// When the final realized presentation time is available, e.g.
+/// after a framebuffer flip completes, the requested
+/// presentation_feedback.presented events are sent. The final
+/// presentation time can differ from the compositor's predicted
+/// display update time and the update's target time, especially
+/// when the compositor misses its target vertical blanking period.
+#[derive(Clone, Copy)]
+pub struct WpPresentation{
+    pub id: u32,
+}
+
+impl Interface for WpPresentation {
+    const NAME: &'static str = "wp_presentation";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::WpPresentation;
+
+    fn from_id(id: u32) -> Self {
+        WpPresentation { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpPresentation {
+
+    /// `wp_presentation:destroy` request
+    /// Informs the server that the client will no longer be using
+    /// this protocol object. Existing objects created by this object
+    /// are not affected.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpPresentationDestroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_presentation:feedback` request
+    /// Request presentation feedback for the current content submission
+    /// on the given surface. This creates a new presentation_feedback
+    /// object, which will deliver the feedback information once. If
+    /// multiple presentation_feedback objects are created for the same
+    /// submission, they will all deliver the same information.
+    ///
+    /// For details on what information is returned, see the
+    /// presentation_feedback interface.
+    pub fn r#feedback(&self, connection: &WaylandConnection, surface: WlSurface) -> WpPresentationFeedback {
+
+        let _enq_id = connection.enqueue(
+            Request::WpPresentationFeedback {
+                sendto: self.id,
+                surface: surface.id,
+            }
+        );
+        return WpPresentationFeedback{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// A presentation_feedback object returns an indication that a
+/// wl_surface content update has become visible to the user.
+/// One object corresponds to one content update submission
+/// (wl_surface.commit). There are two possible outcomes: the
+/// content update is presented to the user, and a presentation
+/// timestamp delivered; or, the user did not see the content
+/// update because it was superseded or its surface destroyed,
+/// and the content update is discarded.
+///
+/// Once a presentation_feedback object has delivered a 'presented'
+/// or 'discarded' event it is automatically destroyed.
+#[derive(Clone, Copy)]
+pub struct WpPresentationFeedback{
+    pub id: u32,
+}
+
+impl Interface for WpPresentationFeedback {
+    const NAME: &'static str = "wp_presentation_feedback";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::WpPresentationFeedback;
+
+    fn from_id(id: u32) -> Self {
+        WpPresentationFeedback { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+
+/// This global is a factory interface, allowing clients to request
+/// explicit synchronization for buffers on a per-surface basis.
+///
+/// See zwp_linux_surface_synchronization_v1 for more information.
+///
+/// This interface is derived from Chromium's
+/// zcr_linux_explicit_synchronization_v1.
+///
+/// Note: this protocol is superseded by linux-drm-syncobj.
+///
+/// Warning! The protocol described in this file is experimental and
+/// backward incompatible changes may be made. Backward compatible changes
+/// may be added together with the corresponding interface version bump.
+/// Backward incompatible changes are done by bumping the version number in
+/// the protocol and interface names and resetting the interface version.
+/// Once the protocol is to be declared stable, the 'z' prefix and the
+/// version number in the protocol and interface names are removed and the
+/// interface version number is reset.
+#[derive(Clone, Copy)]
+pub struct ZwpLinuxExplicitSynchronizationV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpLinuxExplicitSynchronizationV1 {
+    const NAME: &'static str = "zwp_linux_explicit_synchronization_v1";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpLinuxExplicitSynchronizationV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpLinuxExplicitSynchronizationV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpLinuxExplicitSynchronizationV1 {
+
+    /// `zwp_linux_explicit_synchronization_v1:destroy` request
+    /// Destroy this explicit synchronization factory object. Other objects,
+    /// including zwp_linux_surface_synchronization_v1 objects created by this
+    /// factory, shall not be affected by this request.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpLinuxExplicitSynchronizationV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_linux_explicit_synchronization_v1:get_synchronization` request
+    /// Instantiate an interface extension for the given wl_surface to provide
+    /// explicit synchronization.
+    ///
+    /// If the given wl_surface already has an explicit synchronization object
+    /// associated, the synchronization_exists protocol error is raised.
+    ///
+    /// Graphics APIs, like EGL or Vulkan, that manage the buffer queue and
+    /// commits of a wl_surface themselves, are likely to be using this
+    /// extension internally. If a client is using such an API for a
+    /// wl_surface, it should not directly use this extension on that surface,
+    /// to avoid raising a synchronization_exists protocol error.
+    pub fn r#get_synchronization(&self, connection: &WaylandConnection, surface: WlSurface) -> ZwpLinuxSurfaceSynchronizationV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpLinuxExplicitSynchronizationV1GetSynchronization {
+                sendto: self.id,
+                surface: surface.id,
+            }
+        );
+        return ZwpLinuxSurfaceSynchronizationV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// This object implements per-surface explicit synchronization.
+///
+/// Synchronization refers to co-ordination of pipelined operations performed
+/// on buffers. Most GPU clients will schedule an asynchronous operation to
+/// render to the buffer, then immediately send the buffer to the compositor
+/// to be attached to a surface.
+///
+/// In implicit synchronization, ensuring that the rendering operation is
+/// complete before the compositor displays the buffer is an implementation
+/// detail handled by either the kernel or userspace graphics driver.
+///
+/// By contrast, in explicit synchronization, dma_fence objects mark when the
+/// asynchronous operations are complete. When submitting a buffer, the
+/// client provides an acquire fence which will be waited on before the
+/// compositor accesses the buffer. The Wayland server, through a
+/// zwp_linux_buffer_release_v1 object, will inform the client with an event
+/// which may be accompanied by a release fence, when the compositor will no
+/// longer access the buffer contents due to the specific commit that
+/// requested the release event.
+///
+/// Each surface can be associated with only one object of this interface at
+/// any time.
+///
+/// In version 1 of this interface, explicit synchronization is only
+/// guaranteed to be supported for buffers created with any version of the
+/// wp_linux_dmabuf buffer factory. Version 2 additionally guarantees
+/// explicit synchronization support for opaque EGL buffers, which is a type
+/// of platform specific buffers described in the EGL_WL_bind_wayland_display
+/// extension. Compositors are free to support explicit synchronization for
+/// additional buffer types.
+#[derive(Clone, Copy)]
+pub struct ZwpLinuxSurfaceSynchronizationV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpLinuxSurfaceSynchronizationV1 {
+    const NAME: &'static str = "zwp_linux_surface_synchronization_v1";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpLinuxSurfaceSynchronizationV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpLinuxSurfaceSynchronizationV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpLinuxSurfaceSynchronizationV1 {
+
+    /// `zwp_linux_surface_synchronization_v1:destroy` request
+    /// Destroy this explicit synchronization object.
+    ///
+    /// Any fence set by this object with set_acquire_fence since the last
+    /// commit will be discarded by the server. Any fences set by this object
+    /// before the last commit are not affected.
+    ///
+    /// zwp_linux_buffer_release_v1 objects created by this object are not
+    /// affected by this request.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpLinuxSurfaceSynchronizationV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_linux_surface_synchronization_v1:set_acquire_fence` request
+    /// Set the acquire fence that must be signaled before the compositor
+    /// may sample from the buffer attached with wl_surface.attach. The fence
+    /// is a dma_fence kernel object.
+    ///
+    /// The acquire fence is double-buffered state, and will be applied on the
+    /// next wl_surface.commit request for the associated surface. Thus, it
+    /// applies only to the buffer that is attached to the surface at commit
+    /// time.
+    ///
+    /// If the provided fd is not a valid dma_fence fd, then an INVALID_FENCE
+    /// error is raised.
+    ///
+    /// If a fence has already been attached during the same commit cycle, a
+    /// DUPLICATE_FENCE error is raised.
+    ///
+    /// If the associated wl_surface was destroyed, a NO_SURFACE error is
+    /// raised.
+    ///
+    /// If at surface commit time the attached buffer does not support explicit
+    /// synchronization, an UNSUPPORTED_BUFFER error is raised.
+    ///
+    /// If at surface commit time there is no buffer attached, a NO_BUFFER
+    /// error is raised.
+    pub fn r#set_acquire_fence(&self, connection: &WaylandConnection, fd: RawFd)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpLinuxSurfaceSynchronizationV1SetAcquireFence {
+                sendto: self.id,
+                fd,
+            }
+        );
+    }
+
+    /// `zwp_linux_surface_synchronization_v1:get_release` request
+    /// Create a listener for the release of the buffer attached by the
+    /// client with wl_surface.attach. See zwp_linux_buffer_release_v1
+    /// documentation for more information.
+    ///
+    /// The release object is double-buffered state, and will be associated
+    /// with the buffer that is attached to the surface at wl_surface.commit
+    /// time.
+    ///
+    /// If a zwp_linux_buffer_release_v1 object has already been requested for
+    /// the surface in the same commit cycle, a DUPLICATE_RELEASE error is
+    /// raised.
+    ///
+    /// If the associated wl_surface was destroyed, a NO_SURFACE error
+    /// is raised.
+    ///
+    /// If at surface commit time there is no buffer attached, a NO_BUFFER
+    /// error is raised.
+    pub fn r#get_release(&self, connection: &WaylandConnection) -> ZwpLinuxBufferReleaseV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpLinuxSurfaceSynchronizationV1GetRelease {
+                sendto: self.id,
+            }
+        );
+        return ZwpLinuxBufferReleaseV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// This object is instantiated in response to a
+/// zwp_linux_surface_synchronization_v1.get_release request.
+///
+/// It provides an alternative to wl_buffer.release events, providing a
+/// unique release from a single wl_surface.commit request. The release event
+/// also supports explicit synchronization, providing a fence FD for the
+/// client to synchronize against.
+///
+/// Exactly one event, either a fenced_release or an immediate_release, will
+/// be emitted for the wl_surface.commit request. The compositor can choose
+/// release by release which event it uses.
+///
+/// This event does not replace wl_buffer.release events; servers are still
+/// required to send those events.
+///
+/// Once a buffer release object has delivered a 'fenced_release' or an
+/// 'immediate_release' event it is automatically destroyed.
+#[derive(Clone, Copy)]
+pub struct ZwpLinuxBufferReleaseV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpLinuxBufferReleaseV1 {
+    const NAME: &'static str = "zwp_linux_buffer_release_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpLinuxBufferReleaseV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpLinuxBufferReleaseV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+
+/// This global is a factory interface, allowing clients to request
+/// explicit synchronization for buffers on a per-surface basis.
+///
+/// See wp_linux_drm_syncobj_surface_v1 for more information.
+#[derive(Clone, Copy)]
+pub struct WpLinuxDrmSyncobjManagerV1{
+    pub id: u32,
+}
+
+impl Interface for WpLinuxDrmSyncobjManagerV1 {
+    const NAME: &'static str = "wp_linux_drm_syncobj_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpLinuxDrmSyncobjManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        WpLinuxDrmSyncobjManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpLinuxDrmSyncobjManagerV1 {
+
+    /// `wp_linux_drm_syncobj_manager_v1:destroy` request
+    /// Destroy this explicit synchronization factory object. Other objects
+    /// shall not be affected by this request.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpLinuxDrmSyncobjManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_linux_drm_syncobj_manager_v1:get_surface` request
+    /// Instantiate an interface extension for the given wl_surface to provide
+    /// explicit synchronization.
+    ///
+    /// If the given wl_surface already has an explicit synchronization object
+    /// associated, the surface_exists protocol error is raised.
+    ///
+    /// Graphics APIs, like EGL or Vulkan, that manage the buffer queue and
+    /// commits of a wl_surface themselves, are likely to be using this
+    /// extension internally. If a client is using such an API for a
+    /// wl_surface, it should not directly use this extension on that surface,
+    /// to avoid raising a surface_exists protocol error.
+    pub fn r#get_surface(&self, connection: &WaylandConnection, surface: WlSurface) -> WpLinuxDrmSyncobjSurfaceV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::WpLinuxDrmSyncobjManagerV1GetSurface {
+                sendto: self.id,
+                surface: surface.id,
+            }
+        );
+        return WpLinuxDrmSyncobjSurfaceV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `wp_linux_drm_syncobj_manager_v1:import_timeline` request
+    /// Import a DRM synchronization object timeline.
+    ///
+    /// If the FD cannot be imported, the invalid_timeline error is raised.
+    pub fn r#import_timeline(&self, connection: &WaylandConnection, fd: RawFd) -> WpLinuxDrmSyncobjTimelineV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::WpLinuxDrmSyncobjManagerV1ImportTimeline {
+                sendto: self.id,
+                fd,
+            }
+        );
+        return WpLinuxDrmSyncobjTimelineV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// This object represents an explicit synchronization object timeline
+/// imported by the client to the compositor.
+#[derive(Clone, Copy)]
+pub struct WpLinuxDrmSyncobjTimelineV1{
+    pub id: u32,
+}
+
+impl Interface for WpLinuxDrmSyncobjTimelineV1 {
+    const NAME: &'static str = "wp_linux_drm_syncobj_timeline_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpLinuxDrmSyncobjTimelineV1;
+
+    fn from_id(id: u32) -> Self {
+        WpLinuxDrmSyncobjTimelineV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpLinuxDrmSyncobjTimelineV1 {
+
+    /// `wp_linux_drm_syncobj_timeline_v1:destroy` request
+    /// Destroy the synchronization object timeline. Other objects are not
+    /// affected by this request, in particular timeline points set by
+    /// set_acquire_point and set_release_point are not unset.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpLinuxDrmSyncobjTimelineV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// This object is an add-on interface for wl_surface to enable explicit
+/// synchronization.
+///
+/// Each surface can be associated with only one object of this interface at
+/// any time.
+///
+/// Explicit synchronization is guaranteed to be supported for buffers
+/// created with any version of the linux-dmabuf protocol. Compositors are
+/// free to support explicit synchronization for additional buffer types.
+/// If at surface commit time the attached buffer does not support explicit
+/// synchronization, an unsupported_buffer error is raised.
+///
+/// As long as the wp_linux_drm_syncobj_surface_v1 object is alive, the
+/// compositor may ignore implicit synchronization for buffers attached and
+/// committed to the wl_surface. The delivery of wl_buffer.release events
+/// for buffers attached to the surface becomes undefined.
+///
+/// Clients must set both acquire and release points if and only if a
+/// non-null buffer is attached in the same surface commit. See the
+/// no_buffer, no_acquire_point and no_release_point protocol errors.
+///
+/// If at surface commit time the acquire and release DRM syncobj timelines
+/// are identical, the acquire point value must be strictly less than the
+/// release point value, or else the conflicting_points protocol error is
+/// raised.
+#[derive(Clone, Copy)]
+pub struct WpLinuxDrmSyncobjSurfaceV1{
+    pub id: u32,
+}
+
+impl Interface for WpLinuxDrmSyncobjSurfaceV1 {
+    const NAME: &'static str = "wp_linux_drm_syncobj_surface_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpLinuxDrmSyncobjSurfaceV1;
+
+    fn from_id(id: u32) -> Self {
+        WpLinuxDrmSyncobjSurfaceV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpLinuxDrmSyncobjSurfaceV1 {
+
+    /// `wp_linux_drm_syncobj_surface_v1:destroy` request
+    /// Destroy this surface synchronization object.
+    ///
+    /// Any timeline point set by this object with set_acquire_point or
+    /// set_release_point since the last commit may be discarded by the
+    /// compositor. Any timeline point set by this object before the last
+    /// commit will not be affected.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpLinuxDrmSyncobjSurfaceV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_linux_drm_syncobj_surface_v1:set_acquire_point` request
+    /// Set the timeline point that must be signalled before the compositor may
+    /// sample from the buffer attached with wl_surface.attach.
+    ///
+    /// The 64-bit unsigned value combined from point_hi and point_lo is the
+    /// point value.
+    ///
+    /// The acquire point is double-buffered state, and will be applied on the
+    /// next wl_surface.commit request for the associated surface. Thus, it
+    /// applies only to the buffer that is attached to the surface at commit
+    /// time.
+    ///
+    /// If an acquire point has already been attached during the same commit
+    /// cycle, the new point replaces the old one.
+    ///
+    /// If the associated wl_surface was destroyed, a no_surface error is
+    /// raised.
+    ///
+    /// If at surface commit time there is a pending acquire timeline point set
+    /// but no pending buffer attached, a no_buffer error is raised. If at
+    /// surface commit time there is a pending buffer attached but no pending
+    /// acquire timeline point set, the no_acquire_point protocol error is
+    /// raised.
+    pub fn r#set_acquire_point(&self, connection: &WaylandConnection, timeline: WpLinuxDrmSyncobjTimelineV1, point_hi: u32, point_lo: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpLinuxDrmSyncobjSurfaceV1SetAcquirePoint {
+                sendto: self.id,
+                timeline: timeline.id,
+                point_hi,
+                point_lo,
+            }
+        );
+    }
+
+    /// `wp_linux_drm_syncobj_surface_v1:set_release_point` request
+    /// Set the timeline point that must be signalled by the compositor when it
+    /// has finished its usage of the buffer attached with wl_surface.attach
+    /// for the relevant commit.
+    ///
+    /// Once the timeline point is signaled, and assuming the associated buffer
+    /// is not pending release from other wl_surface.commit requests, no
+    /// additional explicit or implicit synchronization with the compositor is
+    /// required to safely re-use the buffer.
+    ///
+    /// Note that clients cannot rely on the release point being always
+    /// signaled after the acquire point: compositors may release buffers
+    /// without ever reading from them. In addition, the compositor may use
+    /// different presentation paths for different commits, which may have
+    /// different release behavior. As a result, the compositor may signal the
+    /// release points in a different order than the client committed them.
+    ///
+    /// Because signaling a timeline point also signals every previous point,
+    /// it is generally not safe to use the same timeline object for the
+    /// release points of multiple buffers. The out-of-order signaling
+    /// described above may lead to a release point being signaled before the
+    /// compositor has finished reading. To avoid this, it is strongly
+    /// recommended that each buffer should use a separate timeline for its
+    /// release points.
+    ///
+    /// The 64-bit unsigned value combined from point_hi and point_lo is the
+    /// point value.
+    ///
+    /// The release point is double-buffered state, and will be applied on the
+    /// next wl_surface.commit request for the associated surface. Thus, it
+    /// applies only to the buffer that is attached to the surface at commit
+    /// time.
+    ///
+    /// If a release point has already been attached during the same commit
+    /// cycle, the new point replaces the old one.
+    ///
+    /// If the associated wl_surface was destroyed, a no_surface error is
+    /// raised.
+    ///
+    /// If at surface commit time there is a pending release timeline point set
+    /// but no pending buffer attached, a no_buffer error is raised. If at
+    /// surface commit time there is a pending buffer attached but no pending
+    /// release timeline point set, the no_release_point protocol error is
+    /// raised.
+    pub fn r#set_release_point(&self, connection: &WaylandConnection, timeline: WpLinuxDrmSyncobjTimelineV1, point_hi: u32, point_lo: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpLinuxDrmSyncobjSurfaceV1SetReleasePoint {
+                sendto: self.id,
+                timeline: timeline.id,
+                point_hi,
+                point_lo,
+            }
+        );
+    }
+}
+
+
+/// A global interface used for informing the compositor about applications
+/// being activated or started, or for applications to request to be
+/// activated.
+#[derive(Clone, Copy)]
+pub struct XdgActivationV1{
+    pub id: u32,
+}
+
+impl Interface for XdgActivationV1 {
+    const NAME: &'static str = "xdg_activation_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::XdgActivationV1;
+
+    fn from_id(id: u32) -> Self {
+        XdgActivationV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl XdgActivationV1 {
+
+    /// `xdg_activation_v1:destroy` request
+    /// Notify the compositor that the xdg_activation object will no longer be
+    /// used.
+    ///
+    /// The child objects created via this interface are unaffected and should
+    /// be destroyed separately.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::XdgActivationV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `xdg_activation_v1:get_activation_token` request
+    /// Creates an xdg_activation_token_v1 object that will provide
+    /// the initiating client with a unique token for this activation. This
+    /// token should be offered to the clients to be activated.
+    pub fn r#get_activation_token(&self, connection: &WaylandConnection) -> XdgActivationTokenV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::XdgActivationV1GetActivationToken {
+                sendto: self.id,
+            }
+        );
+        return XdgActivationTokenV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `xdg_activation_v1:activate` request
+    /// Requests surface activation. It's up to the compositor to display
+    /// this information as desired, for example by placing the surface above
+    /// the rest.
+    ///
+    /// The compositor may know who requested this by checking the activation
+    /// token and might decide not to follow through with the activation if it's
+    /// considered unwanted.
+    ///
+    /// Compositors can ignore unknown activation tokens when an invalid
+    /// token is passed.
+    pub fn r#activate(&self, connection: &WaylandConnection, token: &str, surface: WlSurface)  {
+
+        let _enq_id = connection.enqueue(
+            Request::XdgActivationV1Activate {
+                sendto: self.id,
+                token,
+                surface: surface.id,
+            }
+        );
+    }
+}
+
+
+/// An object for setting up a token and receiving a token handle that can
+/// be passed as an activation token to another client.
+///
+/// The object is created using the xdg_activation_v1.get_activation_token
+/// request. This object should then be populated with the app_id, surface
+/// and serial information and committed. The compositor shall then issue a
+/// done event with the token. In case the request's parameters are invalid,
+/// the compositor will provide an invalid token.
+#[derive(Clone, Copy)]
+pub struct XdgActivationTokenV1{
+    pub id: u32,
+}
+
+impl Interface for XdgActivationTokenV1 {
+    const NAME: &'static str = "xdg_activation_token_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::XdgActivationTokenV1;
+
+    fn from_id(id: u32) -> Self {
+        XdgActivationTokenV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl XdgActivationTokenV1 {
+
+    /// `xdg_activation_token_v1:set_serial` request
+    /// Provides information about the seat and serial event that requested the
+    /// token.
+    ///
+    /// The serial can come from an input or focus event. For instance, if a
+    /// click triggers the launch of a third-party client, the launcher client
+    /// should send a set_serial request with the serial and seat from the
+    /// wl_pointer.button event.
+    ///
+    /// Some compositors might refuse to activate toplevels when the token
+    /// doesn't have a valid and recent enough event serial.
+    ///
+    /// Must be sent before commit. This information is optional.
+    pub fn r#set_serial(&self, connection: &WaylandConnection, serial: u32, seat: WlSeat)  {
+
+        let _enq_id = connection.enqueue(
+            Request::XdgActivationTokenV1SetSerial {
+                sendto: self.id,
+                serial,
+                seat: seat.id,
+            }
+        );
+    }
+
+    /// `xdg_activation_token_v1:set_app_id` request
+    /// The requesting client can specify an app_id to associate the token
+    /// being created with it.
+    ///
+    /// Must be sent before commit. This information is optional.
+    pub fn r#set_app_id(&self, connection: &WaylandConnection, app_id: &str)  {
+
+        let _enq_id = connection.enqueue(
+            Request::XdgActivationTokenV1SetAppId {
+                sendto: self.id,
+                app_id,
+            }
+        );
+    }
+
+    /// `xdg_activation_token_v1:set_surface` request
+    /// This request sets the surface requesting the activation. Note, this is
+    /// different from the surface that will be activated.
+    ///
+    /// Some compositors might refuse to activate toplevels when the token
+    /// doesn't have a requesting surface.
+    ///
+    /// Must be sent before commit. This information is optional.
+    pub fn r#set_surface(&self, connection: &WaylandConnection, surface: WlSurface)  {
+
+        let _enq_id = connection.enqueue(
+            Request::XdgActivationTokenV1SetSurface {
+                sendto: self.id,
+                surface: surface.id,
+            }
+        );
+    }
+
+    /// `xdg_activation_token_v1:commit` request
+    /// Requests an activation token based on the different parameters that
+    /// have been offered through set_serial, set_surface and set_app_id.
+    pub fn r#commit(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::XdgActivationTokenV1Commit {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `xdg_activation_token_v1:destroy` request
+    /// Notify the compositor that the xdg_activation_token_v1 object will no
+    /// longer be used. The received token stays valid.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::XdgActivationTokenV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// This global offers an alternative, optional way to set cursor images. This
+/// new way uses enumerated cursors instead of a wl_surface like
+/// wl_pointer.set_cursor does.
+///
+/// Warning! The protocol described in this file is currently in the testing
+/// phase. Backward compatible changes may be added together with the
+/// corresponding interface version bump. Backward incompatible changes can
+/// only be done by creating a new major version of the extension.
+#[derive(Clone, Copy)]
+pub struct WpCursorShapeManagerV1{
+    pub id: u32,
+}
+
+impl Interface for WpCursorShapeManagerV1 {
+    const NAME: &'static str = "wp_cursor_shape_manager_v1";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::WpCursorShapeManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        WpCursorShapeManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpCursorShapeManagerV1 {
+
+    /// `wp_cursor_shape_manager_v1:destroy` request
+    /// Destroy the cursor shape manager.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpCursorShapeManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_cursor_shape_manager_v1:get_pointer` request
+    /// Obtain a wp_cursor_shape_device_v1 for a wl_pointer object.
+    ///
+    /// When the pointer capability is removed from the wl_seat, the
+    /// wp_cursor_shape_device_v1 object becomes inert.
+    pub fn r#get_pointer(&self, connection: &WaylandConnection, pointer: WlPointer) -> WpCursorShapeDeviceV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::WpCursorShapeManagerV1GetPointer {
+                sendto: self.id,
+                pointer: pointer.id,
+            }
+        );
+        return WpCursorShapeDeviceV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `wp_cursor_shape_manager_v1:get_tablet_tool_v2` request
+    /// Obtain a wp_cursor_shape_device_v1 for a zwp_tablet_tool_v2 object.
+    ///
+    /// When the zwp_tablet_tool_v2 is removed, the wp_cursor_shape_device_v1
+    /// object becomes inert.
+    pub fn r#get_tablet_tool_v2(&self, connection: &WaylandConnection, tablet_tool: ZwpTabletToolV2) -> WpCursorShapeDeviceV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::WpCursorShapeManagerV1GetTabletToolV2 {
+                sendto: self.id,
+                tablet_tool: tablet_tool.id,
+            }
+        );
+        return WpCursorShapeDeviceV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// This interface allows clients to set the cursor shape.
+#[derive(Clone, Copy)]
+pub struct WpCursorShapeDeviceV1{
+    pub id: u32,
+}
+
+impl Interface for WpCursorShapeDeviceV1 {
+    const NAME: &'static str = "wp_cursor_shape_device_v1";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::WpCursorShapeDeviceV1;
+
+    fn from_id(id: u32) -> Self {
+        WpCursorShapeDeviceV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpCursorShapeDeviceV1 {
+
+    /// `wp_cursor_shape_device_v1:destroy` request
+    /// Destroy the cursor shape device.
+    ///
+    /// The device cursor shape remains unchanged.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpCursorShapeDeviceV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_cursor_shape_device_v1:set_shape` request
+    /// Sets the device cursor to the specified shape. The compositor will
+    /// change the cursor image based on the specified shape.
+    ///
+    /// The cursor actually changes only if the input device focus is one of
+    /// the requesting client's surfaces. If any, the previous cursor image
+    /// (surface or shape) is replaced.
+    ///
+    /// The "shape" argument must be a valid enum entry, otherwise the
+    /// invalid_shape protocol error is raised.
+    ///
+    /// This is similar to the wl_pointer.set_cursor and
+    /// zwp_tablet_tool_v2.set_cursor requests, but this request accepts a
+    /// shape instead of contents in the form of a surface. Clients can mix
+    /// set_cursor and set_shape requests.
+    ///
+    /// The serial parameter must match the latest wl_pointer.enter or
+    /// zwp_tablet_tool_v2.proximity_in serial number sent to the client.
+    /// Otherwise the request will be ignored.
+    pub fn r#set_shape(&self, connection: &WaylandConnection, serial: u32, shape: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpCursorShapeDeviceV1SetShape {
+                sendto: self.id,
+                serial,
+                shape,
+            }
+        );
+    }
+}
+
+
+/// The zwp_text_input_v3 interface represents text input and input methods
+/// associated with a seat. It provides enter/leave events to follow the
+/// text input focus for a seat.
+///
+/// Requests are used to enable/disable the text-input object and set
+/// state information like surrounding and selected text or the content type.
+/// The information about the entered text is sent to the text-input object
+/// via the preedit_string and commit_string events.
+///
+/// Text is valid UTF-8 encoded, indices and lengths are in bytes. Indices
+/// must not point to middle bytes inside a code point: they must either
+/// point to the first byte of a code point or to the end of the buffer.
+/// Lengths must be measured between two valid indices.
+///
+/// Focus moving throughout surfaces will result in the emission of
+/// zwp_text_input_v3.enter and zwp_text_input_v3.leave events. The focused
+/// surface must commit zwp_text_input_v3.enable and
+/// zwp_text_input_v3.disable requests as the keyboard focus moves across
+/// editable and non-editable elements of the UI. Those two requests are not
+/// expected to be paired with each other, the compositor must be able to
+/// handle consecutive series of the same request.
+///
+/// State is sent by the state requests (set_surrounding_text,
+/// set_content_type and set_cursor_rectangle) and a commit request. After an
+/// enter event or disable request all state information is invalidated and
+/// needs to be resent by the client.
+#[derive(Clone, Copy)]
+pub struct ZwpTextInputV3{
+    pub id: u32,
+}
+
+impl Interface for ZwpTextInputV3 {
+    const NAME: &'static str = "zwp_text_input_v3";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTextInputV3;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTextInputV3 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTextInputV3 {
+
+    /// `zwp_text_input_v3:destroy` request
+    /// Destroy the wp_text_input object. Also disables all surfaces enabled
+    /// through this wp_text_input object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:enable` request
+    /// Requests text input on the surface previously obtained from the enter
+    /// event.
+    ///
+    /// This request must be issued every time the focused text input changes
+    /// to a new one, including within the current surface. Use
+    /// zwp_text_input_v3.disable when there is no longer any input focus on
+    /// the current surface.
+    ///
+    /// Clients must not enable more than one text input on the single seat
+    /// and should disable the current text input before enabling the new one.
+    /// Requests to enable a text input when another text input is enabled
+    /// on the same seat must be ignored by compositor.
+    ///
+    /// This request resets all state associated with previous enable, disable,
+    /// set_surrounding_text, set_text_change_cause, set_content_type, and
+    /// set_cursor_rectangle requests, as well as the state associated with
+    /// preedit_string, commit_string, and delete_surrounding_text events.
+    ///
+    /// The set_surrounding_text, set_content_type and set_cursor_rectangle
+    /// requests must follow if the text input supports the necessary
+    /// functionality.
+    ///
+    /// State set with this request is double-buffered. It will get applied on
+    /// the next zwp_text_input_v3.commit request, and stay valid until the
+    /// next committed enable or disable request.
+    ///
+    /// The changes must be applied by the compositor after issuing a
+    /// zwp_text_input_v3.commit request.
+    pub fn r#enable(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3Enable {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:disable` request
+    /// Explicitly disable text input on the current surface (typically when
+    /// there is no focus on any text entry inside the surface).
+    ///
+    /// State set with this request is double-buffered. It will get applied on
+    /// the next zwp_text_input_v3.commit request.
+    pub fn r#disable(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3Disable {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:set_surrounding_text` request
+    /// Sets the surrounding plain text around the input, excluding the preedit
+    /// text.
+    ///
+    /// The client should notify the compositor of any changes in any of the
+    /// values carried with this request, including changes caused by handling
+    /// incoming text-input events as well as changes caused by other
+    /// mechanisms like keyboard typing.
+    ///
+    /// If the client is unaware of the text around the cursor, it should not
+    /// issue this request, to signify lack of support to the compositor.
+    ///
+    /// Text is UTF-8 encoded, and should include the cursor position, the
+    /// complete selection and additional characters before and after them.
+    /// There is a maximum length of wayland messages, so text can not be
+    /// longer than 4000 bytes.
+    ///
+    /// Cursor is the byte offset of the cursor within text buffer.
+    ///
+    /// Anchor is the byte offset of the selection anchor within text buffer.
+    /// If there is no selected text, anchor is the same as cursor.
+    ///
+    /// If any preedit text is present, it is replaced with a cursor for the
+    /// purpose of this event.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request, and stay valid until the
+    /// next committed enable or disable request.
+    ///
+    /// The initial state for affected fields is empty, meaning that the text
+    /// input does not support sending surrounding text. If the empty values
+    /// get applied, subsequent attempts to change them may have no effect.
+    pub fn r#set_surrounding_text(&self, connection: &WaylandConnection, text: &str, cursor: i32, anchor: i32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3SetSurroundingText {
+                sendto: self.id,
+                text,
+                cursor,
+                anchor,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:set_text_change_cause` request
+    /// Tells the compositor why the text surrounding the cursor changed.
+    ///
+    /// Whenever the client detects an external change in text, cursor, or
+    /// anchor posision, it must issue this request to the compositor. This
+    /// request is intended to give the input method a chance to update the
+    /// preedit text in an appropriate way, e.g. by removing it when the user
+    /// starts typing with a keyboard.
+    ///
+    /// cause describes the source of the change.
+    ///
+    /// The value set with this request is double-buffered. It must be applied
+    /// and reset to initial at the next zwp_text_input_v3.commit request.
+    ///
+    /// The initial value of cause is input_method.
+    pub fn r#set_text_change_cause(&self, connection: &WaylandConnection, cause: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3SetTextChangeCause {
+                sendto: self.id,
+                cause,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:set_content_type` request
+    /// Sets the content purpose and content hint. While the purpose is the
+    /// basic purpose of an input field, the hint flags allow to modify some of
+    /// the behavior.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request.
+    /// Subsequent attempts to update them may have no effect. The values
+    /// remain valid until the next committed enable or disable request.
+    ///
+    /// The initial value for hint is none, and the initial value for purpose
+    /// is normal.
+    pub fn r#set_content_type(&self, connection: &WaylandConnection, hint: u32, purpose: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3SetContentType {
+                sendto: self.id,
+                hint,
+                purpose,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:set_cursor_rectangle` request
+    /// Marks an area around the cursor as a x, y, width, height rectangle in
+    /// surface local coordinates.
+    ///
+    /// Allows the compositor to put a window with word suggestions near the
+    /// cursor, without obstructing the text being input.
+    ///
+    /// If the client is unaware of the position of edited text, it should not
+    /// issue this request, to signify lack of support to the compositor.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request, and stay valid until the
+    /// next committed enable or disable request.
+    ///
+    /// The initial values describing a cursor rectangle are empty. That means
+    /// the text input does not support describing the cursor area. If the
+    /// empty values get applied, subsequent attempts to change them may have
+    /// no effect.
+    ///
+    /// As of version 2, the zwp_text_input_v3.commit request does not apply
+    /// values sent with this request. Instead, it stores them in a separate
+    /// "committed" area. The committed values, if still valid, get applied on
+    /// the next wl_surface.commit request on the surface with text-input focus.
+    /// Both committed and applied values get invalidated on:
+    ///
+    /// - the next committed enable or disable request, or
+    /// - a change of the focused surface of the text-input (leave or enter events).
+    ///
+    /// This double stage application allows the compositor to position
+    /// the input method popup in the same frame as the contents
+    /// of the text on the surface are updated.
+    pub fn r#set_cursor_rectangle(&self, connection: &WaylandConnection, x: i32, y: i32, width: i32, height: i32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3SetCursorRectangle {
+                sendto: self.id,
+                x,
+                y,
+                width,
+                height,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:commit` request
+    /// Atomically applies state changes recently sent to the compositor.
+    ///
+    /// The commit request establishes and updates the state of the client, and
+    /// must be issued after any changes to apply them.
+    ///
+    /// Text input state (enabled status, content purpose, content hint,
+    /// surrounding text and change cause, cursor rectangle) is conceptually
+    /// double-buffered within the context of a text input, i.e. between a
+    /// committed enable request and the following committed enable or disable
+    /// request.
+    ///
+    /// Protocol requests modify the pending state, as opposed to the current
+    /// state in use by the input method. A commit request atomically applies
+    /// all pending state, replacing the current state. After commit, the new
+    /// pending state is as documented for each related request.
+    ///
+    /// Requests are applied in the order of arrival.
+    ///
+    /// Neither current nor pending state are modified unless noted otherwise.
+    ///
+    /// The compositor must count the number of commit requests coming from
+    /// each zwp_text_input_v3 object and use the count as the serial in done
+    /// events.
+    pub fn r#commit(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3Commit {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:set_available_actions` request
+    /// Set the actions available for this text input.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request.
+    ///
+    /// If the available_actions array contains the none action, or contains the
+    /// same action multiple times, the compositor must raise the invalid_action
+    /// protocol error.
+    ///
+    /// Initially, no actions are available.
+    pub fn r#set_available_actions(&self, connection: &WaylandConnection, available_actions: &[u8])  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3SetAvailableActions {
+                sendto: self.id,
+                available_actions,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:show_input_panel` request
+    /// Requests an input panel to be shown (e.g. a on-screen keyboard).
+    ///
+    /// This request only hints the desired interaction pattern from the
+    /// client side, and its effect may be ignored by compositors given
+    /// other environmental factors. Repeated calls will be ignored.
+    pub fn r#show_input_panel(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3ShowInputPanel {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_text_input_v3:hide_input_panel` request
+    /// Requests an input panel to be hidden.
+    ///
+    /// This request only hints the desired interaction pattern from the
+    /// client side, and its effect may be ignored by compositors given
+    /// other environmental factors. Repeated calls will be ignored.
+    pub fn r#hide_input_panel(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputV3HideInputPanel {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A factory for text-input objects. This object is a global singleton.
+#[derive(Clone, Copy)]
+pub struct ZwpTextInputManagerV3{
+    pub id: u32,
+}
+
+impl Interface for ZwpTextInputManagerV3 {
+    const NAME: &'static str = "zwp_text_input_manager_v3";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTextInputManagerV3;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTextInputManagerV3 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTextInputManagerV3 {
+
+    /// `zwp_text_input_manager_v3:destroy` request
+    /// Destroy the wp_text_input_manager object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputManagerV3Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_text_input_manager_v3:get_text_input` request
+    /// Creates a new text-input object for a given seat.
+    pub fn r#get_text_input(&self, connection: &WaylandConnection, seat: WlSeat) -> ZwpTextInputV3 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTextInputManagerV3GetTextInput {
+                sendto: self.id,
+                seat: seat.id,
+            }
+        );
+        return ZwpTextInputV3{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// The primary selection device manager is a singleton global object that
+/// provides access to the primary selection. It allows to create
+/// wp_primary_selection_source objects, as well as retrieving the per-seat
+/// wp_primary_selection_device objects.
+#[derive(Clone, Copy)]
+pub struct ZwpPrimarySelectionDeviceManagerV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpPrimarySelectionDeviceManagerV1 {
+    const NAME: &'static str = "zwp_primary_selection_device_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpPrimarySelectionDeviceManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpPrimarySelectionDeviceManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpPrimarySelectionDeviceManagerV1 {
+
+    /// `zwp_primary_selection_device_manager_v1:create_source` request
+    /// Create a new primary selection source.
+    pub fn r#create_source(&self, connection: &WaylandConnection) -> ZwpPrimarySelectionSourceV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPrimarySelectionDeviceManagerV1CreateSource {
+                sendto: self.id,
+            }
+        );
+        return ZwpPrimarySelectionSourceV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `zwp_primary_selection_device_manager_v1:get_device` request
+    /// Create a new data device for a given seat.
+    pub fn r#get_device(&self, connection: &WaylandConnection, seat: WlSeat) -> ZwpPrimarySelectionDeviceV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPrimarySelectionDeviceManagerV1GetDevice {
+                sendto: self.id,
+                seat: seat.id,
+            }
+        );
+        return ZwpPrimarySelectionDeviceV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `zwp_primary_selection_device_manager_v1:destroy` request
+    /// Destroy the primary selection device manager.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPrimarySelectionDeviceManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+#[derive(Clone, Copy)]
+pub struct ZwpPrimarySelectionDeviceV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpPrimarySelectionDeviceV1 {
+    const NAME: &'static str = "zwp_primary_selection_device_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpPrimarySelectionDeviceV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpPrimarySelectionDeviceV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpPrimarySelectionDeviceV1 {
+
+    /// `zwp_primary_selection_device_v1:set_selection` request
+    /// Replaces the current selection. The previous owner of the primary
+    /// selection will receive a wp_primary_selection_source.cancelled event.
+    ///
+    /// To unset the selection, set the source to NULL.
+    pub fn r#set_selection(&self, connection: &WaylandConnection, source: Option<ZwpPrimarySelectionSourceV1>, serial: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPrimarySelectionDeviceV1SetSelection {
+                sendto: self.id,
+                source: source.map(|o| o.id),
+                serial,
+            }
+        );
+    }
+
+    /// `zwp_primary_selection_device_v1:destroy` request
+    /// Destroy the primary selection device.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPrimarySelectionDeviceV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A wp_primary_selection_offer represents an offer to transfer the contents
+/// of the primary selection clipboard to the client. Similar to
+/// wl_data_offer, the offer also describes the mime types that the data can
+/// be converted to and provides the mechanisms for transferring the data
+/// directly to the client.
+#[derive(Clone, Copy)]
+pub struct ZwpPrimarySelectionOfferV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpPrimarySelectionOfferV1 {
+    const NAME: &'static str = "zwp_primary_selection_offer_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpPrimarySelectionOfferV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpPrimarySelectionOfferV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpPrimarySelectionOfferV1 {
+
+    /// `zwp_primary_selection_offer_v1:receive` request
+    /// To transfer the contents of the primary selection clipboard, the client
+    /// issues this request and indicates the mime type that it wants to
+    /// receive. The transfer happens through the passed file descriptor
+    /// (typically created with the pipe system call). The source client writes
+    /// the data in the mime type representation requested and then closes the
+    /// file descriptor.
+    ///
+    /// The receiving client reads from the read end of the pipe until EOF and
+    /// closes its end, at which point the transfer is complete.
+    pub fn r#receive(&self, connection: &WaylandConnection, mime_type: &str, fd: RawFd)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPrimarySelectionOfferV1Receive {
+                sendto: self.id,
+                mime_type,
+                fd,
+            }
+        );
+    }
+
+    /// `zwp_primary_selection_offer_v1:destroy` request
+    /// Destroy the primary selection offer.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPrimarySelectionOfferV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// The source side of a wp_primary_selection_offer, it provides a way to
+/// describe the offered data and respond to requests to transfer the
+/// requested contents of the primary selection clipboard.
+#[derive(Clone, Copy)]
+pub struct ZwpPrimarySelectionSourceV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpPrimarySelectionSourceV1 {
+    const NAME: &'static str = "zwp_primary_selection_source_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpPrimarySelectionSourceV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpPrimarySelectionSourceV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpPrimarySelectionSourceV1 {
+
+    /// `zwp_primary_selection_source_v1:offer` request
+    /// This request adds a mime type to the set of mime types advertised to
+    /// targets. Can be called several times to offer multiple types.
+    pub fn r#offer(&self, connection: &WaylandConnection, mime_type: &str)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPrimarySelectionSourceV1Offer {
+                sendto: self.id,
+                mime_type,
+            }
+        );
+    }
+
+    /// `zwp_primary_selection_source_v1:destroy` request
+    /// Destroy the primary selection source.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPrimarySelectionSourceV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// The global interface exposing pointer constraining functionality. It
+/// exposes two requests: lock_pointer for locking the pointer to its
+/// position, and confine_pointer for locking the pointer to a region.
+///
+/// The lock_pointer and confine_pointer requests create the objects
+/// wp_locked_pointer and wp_confined_pointer respectively, and the client can
+/// use these objects to interact with the lock.
+///
+/// For any surface, only one lock or confinement may be active across all
+/// wl_pointer objects of the same seat. If a lock or confinement is requested
+/// when another lock or confinement is active or requested on the same surface
+/// and with any of the wl_pointer objects of the same seat, an
+/// 'already_constrained' error will be raised.
+#[derive(Clone, Copy)]
+pub struct ZwpPointerConstraintsV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpPointerConstraintsV1 {
+    const NAME: &'static str = "zwp_pointer_constraints_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpPointerConstraintsV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpPointerConstraintsV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpPointerConstraintsV1 {
+
+    /// `zwp_pointer_constraints_v1:destroy` request
+    /// Used by the client to notify the server that it will no longer use this
+    /// pointer constraints object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerConstraintsV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_pointer_constraints_v1:lock_pointer` request
+    /// The lock_pointer request lets the client request to disable movements of
+    /// the virtual pointer (i.e. the cursor), effectively locking the pointer
+    /// to a position. This request may not take effect immediately; in the
+    /// future, when the compositor deems implementation-specific constraints
+    /// are satisfied, the pointer lock will be activated and the compositor
+    /// sends a locked event.
+    ///
+    /// The protocol provides no guarantee that the constraints are ever
+    /// satisfied, and does not require the compositor to send an error if the
+    /// constraints cannot ever be satisfied. It is thus possible to request a
+    /// lock that will never activate.
+    ///
+    /// There may not be another pointer constraint of any kind requested or
+    /// active on the surface for any of the wl_pointer objects of the seat of
+    /// the passed pointer when requesting a lock. If there is, an error will be
+    /// raised. See general pointer lock documentation for more details.
+    ///
+    /// The intersection of the region passed with this request and the input
+    /// region of the surface is used to determine where the pointer must be
+    /// in order for the lock to activate. It is up to the compositor whether to
+    /// warp the pointer or require some kind of user interaction for the lock
+    /// to activate. If the region is null the surface input region is used.
+    ///
+    /// A surface may receive pointer focus without the lock being activated.
+    ///
+    /// The request creates a new object wp_locked_pointer which is used to
+    /// interact with the lock as well as receive updates about its state. See
+    /// the the description of wp_locked_pointer for further information.
+    ///
+    /// Note that while a pointer is locked, the wl_pointer objects of the
+    /// corresponding seat will not emit any wl_pointer.motion events, but
+    /// relative motion events will still be emitted via wp_relative_pointer
+    /// objects of the same seat. wl_pointer.axis and wl_pointer.button events
+    /// are unaffected.
+    pub fn r#lock_pointer(&self, connection: &WaylandConnection, surface: WlSurface, pointer: WlPointer, region: Option<WlRegion>, lifetime: u32) -> ZwpLockedPointerV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerConstraintsV1LockPointer {
+                sendto: self.id,
+                surface: surface.id,
+                pointer: pointer.id,
+                region: region.map(|o| o.id),
+                lifetime,
+            }
+        );
+        return ZwpLockedPointerV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `zwp_pointer_constraints_v1:confine_pointer` request
+    /// The confine_pointer request lets the client request to confine the
+    /// pointer cursor to a given region. This request may not take effect
+    /// immediately; in the future, when the compositor deems implementation-
+    /// specific constraints are satisfied, the pointer confinement will be
+    /// activated and the compositor sends a confined event.
+    ///
+    /// The intersection of the region passed with this request and the input
+    /// region of the surface is used to determine where the pointer must be
+    /// in order for the confinement to activate. It is up to the compositor
+    /// whether to warp the pointer or require some kind of user interaction for
+    /// the confinement to activate. If the region is null the surface input
+    /// region is used.
+    ///
+    /// The request will create a new object wp_confined_pointer which is used
+    /// to interact with the confinement as well as receive updates about its
+    /// state. See the the description of wp_confined_pointer for further
+    /// information.
+    pub fn r#confine_pointer(&self, connection: &WaylandConnection, surface: WlSurface, pointer: WlPointer, region: Option<WlRegion>, lifetime: u32) -> ZwpConfinedPointerV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerConstraintsV1ConfinePointer {
+                sendto: self.id,
+                surface: surface.id,
+                pointer: pointer.id,
+                region: region.map(|o| o.id),
+                lifetime,
+            }
+        );
+        return ZwpConfinedPointerV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// The wp_locked_pointer interface represents a locked pointer state.
+///
+/// While the lock of this object is active, the wl_pointer objects of the
+/// associated seat will not emit any wl_pointer.motion events.
+///
+/// This object will send the event 'locked' when the lock is activated.
+/// Whenever the lock is activated, it is guaranteed that the locked surface
+/// will already have received pointer focus and that the pointer will be
+/// within the region passed to the request creating this object.
+///
+/// To unlock the pointer, send the destroy request. This will also destroy
+/// the wp_locked_pointer object.
+///
+/// If the compositor decides to unlock the pointer the unlocked event is
+/// sent. See wp_locked_pointer.unlock for details.
+///
+/// When unlocking, the compositor may warp the cursor position to the set
+/// cursor position hint. If it does, it will not result in any relative
+/// motion events emitted via wp_relative_pointer.
+///
+/// If the surface the lock was requested on is destroyed and the lock is not
+/// yet activated, the wp_locked_pointer object is now defunct and must be
+/// destroyed.
+#[derive(Clone, Copy)]
+pub struct ZwpLockedPointerV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpLockedPointerV1 {
+    const NAME: &'static str = "zwp_locked_pointer_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpLockedPointerV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpLockedPointerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpLockedPointerV1 {
+
+    /// `zwp_locked_pointer_v1:destroy` request
+    /// Destroy the locked pointer object. If applicable, the compositor will
+    /// unlock the pointer.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpLockedPointerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_locked_pointer_v1:set_cursor_position_hint` request
+    /// Set the cursor position hint relative to the top left corner of the
+    /// surface.
+    ///
+    /// If the client is drawing its own cursor, it should update the position
+    /// hint to the position of its own cursor. A compositor may use this
+    /// information to warp the pointer upon unlock in order to avoid pointer
+    /// jumps.
+    ///
+    /// The cursor position hint is double-buffered state, see
+    /// wl_surface.commit.
+    pub fn r#set_cursor_position_hint(&self, connection: &WaylandConnection, surface_x: Fixed, surface_y: Fixed)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpLockedPointerV1SetCursorPositionHint {
+                sendto: self.id,
+                surface_x,
+                surface_y,
+            }
+        );
+    }
+
+    /// `zwp_locked_pointer_v1:set_region` request
+    /// Set a new region used to lock the pointer.
+    ///
+    /// The new lock region is double-buffered, see wl_surface.commit.
+    ///
+    /// For details about the lock region, see wp_locked_pointer.
+    pub fn r#set_region(&self, connection: &WaylandConnection, region: Option<WlRegion>)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpLockedPointerV1SetRegion {
+                sendto: self.id,
+                region: region.map(|o| o.id),
+            }
+        );
+    }
+}
+
+
+/// The wp_confined_pointer interface represents a confined pointer state.
+///
+/// This object will send the event 'confined' when the confinement is
+/// activated. Whenever the confinement is activated, it is guaranteed that
+/// the surface the pointer is confined to will already have received pointer
+/// focus and that the pointer will be within the region passed to the request
+/// creating this object. It is up to the compositor to decide whether this
+/// requires some user interaction and if the pointer will warp to within the
+/// passed region if outside.
+///
+/// To unconfine the pointer, send the destroy request. This will also destroy
+/// the wp_confined_pointer object.
+///
+/// If the compositor decides to unconfine the pointer the unconfined event is
+/// sent. The wp_confined_pointer object is at this point defunct and should
+/// be destroyed.
+#[derive(Clone, Copy)]
+pub struct ZwpConfinedPointerV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpConfinedPointerV1 {
+    const NAME: &'static str = "zwp_confined_pointer_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpConfinedPointerV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpConfinedPointerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpConfinedPointerV1 {
+
+    /// `zwp_confined_pointer_v1:destroy` request
+    /// Destroy the confined pointer object. If applicable, the compositor will
+    /// unconfine the pointer.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpConfinedPointerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_confined_pointer_v1:set_region` request
+    /// Set a new region used to confine the pointer.
+    ///
+    /// The new confine region is double-buffered, see wl_surface.commit.
+    ///
+    /// If the confinement is active when the new confinement region is applied
+    /// and the pointer ends up outside of newly applied region, the pointer may
+    /// warped to a position within the new confinement region. If warped, a
+    /// wl_pointer.motion event will be emitted, but no
+    /// wp_relative_pointer.relative_motion event.
+    ///
+    /// The compositor may also, instead of using the new region, unconfine the
+    /// pointer.
+    ///
+    /// For details about the confine region, see wp_confined_pointer.
+    pub fn r#set_region(&self, connection: &WaylandConnection, region: Option<WlRegion>)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpConfinedPointerV1SetRegion {
+                sendto: self.id,
+                region: region.map(|o| o.id),
+            }
+        );
+    }
+}
+
+
+/// A global interface used for getting the relative pointer object for a
+/// given pointer.
+#[derive(Clone, Copy)]
+pub struct ZwpRelativePointerManagerV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpRelativePointerManagerV1 {
+    const NAME: &'static str = "zwp_relative_pointer_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpRelativePointerManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpRelativePointerManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpRelativePointerManagerV1 {
+
+    /// `zwp_relative_pointer_manager_v1:destroy` request
+    /// Used by the client to notify the server that it will no longer use this
+    /// relative pointer manager object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpRelativePointerManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_relative_pointer_manager_v1:get_relative_pointer` request
+    /// Create a relative pointer interface given a wl_pointer object. See the
+    /// wp_relative_pointer interface for more details.
+    pub fn r#get_relative_pointer(&self, connection: &WaylandConnection, pointer: WlPointer) -> ZwpRelativePointerV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpRelativePointerManagerV1GetRelativePointer {
+                sendto: self.id,
+                pointer: pointer.id,
+            }
+        );
+        return ZwpRelativePointerV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// A wp_relative_pointer object is an extension to the wl_pointer interface
+/// used for emitting relative pointer events. It shares the same focus as
+/// wl_pointer objects of the same seat and will only emit events when it has
+/// focus.
+#[derive(Clone, Copy)]
+pub struct ZwpRelativePointerV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpRelativePointerV1 {
+    const NAME: &'static str = "zwp_relative_pointer_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpRelativePointerV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpRelativePointerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpRelativePointerV1 {
+
+    /// `zwp_relative_pointer_v1:destroy` request
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpRelativePointerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// This interface permits inhibiting the idle behavior such as screen
+/// blanking, locking, and screensaving.  The client binds the idle manager
+/// globally, then creates idle-inhibitor objects for each surface.
+///
+/// Warning! The protocol described in this file is experimental and
+/// backward incompatible changes may be made. Backward compatible changes
+/// may be added together with the corresponding interface version bump.
+/// Backward incompatible changes are done by bumping the version number in
+/// the protocol and interface names and resetting the interface version.
+/// Once the protocol is to be declared stable, the 'z' prefix and the
+/// version number in the protocol and interface names are removed and the
+/// interface version number is reset.
+#[derive(Clone, Copy)]
+pub struct ZwpIdleInhibitManagerV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpIdleInhibitManagerV1 {
+    const NAME: &'static str = "zwp_idle_inhibit_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpIdleInhibitManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpIdleInhibitManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpIdleInhibitManagerV1 {
+
+    /// `zwp_idle_inhibit_manager_v1:destroy` request
+    /// Destroy the inhibit manager.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpIdleInhibitManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_idle_inhibit_manager_v1:create_inhibitor` request
+    /// Create a new inhibitor object associated with the given surface.
+    pub fn r#create_inhibitor(&self, connection: &WaylandConnection, surface: WlSurface) -> ZwpIdleInhibitorV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpIdleInhibitManagerV1CreateInhibitor {
+                sendto: self.id,
+                surface: surface.id,
+            }
+        );
+        return ZwpIdleInhibitorV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// An idle inhibitor prevents the output that the associated surface is
+/// visible on from being set to a state where it is not visually usable due
+/// to lack of user interaction (e.g. blanked, dimmed, locked, set to power
+/// save, etc.)  Any screensaver processes are also blocked from displaying.
+///
+/// If the surface is destroyed, unmapped, becomes occluded, loses
+/// visibility, or otherwise becomes not visually relevant for the user, the
+/// idle inhibitor will not be honored by the compositor; if the surface
+/// subsequently regains visibility the inhibitor takes effect once again.
+/// Likewise, the inhibitor isn't honored if the system was already idled at
+/// the time the inhibitor was established, although if the system later
+/// de-idles and re-idles the inhibitor will take effect.
+#[derive(Clone, Copy)]
+pub struct ZwpIdleInhibitorV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpIdleInhibitorV1 {
+    const NAME: &'static str = "zwp_idle_inhibitor_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ZwpIdleInhibitorV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpIdleInhibitorV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpIdleInhibitorV1 {
+
+    /// `zwp_idle_inhibitor_v1:destroy` request
+    /// Remove the inhibitor effect from the associated wl_surface.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpIdleInhibitorV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// This interface allows clients to monitor user idle status.
+///
+/// After binding to this global, clients can create ext_idle_notification_v1
+/// objects to get notified when the user is idle for a given amount of time.
+#[derive(Clone, Copy)]
+pub struct ExtIdleNotifierV1{
+    pub id: u32,
+}
+
+impl Interface for ExtIdleNotifierV1 {
+    const NAME: &'static str = "ext_idle_notifier_v1";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ExtIdleNotifierV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtIdleNotifierV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtIdleNotifierV1 {
+
+    /// `ext_idle_notifier_v1:destroy` request
+    /// Destroy the manager object. All objects created via this interface
+    /// remain valid.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtIdleNotifierV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `ext_idle_notifier_v1:get_idle_notification` request
+    /// Create a new idle notification object.
+    ///
+    /// The notification object has a minimum timeout duration and is tied to a
+    /// seat. The client will be notified if the seat is inactive for at least
+    /// the provided timeout. See ext_idle_notification_v1 for more details.
+    ///
+    /// A zero timeout is valid and means the client wants to be notified as
+    /// soon as possible when the seat is inactive.
+    pub fn r#get_idle_notification(&self, connection: &WaylandConnection, timeout: u32, seat: WlSeat) -> ExtIdleNotificationV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtIdleNotifierV1GetIdleNotification {
+                sendto: self.id,
+                timeout,
+                seat: seat.id,
+            }
+        );
+        return ExtIdleNotificationV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `ext_idle_notifier_v1:get_input_idle_notification` request
+    /// Create a new idle notification object to track input from the
+    /// user, such as keyboard and mouse movement. Because this object is
+    /// meant to track user input alone, it ignores idle inhibitors.
+    ///
+    /// The notification object has a minimum timeout duration and is tied to a
+    /// seat. The client will be notified if the seat is inactive for at least
+    /// the provided timeout. See ext_idle_notification_v1 for more details.
+    ///
+    /// A zero timeout is valid and means the client wants to be notified as
+    /// soon as possible when the seat is inactive.
+    pub fn r#get_input_idle_notification(&self, connection: &WaylandConnection, timeout: u32, seat: WlSeat) -> ExtIdleNotificationV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtIdleNotifierV1GetInputIdleNotification {
+                sendto: self.id,
+                timeout,
+                seat: seat.id,
+            }
+        );
+        return ExtIdleNotificationV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// This interface is used by the compositor to send idle notification events
+/// to clients.
+///
+/// Initially the notification object is not idle. The notification object
+/// becomes idle when no user activity has happened for at least the timeout
+/// duration, starting from the creation of the notification object. User
+/// activity may include input events or a presence sensor, but is
+/// compositor-specific.
+///
+/// How this notification responds to idle inhibitors depends on how
+/// it was constructed. If constructed from the
+/// get_idle_notification request, then if an idle inhibitor is
+/// active (e.g. another client has created a zwp_idle_inhibitor_v1
+/// on a visible surface), the compositor must not make the
+/// notification object idle. However, if constructed from the
+/// get_input_idle_notification request, then idle inhibitors are
+/// ignored, and only input from the user, e.g. from a keyboard or
+/// mouse, counts as activity.
+///
+/// When the notification object becomes idle, an idled event is sent. When
+/// user activity starts again, the notification object stops being idle,
+/// a resumed event is sent and the timeout is restarted.
+#[derive(Clone, Copy)]
+pub struct ExtIdleNotificationV1{
+    pub id: u32,
+}
+
+impl Interface for ExtIdleNotificationV1 {
+    const NAME: &'static str = "ext_idle_notification_v1";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ExtIdleNotificationV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtIdleNotificationV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtIdleNotificationV1 {
+
+    /// `ext_idle_notification_v1:destroy` request
+    /// Destroy the notification object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtIdleNotificationV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// Clients can use this interface to assign the surface_layer role to
+/// wl_surfaces. Such surfaces are assigned to a "layer" of the output and
+/// rendered with a defined z-depth respective to each other. They may also be
+/// anchored to the edges and corners of a screen and specify input handling
+/// semantics. This interface should be suitable for the implementation of
+/// many desktop shell components, and a broad number of other applications
+/// that interact with the desktop.
+#[derive(Clone, Copy)]
+pub struct ZwlrLayerShellV1{
+    pub id: u32,
+}
+
+impl Interface for ZwlrLayerShellV1 {
+    const NAME: &'static str = "zwlr_layer_shell_v1";
+    const VERSION: u32 = 5;
+    const OBJECT: Object = Object::ZwlrLayerShellV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwlrLayerShellV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwlrLayerShellV1 {
+
+    /// `zwlr_layer_shell_v1:get_layer_surface` request
+    /// Create a layer surface for an existing surface. This assigns the role of
+    /// layer_surface, or raises a protocol error if another role is already
+    /// assigned.
+    ///
+    /// Creating a layer surface from a wl_surface which has a buffer attached
+    /// or committed is a client error, and any attempts by a client to attach
+    /// or manipulate a buffer prior to the first layer_surface.configure call
+    /// must also be treated as errors.
+    ///
+    /// After creating a layer_surface object and setting it up, the client
+    /// must perform an initial commit without any buffer attached.
+    /// The compositor will reply with a layer_surface.configure event.
+    /// The client must acknowledge it and is then allowed to attach a buffer
+    /// to map the surface.
+    ///
+    /// You may pass NULL for output to allow the compositor to decide which
+    /// output to use. Generally this will be the one that the user most
+    /// recently interacted with.
+    ///
+    /// Clients can specify a namespace that defines the purpose of the layer
+    /// surface.
+    pub fn r#get_layer_surface(&self, connection: &WaylandConnection, surface: WlSurface, output: Option<WlOutput>, layer: u32, namespace: &str) -> ZwlrLayerSurfaceV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerShellV1GetLayerSurface {
+                sendto: self.id,
+                surface: surface.id,
+                output: output.map(|o| o.id),
+                layer,
+                namespace,
+            }
+        );
+        return ZwlrLayerSurfaceV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `zwlr_layer_shell_v1:destroy` request
+    /// This request indicates that the client will not use the layer_shell
+    /// object any more. Objects that have been created through this instance
+    /// are not affected.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerShellV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// An interface that may be implemented by a wl_surface, for surfaces that
+/// are designed to be rendered as a layer of a stacked desktop-like
+/// environment.
+///
+/// Layer surface state (layer, size, anchor, exclusive zone,
+/// margin, interactivity) is double-buffered, and will be applied at the
+/// time wl_surface.commit of the corresponding wl_surface is called.
+///
+/// Attaching a null buffer to a layer surface unmaps it.
+///
+/// Unmapping a layer_surface means that the surface cannot be shown by the
+/// compositor until it is explicitly mapped again. The layer_surface
+/// returns to the state it had right after layer_shell.get_layer_surface.
+/// The client can re-map the surface by performing a commit without any
+/// buffer attached, waiting for a configure event and handling it as usual.
+#[derive(Clone, Copy)]
+pub struct ZwlrLayerSurfaceV1{
+    pub id: u32,
+}
+
+impl Interface for ZwlrLayerSurfaceV1 {
+    const NAME: &'static str = "zwlr_layer_surface_v1";
+    const VERSION: u32 = 5;
+    const OBJECT: Object = Object::ZwlrLayerSurfaceV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwlrLayerSurfaceV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwlrLayerSurfaceV1 {
+
+    /// `zwlr_layer_surface_v1:set_size` request
+    /// Sets the size of the surface in surface-local coordinates. The
+    /// compositor will display the surface centered with respect to its
+    /// anchors.
+    ///
+    /// If you pass 0 for either value, the compositor will assign it and
+    /// inform you of the assignment in the configure event. You must set your
+    /// anchor to opposite edges in the dimensions you omit; not doing so is a
+    /// protocol error. Both values are 0 by default.
+    ///
+    /// Size is double-buffered, see wl_surface.commit.
+    pub fn r#set_size(&self, connection: &WaylandConnection, width: u32, height: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1SetSize {
+                sendto: self.id,
+                width,
+                height,
+            }
+        );
+    }
+
+    /// `zwlr_layer_surface_v1:set_anchor` request
+    /// Requests that the compositor anchor the surface to the specified edges
+    /// and corners. If two orthogonal edges are specified (e.g. 'top' and
+    /// 'left'), then the anchor point will be the intersection of the edges
+    /// (e.g. the top left corner of the output); otherwise the anchor point
+    /// will be centered on that edge, or in the center if none is specified.
+    ///
+    /// Anchor is double-buffered, see wl_surface.commit.
+    pub fn r#set_anchor(&self, connection: &WaylandConnection, anchor: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1SetAnchor {
+                sendto: self.id,
+                anchor,
+            }
+        );
+    }
+
+    /// `zwlr_layer_surface_v1:set_exclusive_zone` request
+    /// Requests that the compositor avoids occluding an area with other
+    /// surfaces. The compositor's use of this information is
+    /// implementation-dependent - do not assume that this region will not
+    /// actually be occluded.
+    ///
+    /// A positive value is only meaningful if the surface is anchored to one
+    /// edge or an edge and both perpendicular edges. If the surface is not
+    /// anchored, anchored to only two perpendicular edges (a corner), anchored
+    /// to only two parallel edges or anchored to all edges, a positive value
+    /// will be treated the same as zero.
+    ///
+    /// A positive zone is the distance from the edge in surface-local
+    /// coordinates to consider exclusive.
+    ///
+    /// Surfaces that do not wish to have an exclusive zone may instead specify
+    /// how they should interact with surfaces that do. If set to zero, the
+    /// surface indicates that it would like to be moved to avoid occluding
+    /// surfaces with a positive exclusive zone. If set to -1, the surface
+    /// indicates that it would not like to be moved to accommodate for other
+    /// surfaces, and the compositor should extend it all the way to the edges
+    /// it is anchored to.
+    ///
+    /// For example, a panel might set its exclusive zone to 10, so that
+    /// maximized shell surfaces are not shown on top of it. A notification
+    /// might set its exclusive zone to 0, so that it is moved to avoid
+    /// occluding the panel, but shell surfaces are shown underneath it. A
+    /// wallpaper or lock screen might set their exclusive zone to -1, so that
+    /// they stretch below or over the panel.
+    ///
+    /// The default value is 0.
+    ///
+    /// Exclusive zone is double-buffered, see wl_surface.commit.
+    pub fn r#set_exclusive_zone(&self, connection: &WaylandConnection, zone: i32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1SetExclusiveZone {
+                sendto: self.id,
+                zone,
+            }
+        );
+    }
+
+    /// `zwlr_layer_surface_v1:set_margin` request
+    /// Requests that the surface be placed some distance away from the anchor
+    /// point on the output, in surface-local coordinates. Setting this value
+    /// for edges you are not anchored to has no effect.
+    ///
+    /// The exclusive zone includes the margin.
+    ///
+    /// Margin is double-buffered, see wl_surface.commit.
+    pub fn r#set_margin(&self, connection: &WaylandConnection, top: i32, right: i32, bottom: i32, left: i32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1SetMargin {
+                sendto: self.id,
+                top,
+                right,
+                bottom,
+                left,
+            }
+        );
+    }
+
+    /// `zwlr_layer_surface_v1:set_keyboard_interactivity` request
+    /// Set how keyboard events are delivered to this surface. By default,
+    /// layer shell surfaces do not receive keyboard events; this request can
+    /// be used to change this.
+    ///
+    /// This setting is inherited by child surfaces set by the get_popup
+    /// request.
+    ///
+    /// Layer surfaces receive pointer, touch, and tablet events normally. If
+    /// you do not want to receive them, set the input region on your surface
+    /// to an empty region.
+    ///
+    /// Keyboard interactivity is double-buffered, see wl_surface.commit.
+    pub fn r#set_keyboard_interactivity(&self, connection: &WaylandConnection, keyboard_interactivity: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1SetKeyboardInteractivity {
+                sendto: self.id,
+                keyboard_interactivity,
+            }
+        );
+    }
+
+    /// `zwlr_layer_surface_v1:get_popup` request
+    /// This assigns an xdg_popup's parent to this layer_surface.  This popup
+    /// should have been created via xdg_surface::get_popup with the parent set
+    /// to NULL, and this request must be invoked before committing the popup's
+    /// initial state.
+    ///
+    /// See the documentation of xdg_popup for more details about what an
+    /// xdg_popup is and how it is used.
+    pub fn r#get_popup(&self, connection: &WaylandConnection, popup: XdgPopup)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1GetPopup {
+                sendto: self.id,
+                popup: popup.id,
+            }
+        );
+    }
+
+    /// `zwlr_layer_surface_v1:ack_configure` request
+    /// When a configure event is received, if a client commits the
+    /// surface in response to the configure event, then the client
+    /// must make an ack_configure request sometime before the commit
+    /// request, passing along the serial of the configure event.
+    ///
+    /// If the client receives multiple configure events before it
+    /// can respond to one, it only has to ack the last configure event.
+    ///
+    /// A client is not required to commit immediately after sending
+    /// an ack_configure request - it may even ack_configure several times
+    /// before its next surface commit.
+    ///
+    /// A client may send multiple ack_configure requests before committing, but
+    /// only the last request sent before a commit indicates which configure
+    /// event the client really is responding to.
+    pub fn r#ack_configure(&self, connection: &WaylandConnection, serial: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1AckConfigure {
+                sendto: self.id,
+                serial,
+            }
+        );
+    }
+
+    /// `zwlr_layer_surface_v1:destroy` request
+    /// This request destroys the layer surface.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwlr_layer_surface_v1:set_layer` request
+    /// Change the layer that the surface is rendered on.
+    ///
+    /// Layer is double-buffered, see wl_surface.commit.
+    pub fn r#set_layer(&self, connection: &WaylandConnection, layer: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1SetLayer {
+                sendto: self.id,
+                layer,
+            }
+        );
+    }
+
+    /// `zwlr_layer_surface_v1:set_exclusive_edge` request
+    /// Requests an edge for the exclusive zone to apply. The exclusive
+    /// edge will be automatically deduced from anchor points when possible,
+    /// but when the surface is anchored to a corner, it will be necessary
+    /// to set it explicitly to disambiguate, as it is not possible to deduce
+    /// which one of the two corner edges should be used.
+    ///
+    /// The edge must be one the surface is anchored to, otherwise the
+    /// invalid_exclusive_edge protocol error will be raised.
+    pub fn r#set_exclusive_edge(&self, connection: &WaylandConnection, edge: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrLayerSurfaceV1SetExclusiveEdge {
+                sendto: self.id,
+                edge,
+            }
+        );
+    }
+}
+
+
+/// This interface is used to request that the session be locked.
+#[derive(Clone, Copy)]
+pub struct ExtSessionLockManagerV1{
+    pub id: u32,
+}
+
+impl Interface for ExtSessionLockManagerV1 {
+    const NAME: &'static str = "ext_session_lock_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ExtSessionLockManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtSessionLockManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtSessionLockManagerV1 {
+
+    /// `ext_session_lock_manager_v1:destroy` request
+    /// This informs the compositor that the session lock manager object will
+    /// no longer be used. Existing objects created through this interface
+    /// remain valid.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtSessionLockManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `ext_session_lock_manager_v1:lock` request
+    /// This request creates a session lock and asks the compositor to lock the
+    /// session. The compositor will send either the ext_session_lock_v1.locked
+    /// or ext_session_lock_v1.finished event on the created object in
+    /// response to this request.
+    pub fn r#lock(&self, connection: &WaylandConnection) -> ExtSessionLockV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtSessionLockManagerV1Lock {
+                sendto: self.id,
+            }
+        );
+        return ExtSessionLockV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// In response to the creation of this object the compositor must send
+/// either the locked or finished event.
+///
+/// The locked event indicates that the session is locked. This means
+/// that the compositor must stop rendering and providing input to normal
+/// clients. Instead the compositor must blank all outputs with an opaque
+/// color such that their normal content is fully hidden.
+///
+/// The only surfaces that should be rendered while the session is locked
+/// are the lock surfaces created through this interface and optionally,
+/// at the compositor's discretion, special privileged surfaces such as
+/// input methods or portions of desktop shell UIs.
+///
+/// The locked event must not be sent until a new "locked" frame (either
+/// from a session lock surface or the compositor blanking the output) has
+/// been presented on all outputs and no security sensitive normal/unlocked
+/// content is possibly visible.
+///
+/// The finished event should be sent immediately on creation of this
+/// object if the compositor decides that the locked event will not be sent.
+///
+/// The compositor may wait for the client to create and render session lock
+/// surfaces before sending the locked event to avoid displaying intermediate
+/// blank frames. However, it must impose a reasonable time limit if
+/// waiting and send the locked event as soon as the hard requirements
+/// described above can be met if the time limit expires. Clients should
+/// immediately create lock surfaces for all outputs on creation of this
+/// object to make this possible.
+///
+/// This behavior of the locked event is required in order to prevent
+/// possible race conditions with clients that wish to suspend the system
+/// or similar after locking the session. Without these semantics, clients
+/// triggering a suspend after receiving the locked event would race with
+/// the first "locked" frame being presented and normal/unlocked frames
+/// might be briefly visible as the system is resumed if the suspend
+/// operation wins the race.
+///
+/// If the client dies while the session is locked, the compositor must not
+/// unlock the session in response. It is acceptable for the session to be
+/// permanently locked if this happens. The compositor may choose to continue
+/// to display the lock surfaces the client had mapped before it died or
+/// alternatively fall back to a solid color, this is compositor policy.
+///
+/// Compositors may also allow a secure way to recover the session, the
+/// details of this are compositor policy. Compositors may allow a new
+/// client to create a ext_session_lock_v1 object and take responsibility
+/// for unlocking the session, they may even start a new lock client
+/// instance automatically.
+#[derive(Clone, Copy)]
+pub struct ExtSessionLockV1{
+    pub id: u32,
+}
+
+impl Interface for ExtSessionLockV1 {
+    const NAME: &'static str = "ext_session_lock_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ExtSessionLockV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtSessionLockV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtSessionLockV1 {
+
+    /// `ext_session_lock_v1:destroy` request
+    /// This informs the compositor that the lock object will no longer be
+    /// used. Existing objects created through this interface remain valid.
+    ///
+    /// After this request is made, lock surfaces created through this object
+    /// should be destroyed by the client as they will no longer be used by
+    /// the compositor.
+    ///
+    /// It is a protocol error to make this request if the locked event was
+    /// sent, the unlock_and_destroy request must be used instead.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtSessionLockV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `ext_session_lock_v1:get_lock_surface` request
+    /// The client is expected to create lock surfaces for all outputs
+    /// currently present and any new outputs as they are advertised. These
+    /// won't be displayed by the compositor unless the lock is successful
+    /// and the locked event is sent.
+    ///
+    /// Providing a wl_surface which already has a role or already has a buffer
+    /// attached or committed is a protocol error, as is attaching/committing
+    /// a buffer before the first ext_session_lock_surface_v1.configure event.
+    ///
+    /// Attempting to create more than one lock surface for a given output
+    /// is a duplicate_output protocol error.
+    pub fn r#get_lock_surface(&self, connection: &WaylandConnection, surface: WlSurface, output: WlOutput) -> ExtSessionLockSurfaceV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtSessionLockV1GetLockSurface {
+                sendto: self.id,
+                surface: surface.id,
+                output: output.id,
+            }
+        );
+        return ExtSessionLockSurfaceV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `ext_session_lock_v1:unlock_and_destroy` request
+    /// This request indicates that the session should be unlocked, for
+    /// example because the user has entered their password and it has been
+    /// verified by the client.
+    ///
+    /// This request also informs the compositor that the lock object will
+    /// no longer be used and should be destroyed. Existing objects created
+    /// through this interface remain valid.
+    ///
+    /// After this request is made, lock surfaces created through this object
+    /// should be destroyed by the client as they will no longer be used by
+    /// the compositor.
+    ///
+    /// It is a protocol error to make this request if the locked event has
+    /// not been sent. In that case, the lock object must be destroyed using
+    /// the destroy request.
+    ///
+    /// Note that a correct client that wishes to exit directly after unlocking
+    /// the session must use the wl_display.sync request to ensure the server
+    /// receives and processes the unlock_and_destroy request. Otherwise
+    /// there is no guarantee that the server has unlocked the session due
+    /// to the asynchronous nature of the Wayland protocol. For example,
+    /// the server might terminate the client with a protocol error before
+    /// it processes the unlock_and_destroy request.
+    pub fn r#unlock_and_destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtSessionLockV1UnlockAndDestroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// The client may use lock surfaces to display a screensaver, render a
+/// dialog to enter a password and unlock the session, or however else it
+/// sees fit.
+///
+/// On binding this interface the compositor will immediately send the
+/// first configure event. After making the ack_configure request in
+/// response to this event the client should attach and commit the first
+/// buffer. Committing the surface before acking the first configure is a
+/// protocol error. Committing the surface with a null buffer at any time
+/// is a protocol error.
+///
+/// The compositor is free to handle keyboard/pointer focus for lock
+/// surfaces however it chooses. A reasonable way to do this would be to
+/// give the first lock surface created keyboard focus and change keyboard
+/// focus if the user clicks on other surfaces.
+#[derive(Clone, Copy)]
+pub struct ExtSessionLockSurfaceV1{
+    pub id: u32,
+}
+
+impl Interface for ExtSessionLockSurfaceV1 {
+    const NAME: &'static str = "ext_session_lock_surface_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ExtSessionLockSurfaceV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtSessionLockSurfaceV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtSessionLockSurfaceV1 {
+
+    /// `ext_session_lock_surface_v1:destroy` request
+    /// This informs the compositor that the lock surface object will no
+    /// longer be used.
+    ///
+    /// It is recommended for a lock client to destroy lock surfaces if
+    /// their corresponding wl_output global is removed.
+    ///
+    /// If a lock surface on an active output is destroyed before the
+    /// ext_session_lock_v1.unlock_and_destroy event is sent, the compositor
+    /// must fall back to rendering a solid color.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtSessionLockSurfaceV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `ext_session_lock_surface_v1:ack_configure` request
+    /// When a configure event is received, if a client commits the surface
+    /// in response to the configure event, then the client must make an
+    /// ack_configure request sometime before the commit request, passing
+    /// along the serial of the configure event.
+    ///
+    /// If the client receives multiple configure events before it can
+    /// respond to one, it only has to ack the last configure event.
+    ///
+    /// A client is not required to commit immediately after sending an
+    /// ack_configure request - it may even ack_configure several times
+    /// before its next surface commit.
+    ///
+    /// A client may send multiple ack_configure requests before committing,
+    /// but only the last request sent before a commit indicates which
+    /// configure event the client really is responding to.
+    ///
+    /// Sending an ack_configure request consumes the configure event
+    /// referenced by the given serial, as well as all older configure events
+    /// sent on this object.
+    ///
+    /// It is a protocol error to issue multiple ack_configure requests
+    /// referencing the same configure event or to issue an ack_configure
+    /// request referencing a configure event older than the last configure
+    /// event acked for a given lock surface.
+    pub fn r#ack_configure(&self, connection: &WaylandConnection, serial: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtSessionLockSurfaceV1AckConfigure {
+                sendto: self.id,
+                serial,
+            }
+        );
+    }
+}
+
+
+/// The image capture source object is an opaque descriptor for a capturable
+/// resource.  This resource may be any sort of entity from which an image
+/// may be derived.
+///
+/// Note, because ext_image_capture_source_v1 objects are created from multiple
+/// independent factory interfaces, the ext_image_capture_source_v1 interface is
+/// frozen at version 1.
+#[derive(Clone, Copy)]
+pub struct ExtImageCaptureSourceV1{
+    pub id: u32,
+}
+
+impl Interface for ExtImageCaptureSourceV1 {
+    const NAME: &'static str = "ext_image_capture_source_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ExtImageCaptureSourceV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtImageCaptureSourceV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtImageCaptureSourceV1 {
+
+    /// `ext_image_capture_source_v1:destroy` request
+    /// Destroys the image capture source. This request may be sent at any time
+    /// by the client.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCaptureSourceV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A manager for creating image capture source objects for wl_output objects.
+#[derive(Clone, Copy)]
+pub struct ExtOutputImageCaptureSourceManagerV1{
+    pub id: u32,
+}
+
+impl Interface for ExtOutputImageCaptureSourceManagerV1 {
+    const NAME: &'static str = "ext_output_image_capture_source_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ExtOutputImageCaptureSourceManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtOutputImageCaptureSourceManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtOutputImageCaptureSourceManagerV1 {
+
+    /// `ext_output_image_capture_source_manager_v1:create_source` request
+    /// Creates a source object for an output. Images captured from this source
+    /// will show the same content as the output. Some elements may be omitted,
+    /// such as cursors and overlays that have been marked as transparent to
+    /// capturing.
+    pub fn r#create_source(&self, connection: &WaylandConnection, output: WlOutput) -> ExtImageCaptureSourceV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtOutputImageCaptureSourceManagerV1CreateSource {
+                sendto: self.id,
+                output: output.id,
+            }
+        );
+        return ExtImageCaptureSourceV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `ext_output_image_capture_source_manager_v1:destroy` request
+    /// Destroys the manager. This request may be sent at any time by the client
+    /// and objects created by the manager will remain valid after its
+    /// destruction.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtOutputImageCaptureSourceManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// This object is a manager which offers requests to start capturing from a
+/// source.
+#[derive(Clone, Copy)]
+pub struct ExtImageCopyCaptureManagerV1{
+    pub id: u32,
+}
+
+impl Interface for ExtImageCopyCaptureManagerV1 {
+    const NAME: &'static str = "ext_image_copy_capture_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ExtImageCopyCaptureManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtImageCopyCaptureManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtImageCopyCaptureManagerV1 {
+
+    /// `ext_image_copy_capture_manager_v1:create_session` request
+    /// Create a capturing session for an image capture source.
+    ///
+    /// If the paint_cursors option is set, cursors shall be composited onto
+    /// the captured frame. The cursor must not be composited onto the frame
+    /// if this flag is not set.
+    ///
+    /// If the options bitfield is invalid, the invalid_option protocol error
+    /// is sent.
+    pub fn r#create_session(&self, connection: &WaylandConnection, source: ExtImageCaptureSourceV1, options: u32) -> ExtImageCopyCaptureSessionV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureManagerV1CreateSession {
+                sendto: self.id,
+                source: source.id,
+                options,
+            }
+        );
+        return ExtImageCopyCaptureSessionV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `ext_image_copy_capture_manager_v1:create_pointer_cursor_session` request
+    /// Create a cursor capturing session for the pointer of an image capture
+    /// source.
+    pub fn r#create_pointer_cursor_session(&self, connection: &WaylandConnection, source: ExtImageCaptureSourceV1, pointer: WlPointer) -> ExtImageCopyCaptureCursorSessionV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureManagerV1CreatePointerCursorSession {
+                sendto: self.id,
+                source: source.id,
+                pointer: pointer.id,
+            }
+        );
+        return ExtImageCopyCaptureCursorSessionV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `ext_image_copy_capture_manager_v1:destroy` request
+    /// Destroy the manager object.
+    ///
+    /// Other objects created via this interface are unaffected.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// This object represents an active image copy capture session.
+///
+/// After a capture session is created, buffer constraint events will be
+/// emitted from the compositor to tell the client which buffer types and
+/// formats are supported for reading from the session. The compositor may
+/// re-send buffer constraint events whenever they change.
+///
+/// To advertise buffer constraints, the compositor must send in no
+/// particular order: zero or more shm_format and dmabuf_format events, zero
+/// or one dmabuf_device event, and exactly one buffer_size event. Then the
+/// compositor must send a done event.
+///
+/// When the client has received all the buffer constraints, it can create a
+/// buffer accordingly, attach it to the capture session using the
+/// attach_buffer request, set the buffer damage using the damage_buffer
+/// request and then send the capture request.
+#[derive(Clone, Copy)]
+pub struct ExtImageCopyCaptureSessionV1{
+    pub id: u32,
+}
+
+impl Interface for ExtImageCopyCaptureSessionV1 {
+    const NAME: &'static str = "ext_image_copy_capture_session_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ExtImageCopyCaptureSessionV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtImageCopyCaptureSessionV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtImageCopyCaptureSessionV1 {
+
+    /// `ext_image_copy_capture_session_v1:create_frame` request
+    /// Create a capture frame for this session.
+    ///
+    /// At most one frame object can exist for a given session at any time. If
+    /// a client sends a create_frame request before a previous frame object
+    /// has been destroyed, the duplicate_frame protocol error is raised.
+    pub fn r#create_frame(&self, connection: &WaylandConnection) -> ExtImageCopyCaptureFrameV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureSessionV1CreateFrame {
+                sendto: self.id,
+            }
+        );
+        return ExtImageCopyCaptureFrameV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `ext_image_copy_capture_session_v1:destroy` request
+    /// Destroys the session. This request can be sent at any time by the
+    /// client.
+    ///
+    /// This request doesn't affect ext_image_copy_capture_frame_v1 objects created by
+    /// this object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureSessionV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// This object represents an image capture frame.
+///
+/// The client should attach a buffer, damage the buffer, and then send a
+/// capture request.
+///
+/// If the capture is successful, the compositor must send the frame metadata
+/// (transform, damage, presentation_time in any order) followed by the ready
+/// event.
+///
+/// If the capture fails, the compositor must send the failed event.
+#[derive(Clone, Copy)]
+pub struct ExtImageCopyCaptureFrameV1{
+    pub id: u32,
+}
+
+impl Interface for ExtImageCopyCaptureFrameV1 {
+    const NAME: &'static str = "ext_image_copy_capture_frame_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ExtImageCopyCaptureFrameV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtImageCopyCaptureFrameV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtImageCopyCaptureFrameV1 {
+
+    /// `ext_image_copy_capture_frame_v1:destroy` request
+    /// Destroys the frame. This request can be sent at any time by the
+    /// client.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureFrameV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `ext_image_copy_capture_frame_v1:attach_buffer` request
+    /// Attach a buffer to the session.
+    ///
+    /// The wl_buffer.release request is unused.
+    ///
+    /// The new buffer replaces any previously attached buffer.
+    ///
+    /// This request must not be sent after capture, or else the
+    /// already_captured protocol error is raised.
+    pub fn r#attach_buffer(&self, connection: &WaylandConnection, buffer: WlBuffer)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureFrameV1AttachBuffer {
+                sendto: self.id,
+                buffer: buffer.id,
+            }
+        );
+    }
+
+    /// `ext_image_copy_capture_frame_v1:damage_buffer` request
+    /// Apply damage to the buffer which is to be captured next. This request
+    /// may be sent multiple times to describe a region.
+    ///
+    /// The client indicates the accumulated damage since this wl_buffer was
+    /// last captured. During capture, the compositor will update the buffer
+    /// with at least the union of the region passed by the client and the
+    /// region advertised by ext_image_copy_capture_frame_v1.damage.
+    ///
+    /// When a wl_buffer is captured for the first time, or when the client
+    /// doesn't track damage, the client must damage the whole buffer.
+    ///
+    /// This is for optimisation purposes. The compositor may use this
+    /// information to reduce copying.
+    ///
+    /// These coordinates originate from the upper left corner of the buffer.
+    ///
+    /// If x or y are strictly negative, or if width or height are negative or
+    /// zero, the invalid_buffer_damage protocol error is raised.
+    ///
+    /// This request must not be sent after capture, or else the
+    /// already_captured protocol error is raised.
+    pub fn r#damage_buffer(&self, connection: &WaylandConnection, x: i32, y: i32, width: i32, height: i32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureFrameV1DamageBuffer {
+                sendto: self.id,
+                x,
+                y,
+                width,
+                height,
+            }
+        );
+    }
+
+    /// `ext_image_copy_capture_frame_v1:capture` request
+    /// Capture a frame.
+    ///
+    /// Unless this is the first successful captured frame performed in this
+    /// session, the compositor may wait an indefinite amount of time for the
+    /// source content to change before performing the copy.
+    ///
+    /// This request may only be sent once, or else the already_captured
+    /// protocol error is raised. A buffer must be attached before this request
+    /// is sent, or else the no_buffer protocol error is raised.
+    pub fn r#capture(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureFrameV1Capture {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// This object represents a cursor capture session. It extends the base
+/// capture session with cursor-specific metadata.
+#[derive(Clone, Copy)]
+pub struct ExtImageCopyCaptureCursorSessionV1{
+    pub id: u32,
+}
+
+impl Interface for ExtImageCopyCaptureCursorSessionV1 {
+    const NAME: &'static str = "ext_image_copy_capture_cursor_session_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::ExtImageCopyCaptureCursorSessionV1;
+
+    fn from_id(id: u32) -> Self {
+        ExtImageCopyCaptureCursorSessionV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ExtImageCopyCaptureCursorSessionV1 {
+
+    /// `ext_image_copy_capture_cursor_session_v1:destroy` request
+    /// Destroys the session. This request can be sent at any time by the
+    /// client.
+    ///
+    /// This request doesn't affect ext_image_copy_capture_frame_v1 objects created by
+    /// this object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureCursorSessionV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `ext_image_copy_capture_cursor_session_v1:get_capture_session` request
+    /// Gets the image copy capture session for this cursor session.
+    ///
+    /// The session will produce frames of the cursor image. The compositor may
+    /// pause the session when the cursor leaves the captured area.
+    ///
+    /// This request must not be sent more than once, or else the
+    /// duplicate_session protocol error is raised.
+    pub fn r#get_capture_session(&self, connection: &WaylandConnection) -> ExtImageCopyCaptureSessionV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ExtImageCopyCaptureCursorSessionV1GetCaptureSession {
+                sendto: self.id,
+            }
+        );
+        return ExtImageCopyCaptureSessionV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// This object is a manager which offers requests to start capturing from a
+/// source.
+#[derive(Clone, Copy)]
+pub struct ZwlrScreencopyManagerV1{
+    pub id: u32,
+}
+
+impl Interface for ZwlrScreencopyManagerV1 {
+    const NAME: &'static str = "zwlr_screencopy_manager_v1";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::ZwlrScreencopyManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwlrScreencopyManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwlrScreencopyManagerV1 {
+
+    /// `zwlr_screencopy_manager_v1:capture_output` request
+    /// Capture the next frame of an entire output.
+    pub fn r#capture_output(&self, connection: &WaylandConnection, overlay_cursor: i32, output: WlOutput) -> ZwlrScreencopyFrameV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrScreencopyManagerV1CaptureOutput {
+                sendto: self.id,
+                overlay_cursor,
+                output: output.id,
+            }
+        );
+        return ZwlrScreencopyFrameV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `zwlr_screencopy_manager_v1:capture_output_region` request
+    /// Capture the next frame of an output's region.
+    ///
+    /// The region is given in output logical coordinates, see
+    /// xdg_output.logical_size. The region will be clipped to the output's
+    /// extents.
+    pub fn r#capture_output_region(&self, connection: &WaylandConnection, overlay_cursor: i32, output: WlOutput, x: i32, y: i32, width: i32, height: i32) -> ZwlrScreencopyFrameV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrScreencopyManagerV1CaptureOutputRegion {
+                sendto: self.id,
+                overlay_cursor,
+                output: output.id,
+                x,
+                y,
+                width,
+                height,
+            }
+        );
+        return ZwlrScreencopyFrameV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `zwlr_screencopy_manager_v1:destroy` request
+    /// All objects created by the manager will still remain valid, until their
+    /// appropriate destroy request has been called.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrScreencopyManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// This object represents a single frame.
+///
+/// When created, a series of buffer events will be sent, each representing a
+/// supported buffer type. The "buffer_done" event is sent afterwards to
+/// indicate that all supported buffer types have been enumerated. The client
+/// will then be able to send a "copy" request. If the capture is successful,
+/// the compositor will send a "flags" event followed by a "ready" event.
+///
+/// For objects version 2 or lower, wl_shm buffers are always supported, ie.
+/// the "buffer" event is guaranteed to be sent.
+///
+/// If the capture failed, the "failed" event is sent. This can happen anytime
+/// before the "ready" event.
+///
+/// Once either a "ready" or a "failed" event is received, the client should
+/// destroy the frame.
+#[derive(Clone, Copy)]
+pub struct ZwlrScreencopyFrameV1{
+    pub id: u32,
+}
+
+impl Interface for ZwlrScreencopyFrameV1 {
+    const NAME: &'static str = "zwlr_screencopy_frame_v1";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::ZwlrScreencopyFrameV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwlrScreencopyFrameV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwlrScreencopyFrameV1 {
+
+    /// `zwlr_screencopy_frame_v1:copy` request
+    /// Copy the frame to the supplied buffer. The buffer must have the
+    /// correct size, see zwlr_screencopy_frame_v1.buffer and
+    /// zwlr_screencopy_frame_v1.linux_dmabuf. The buffer needs to have a
+    /// supported format.
+    ///
+    /// If the frame is successfully copied, "flags" and "ready" events are
+    /// sent. Otherwise, a "failed" event is sent.
+    pub fn r#copy(&self, connection: &WaylandConnection, buffer: WlBuffer)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrScreencopyFrameV1Copy {
+                sendto: self.id,
+                buffer: buffer.id,
+            }
+        );
+    }
+
+    /// `zwlr_screencopy_frame_v1:destroy` request
+    /// Destroys the frame. This request can be sent at any time by the client.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrScreencopyFrameV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwlr_screencopy_frame_v1:copy_with_damage` request
+    /// Same as copy, except it waits until there is damage to copy.
+    pub fn r#copy_with_damage(&self, connection: &WaylandConnection, buffer: WlBuffer)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwlrScreencopyFrameV1CopyWithDamage {
+                sendto: self.id,
+                buffer: buffer.id,
+            }
+        );
+    }
+}
+
+
+/// An object that provides access to the graphics tablets available on this
+/// system. All tablets are associated with a seat, to get access to the
+/// actual tablets, use zwp_tablet_manager_v2.get_tablet_seat.
+#[derive(Clone, Copy)]
+pub struct ZwpTabletManagerV2{
+    pub id: u32,
+}
+
+impl Interface for ZwpTabletManagerV2 {
+    const NAME: &'static str = "zwp_tablet_manager_v2";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTabletManagerV2;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTabletManagerV2 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTabletManagerV2 {
+
+    /// `zwp_tablet_manager_v2:get_tablet_seat` request
+    /// Get the zwp_tablet_seat_v2 object for the given seat. This object
+    /// provides access to all graphics tablets in this seat.
+    pub fn r#get_tablet_seat(&self, connection: &WaylandConnection, seat: WlSeat) -> ZwpTabletSeatV2 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletManagerV2GetTabletSeat {
+                sendto: self.id,
+                seat: seat.id,
+            }
+        );
+        return ZwpTabletSeatV2{
+            id: _enq_id,
+        };
+    }
+
+    /// `zwp_tablet_manager_v2:destroy` request
+    /// Destroy the zwp_tablet_manager_v2 object. Objects created from this
+    /// object are unaffected and should be destroyed separately.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletManagerV2Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// An object that provides access to the graphics tablets available on this
+/// seat. After binding to this interface, the compositor sends a set of
+/// zwp_tablet_seat_v2.tablet_added and zwp_tablet_seat_v2.tool_added events.
+#[derive(Clone, Copy)]
+pub struct ZwpTabletSeatV2{
+    pub id: u32,
+}
+
+impl Interface for ZwpTabletSeatV2 {
+    const NAME: &'static str = "zwp_tablet_seat_v2";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTabletSeatV2;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTabletSeatV2 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTabletSeatV2 {
+
+    /// `zwp_tablet_seat_v2:destroy` request
+    /// Destroy the zwp_tablet_seat_v2 object. Objects created from this
+    /// object are unaffected and should be destroyed separately.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletSeatV2Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// An object that represents a physical tool that has been, or is
+/// currently in use with a tablet in this seat. Each zwp_tablet_tool_v2
+/// object stays valid until the client destroys it; the compositor
+/// reuses the zwp_tablet_tool_v2 object to indicate that the object's
+/// respective physical tool has come into proximity of a tablet again.
+///
+/// A zwp_tablet_tool_v2 object's relation to a physical tool depends on the
+/// tablet's ability to report serial numbers. If the tablet supports
+/// this capability, then the object represents a specific physical tool
+/// and can be identified even when used on multiple tablets.
+///
+/// A tablet tool has a number of static characteristics, e.g. tool type,
+/// hardware_serial and capabilities. These capabilities are sent in an
+/// event sequence after the zwp_tablet_seat_v2.tool_added event before any
+/// actual events from this tool. This initial event sequence is
+/// terminated by a zwp_tablet_tool_v2.done event.
+///
+/// Tablet tool events are grouped by zwp_tablet_tool_v2.frame events.
+/// Any events received before a zwp_tablet_tool_v2.frame event should be
+/// considered part of the same hardware state change.
+#[derive(Clone, Copy)]
+pub struct ZwpTabletToolV2{
+    pub id: u32,
+}
+
+impl Interface for ZwpTabletToolV2 {
+    const NAME: &'static str = "zwp_tablet_tool_v2";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTabletToolV2;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTabletToolV2 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTabletToolV2 {
+
+    /// `zwp_tablet_tool_v2:set_cursor` request
+    /// Sets the surface of the cursor used for this tool on the given
+    /// tablet. This request only takes effect if the tool is in proximity
+    /// of one of the requesting client's surfaces or the surface parameter
+    /// is the current pointer surface. If there was a previous surface set
+    /// with this request it is replaced. If surface is NULL, the cursor
+    /// image is hidden.
+    ///
+    /// The parameters hotspot_x and hotspot_y define the position of the
+    /// pointer surface relative to the pointer location. Its top-left corner
+    /// is always at (x, y) - (hotspot_x, hotspot_y), where (x, y) are the
+    /// coordinates of the pointer location, in surface-local coordinates.
+    ///
+    /// On surface.attach requests to the pointer surface, hotspot_x and
+    /// hotspot_y are decremented by the x and y parameters passed to the
+    /// request. Attach must be confirmed by wl_surface.commit as usual.
+    ///
+    /// The hotspot can also be updated by passing the currently set pointer
+    /// surface to this request with new values for hotspot_x and hotspot_y.
+    ///
+    /// The current and pending input regions of the wl_surface are cleared,
+    /// and wl_surface.set_input_region is ignored until the wl_surface is no
+    /// longer used as the cursor. When the use as a cursor ends, the current
+    /// and pending input regions become undefined, and the wl_surface is
+    /// unmapped.
+    ///
+    /// This request gives the surface the role of a zwp_tablet_tool_v2 cursor. A
+    /// surface may only ever be used as the cursor surface for one
+    /// zwp_tablet_tool_v2. If the surface already has another role or has
+    /// previously been used as cursor surface for a different tool, a
+    /// protocol error is raised.
+    pub fn r#set_cursor(&self, connection: &WaylandConnection, serial: u32, surface: Option<WlSurface>, hotspot_x: i32, hotspot_y: i32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletToolV2SetCursor {
+                sendto: self.id,
+                serial,
+                surface: surface.map(|o| o.id),
+                hotspot_x,
+                hotspot_y,
+            }
+        );
+    }
+
+    /// `zwp_tablet_tool_v2:destroy` request
+    /// This destroys the client's resource for this tool object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletToolV2Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// The zwp_tablet_v2 interface represents one graphics tablet device. The
+/// tablet interface itself does not generate events; all events are
+/// generated by zwp_tablet_tool_v2 objects when in proximity above a tablet.
+///
+/// A tablet has a number of static characteristics, e.g. device name and
+/// pid/vid. These capabilities are sent in an event sequence after the
+/// zwp_tablet_seat_v2.tablet_added event. This initial event sequence is
+/// terminated by a zwp_tablet_v2.done event.
+#[derive(Clone, Copy)]
+pub struct ZwpTabletV2{
+    pub id: u32,
+}
+
+impl Interface for ZwpTabletV2 {
+    const NAME: &'static str = "zwp_tablet_v2";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTabletV2;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTabletV2 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTabletV2 {
+
+    /// `zwp_tablet_v2:destroy` request
+    /// This destroys the client's resource for this tablet object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletV2Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A circular interaction area, such as the touch ring on the Wacom Intuos
+/// Pro series tablets.
+///
+/// Events on a ring are logically grouped by the zwp_tablet_pad_ring_v2.frame
+/// event.
+#[derive(Clone, Copy)]
+pub struct ZwpTabletPadRingV2{
+    pub id: u32,
+}
+
+impl Interface for ZwpTabletPadRingV2 {
+    const NAME: &'static str = "zwp_tablet_pad_ring_v2";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTabletPadRingV2;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTabletPadRingV2 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTabletPadRingV2 {
+
+    /// `zwp_tablet_pad_ring_v2:set_feedback` request
+    /// Request that the compositor use the provided feedback string
+    /// associated with this ring. This request should be issued immediately
+    /// after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+    /// group is received, or whenever the ring is mapped to a different
+    /// action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+    ///
+    /// Clients are encouraged to provide context-aware descriptions for
+    /// the actions associated with the ring; compositors may use this
+    /// information to offer visual feedback about the button layout
+    /// (eg. on-screen displays).
+    ///
+    /// The provided string 'description' is a UTF-8 encoded string to be
+    /// associated with this ring, and is considered user-visible; general
+    /// internationalization rules apply.
+    ///
+    /// The serial argument will be that of the last
+    /// zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+    /// ring. Requests providing other serials than the most recent one will be
+    /// ignored.
+    pub fn r#set_feedback(&self, connection: &WaylandConnection, description: &str, serial: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletPadRingV2SetFeedback {
+                sendto: self.id,
+                description,
+                serial,
+            }
+        );
+    }
+
+    /// `zwp_tablet_pad_ring_v2:destroy` request
+    /// This destroys the client's resource for this ring object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletPadRingV2Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A linear interaction area, such as the strips found in Wacom Cintiq
+/// models.
+///
+/// Events on a strip are logically grouped by the zwp_tablet_pad_strip_v2.frame
+/// event.
+#[derive(Clone, Copy)]
+pub struct ZwpTabletPadStripV2{
+    pub id: u32,
+}
+
+impl Interface for ZwpTabletPadStripV2 {
+    const NAME: &'static str = "zwp_tablet_pad_strip_v2";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTabletPadStripV2;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTabletPadStripV2 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTabletPadStripV2 {
+
+    /// `zwp_tablet_pad_strip_v2:set_feedback` request
+    /// Requests the compositor to use the provided feedback string
+    /// associated with this strip. This request should be issued immediately
+    /// after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+    /// group is received, or whenever the strip is mapped to a different
+    /// action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+    ///
+    /// Clients are encouraged to provide context-aware descriptions for
+    /// the actions associated with the strip, and compositors may use this
+    /// information to offer visual feedback about the button layout
+    /// (eg. on-screen displays).
+    ///
+    /// The provided string 'description' is a UTF-8 encoded string to be
+    /// associated with this ring, and is considered user-visible; general
+    /// internationalization rules apply.
+    ///
+    /// The serial argument will be that of the last
+    /// zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+    /// strip. Requests providing other serials than the most recent one will be
+    /// ignored.
+    pub fn r#set_feedback(&self, connection: &WaylandConnection, description: &str, serial: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletPadStripV2SetFeedback {
+                sendto: self.id,
+                description,
+                serial,
+            }
+        );
+    }
+
+    /// `zwp_tablet_pad_strip_v2:destroy` request
+    /// This destroys the client's resource for this strip object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletPadStripV2Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A pad group describes a distinct (sub)set of buttons, rings and strips
+/// present in the tablet. The criteria of this grouping is usually positional,
+/// eg. if a tablet has buttons on the left and right side, 2 groups will be
+/// presented. The physical arrangement of groups is undisclosed and may
+/// change on the fly.
+///
+/// Pad groups will announce their features during pad initialization. Between
+/// the corresponding zwp_tablet_pad_v2.group event and zwp_tablet_pad_group_v2.done, the
+/// pad group will announce the buttons, rings and strips contained in it,
+/// plus the number of supported modes.
+///
+/// Modes are a mechanism to allow multiple groups of actions for every element
+/// in the pad group. The number of groups and available modes in each is
+/// persistent across device plugs. The current mode is user-switchable, it
+/// will be announced through the zwp_tablet_pad_group_v2.mode_switch event both
+/// whenever it is switched, and after zwp_tablet_pad_v2.enter.
+///
+/// The current mode logically applies to all elements in the pad group,
+/// although it is at clients' discretion whether to actually perform different
+/// actions, and/or issue the respective .set_feedback requests to notify the
+/// compositor. See the zwp_tablet_pad_group_v2.mode_switch event for more details.
+#[derive(Clone, Copy)]
+pub struct ZwpTabletPadGroupV2{
+    pub id: u32,
+}
+
+impl Interface for ZwpTabletPadGroupV2 {
+    const NAME: &'static str = "zwp_tablet_pad_group_v2";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTabletPadGroupV2;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTabletPadGroupV2 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTabletPadGroupV2 {
+
+    /// `zwp_tablet_pad_group_v2:destroy` request
+    /// Destroy the zwp_tablet_pad_group_v2 object. Objects created from this object
+    /// are unaffected and should be destroyed separately.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletPadGroupV2Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A pad device is a set of buttons, rings, strips and dials
+/// usually physically present on the tablet device itself. Some
+/// exceptions exist where the pad device is physically detached, e.g. the
+/// Wacom ExpressKey Remote.
+///
+/// Pad devices have no axes that control the cursor and are generally
+/// auxiliary devices to the tool devices used on the tablet surface.
+///
+/// A pad device has a number of static characteristics, e.g. the number
+/// of rings. These capabilities are sent in an event sequence after the
+/// zwp_tablet_seat_v2.pad_added event before any actual events from this pad.
+/// This initial event sequence is terminated by a zwp_tablet_pad_v2.done
+/// event.
+///
+/// All pad features (buttons, rings, strips and dials) are logically divided into
+/// groups and all pads have at least one group. The available groups are
+/// notified through the zwp_tablet_pad_v2.group event; the compositor will
+/// emit one event per group before emitting zwp_tablet_pad_v2.done.
+///
+/// Groups may have multiple modes. Modes allow clients to map multiple
+/// actions to a single pad feature. Only one mode can be active per group,
+/// although different groups may have different active modes.
+#[derive(Clone, Copy)]
+pub struct ZwpTabletPadV2{
+    pub id: u32,
+}
+
+impl Interface for ZwpTabletPadV2 {
+    const NAME: &'static str = "zwp_tablet_pad_v2";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTabletPadV2;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTabletPadV2 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTabletPadV2 {
+
+    /// `zwp_tablet_pad_v2:set_feedback` request
+    /// Requests the compositor to use the provided feedback string
+    /// associated with this button. This request should be issued immediately
+    /// after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+    /// group is received, or whenever a button is mapped to a different
+    /// action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+    ///
+    /// Clients are encouraged to provide context-aware descriptions for
+    /// the actions associated with each button, and compositors may use
+    /// this information to offer visual feedback on the button layout
+    /// (e.g. on-screen displays).
+    ///
+    /// Button indices start at 0. Setting the feedback string on a button
+    /// that is reserved by the compositor (i.e. not belonging to any
+    /// zwp_tablet_pad_group_v2) does not generate an error but the compositor
+    /// is free to ignore the request.
+    ///
+    /// The provided string 'description' is a UTF-8 encoded string to be
+    /// associated with this ring, and is considered user-visible; general
+    /// internationalization rules apply.
+    ///
+    /// The serial argument will be that of the last
+    /// zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+    /// button. Requests providing other serials than the most recent one will
+    /// be ignored.
+    pub fn r#set_feedback(&self, connection: &WaylandConnection, button: u32, description: &str, serial: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletPadV2SetFeedback {
+                sendto: self.id,
+                button,
+                description,
+                serial,
+            }
+        );
+    }
+
+    /// `zwp_tablet_pad_v2:destroy` request
+    /// Destroy the zwp_tablet_pad_v2 object. Objects created from this object
+    /// are unaffected and should be destroyed separately.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletPadV2Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A rotary control, e.g. a dial or a wheel.
+///
+/// Events on a dial are logically grouped by the zwp_tablet_pad_dial_v2.frame
+/// event.
+#[derive(Clone, Copy)]
+pub struct ZwpTabletPadDialV2{
+    pub id: u32,
+}
+
+impl Interface for ZwpTabletPadDialV2 {
+    const NAME: &'static str = "zwp_tablet_pad_dial_v2";
+    const VERSION: u32 = 2;
+    const OBJECT: Object = Object::ZwpTabletPadDialV2;
+
+    fn from_id(id: u32) -> Self {
+        ZwpTabletPadDialV2 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpTabletPadDialV2 {
+
+    /// `zwp_tablet_pad_dial_v2:set_feedback` request
+    /// Requests the compositor to use the provided feedback string
+    /// associated with this dial. This request should be issued immediately
+    /// after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+    /// group is received, or whenever the dial is mapped to a different
+    /// action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+    ///
+    /// Clients are encouraged to provide context-aware descriptions for
+    /// the actions associated with the dial, and compositors may use this
+    /// information to offer visual feedback about the button layout
+    /// (eg. on-screen displays).
+    ///
+    /// The provided string 'description' is a UTF-8 encoded string to be
+    /// associated with this ring, and is considered user-visible; general
+    /// internationalization rules apply.
+    ///
+    /// The serial argument will be that of the last
+    /// zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+    /// dial. Requests providing other serials than the most recent one will be
+    /// ignored.
+    pub fn r#set_feedback(&self, connection: &WaylandConnection, description: &str, serial: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletPadDialV2SetFeedback {
+                sendto: self.id,
+                description,
+                serial,
+            }
+        );
+    }
+
+    /// `zwp_tablet_pad_dial_v2:destroy` request
+    /// This destroys the client's resource for this dial object.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpTabletPadDialV2Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A global interface to provide semantic touchpad gestures for a given
+/// pointer.
+///
+/// Three gestures are currently supported: swipe, pinch, and hold.
+/// Pinch and swipe gestures follow a three-stage cycle: begin, update,
+/// end. Hold gestures follow a two-stage cycle: begin and end. All
+/// gestures are identified by a unique id.
+///
+/// Warning! The protocol described in this file is experimental and
+/// backward incompatible changes may be made. Backward compatible changes
+/// may be added together with the corresponding interface version bump.
+/// Backward incompatible changes are done by bumping the version number in
+/// the protocol and interface names and resetting the interface version.
+/// Once the protocol is to be declared stable, the 'z' prefix and the
+/// version number in the protocol and interface names are removed and the
+/// interface version number is reset.
+#[derive(Clone, Copy)]
+pub struct ZwpPointerGesturesV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpPointerGesturesV1 {
+    const NAME: &'static str = "zwp_pointer_gestures_v1";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::ZwpPointerGesturesV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpPointerGesturesV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpPointerGesturesV1 {
+
+    /// `zwp_pointer_gestures_v1:get_swipe_gesture` request
+    /// Create a swipe gesture object. See the
+    /// wl_pointer_gesture_swipe interface for details.
+    pub fn r#get_swipe_gesture(&self, connection: &WaylandConnection, pointer: WlPointer) -> ZwpPointerGestureSwipeV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerGesturesV1GetSwipeGesture {
+                sendto: self.id,
+                pointer: pointer.id,
+            }
+        );
+        return ZwpPointerGestureSwipeV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `zwp_pointer_gestures_v1:get_pinch_gesture` request
+    /// Create a pinch gesture object. See the
+    /// wl_pointer_gesture_pinch interface for details.
+    pub fn r#get_pinch_gesture(&self, connection: &WaylandConnection, pointer: WlPointer) -> ZwpPointerGesturePinchV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerGesturesV1GetPinchGesture {
+                sendto: self.id,
+                pointer: pointer.id,
+            }
+        );
+        return ZwpPointerGesturePinchV1{
+            id: _enq_id,
+        };
+    }
+
+    /// `zwp_pointer_gestures_v1:release` request
+    /// Destroy the pointer gesture object. Swipe, pinch and hold objects
+    /// created via this gesture object remain valid.
+    pub fn r#release(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerGesturesV1Release {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zwp_pointer_gestures_v1:get_hold_gesture` request
+    /// Create a hold gesture object. See the
+    /// wl_pointer_gesture_hold interface for details.
+    pub fn r#get_hold_gesture(&self, connection: &WaylandConnection, pointer: WlPointer) -> ZwpPointerGestureHoldV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerGesturesV1GetHoldGesture {
+                sendto: self.id,
+                pointer: pointer.id,
+            }
+        );
+        return ZwpPointerGestureHoldV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// A swipe gesture object notifies a client about a multi-finger swipe
+/// gesture detected on an indirect input device such as a touchpad.
+/// The gesture is usually initiated by multiple fingers moving in the
+/// same direction but once initiated the direction may change.
+/// The precise conditions of when such a gesture is detected are
+/// implementation-dependent.
+///
+/// A gesture consists of three stages: begin, update (optional) and end.
+/// There cannot be multiple simultaneous hold, pinch or swipe gestures on a
+/// same pointer/seat, how compositors prevent these situations is
+/// implementation-dependent.
+///
+/// A gesture may be cancelled by the compositor or the hardware.
+/// Clients should not consider performing permanent or irreversible
+/// actions until the end of a gesture has been received.
+#[derive(Clone, Copy)]
+pub struct ZwpPointerGestureSwipeV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpPointerGestureSwipeV1 {
+    const NAME: &'static str = "zwp_pointer_gesture_swipe_v1";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::ZwpPointerGestureSwipeV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpPointerGestureSwipeV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpPointerGestureSwipeV1 {
+
+    /// `zwp_pointer_gesture_swipe_v1:destroy` request
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerGestureSwipeV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A pinch gesture object notifies a client about a multi-finger pinch
+/// gesture detected on an indirect input device such as a touchpad.
+/// The gesture is usually initiated by multiple fingers moving towards
+/// each other or away from each other, or by two or more fingers rotating
+/// around a logical center of gravity. The precise conditions of when
+/// such a gesture is detected are implementation-dependent.
+///
+/// A gesture consists of three stages: begin, update (optional) and end.
+/// There cannot be multiple simultaneous hold, pinch or swipe gestures on a
+/// same pointer/seat, how compositors prevent these situations is
+/// implementation-dependent.
+///
+/// A gesture may be cancelled by the compositor or the hardware.
+/// Clients should not consider performing permanent or irreversible
+/// actions until the end of a gesture has been received.
+#[derive(Clone, Copy)]
+pub struct ZwpPointerGesturePinchV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpPointerGesturePinchV1 {
+    const NAME: &'static str = "zwp_pointer_gesture_pinch_v1";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::ZwpPointerGesturePinchV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpPointerGesturePinchV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpPointerGesturePinchV1 {
+
+    /// `zwp_pointer_gesture_pinch_v1:destroy` request
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerGesturePinchV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A hold gesture object notifies a client about a single- or
+/// multi-finger hold gesture detected on an indirect input device such as
+/// a touchpad. The gesture is usually initiated by one or more fingers
+/// being held down without significant movement. The precise conditions
+/// of when such a gesture is detected are implementation-dependent.
+///
+/// In particular, this gesture may be used to cancel kinetic scrolling.
+///
+/// A hold gesture consists of two stages: begin and end. Unlike pinch and
+/// swipe there is no update stage.
+/// There cannot be multiple simultaneous hold, pinch or swipe gestures on a
+/// same pointer/seat, how compositors prevent these situations is
+/// implementation-dependent.
+///
+/// A gesture may be cancelled by the compositor or the hardware.
+/// Clients should not consider performing permanent or irreversible
+/// actions until the end of a gesture has been received.
+#[derive(Clone, Copy)]
+pub struct ZwpPointerGestureHoldV1{
+    pub id: u32,
+}
+
+impl Interface for ZwpPointerGestureHoldV1 {
+    const NAME: &'static str = "zwp_pointer_gesture_hold_v1";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::ZwpPointerGestureHoldV1;
+
+    fn from_id(id: u32) -> Self {
+        ZwpPointerGestureHoldV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZwpPointerGestureHoldV1 {
+
+    /// `zwp_pointer_gesture_hold_v1:destroy` request
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZwpPointerGestureHoldV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// A global factory interface for xdg_output objects.
+#[derive(Clone, Copy)]
+pub struct ZxdgOutputManagerV1{
+    pub id: u32,
+}
+
+impl Interface for ZxdgOutputManagerV1 {
+    const NAME: &'static str = "zxdg_output_manager_v1";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::ZxdgOutputManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        ZxdgOutputManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZxdgOutputManagerV1 {
+
+    /// `zxdg_output_manager_v1:destroy` request
+    /// Using this request a client can tell the server that it is not
+    /// going to use the xdg_output_manager object anymore.
+    ///
+    /// Any objects already created through this instance are not affected.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZxdgOutputManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `zxdg_output_manager_v1:get_xdg_output` request
+    /// This creates a new xdg_output object for the given wl_output.
+    pub fn r#get_xdg_output(&self, connection: &WaylandConnection, output: WlOutput) -> ZxdgOutputV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::ZxdgOutputManagerV1GetXdgOutput {
+                sendto: self.id,
+                output: output.id,
+            }
+        );
+        return ZxdgOutputV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// An xdg_output describes part of the compositor geometry.
+///
+/// This typically corresponds to a monitor that displays part of the
+/// compositor space.
+///
+/// For objects version 3 onwards, after all xdg_output properties have been
+/// sent (when the object is created and when properties are updated), a
+/// wl_output.done event is sent. This allows changes to the output
+/// properties to be seen as atomic, even if they happen via multiple events.
+#[derive(Clone, Copy)]
+pub struct ZxdgOutputV1{
+    pub id: u32,
+}
+
+impl Interface for ZxdgOutputV1 {
+    const NAME: &'static str = "zxdg_output_v1";
+    const VERSION: u32 = 3;
+    const OBJECT: Object = Object::ZxdgOutputV1;
+
+    fn from_id(id: u32) -> Self {
+        ZxdgOutputV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl ZxdgOutputV1 {
+
+    /// `zxdg_output_v1:destroy` request
+    /// Using this request a client can tell the server that it is not
+    /// going to use the xdg_output object anymore.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::ZxdgOutputV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
+
+
+/// The wp_single_pixel_buffer_manager_v1 interface is a factory for
+/// single-pixel buffers.
+#[derive(Clone, Copy)]
+pub struct WpSinglePixelBufferManagerV1{
+    pub id: u32,
+}
+
+impl Interface for WpSinglePixelBufferManagerV1 {
+    const NAME: &'static str = "wp_single_pixel_buffer_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpSinglePixelBufferManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        WpSinglePixelBufferManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpSinglePixelBufferManagerV1 {
+
+    /// `wp_single_pixel_buffer_manager_v1:destroy` request
+    /// Destroy the wp_single_pixel_buffer_manager_v1 object.
+    ///
+    /// The child objects created via this interface are unaffected.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpSinglePixelBufferManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_single_pixel_buffer_manager_v1:create_u32_rgba_buffer` request
+    /// Create a single-pixel buffer from four 32-bit RGBA values.
+    ///
+    /// Unless specified in another protocol extension, the RGBA values use
+    /// pre-multiplied alpha.
+    ///
+    /// The width and height of the buffer are 1.
+    ///
+    /// The r, g, b and a arguments valid range is from UINT32_MIN (0)
+    /// to UINT32_MAX (0xffffffff).
+    ///
+    /// These arguments should be interpreted as a percentage, i.e.
+    /// - UINT32_MIN = 0% of the given color component
+    /// - UINT32_MAX = 100% of the given color component
+    pub fn r#create_u32_rgba_buffer(&self, connection: &WaylandConnection, r: u32, g: u32, b: u32, a: u32) -> WlBuffer {
+
+        let _enq_id = connection.enqueue(
+            Request::WpSinglePixelBufferManagerV1CreateU32RgbaBuffer {
+                sendto: self.id,
+                r,
+                g,
+                b,
+                a,
+            }
+        );
+        return WlBuffer{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// This interface allows a client to describe the kind of content a surface
+/// will display, to allow the compositor to optimize its behavior for it.
+///
+/// Warning! The protocol described in this file is currently in the testing
+/// phase. Backward compatible changes may be added together with the
+/// corresponding interface version bump. Backward incompatible changes can
+/// only be done by creating a new major version of the extension.
+#[derive(Clone, Copy)]
+pub struct WpContentTypeManagerV1{
+    pub id: u32,
+}
+
+impl Interface for WpContentTypeManagerV1 {
+    const NAME: &'static str = "wp_content_type_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpContentTypeManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        WpContentTypeManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpContentTypeManagerV1 {
+
+    /// `wp_content_type_manager_v1:destroy` request
+    /// Destroy the content type manager. This doesn't destroy objects created
+    /// with the manager.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpContentTypeManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_content_type_manager_v1:get_surface_content_type` request
+    /// Create a new content type object associated with the given surface.
+    ///
+    /// Creating a wp_content_type_v1 from a wl_surface which already has one
+    /// attached is a client error: already_constructed.
+    pub fn r#get_surface_content_type(&self, connection: &WaylandConnection, surface: WlSurface) -> WpContentTypeV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::WpContentTypeManagerV1GetSurfaceContentType {
+                sendto: self.id,
+                surface: surface.id,
+            }
+        );
+        return WpContentTypeV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// The content type object allows the compositor to optimize for the kind
+/// of content shown on the surface. A compositor may for example use it to
+/// set relevant drm properties like "content type".
+///
+/// The client may request to switch to another content type at any time.
+/// When the associated surface gets destroyed, this object becomes inert and
+/// the client should destroy it.
+#[derive(Clone, Copy)]
+pub struct WpContentTypeV1{
+    pub id: u32,
+}
+
+impl Interface for WpContentTypeV1 {
+    const NAME: &'static str = "wp_content_type_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpContentTypeV1;
+
+    fn from_id(id: u32) -> Self {
+        WpContentTypeV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpContentTypeV1 {
+
+    /// `wp_content_type_v1:destroy` request
+    /// Switch back to not specifying the content type of this surface. This is
+    /// equivalent to setting the content type to none, including double
+    /// buffering semantics. See set_content_type for details.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpContentTypeV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_content_type_v1:set_content_type` request
+    /// Set the surface content type. This informs the compositor that the
+    /// client believes it is displaying buffers matching this content type.
+    ///
+    /// This is purely a hint for the compositor, which can be used to adjust
+    /// its behavior or hardware settings to fit the presented content best.
+    ///
+    /// The content type is double-buffered state, see wl_surface.commit for
+    /// details.
+    pub fn r#set_content_type(&self, connection: &WaylandConnection, content_type: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpContentTypeV1SetContentType {
+                sendto: self.id,
+                content_type,
+            }
+        );
+    }
+}
+
+
+/// For some use cases like games or drawing tablets it can make sense to
+/// reduce latency by accepting tearing with the use of asynchronous page
+/// flips. This global is a factory interface, allowing clients to inform
+/// which type of presentation the content of their surfaces is suitable for.
+///
+/// Graphics APIs like EGL or Vulkan, that manage the buffer queue and commits
+/// of a wl_surface themselves, are likely to be using this extension
+/// internally. If a client is using such an API for a wl_surface, it should
+/// not directly use this extension on that surface, to avoid raising a
+/// tearing_control_exists protocol error.
+///
+/// Warning! The protocol described in this file is currently in the testing
+/// phase. Backward compatible changes may be added together with the
+/// corresponding interface version bump. Backward incompatible changes can
+/// only be done by creating a new major version of the extension.
+#[derive(Clone, Copy)]
+pub struct WpTearingControlManagerV1{
+    pub id: u32,
+}
+
+impl Interface for WpTearingControlManagerV1 {
+    const NAME: &'static str = "wp_tearing_control_manager_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpTearingControlManagerV1;
+
+    fn from_id(id: u32) -> Self {
+        WpTearingControlManagerV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpTearingControlManagerV1 {
+
+    /// `wp_tearing_control_manager_v1:destroy` request
+    /// Destroy this tearing control factory object. Other objects, including
+    /// wp_tearing_control_v1 objects created by this factory, are not affected
+    /// by this request.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpTearingControlManagerV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+
+    /// `wp_tearing_control_manager_v1:get_tearing_control` request
+    /// Instantiate an interface extension for the given wl_surface to request
+    /// asynchronous page flips for presentation.
+    ///
+    /// If the given wl_surface already has a wp_tearing_control_v1 object
+    /// associated, the tearing_control_exists protocol error is raised.
+    pub fn r#get_tearing_control(&self, connection: &WaylandConnection, surface: WlSurface) -> WpTearingControlV1 {
+
+        let _enq_id = connection.enqueue(
+            Request::WpTearingControlManagerV1GetTearingControl {
+                sendto: self.id,
+                surface: surface.id,
+            }
+        );
+        return WpTearingControlV1{
+            id: _enq_id,
+        };
+    }
+}
+
+
+/// An additional interface to a wl_surface object, which allows the client
+/// to hint to the compositor if the content on the surface is suitable for
+/// presentation with tearing.
+/// The default presentation hint is vsync. See presentation_hint for more
+/// details.
+///
+/// If the associated wl_surface is destroyed, this object becomes inert and
+/// should be destroyed.
+#[derive(Clone, Copy)]
+pub struct WpTearingControlV1{
+    pub id: u32,
+}
+
+impl Interface for WpTearingControlV1 {
+    const NAME: &'static str = "wp_tearing_control_v1";
+    const VERSION: u32 = 1;
+    const OBJECT: Object = Object::WpTearingControlV1;
+
+    fn from_id(id: u32) -> Self {
+        WpTearingControlV1 { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl WpTearingControlV1 {
+
+    /// `wp_tearing_control_v1:set_presentation_hint` request
+    /// Set the presentation hint for the associated wl_surface. This state is
+    /// double-buffered, see wl_surface.commit.
+    ///
+    /// The compositor is free to dynamically respect or ignore this hint based
+    /// on various conditions like hardware capabilities, surface state and
+    /// user preferences.
+    pub fn r#set_presentation_hint(&self, connection: &WaylandConnection, hint: u32)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpTearingControlV1SetPresentationHint {
+                sendto: self.id,
+                hint,
+            }
+        );
+    }
+
+    /// `wp_tearing_control_v1:destroy` request
+    /// Destroy this surface tearing object and revert the presentation hint to
+    /// vsync. The change will be applied on the next wl_surface.commit.
+    pub fn r#destroy(&self, connection: &WaylandConnection)  {
+
+        let _enq_id = connection.enqueue(
+            Request::WpTearingControlV1Destroy {
+                sendto: self.id,
+            }
+        );
+    }
+}
@@ -1,5 +1,8 @@
+use crate::types::Object;
+use crate::types::Fixed;
+
 #[derive(Debug, Clone)]
-pub enum Request {
+pub enum Request<'a> {
 
     /// zwp_linux_dmabuf_v1:destroy request
     /// Objects created through this interface, especially wl_buffers, will
@@ -443,7 +446,7 @@ pub enum Request {
     XdgSurfaceGetPopup {
         /// id of the object to send a request to
         sendto: u32,
-        parent: u32,
+        parent: Option<u32>,
         positioner: u32,
     },
 
@@ -567,7 +570,7 @@ pub enum Request {
     XdgToplevelSetParent {
         /// id of the object to send a request to
         sendto: u32,
-        parent: u32,
+        parent: Option<u32>,
     },
 
     /// xdg_toplevel:set_title request
@@ -581,7 +584,7 @@ pub enum Request {
     XdgToplevelSetTitle {
         /// id of the object to send a request to
         sendto: u32,
-        title: String,
+        title: &'a str,
     },
 
     /// xdg_toplevel:set_app_id request
@@ -611,7 +614,7 @@ pub enum Request {
     XdgToplevelSetAppId {
         /// id of the object to send a request to
         sendto: u32,
-        app_id: String,
+        app_id: &'a str,
     },
 
     /// xdg_toplevel:show_window_menu request
@@ -872,7 +875,7 @@ pub enum Request {
     XdgToplevelSetFullscreen {
         /// id of the object to send a request to
         sendto: u32,
-        output: u32,
+        output: Option<u32>,
     },
 
     /// xdg_toplevel:unset_fullscreen request
@@ -1043,9 +1046,11 @@ pub enum Request {
         /// unique numeric name of the object
         name: u32,
         /// new_id value without iterface must be preceeded by a string and a version
-        if_name: String,
+        if_name: &'a str,
         /// new_id value without iterface must be preceeded by a string and a version
-        if_version: u32
+        if_version: u32,
+        /// object type allocated for the new id
+        object: Object,
     },
 
     /// wl_compositor:create_surface request
@@ -1164,7 +1169,7 @@ pub enum Request {
         /// serial number of the accept request
         serial: u32,
         /// mime type accepted by the client
-        mime_type: String,
+        mime_type: Option<&'a str>,
     },
 
     /// wl_data_offer:receive request
@@ -1187,7 +1192,7 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// mime type desired by receiver
-        mime_type: String,
+        mime_type: &'a str,
         /// file descriptor for data transfer
         fd: std::os::fd::RawFd,
     },
@@ -1268,7 +1273,7 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// mime type offered by the data source
-        mime_type: String,
+        mime_type: &'a str,
     },
 
     /// wl_data_source:destroy request
@@ -1333,11 +1338,11 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// data source for the eventual transfer
-        source: u32,
+        source: Option<u32>,
         /// surface where the drag originates
         origin: u32,
         /// drag-and-drop icon surface
-        icon: u32,
+        icon: Option<u32>,
         /// serial number of the implicit grab on the origin
         serial: u32,
     },
@@ -1355,7 +1360,7 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// data source for the selection
-        source: u32,
+        source: Option<u32>,
         /// serial number of the event that triggered this request
         serial: u32,
     },
@@ -1510,7 +1515,7 @@ pub enum Request {
         /// framerate in mHz
         framerate: u32,
         /// output on which the surface is to be fullscreen
-        output: u32,
+        output: Option<u32>,
     },
 
     /// wl_shell_surface:set_popup request
@@ -1573,7 +1578,7 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// output on which the surface is to be maximized
-        output: u32,
+        output: Option<u32>,
     },
 
     /// wl_shell_surface:set_title request
@@ -1588,7 +1593,7 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// surface title
-        title: String,
+        title: &'a str,
     },
 
     /// wl_shell_surface:set_class request
@@ -1602,7 +1607,7 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// surface class
-        class_: String,
+        class_: &'a str,
     },
 
     /// wl_surface:destroy request
@@ -1674,7 +1679,7 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// buffer of surface contents
-        buffer: u32,
+        buffer: Option<u32>,
         /// surface-local x coordinate
         x: i32,
         /// surface-local y coordinate
@@ -1783,7 +1788,7 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// opaque region of the surface
-        region: u32,
+        region: Option<u32>,
     },
 
     /// wl_surface:set_input_region request
@@ -1813,7 +1818,7 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
         /// input region of the surface
-        region: u32,
+        region: Option<u32>,
     },
 
     /// wl_surface:commit request
@@ -2065,7 +2070,7 @@ pub enum Request {
         /// serial number of the enter event
         serial: u32,
         /// pointer surface
-        surface: u32,
+        surface: Option<u32>,
         /// surface-local x coordinate
         hotspot_x: i32,
         /// surface-local y coordinate
@@ -2290,6 +2295,2263 @@ pub enum Request {
         /// id of the object to send a request to
         sendto: u32,
     },
+
+    /// zxdg_decoration_manager_v1:destroy request
+    /// Destroy the decoration manager. This doesn't destroy objects created
+    /// with the manager.
+    ZxdgDecorationManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zxdg_decoration_manager_v1:get_toplevel_decoration request
+    /// Create a new decoration object associated with the given toplevel.
+    ///
+    /// For objects of version 1, creating an xdg_toplevel_decoration from an
+    /// xdg_toplevel which has a buffer attached or committed is a client
+    /// error, and any attempts by a client to attach or manipulate a buffer
+    /// prior to the first xdg_toplevel_decoration.configure event must also be
+    /// treated as errors.
+    ///
+    /// For objects of version 2 or newer, creating an xdg_toplevel_decoration
+    /// from an xdg_toplevel which has a buffer attached or committed is
+    /// allowed. The initial decoration mode of the surface if a buffer is
+    /// already attached depends on whether a xdg_toplevel_decoration object
+    /// has been associated with the surface or not prior to this request.
+    ///
+    /// If an xdg_toplevel_decoration was associated with the surface, then
+    /// destroyed without a surface commit, the previous decoration mode is
+    /// retained.
+    ///
+    /// If no xdg_toplevel_decoration was associated with the surface prior to
+    /// this request, or if a surface commit has been performed after a previous
+    /// xdg_toplevel_decoration object associated with the surface was
+    /// destroyed, the decoration mode is assumed to be client-side.
+    ZxdgDecorationManagerV1GetToplevelDecoration {
+        /// id of the object to send a request to
+        sendto: u32,
+        toplevel: u32,
+    },
+
+    /// zxdg_toplevel_decoration_v1:destroy request
+    /// Switch back to a mode without any server-side decorations at the next
+    /// commit, unless a new xdg_toplevel_decoration is created for the surface
+    /// first.
+    ZxdgToplevelDecorationV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zxdg_toplevel_decoration_v1:set_mode request
+    /// Set the toplevel surface decoration mode. This informs the compositor
+    /// that the client prefers the provided decoration mode.
+    ///
+    /// After requesting a decoration mode, the compositor will respond by
+    /// emitting an xdg_surface.configure event. The client should then update
+    /// its content, drawing it without decorations if the received mode is
+    /// server-side decorations. The client must also acknowledge the configure
+    /// when committing the new content (see xdg_surface.ack_configure).
+    ///
+    /// The compositor can decide not to use the client's mode and enforce a
+    /// different mode instead.
+    ///
+    /// Clients whose decoration mode depend on the xdg_toplevel state may send
+    /// a set_mode request in response to an xdg_surface.configure event and wait
+    /// for the next xdg_surface.configure event to prevent unwanted state.
+    /// Such clients are responsible for preventing configure loops and must
+    /// make sure not to send multiple successive set_mode requests with the
+    /// same decoration mode.
+    ///
+    /// If an invalid mode is supplied by the client, the invalid_mode protocol
+    /// error is raised by the compositor.
+    ZxdgToplevelDecorationV1SetMode {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the decoration mode
+        mode: u32,
+    },
+
+    /// zxdg_toplevel_decoration_v1:unset_mode request
+    /// Unset the toplevel surface decoration mode. This informs the compositor
+    /// that the client doesn't prefer a particular decoration mode.
+    ///
+    /// This request has the same semantics as set_mode.
+    ZxdgToplevelDecorationV1UnsetMode {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_viewporter:destroy request
+    /// Informs the server that the client will not be using this
+    /// protocol object anymore. This does not affect any other objects,
+    /// wp_viewport objects included.
+    WpViewporterDestroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_viewporter:get_viewport request
+    /// Instantiate an interface extension for the given wl_surface to
+    /// crop and scale its content. If the given wl_surface already has
+    /// a wp_viewport object associated, the viewport_exists
+    /// protocol error is raised.
+    WpViewporterGetViewport {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the surface
+        surface: u32,
+    },
+
+    /// wp_viewport:destroy request
+    /// The associated wl_surface's crop and scale state is removed.
+    /// The change is applied on the next wl_surface.commit.
+    WpViewportDestroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_viewport:set_source request
+    /// Set the source rectangle of the associated wl_surface. See
+    /// wp_viewport for the description, and relation to the wl_buffer
+    /// size.
+    ///
+    /// If all of x, y, width and height are -1.0, the source rectangle is
+    /// unset instead. Any other set of values where width or height are zero
+    /// or negative, or x or y are negative, raise the bad_value protocol
+    /// error.
+    ///
+    /// The crop and scale state is double-buffered, see wl_surface.commit.
+    WpViewportSetSource {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// source rectangle x
+        x: Fixed,
+        /// source rectangle y
+        y: Fixed,
+        /// source rectangle width
+        width: Fixed,
+        /// source rectangle height
+        height: Fixed,
+    },
+
+    /// wp_viewport:set_destination request
+    /// Set the destination size of the associated wl_surface. See
+    /// wp_viewport for the description, and relation to the wl_buffer
+    /// size.
+    ///
+    /// If width is -1 and height is -1, the destination size is unset
+    /// instead. Any other pair of values for width and height that
+    /// contains zero or negative values raises the bad_value protocol
+    /// error.
+    ///
+    /// The crop and scale state is double-buffered, see wl_surface.commit.
+    WpViewportSetDestination {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// surface width
+        width: i32,
+        /// surface height
+        height: i32,
+    },
+
+    /// wp_fractional_scale_manager_v1:destroy request
+    /// Informs the server that the client will not be using this protocol
+    /// object anymore. This does not affect any other objects,
+    /// wp_fractional_scale_v1 objects included.
+    WpFractionalScaleManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_fractional_scale_manager_v1:get_fractional_scale request
+    /// Create an add-on object for the the wl_surface to let the compositor
+    /// request fractional scales. If the given wl_surface already has a
+    /// wp_fractional_scale_v1 object associated, the fractional_scale_exists
+    /// protocol error is raised.
+    WpFractionalScaleManagerV1GetFractionalScale {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the surface
+        surface: u32,
+    },
+
+    /// wp_fractional_scale_v1:destroy request
+    /// Destroy the fractional scale object. When this object is destroyed,
+    /// preferred_scale events will no longer be sent.
+    WpFractionalScaleV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_presentation:destroy request
+    /// Informs the server that the client will no longer be using
+    /// this protocol object. Existing objects created by this object
+    /// are not affected.
+    WpPresentationDestroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_presentation:feedback request
+    /// Request presentation feedback for the current content submission
+    /// on the given surface. This creates a new presentation_feedback
+    /// object, which will deliver the feedback information once. If
+    /// multiple presentation_feedback objects are created for the same
+    /// submission, they will all deliver the same information.
+    ///
+    /// For details on what information is returned, see the
+    /// presentation_feedback interface.
+    WpPresentationFeedback {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// target surface
+        surface: u32,
+    },
+
+    /// zwp_linux_explicit_synchronization_v1:destroy request
+    /// Destroy this explicit synchronization factory object. Other objects,
+    /// including zwp_linux_surface_synchronization_v1 objects created by this
+    /// factory, shall not be affected by this request.
+    ZwpLinuxExplicitSynchronizationV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_linux_explicit_synchronization_v1:get_synchronization request
+    /// Instantiate an interface extension for the given wl_surface to provide
+    /// explicit synchronization.
+    ///
+    /// If the given wl_surface already has an explicit synchronization object
+    /// associated, the synchronization_exists protocol error is raised.
+    ///
+    /// Graphics APIs, like EGL or Vulkan, that manage the buffer queue and
+    /// commits of a wl_surface themselves, are likely to be using this
+    /// extension internally. If a client is using such an API for a
+    /// wl_surface, it should not directly use this extension on that surface,
+    /// to avoid raising a synchronization_exists protocol error.
+    ZwpLinuxExplicitSynchronizationV1GetSynchronization {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the surface
+        surface: u32,
+    },
+
+    /// zwp_linux_surface_synchronization_v1:destroy request
+    /// Destroy this explicit synchronization object.
+    ///
+    /// Any fence set by this object with set_acquire_fence since the last
+    /// commit will be discarded by the server. Any fences set by this object
+    /// before the last commit are not affected.
+    ///
+    /// zwp_linux_buffer_release_v1 objects created by this object are not
+    /// affected by this request.
+    ZwpLinuxSurfaceSynchronizationV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_linux_surface_synchronization_v1:set_acquire_fence request
+    /// Set the acquire fence that must be signaled before the compositor
+    /// may sample from the buffer attached with wl_surface.attach. The fence
+    /// is a dma_fence kernel object.
+    ///
+    /// The acquire fence is double-buffered state, and will be applied on the
+    /// next wl_surface.commit request for the associated surface. Thus, it
+    /// applies only to the buffer that is attached to the surface at commit
+    /// time.
+    ///
+    /// If the provided fd is not a valid dma_fence fd, then an INVALID_FENCE
+    /// error is raised.
+    ///
+    /// If a fence has already been attached during the same commit cycle, a
+    /// DUPLICATE_FENCE error is raised.
+    ///
+    /// If the associated wl_surface was destroyed, a NO_SURFACE error is
+    /// raised.
+    ///
+    /// If at surface commit time the attached buffer does not support explicit
+    /// synchronization, an UNSUPPORTED_BUFFER error is raised.
+    ///
+    /// If at surface commit time there is no buffer attached, a NO_BUFFER
+    /// error is raised.
+    ZwpLinuxSurfaceSynchronizationV1SetAcquireFence {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// acquire fence fd
+        fd: std::os::fd::RawFd,
+    },
+
+    /// zwp_linux_surface_synchronization_v1:get_release request
+    /// Create a listener for the release of the buffer attached by the
+    /// client with wl_surface.attach. See zwp_linux_buffer_release_v1
+    /// documentation for more information.
+    ///
+    /// The release object is double-buffered state, and will be associated
+    /// with the buffer that is attached to the surface at wl_surface.commit
+    /// time.
+    ///
+    /// If a zwp_linux_buffer_release_v1 object has already been requested for
+    /// the surface in the same commit cycle, a DUPLICATE_RELEASE error is
+    /// raised.
+    ///
+    /// If the associated wl_surface was destroyed, a NO_SURFACE error
+    /// is raised.
+    ///
+    /// If at surface commit time there is no buffer attached, a NO_BUFFER
+    /// error is raised.
+    ZwpLinuxSurfaceSynchronizationV1GetRelease {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_linux_drm_syncobj_manager_v1:destroy request
+    /// Destroy this explicit synchronization factory object. Other objects
+    /// shall not be affected by this request.
+    WpLinuxDrmSyncobjManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_linux_drm_syncobj_manager_v1:get_surface request
+    /// Instantiate an interface extension for the given wl_surface to provide
+    /// explicit synchronization.
+    ///
+    /// If the given wl_surface already has an explicit synchronization object
+    /// associated, the surface_exists protocol error is raised.
+    ///
+    /// Graphics APIs, like EGL or Vulkan, that manage the buffer queue and
+    /// commits of a wl_surface themselves, are likely to be using this
+    /// extension internally. If a client is using such an API for a
+    /// wl_surface, it should not directly use this extension on that surface,
+    /// to avoid raising a surface_exists protocol error.
+    WpLinuxDrmSyncobjManagerV1GetSurface {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the surface
+        surface: u32,
+    },
+
+    /// wp_linux_drm_syncobj_manager_v1:import_timeline request
+    /// Import a DRM synchronization object timeline.
+    ///
+    /// If the FD cannot be imported, the invalid_timeline error is raised.
+    WpLinuxDrmSyncobjManagerV1ImportTimeline {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// drm_syncobj file descriptor
+        fd: std::os::fd::RawFd,
+    },
+
+    /// wp_linux_drm_syncobj_timeline_v1:destroy request
+    /// Destroy the synchronization object timeline. Other objects are not
+    /// affected by this request, in particular timeline points set by
+    /// set_acquire_point and set_release_point are not unset.
+    WpLinuxDrmSyncobjTimelineV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_linux_drm_syncobj_surface_v1:destroy request
+    /// Destroy this surface synchronization object.
+    ///
+    /// Any timeline point set by this object with set_acquire_point or
+    /// set_release_point since the last commit may be discarded by the
+    /// compositor. Any timeline point set by this object before the last
+    /// commit will not be affected.
+    WpLinuxDrmSyncobjSurfaceV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_linux_drm_syncobj_surface_v1:set_acquire_point request
+    /// Set the timeline point that must be signalled before the compositor may
+    /// sample from the buffer attached with wl_surface.attach.
+    ///
+    /// The 64-bit unsigned value combined from point_hi and point_lo is the
+    /// point value.
+    ///
+    /// The acquire point is double-buffered state, and will be applied on the
+    /// next wl_surface.commit request for the associated surface. Thus, it
+    /// applies only to the buffer that is attached to the surface at commit
+    /// time.
+    ///
+    /// If an acquire point has already been attached during the same commit
+    /// cycle, the new point replaces the old one.
+    ///
+    /// If the associated wl_surface was destroyed, a no_surface error is
+    /// raised.
+    ///
+    /// If at surface commit time there is a pending acquire timeline point set
+    /// but no pending buffer attached, a no_buffer error is raised. If at
+    /// surface commit time there is a pending buffer attached but no pending
+    /// acquire timeline point set, the no_acquire_point protocol error is
+    /// raised.
+    WpLinuxDrmSyncobjSurfaceV1SetAcquirePoint {
+        /// id of the object to send a request to
+        sendto: u32,
+        timeline: u32,
+        /// high 32 bits of the point value
+        point_hi: u32,
+        /// low 32 bits of the point value
+        point_lo: u32,
+    },
+
+    /// wp_linux_drm_syncobj_surface_v1:set_release_point request
+    /// Set the timeline point that must be signalled by the compositor when it
+    /// has finished its usage of the buffer attached with wl_surface.attach
+    /// for the relevant commit.
+    ///
+    /// Once the timeline point is signaled, and assuming the associated buffer
+    /// is not pending release from other wl_surface.commit requests, no
+    /// additional explicit or implicit synchronization with the compositor is
+    /// required to safely re-use the buffer.
+    ///
+    /// Note that clients cannot rely on the release point being always
+    /// signaled after the acquire point: compositors may release buffers
+    /// without ever reading from them. In addition, the compositor may use
+    /// different presentation paths for different commits, which may have
+    /// different release behavior. As a result, the compositor may signal the
+    /// release points in a different order than the client committed them.
+    ///
+    /// Because signaling a timeline point also signals every previous point,
+    /// it is generally not safe to use the same timeline object for the
+    /// release points of multiple buffers. The out-of-order signaling
+    /// described above may lead to a release point being signaled before the
+    /// compositor has finished reading. To avoid this, it is strongly
+    /// recommended that each buffer should use a separate timeline for its
+    /// release points.
+    ///
+    /// The 64-bit unsigned value combined from point_hi and point_lo is the
+    /// point value.
+    ///
+    /// The release point is double-buffered state, and will be applied on the
+    /// next wl_surface.commit request for the associated surface. Thus, it
+    /// applies only to the buffer that is attached to the surface at commit
+    /// time.
+    ///
+    /// If a release point has already been attached during the same commit
+    /// cycle, the new point replaces the old one.
+    ///
+    /// If the associated wl_surface was destroyed, a no_surface error is
+    /// raised.
+    ///
+    /// If at surface commit time there is a pending release timeline point set
+    /// but no pending buffer attached, a no_buffer error is raised. If at
+    /// surface commit time there is a pending buffer attached but no pending
+    /// release timeline point set, the no_release_point protocol error is
+    /// raised.
+    WpLinuxDrmSyncobjSurfaceV1SetReleasePoint {
+        /// id of the object to send a request to
+        sendto: u32,
+        timeline: u32,
+        /// high 32 bits of the point value
+        point_hi: u32,
+        /// low 32 bits of the point value
+        point_lo: u32,
+    },
+
+    /// xdg_activation_v1:destroy request
+    /// Notify the compositor that the xdg_activation object will no longer be
+    /// used.
+    ///
+    /// The child objects created via this interface are unaffected and should
+    /// be destroyed separately.
+    XdgActivationV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// xdg_activation_v1:get_activation_token request
+    /// Creates an xdg_activation_token_v1 object that will provide
+    /// the initiating client with a unique token for this activation. This
+    /// token should be offered to the clients to be activated.
+    XdgActivationV1GetActivationToken {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// xdg_activation_v1:activate request
+    /// Requests surface activation. It's up to the compositor to display
+    /// this information as desired, for example by placing the surface above
+    /// the rest.
+    ///
+    /// The compositor may know who requested this by checking the activation
+    /// token and might decide not to follow through with the activation if it's
+    /// considered unwanted.
+    ///
+    /// Compositors can ignore unknown activation tokens when an invalid
+    /// token is passed.
+    XdgActivationV1Activate {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the activation token of the initiating client
+        token: &'a str,
+        /// the wl_surface to activate
+        surface: u32,
+    },
+
+    /// xdg_activation_token_v1:set_serial request
+    /// Provides information about the seat and serial event that requested the
+    /// token.
+    ///
+    /// The serial can come from an input or focus event. For instance, if a
+    /// click triggers the launch of a third-party client, the launcher client
+    /// should send a set_serial request with the serial and seat from the
+    /// wl_pointer.button event.
+    ///
+    /// Some compositors might refuse to activate toplevels when the token
+    /// doesn't have a valid and recent enough event serial.
+    ///
+    /// Must be sent before commit. This information is optional.
+    XdgActivationTokenV1SetSerial {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the serial of the event that triggered the activation
+        serial: u32,
+        /// the wl_seat of the event
+        seat: u32,
+    },
+
+    /// xdg_activation_token_v1:set_app_id request
+    /// The requesting client can specify an app_id to associate the token
+    /// being created with it.
+    ///
+    /// Must be sent before commit. This information is optional.
+    XdgActivationTokenV1SetAppId {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the application id of the client being activated.
+        app_id: &'a str,
+    },
+
+    /// xdg_activation_token_v1:set_surface request
+    /// This request sets the surface requesting the activation. Note, this is
+    /// different from the surface that will be activated.
+    ///
+    /// Some compositors might refuse to activate toplevels when the token
+    /// doesn't have a requesting surface.
+    ///
+    /// Must be sent before commit. This information is optional.
+    XdgActivationTokenV1SetSurface {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the requesting surface
+        surface: u32,
+    },
+
+    /// xdg_activation_token_v1:commit request
+    /// Requests an activation token based on the different parameters that
+    /// have been offered through set_serial, set_surface and set_app_id.
+    XdgActivationTokenV1Commit {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// xdg_activation_token_v1:destroy request
+    /// Notify the compositor that the xdg_activation_token_v1 object will no
+    /// longer be used. The received token stays valid.
+    XdgActivationTokenV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_cursor_shape_manager_v1:destroy request
+    /// Destroy the cursor shape manager.
+    WpCursorShapeManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_cursor_shape_manager_v1:get_pointer request
+    /// Obtain a wp_cursor_shape_device_v1 for a wl_pointer object.
+    ///
+    /// When the pointer capability is removed from the wl_seat, the
+    /// wp_cursor_shape_device_v1 object becomes inert.
+    WpCursorShapeManagerV1GetPointer {
+        /// id of the object to send a request to
+        sendto: u32,
+        pointer: u32,
+    },
+
+    /// wp_cursor_shape_manager_v1:get_tablet_tool_v2 request
+    /// Obtain a wp_cursor_shape_device_v1 for a zwp_tablet_tool_v2 object.
+    ///
+    /// When the zwp_tablet_tool_v2 is removed, the wp_cursor_shape_device_v1
+    /// object becomes inert.
+    WpCursorShapeManagerV1GetTabletToolV2 {
+        /// id of the object to send a request to
+        sendto: u32,
+        tablet_tool: u32,
+    },
+
+    /// wp_cursor_shape_device_v1:destroy request
+    /// Destroy the cursor shape device.
+    ///
+    /// The device cursor shape remains unchanged.
+    WpCursorShapeDeviceV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_cursor_shape_device_v1:set_shape request
+    /// Sets the device cursor to the specified shape. The compositor will
+    /// change the cursor image based on the specified shape.
+    ///
+    /// The cursor actually changes only if the input device focus is one of
+    /// the requesting client's surfaces. If any, the previous cursor image
+    /// (surface or shape) is replaced.
+    ///
+    /// The "shape" argument must be a valid enum entry, otherwise the
+    /// invalid_shape protocol error is raised.
+    ///
+    /// This is similar to the wl_pointer.set_cursor and
+    /// zwp_tablet_tool_v2.set_cursor requests, but this request accepts a
+    /// shape instead of contents in the form of a surface. Clients can mix
+    /// set_cursor and set_shape requests.
+    ///
+    /// The serial parameter must match the latest wl_pointer.enter or
+    /// zwp_tablet_tool_v2.proximity_in serial number sent to the client.
+    /// Otherwise the request will be ignored.
+    WpCursorShapeDeviceV1SetShape {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// serial number of the enter event
+        serial: u32,
+        shape: u32,
+    },
+
+    /// zwp_text_input_v3:destroy request
+    /// Destroy the wp_text_input object. Also disables all surfaces enabled
+    /// through this wp_text_input object.
+    ZwpTextInputV3Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_text_input_v3:enable request
+    /// Requests text input on the surface previously obtained from the enter
+    /// event.
+    ///
+    /// This request must be issued every time the focused text input changes
+    /// to a new one, including within the current surface. Use
+    /// zwp_text_input_v3.disable when there is no longer any input focus on
+    /// the current surface.
+    ///
+    /// Clients must not enable more than one text input on the single seat
+    /// and should disable the current text input before enabling the new one.
+    /// Requests to enable a text input when another text input is enabled
+    /// on the same seat must be ignored by compositor.
+    ///
+    /// This request resets all state associated with previous enable, disable,
+    /// set_surrounding_text, set_text_change_cause, set_content_type, and
+    /// set_cursor_rectangle requests, as well as the state associated with
+    /// preedit_string, commit_string, and delete_surrounding_text events.
+    ///
+    /// The set_surrounding_text, set_content_type and set_cursor_rectangle
+    /// requests must follow if the text input supports the necessary
+    /// functionality.
+    ///
+    /// State set with this request is double-buffered. It will get applied on
+    /// the next zwp_text_input_v3.commit request, and stay valid until the
+    /// next committed enable or disable request.
+    ///
+    /// The changes must be applied by the compositor after issuing a
+    /// zwp_text_input_v3.commit request.
+    ZwpTextInputV3Enable {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_text_input_v3:disable request
+    /// Explicitly disable text input on the current surface (typically when
+    /// there is no focus on any text entry inside the surface).
+    ///
+    /// State set with this request is double-buffered. It will get applied on
+    /// the next zwp_text_input_v3.commit request.
+    ZwpTextInputV3Disable {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_text_input_v3:set_surrounding_text request
+    /// Sets the surrounding plain text around the input, excluding the preedit
+    /// text.
+    ///
+    /// The client should notify the compositor of any changes in any of the
+    /// values carried with this request, including changes caused by handling
+    /// incoming text-input events as well as changes caused by other
+    /// mechanisms like keyboard typing.
+    ///
+    /// If the client is unaware of the text around the cursor, it should not
+    /// issue this request, to signify lack of support to the compositor.
+    ///
+    /// Text is UTF-8 encoded, and should include the cursor position, the
+    /// complete selection and additional characters before and after them.
+    /// There is a maximum length of wayland messages, so text can not be
+    /// longer than 4000 bytes.
+    ///
+    /// Cursor is the byte offset of the cursor within text buffer.
+    ///
+    /// Anchor is the byte offset of the selection anchor within text buffer.
+    /// If there is no selected text, anchor is the same as cursor.
+    ///
+    /// If any preedit text is present, it is replaced with a cursor for the
+    /// purpose of this event.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request, and stay valid until the
+    /// next committed enable or disable request.
+    ///
+    /// The initial state for affected fields is empty, meaning that the text
+    /// input does not support sending surrounding text. If the empty values
+    /// get applied, subsequent attempts to change them may have no effect.
+    ZwpTextInputV3SetSurroundingText {
+        /// id of the object to send a request to
+        sendto: u32,
+        text: &'a str,
+        cursor: i32,
+        anchor: i32,
+    },
+
+    /// zwp_text_input_v3:set_text_change_cause request
+    /// Tells the compositor why the text surrounding the cursor changed.
+    ///
+    /// Whenever the client detects an external change in text, cursor, or
+    /// anchor posision, it must issue this request to the compositor. This
+    /// request is intended to give the input method a chance to update the
+    /// preedit text in an appropriate way, e.g. by removing it when the user
+    /// starts typing with a keyboard.
+    ///
+    /// cause describes the source of the change.
+    ///
+    /// The value set with this request is double-buffered. It must be applied
+    /// and reset to initial at the next zwp_text_input_v3.commit request.
+    ///
+    /// The initial value of cause is input_method.
+    ZwpTextInputV3SetTextChangeCause {
+        /// id of the object to send a request to
+        sendto: u32,
+        cause: u32,
+    },
+
+    /// zwp_text_input_v3:set_content_type request
+    /// Sets the content purpose and content hint. While the purpose is the
+    /// basic purpose of an input field, the hint flags allow to modify some of
+    /// the behavior.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request.
+    /// Subsequent attempts to update them may have no effect. The values
+    /// remain valid until the next committed enable or disable request.
+    ///
+    /// The initial value for hint is none, and the initial value for purpose
+    /// is normal.
+    ZwpTextInputV3SetContentType {
+        /// id of the object to send a request to
+        sendto: u32,
+        hint: u32,
+        purpose: u32,
+    },
+
+    /// zwp_text_input_v3:set_cursor_rectangle request
+    /// Marks an area around the cursor as a x, y, width, height rectangle in
+    /// surface local coordinates.
+    ///
+    /// Allows the compositor to put a window with word suggestions near the
+    /// cursor, without obstructing the text being input.
+    ///
+    /// If the client is unaware of the position of edited text, it should not
+    /// issue this request, to signify lack of support to the compositor.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request, and stay valid until the
+    /// next committed enable or disable request.
+    ///
+    /// The initial values describing a cursor rectangle are empty. That means
+    /// the text input does not support describing the cursor area. If the
+    /// empty values get applied, subsequent attempts to change them may have
+    /// no effect.
+    ///
+    /// As of version 2, the zwp_text_input_v3.commit request does not apply
+    /// values sent with this request. Instead, it stores them in a separate
+    /// "committed" area. The committed values, if still valid, get applied on
+    /// the next wl_surface.commit request on the surface with text-input focus.
+    /// Both committed and applied values get invalidated on:
+    ///
+    /// - the next committed enable or disable request, or
+    /// - a change of the focused surface of the text-input (leave or enter events).
+    ///
+    /// This double stage application allows the compositor to position
+    /// the input method popup in the same frame as the contents
+    /// of the text on the surface are updated.
+    ZwpTextInputV3SetCursorRectangle {
+        /// id of the object to send a request to
+        sendto: u32,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    },
+
+    /// zwp_text_input_v3:commit request
+    /// Atomically applies state changes recently sent to the compositor.
+    ///
+    /// The commit request establishes and updates the state of the client, and
+    /// must be issued after any changes to apply them.
+    ///
+    /// Text input state (enabled status, content purpose, content hint,
+    /// surrounding text and change cause, cursor rectangle) is conceptually
+    /// double-buffered within the context of a text input, i.e. between a
+    /// committed enable request and the following committed enable or disable
+    /// request.
+    ///
+    /// Protocol requests modify the pending state, as opposed to the current
+    /// state in use by the input method. A commit request atomically applies
+    /// all pending state, replacing the current state. After commit, the new
+    /// pending state is as documented for each related request.
+    ///
+    /// Requests are applied in the order of arrival.
+    ///
+    /// Neither current nor pending state are modified unless noted otherwise.
+    ///
+    /// The compositor must count the number of commit requests coming from
+    /// each zwp_text_input_v3 object and use the count as the serial in done
+    /// events.
+    ZwpTextInputV3Commit {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_text_input_v3:set_available_actions request
+    /// Set the actions available for this text input.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request.
+    ///
+    /// If the available_actions array contains the none action, or contains the
+    /// same action multiple times, the compositor must raise the invalid_action
+    /// protocol error.
+    ///
+    /// Initially, no actions are available.
+    ZwpTextInputV3SetAvailableActions {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// available actions
+        available_actions: &'a [u8],
+    },
+
+    /// zwp_text_input_v3:show_input_panel request
+    /// Requests an input panel to be shown (e.g. a on-screen keyboard).
+    ///
+    /// This request only hints the desired interaction pattern from the
+    /// client side, and its effect may be ignored by compositors given
+    /// other environmental factors. Repeated calls will be ignored.
+    ZwpTextInputV3ShowInputPanel {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_text_input_v3:hide_input_panel request
+    /// Requests an input panel to be hidden.
+    ///
+    /// This request only hints the desired interaction pattern from the
+    /// client side, and its effect may be ignored by compositors given
+    /// other environmental factors. Repeated calls will be ignored.
+    ZwpTextInputV3HideInputPanel {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_text_input_manager_v3:destroy request
+    /// Destroy the wp_text_input_manager object.
+    ZwpTextInputManagerV3Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_text_input_manager_v3:get_text_input request
+    /// Creates a new text-input object for a given seat.
+    ZwpTextInputManagerV3GetTextInput {
+        /// id of the object to send a request to
+        sendto: u32,
+        seat: u32,
+    },
+
+    /// zwp_primary_selection_device_manager_v1:create_source request
+    /// Create a new primary selection source.
+    ZwpPrimarySelectionDeviceManagerV1CreateSource {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_primary_selection_device_manager_v1:get_device request
+    /// Create a new data device for a given seat.
+    ZwpPrimarySelectionDeviceManagerV1GetDevice {
+        /// id of the object to send a request to
+        sendto: u32,
+        seat: u32,
+    },
+
+    /// zwp_primary_selection_device_manager_v1:destroy request
+    /// Destroy the primary selection device manager.
+    ZwpPrimarySelectionDeviceManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_primary_selection_device_v1:set_selection request
+    /// Replaces the current selection. The previous owner of the primary
+    /// selection will receive a wp_primary_selection_source.cancelled event.
+    ///
+    /// To unset the selection, set the source to NULL.
+    ZwpPrimarySelectionDeviceV1SetSelection {
+        /// id of the object to send a request to
+        sendto: u32,
+        source: Option<u32>,
+        /// serial of the event that triggered this request
+        serial: u32,
+    },
+
+    /// zwp_primary_selection_device_v1:destroy request
+    /// Destroy the primary selection device.
+    ZwpPrimarySelectionDeviceV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_primary_selection_offer_v1:receive request
+    /// To transfer the contents of the primary selection clipboard, the client
+    /// issues this request and indicates the mime type that it wants to
+    /// receive. The transfer happens through the passed file descriptor
+    /// (typically created with the pipe system call). The source client writes
+    /// the data in the mime type representation requested and then closes the
+    /// file descriptor.
+    ///
+    /// The receiving client reads from the read end of the pipe until EOF and
+    /// closes its end, at which point the transfer is complete.
+    ZwpPrimarySelectionOfferV1Receive {
+        /// id of the object to send a request to
+        sendto: u32,
+        mime_type: &'a str,
+        fd: std::os::fd::RawFd,
+    },
+
+    /// zwp_primary_selection_offer_v1:destroy request
+    /// Destroy the primary selection offer.
+    ZwpPrimarySelectionOfferV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_primary_selection_source_v1:offer request
+    /// This request adds a mime type to the set of mime types advertised to
+    /// targets. Can be called several times to offer multiple types.
+    ZwpPrimarySelectionSourceV1Offer {
+        /// id of the object to send a request to
+        sendto: u32,
+        mime_type: &'a str,
+    },
+
+    /// zwp_primary_selection_source_v1:destroy request
+    /// Destroy the primary selection source.
+    ZwpPrimarySelectionSourceV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_pointer_constraints_v1:destroy request
+    /// Used by the client to notify the server that it will no longer use this
+    /// pointer constraints object.
+    ZwpPointerConstraintsV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_pointer_constraints_v1:lock_pointer request
+    /// The lock_pointer request lets the client request to disable movements of
+    /// the virtual pointer (i.e. the cursor), effectively locking the pointer
+    /// to a position. This request may not take effect immediately; in the
+    /// future, when the compositor deems implementation-specific constraints
+    /// are satisfied, the pointer lock will be activated and the compositor
+    /// sends a locked event.
+    ///
+    /// The protocol provides no guarantee that the constraints are ever
+    /// satisfied, and does not require the compositor to send an error if the
+    /// constraints cannot ever be satisfied. It is thus possible to request a
+    /// lock that will never activate.
+    ///
+    /// There may not be another pointer constraint of any kind requested or
+    /// active on the surface for any of the wl_pointer objects of the seat of
+    /// the passed pointer when requesting a lock. If there is, an error will be
+    /// raised. See general pointer lock documentation for more details.
+    ///
+    /// The intersection of the region passed with this request and the input
+    /// region of the surface is used to determine where the pointer must be
+    /// in order for the lock to activate. It is up to the compositor whether to
+    /// warp the pointer or require some kind of user interaction for the lock
+    /// to activate. If the region is null the surface input region is used.
+    ///
+    /// A surface may receive pointer focus without the lock being activated.
+    ///
+    /// The request creates a new object wp_locked_pointer which is used to
+    /// interact with the lock as well as receive updates about its state. See
+    /// the the description of wp_locked_pointer for further information.
+    ///
+    /// Note that while a pointer is locked, the wl_pointer objects of the
+    /// corresponding seat will not emit any wl_pointer.motion events, but
+    /// relative motion events will still be emitted via wp_relative_pointer
+    /// objects of the same seat. wl_pointer.axis and wl_pointer.button events
+    /// are unaffected.
+    ZwpPointerConstraintsV1LockPointer {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// surface to lock pointer to
+        surface: u32,
+        /// the pointer that should be locked
+        pointer: u32,
+        /// region of surface
+        region: Option<u32>,
+        /// lock lifetime
+        lifetime: u32,
+    },
+
+    /// zwp_pointer_constraints_v1:confine_pointer request
+    /// The confine_pointer request lets the client request to confine the
+    /// pointer cursor to a given region. This request may not take effect
+    /// immediately; in the future, when the compositor deems implementation-
+    /// specific constraints are satisfied, the pointer confinement will be
+    /// activated and the compositor sends a confined event.
+    ///
+    /// The intersection of the region passed with this request and the input
+    /// region of the surface is used to determine where the pointer must be
+    /// in order for the confinement to activate. It is up to the compositor
+    /// whether to warp the pointer or require some kind of user interaction for
+    /// the confinement to activate. If the region is null the surface input
+    /// region is used.
+    ///
+    /// The request will create a new object wp_confined_pointer which is used
+    /// to interact with the confinement as well as receive updates about its
+    /// state. See the the description of wp_confined_pointer for further
+    /// information.
+    ZwpPointerConstraintsV1ConfinePointer {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// surface to lock pointer to
+        surface: u32,
+        /// the pointer that should be confined
+        pointer: u32,
+        /// region of surface
+        region: Option<u32>,
+        /// confinement lifetime
+        lifetime: u32,
+    },
+
+    /// zwp_locked_pointer_v1:destroy request
+    /// Destroy the locked pointer object. If applicable, the compositor will
+    /// unlock the pointer.
+    ZwpLockedPointerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_locked_pointer_v1:set_cursor_position_hint request
+    /// Set the cursor position hint relative to the top left corner of the
+    /// surface.
+    ///
+    /// If the client is drawing its own cursor, it should update the position
+    /// hint to the position of its own cursor. A compositor may use this
+    /// information to warp the pointer upon unlock in order to avoid pointer
+    /// jumps.
+    ///
+    /// The cursor position hint is double-buffered state, see
+    /// wl_surface.commit.
+    ZwpLockedPointerV1SetCursorPositionHint {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// surface-local x coordinate
+        surface_x: Fixed,
+        /// surface-local y coordinate
+        surface_y: Fixed,
+    },
+
+    /// zwp_locked_pointer_v1:set_region request
+    /// Set a new region used to lock the pointer.
+    ///
+    /// The new lock region is double-buffered, see wl_surface.commit.
+    ///
+    /// For details about the lock region, see wp_locked_pointer.
+    ZwpLockedPointerV1SetRegion {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// region of surface
+        region: Option<u32>,
+    },
+
+    /// zwp_confined_pointer_v1:destroy request
+    /// Destroy the confined pointer object. If applicable, the compositor will
+    /// unconfine the pointer.
+    ZwpConfinedPointerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_confined_pointer_v1:set_region request
+    /// Set a new region used to confine the pointer.
+    ///
+    /// The new confine region is double-buffered, see wl_surface.commit.
+    ///
+    /// If the confinement is active when the new confinement region is applied
+    /// and the pointer ends up outside of newly applied region, the pointer may
+    /// warped to a position within the new confinement region. If warped, a
+    /// wl_pointer.motion event will be emitted, but no
+    /// wp_relative_pointer.relative_motion event.
+    ///
+    /// The compositor may also, instead of using the new region, unconfine the
+    /// pointer.
+    ///
+    /// For details about the confine region, see wp_confined_pointer.
+    ZwpConfinedPointerV1SetRegion {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// region of surface
+        region: Option<u32>,
+    },
+
+    /// zwp_relative_pointer_manager_v1:destroy request
+    /// Used by the client to notify the server that it will no longer use this
+    /// relative pointer manager object.
+    ZwpRelativePointerManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_relative_pointer_manager_v1:get_relative_pointer request
+    /// Create a relative pointer interface given a wl_pointer object. See the
+    /// wp_relative_pointer interface for more details.
+    ZwpRelativePointerManagerV1GetRelativePointer {
+        /// id of the object to send a request to
+        sendto: u32,
+        pointer: u32,
+    },
+
+    /// zwp_relative_pointer_v1:destroy request
+    ZwpRelativePointerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_idle_inhibit_manager_v1:destroy request
+    /// Destroy the inhibit manager.
+    ZwpIdleInhibitManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_idle_inhibit_manager_v1:create_inhibitor request
+    /// Create a new inhibitor object associated with the given surface.
+    ZwpIdleInhibitManagerV1CreateInhibitor {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the surface that inhibits the idle behavior
+        surface: u32,
+    },
+
+    /// zwp_idle_inhibitor_v1:destroy request
+    /// Remove the inhibitor effect from the associated wl_surface.
+    ZwpIdleInhibitorV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_idle_notifier_v1:destroy request
+    /// Destroy the manager object. All objects created via this interface
+    /// remain valid.
+    ExtIdleNotifierV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_idle_notifier_v1:get_idle_notification request
+    /// Create a new idle notification object.
+    ///
+    /// The notification object has a minimum timeout duration and is tied to a
+    /// seat. The client will be notified if the seat is inactive for at least
+    /// the provided timeout. See ext_idle_notification_v1 for more details.
+    ///
+    /// A zero timeout is valid and means the client wants to be notified as
+    /// soon as possible when the seat is inactive.
+    ExtIdleNotifierV1GetIdleNotification {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// minimum idle timeout in msec
+        timeout: u32,
+        seat: u32,
+    },
+
+    /// ext_idle_notifier_v1:get_input_idle_notification request
+    /// Create a new idle notification object to track input from the
+    /// user, such as keyboard and mouse movement. Because this object is
+    /// meant to track user input alone, it ignores idle inhibitors.
+    ///
+    /// The notification object has a minimum timeout duration and is tied to a
+    /// seat. The client will be notified if the seat is inactive for at least
+    /// the provided timeout. See ext_idle_notification_v1 for more details.
+    ///
+    /// A zero timeout is valid and means the client wants to be notified as
+    /// soon as possible when the seat is inactive.
+    ExtIdleNotifierV1GetInputIdleNotification {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// minimum idle timeout in msec
+        timeout: u32,
+        seat: u32,
+    },
+
+    /// ext_idle_notification_v1:destroy request
+    /// Destroy the notification object.
+    ExtIdleNotificationV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwlr_layer_shell_v1:get_layer_surface request
+    /// Create a layer surface for an existing surface. This assigns the role of
+    /// layer_surface, or raises a protocol error if another role is already
+    /// assigned.
+    ///
+    /// Creating a layer surface from a wl_surface which has a buffer attached
+    /// or committed is a client error, and any attempts by a client to attach
+    /// or manipulate a buffer prior to the first layer_surface.configure call
+    /// must also be treated as errors.
+    ///
+    /// After creating a layer_surface object and setting it up, the client
+    /// must perform an initial commit without any buffer attached.
+    /// The compositor will reply with a layer_surface.configure event.
+    /// The client must acknowledge it and is then allowed to attach a buffer
+    /// to map the surface.
+    ///
+    /// You may pass NULL for output to allow the compositor to decide which
+    /// output to use. Generally this will be the one that the user most
+    /// recently interacted with.
+    ///
+    /// Clients can specify a namespace that defines the purpose of the layer
+    /// surface.
+    ZwlrLayerShellV1GetLayerSurface {
+        /// id of the object to send a request to
+        sendto: u32,
+        surface: u32,
+        output: Option<u32>,
+        /// layer to add this surface to
+        layer: u32,
+        /// namespace for the layer surface
+        namespace: &'a str,
+    },
+
+    /// zwlr_layer_shell_v1:destroy request
+    /// This request indicates that the client will not use the layer_shell
+    /// object any more. Objects that have been created through this instance
+    /// are not affected.
+    ZwlrLayerShellV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwlr_layer_surface_v1:set_size request
+    /// Sets the size of the surface in surface-local coordinates. The
+    /// compositor will display the surface centered with respect to its
+    /// anchors.
+    ///
+    /// If you pass 0 for either value, the compositor will assign it and
+    /// inform you of the assignment in the configure event. You must set your
+    /// anchor to opposite edges in the dimensions you omit; not doing so is a
+    /// protocol error. Both values are 0 by default.
+    ///
+    /// Size is double-buffered, see wl_surface.commit.
+    ZwlrLayerSurfaceV1SetSize {
+        /// id of the object to send a request to
+        sendto: u32,
+        width: u32,
+        height: u32,
+    },
+
+    /// zwlr_layer_surface_v1:set_anchor request
+    /// Requests that the compositor anchor the surface to the specified edges
+    /// and corners. If two orthogonal edges are specified (e.g. 'top' and
+    /// 'left'), then the anchor point will be the intersection of the edges
+    /// (e.g. the top left corner of the output); otherwise the anchor point
+    /// will be centered on that edge, or in the center if none is specified.
+    ///
+    /// Anchor is double-buffered, see wl_surface.commit.
+    ZwlrLayerSurfaceV1SetAnchor {
+        /// id of the object to send a request to
+        sendto: u32,
+        anchor: u32,
+    },
+
+    /// zwlr_layer_surface_v1:set_exclusive_zone request
+    /// Requests that the compositor avoids occluding an area with other
+    /// surfaces. The compositor's use of this information is
+    /// implementation-dependent - do not assume that this region will not
+    /// actually be occluded.
+    ///
+    /// A positive value is only meaningful if the surface is anchored to one
+    /// edge or an edge and both perpendicular edges. If the surface is not
+    /// anchored, anchored to only two perpendicular edges (a corner), anchored
+    /// to only two parallel edges or anchored to all edges, a positive value
+    /// will be treated the same as zero.
+    ///
+    /// A positive zone is the distance from the edge in surface-local
+    /// coordinates to consider exclusive.
+    ///
+    /// Surfaces that do not wish to have an exclusive zone may instead specify
+    /// how they should interact with surfaces that do. If set to zero, the
+    /// surface indicates that it would like to be moved to avoid occluding
+    /// surfaces with a positive exclusive zone. If set to -1, the surface
+    /// indicates that it would not like to be moved to accommodate for other
+    /// surfaces, and the compositor should extend it all the way to the edges
+    /// it is anchored to.
+    ///
+    /// For example, a panel might set its exclusive zone to 10, so that
+    /// maximized shell surfaces are not shown on top of it. A notification
+    /// might set its exclusive zone to 0, so that it is moved to avoid
+    /// occluding the panel, but shell surfaces are shown underneath it. A
+    /// wallpaper or lock screen might set their exclusive zone to -1, so that
+    /// they stretch below or over the panel.
+    ///
+    /// The default value is 0.
+    ///
+    /// Exclusive zone is double-buffered, see wl_surface.commit.
+    ZwlrLayerSurfaceV1SetExclusiveZone {
+        /// id of the object to send a request to
+        sendto: u32,
+        zone: i32,
+    },
+
+    /// zwlr_layer_surface_v1:set_margin request
+    /// Requests that the surface be placed some distance away from the anchor
+    /// point on the output, in surface-local coordinates. Setting this value
+    /// for edges you are not anchored to has no effect.
+    ///
+    /// The exclusive zone includes the margin.
+    ///
+    /// Margin is double-buffered, see wl_surface.commit.
+    ZwlrLayerSurfaceV1SetMargin {
+        /// id of the object to send a request to
+        sendto: u32,
+        top: i32,
+        right: i32,
+        bottom: i32,
+        left: i32,
+    },
+
+    /// zwlr_layer_surface_v1:set_keyboard_interactivity request
+    /// Set how keyboard events are delivered to this surface. By default,
+    /// layer shell surfaces do not receive keyboard events; this request can
+    /// be used to change this.
+    ///
+    /// This setting is inherited by child surfaces set by the get_popup
+    /// request.
+    ///
+    /// Layer surfaces receive pointer, touch, and tablet events normally. If
+    /// you do not want to receive them, set the input region on your surface
+    /// to an empty region.
+    ///
+    /// Keyboard interactivity is double-buffered, see wl_surface.commit.
+    ZwlrLayerSurfaceV1SetKeyboardInteractivity {
+        /// id of the object to send a request to
+        sendto: u32,
+        keyboard_interactivity: u32,
+    },
+
+    /// zwlr_layer_surface_v1:get_popup request
+    /// This assigns an xdg_popup's parent to this layer_surface.  This popup
+    /// should have been created via xdg_surface::get_popup with the parent set
+    /// to NULL, and this request must be invoked before committing the popup's
+    /// initial state.
+    ///
+    /// See the documentation of xdg_popup for more details about what an
+    /// xdg_popup is and how it is used.
+    ZwlrLayerSurfaceV1GetPopup {
+        /// id of the object to send a request to
+        sendto: u32,
+        popup: u32,
+    },
+
+    /// zwlr_layer_surface_v1:ack_configure request
+    /// When a configure event is received, if a client commits the
+    /// surface in response to the configure event, then the client
+    /// must make an ack_configure request sometime before the commit
+    /// request, passing along the serial of the configure event.
+    ///
+    /// If the client receives multiple configure events before it
+    /// can respond to one, it only has to ack the last configure event.
+    ///
+    /// A client is not required to commit immediately after sending
+    /// an ack_configure request - it may even ack_configure several times
+    /// before its next surface commit.
+    ///
+    /// A client may send multiple ack_configure requests before committing, but
+    /// only the last request sent before a commit indicates which configure
+    /// event the client really is responding to.
+    ZwlrLayerSurfaceV1AckConfigure {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the serial from the configure event
+        serial: u32,
+    },
+
+    /// zwlr_layer_surface_v1:destroy request
+    /// This request destroys the layer surface.
+    ZwlrLayerSurfaceV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwlr_layer_surface_v1:set_layer request
+    /// Change the layer that the surface is rendered on.
+    ///
+    /// Layer is double-buffered, see wl_surface.commit.
+    ZwlrLayerSurfaceV1SetLayer {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// layer to move this surface to
+        layer: u32,
+    },
+
+    /// zwlr_layer_surface_v1:set_exclusive_edge request
+    /// Requests an edge for the exclusive zone to apply. The exclusive
+    /// edge will be automatically deduced from anchor points when possible,
+    /// but when the surface is anchored to a corner, it will be necessary
+    /// to set it explicitly to disambiguate, as it is not possible to deduce
+    /// which one of the two corner edges should be used.
+    ///
+    /// The edge must be one the surface is anchored to, otherwise the
+    /// invalid_exclusive_edge protocol error will be raised.
+    ZwlrLayerSurfaceV1SetExclusiveEdge {
+        /// id of the object to send a request to
+        sendto: u32,
+        edge: u32,
+    },
+
+    /// ext_session_lock_manager_v1:destroy request
+    /// This informs the compositor that the session lock manager object will
+    /// no longer be used. Existing objects created through this interface
+    /// remain valid.
+    ExtSessionLockManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_session_lock_manager_v1:lock request
+    /// This request creates a session lock and asks the compositor to lock the
+    /// session. The compositor will send either the ext_session_lock_v1.locked
+    /// or ext_session_lock_v1.finished event on the created object in
+    /// response to this request.
+    ExtSessionLockManagerV1Lock {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_session_lock_v1:destroy request
+    /// This informs the compositor that the lock object will no longer be
+    /// used. Existing objects created through this interface remain valid.
+    ///
+    /// After this request is made, lock surfaces created through this object
+    /// should be destroyed by the client as they will no longer be used by
+    /// the compositor.
+    ///
+    /// It is a protocol error to make this request if the locked event was
+    /// sent, the unlock_and_destroy request must be used instead.
+    ExtSessionLockV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_session_lock_v1:get_lock_surface request
+    /// The client is expected to create lock surfaces for all outputs
+    /// currently present and any new outputs as they are advertised. These
+    /// won't be displayed by the compositor unless the lock is successful
+    /// and the locked event is sent.
+    ///
+    /// Providing a wl_surface which already has a role or already has a buffer
+    /// attached or committed is a protocol error, as is attaching/committing
+    /// a buffer before the first ext_session_lock_surface_v1.configure event.
+    ///
+    /// Attempting to create more than one lock surface for a given output
+    /// is a duplicate_output protocol error.
+    ExtSessionLockV1GetLockSurface {
+        /// id of the object to send a request to
+        sendto: u32,
+        surface: u32,
+        output: u32,
+    },
+
+    /// ext_session_lock_v1:unlock_and_destroy request
+    /// This request indicates that the session should be unlocked, for
+    /// example because the user has entered their password and it has been
+    /// verified by the client.
+    ///
+    /// This request also informs the compositor that the lock object will
+    /// no longer be used and should be destroyed. Existing objects created
+    /// through this interface remain valid.
+    ///
+    /// After this request is made, lock surfaces created through this object
+    /// should be destroyed by the client as they will no longer be used by
+    /// the compositor.
+    ///
+    /// It is a protocol error to make this request if the locked event has
+    /// not been sent. In that case, the lock object must be destroyed using
+    /// the destroy request.
+    ///
+    /// Note that a correct client that wishes to exit directly after unlocking
+    /// the session must use the wl_display.sync request to ensure the server
+    /// receives and processes the unlock_and_destroy request. Otherwise
+    /// there is no guarantee that the server has unlocked the session due
+    /// to the asynchronous nature of the Wayland protocol. For example,
+    /// the server might terminate the client with a protocol error before
+    /// it processes the unlock_and_destroy request.
+    ExtSessionLockV1UnlockAndDestroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_session_lock_surface_v1:destroy request
+    /// This informs the compositor that the lock surface object will no
+    /// longer be used.
+    ///
+    /// It is recommended for a lock client to destroy lock surfaces if
+    /// their corresponding wl_output global is removed.
+    ///
+    /// If a lock surface on an active output is destroyed before the
+    /// ext_session_lock_v1.unlock_and_destroy event is sent, the compositor
+    /// must fall back to rendering a solid color.
+    ExtSessionLockSurfaceV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_session_lock_surface_v1:ack_configure request
+    /// When a configure event is received, if a client commits the surface
+    /// in response to the configure event, then the client must make an
+    /// ack_configure request sometime before the commit request, passing
+    /// along the serial of the configure event.
+    ///
+    /// If the client receives multiple configure events before it can
+    /// respond to one, it only has to ack the last configure event.
+    ///
+    /// A client is not required to commit immediately after sending an
+    /// ack_configure request - it may even ack_configure several times
+    /// before its next surface commit.
+    ///
+    /// A client may send multiple ack_configure requests before committing,
+    /// but only the last request sent before a commit indicates which
+    /// configure event the client really is responding to.
+    ///
+    /// Sending an ack_configure request consumes the configure event
+    /// referenced by the given serial, as well as all older configure events
+    /// sent on this object.
+    ///
+    /// It is a protocol error to issue multiple ack_configure requests
+    /// referencing the same configure event or to issue an ack_configure
+    /// request referencing a configure event older than the last configure
+    /// event acked for a given lock surface.
+    ExtSessionLockSurfaceV1AckConfigure {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// serial from the configure event
+        serial: u32,
+    },
+
+    /// ext_image_capture_source_v1:destroy request
+    /// Destroys the image capture source. This request may be sent at any time
+    /// by the client.
+    ExtImageCaptureSourceV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_output_image_capture_source_manager_v1:create_source request
+    /// Creates a source object for an output. Images captured from this source
+    /// will show the same content as the output. Some elements may be omitted,
+    /// such as cursors and overlays that have been marked as transparent to
+    /// capturing.
+    ExtOutputImageCaptureSourceManagerV1CreateSource {
+        /// id of the object to send a request to
+        sendto: u32,
+        output: u32,
+    },
+
+    /// ext_output_image_capture_source_manager_v1:destroy request
+    /// Destroys the manager. This request may be sent at any time by the client
+    /// and objects created by the manager will remain valid after its
+    /// destruction.
+    ExtOutputImageCaptureSourceManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_image_copy_capture_manager_v1:create_session request
+    /// Create a capturing session for an image capture source.
+    ///
+    /// If the paint_cursors option is set, cursors shall be composited onto
+    /// the captured frame. The cursor must not be composited onto the frame
+    /// if this flag is not set.
+    ///
+    /// If the options bitfield is invalid, the invalid_option protocol error
+    /// is sent.
+    ExtImageCopyCaptureManagerV1CreateSession {
+        /// id of the object to send a request to
+        sendto: u32,
+        source: u32,
+        options: u32,
+    },
+
+    /// ext_image_copy_capture_manager_v1:create_pointer_cursor_session request
+    /// Create a cursor capturing session for the pointer of an image capture
+    /// source.
+    ExtImageCopyCaptureManagerV1CreatePointerCursorSession {
+        /// id of the object to send a request to
+        sendto: u32,
+        source: u32,
+        pointer: u32,
+    },
+
+    /// ext_image_copy_capture_manager_v1:destroy request
+    /// Destroy the manager object.
+    ///
+    /// Other objects created via this interface are unaffected.
+    ExtImageCopyCaptureManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_image_copy_capture_session_v1:create_frame request
+    /// Create a capture frame for this session.
+    ///
+    /// At most one frame object can exist for a given session at any time. If
+    /// a client sends a create_frame request before a previous frame object
+    /// has been destroyed, the duplicate_frame protocol error is raised.
+    ExtImageCopyCaptureSessionV1CreateFrame {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_image_copy_capture_session_v1:destroy request
+    /// Destroys the session. This request can be sent at any time by the
+    /// client.
+    ///
+    /// This request doesn't affect ext_image_copy_capture_frame_v1 objects created by
+    /// this object.
+    ExtImageCopyCaptureSessionV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_image_copy_capture_frame_v1:destroy request
+    /// Destroys the frame. This request can be sent at any time by the
+    /// client.
+    ExtImageCopyCaptureFrameV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_image_copy_capture_frame_v1:attach_buffer request
+    /// Attach a buffer to the session.
+    ///
+    /// The wl_buffer.release request is unused.
+    ///
+    /// The new buffer replaces any previously attached buffer.
+    ///
+    /// This request must not be sent after capture, or else the
+    /// already_captured protocol error is raised.
+    ExtImageCopyCaptureFrameV1AttachBuffer {
+        /// id of the object to send a request to
+        sendto: u32,
+        buffer: u32,
+    },
+
+    /// ext_image_copy_capture_frame_v1:damage_buffer request
+    /// Apply damage to the buffer which is to be captured next. This request
+    /// may be sent multiple times to describe a region.
+    ///
+    /// The client indicates the accumulated damage since this wl_buffer was
+    /// last captured. During capture, the compositor will update the buffer
+    /// with at least the union of the region passed by the client and the
+    /// region advertised by ext_image_copy_capture_frame_v1.damage.
+    ///
+    /// When a wl_buffer is captured for the first time, or when the client
+    /// doesn't track damage, the client must damage the whole buffer.
+    ///
+    /// This is for optimisation purposes. The compositor may use this
+    /// information to reduce copying.
+    ///
+    /// These coordinates originate from the upper left corner of the buffer.
+    ///
+    /// If x or y are strictly negative, or if width or height are negative or
+    /// zero, the invalid_buffer_damage protocol error is raised.
+    ///
+    /// This request must not be sent after capture, or else the
+    /// already_captured protocol error is raised.
+    ExtImageCopyCaptureFrameV1DamageBuffer {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// region x coordinate
+        x: i32,
+        /// region y coordinate
+        y: i32,
+        /// region width
+        width: i32,
+        /// region height
+        height: i32,
+    },
+
+    /// ext_image_copy_capture_frame_v1:capture request
+    /// Capture a frame.
+    ///
+    /// Unless this is the first successful captured frame performed in this
+    /// session, the compositor may wait an indefinite amount of time for the
+    /// source content to change before performing the copy.
+    ///
+    /// This request may only be sent once, or else the already_captured
+    /// protocol error is raised. A buffer must be attached before this request
+    /// is sent, or else the no_buffer protocol error is raised.
+    ExtImageCopyCaptureFrameV1Capture {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_image_copy_capture_cursor_session_v1:destroy request
+    /// Destroys the session. This request can be sent at any time by the
+    /// client.
+    ///
+    /// This request doesn't affect ext_image_copy_capture_frame_v1 objects created by
+    /// this object.
+    ExtImageCopyCaptureCursorSessionV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// ext_image_copy_capture_cursor_session_v1:get_capture_session request
+    /// Gets the image copy capture session for this cursor session.
+    ///
+    /// The session will produce frames of the cursor image. The compositor may
+    /// pause the session when the cursor leaves the captured area.
+    ///
+    /// This request must not be sent more than once, or else the
+    /// duplicate_session protocol error is raised.
+    ExtImageCopyCaptureCursorSessionV1GetCaptureSession {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwlr_screencopy_manager_v1:capture_output request
+    /// Capture the next frame of an entire output.
+    ZwlrScreencopyManagerV1CaptureOutput {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// composite cursor onto the frame
+        overlay_cursor: i32,
+        output: u32,
+    },
+
+    /// zwlr_screencopy_manager_v1:capture_output_region request
+    /// Capture the next frame of an output's region.
+    ///
+    /// The region is given in output logical coordinates, see
+    /// xdg_output.logical_size. The region will be clipped to the output's
+    /// extents.
+    ZwlrScreencopyManagerV1CaptureOutputRegion {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// composite cursor onto the frame
+        overlay_cursor: i32,
+        output: u32,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    },
+
+    /// zwlr_screencopy_manager_v1:destroy request
+    /// All objects created by the manager will still remain valid, until their
+    /// appropriate destroy request has been called.
+    ZwlrScreencopyManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwlr_screencopy_frame_v1:copy request
+    /// Copy the frame to the supplied buffer. The buffer must have the
+    /// correct size, see zwlr_screencopy_frame_v1.buffer and
+    /// zwlr_screencopy_frame_v1.linux_dmabuf. The buffer needs to have a
+    /// supported format.
+    ///
+    /// If the frame is successfully copied, "flags" and "ready" events are
+    /// sent. Otherwise, a "failed" event is sent.
+    ZwlrScreencopyFrameV1Copy {
+        /// id of the object to send a request to
+        sendto: u32,
+        buffer: u32,
+    },
+
+    /// zwlr_screencopy_frame_v1:destroy request
+    /// Destroys the frame. This request can be sent at any time by the client.
+    ZwlrScreencopyFrameV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwlr_screencopy_frame_v1:copy_with_damage request
+    /// Same as copy, except it waits until there is damage to copy.
+    ZwlrScreencopyFrameV1CopyWithDamage {
+        /// id of the object to send a request to
+        sendto: u32,
+        buffer: u32,
+    },
+
+    /// zwp_tablet_manager_v2:get_tablet_seat request
+    /// Get the zwp_tablet_seat_v2 object for the given seat. This object
+    /// provides access to all graphics tablets in this seat.
+    ZwpTabletManagerV2GetTabletSeat {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// The wl_seat object to retrieve the tablets for
+        seat: u32,
+    },
+
+    /// zwp_tablet_manager_v2:destroy request
+    /// Destroy the zwp_tablet_manager_v2 object. Objects created from this
+    /// object are unaffected and should be destroyed separately.
+    ZwpTabletManagerV2Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_tablet_seat_v2:destroy request
+    /// Destroy the zwp_tablet_seat_v2 object. Objects created from this
+    /// object are unaffected and should be destroyed separately.
+    ZwpTabletSeatV2Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_tablet_tool_v2:set_cursor request
+    /// Sets the surface of the cursor used for this tool on the given
+    /// tablet. This request only takes effect if the tool is in proximity
+    /// of one of the requesting client's surfaces or the surface parameter
+    /// is the current pointer surface. If there was a previous surface set
+    /// with this request it is replaced. If surface is NULL, the cursor
+    /// image is hidden.
+    ///
+    /// The parameters hotspot_x and hotspot_y define the position of the
+    /// pointer surface relative to the pointer location. Its top-left corner
+    /// is always at (x, y) - (hotspot_x, hotspot_y), where (x, y) are the
+    /// coordinates of the pointer location, in surface-local coordinates.
+    ///
+    /// On surface.attach requests to the pointer surface, hotspot_x and
+    /// hotspot_y are decremented by the x and y parameters passed to the
+    /// request. Attach must be confirmed by wl_surface.commit as usual.
+    ///
+    /// The hotspot can also be updated by passing the currently set pointer
+    /// surface to this request with new values for hotspot_x and hotspot_y.
+    ///
+    /// The current and pending input regions of the wl_surface are cleared,
+    /// and wl_surface.set_input_region is ignored until the wl_surface is no
+    /// longer used as the cursor. When the use as a cursor ends, the current
+    /// and pending input regions become undefined, and the wl_surface is
+    /// unmapped.
+    ///
+    /// This request gives the surface the role of a zwp_tablet_tool_v2 cursor. A
+    /// surface may only ever be used as the cursor surface for one
+    /// zwp_tablet_tool_v2. If the surface already has another role or has
+    /// previously been used as cursor surface for a different tool, a
+    /// protocol error is raised.
+    ZwpTabletToolV2SetCursor {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// serial of the proximity_in event
+        serial: u32,
+        surface: Option<u32>,
+        /// surface-local x coordinate
+        hotspot_x: i32,
+        /// surface-local y coordinate
+        hotspot_y: i32,
+    },
+
+    /// zwp_tablet_tool_v2:destroy request
+    /// This destroys the client's resource for this tool object.
+    ZwpTabletToolV2Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_tablet_v2:destroy request
+    /// This destroys the client's resource for this tablet object.
+    ZwpTabletV2Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_tablet_pad_ring_v2:set_feedback request
+    /// Request that the compositor use the provided feedback string
+    /// associated with this ring. This request should be issued immediately
+    /// after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+    /// group is received, or whenever the ring is mapped to a different
+    /// action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+    ///
+    /// Clients are encouraged to provide context-aware descriptions for
+    /// the actions associated with the ring; compositors may use this
+    /// information to offer visual feedback about the button layout
+    /// (eg. on-screen displays).
+    ///
+    /// The provided string 'description' is a UTF-8 encoded string to be
+    /// associated with this ring, and is considered user-visible; general
+    /// internationalization rules apply.
+    ///
+    /// The serial argument will be that of the last
+    /// zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+    /// ring. Requests providing other serials than the most recent one will be
+    /// ignored.
+    ZwpTabletPadRingV2SetFeedback {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// ring description
+        description: &'a str,
+        /// serial of the mode switch event
+        serial: u32,
+    },
+
+    /// zwp_tablet_pad_ring_v2:destroy request
+    /// This destroys the client's resource for this ring object.
+    ZwpTabletPadRingV2Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_tablet_pad_strip_v2:set_feedback request
+    /// Requests the compositor to use the provided feedback string
+    /// associated with this strip. This request should be issued immediately
+    /// after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+    /// group is received, or whenever the strip is mapped to a different
+    /// action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+    ///
+    /// Clients are encouraged to provide context-aware descriptions for
+    /// the actions associated with the strip, and compositors may use this
+    /// information to offer visual feedback about the button layout
+    /// (eg. on-screen displays).
+    ///
+    /// The provided string 'description' is a UTF-8 encoded string to be
+    /// associated with this ring, and is considered user-visible; general
+    /// internationalization rules apply.
+    ///
+    /// The serial argument will be that of the last
+    /// zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+    /// strip. Requests providing other serials than the most recent one will be
+    /// ignored.
+    ZwpTabletPadStripV2SetFeedback {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// strip description
+        description: &'a str,
+        /// serial of the mode switch event
+        serial: u32,
+    },
+
+    /// zwp_tablet_pad_strip_v2:destroy request
+    /// This destroys the client's resource for this strip object.
+    ZwpTabletPadStripV2Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_tablet_pad_group_v2:destroy request
+    /// Destroy the zwp_tablet_pad_group_v2 object. Objects created from this object
+    /// are unaffected and should be destroyed separately.
+    ZwpTabletPadGroupV2Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_tablet_pad_v2:set_feedback request
+    /// Requests the compositor to use the provided feedback string
+    /// associated with this button. This request should be issued immediately
+    /// after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+    /// group is received, or whenever a button is mapped to a different
+    /// action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+    ///
+    /// Clients are encouraged to provide context-aware descriptions for
+    /// the actions associated with each button, and compositors may use
+    /// this information to offer visual feedback on the button layout
+    /// (e.g. on-screen displays).
+    ///
+    /// Button indices start at 0. Setting the feedback string on a button
+    /// that is reserved by the compositor (i.e. not belonging to any
+    /// zwp_tablet_pad_group_v2) does not generate an error but the compositor
+    /// is free to ignore the request.
+    ///
+    /// The provided string 'description' is a UTF-8 encoded string to be
+    /// associated with this ring, and is considered user-visible; general
+    /// internationalization rules apply.
+    ///
+    /// The serial argument will be that of the last
+    /// zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+    /// button. Requests providing other serials than the most recent one will
+    /// be ignored.
+    ZwpTabletPadV2SetFeedback {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// button index
+        button: u32,
+        /// button description
+        description: &'a str,
+        /// serial of the mode switch event
+        serial: u32,
+    },
+
+    /// zwp_tablet_pad_v2:destroy request
+    /// Destroy the zwp_tablet_pad_v2 object. Objects created from this object
+    /// are unaffected and should be destroyed separately.
+    ZwpTabletPadV2Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_tablet_pad_dial_v2:set_feedback request
+    /// Requests the compositor to use the provided feedback string
+    /// associated with this dial. This request should be issued immediately
+    /// after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+    /// group is received, or whenever the dial is mapped to a different
+    /// action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+    ///
+    /// Clients are encouraged to provide context-aware descriptions for
+    /// the actions associated with the dial, and compositors may use this
+    /// information to offer visual feedback about the button layout
+    /// (eg. on-screen displays).
+    ///
+    /// The provided string 'description' is a UTF-8 encoded string to be
+    /// associated with this ring, and is considered user-visible; general
+    /// internationalization rules apply.
+    ///
+    /// The serial argument will be that of the last
+    /// zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+    /// dial. Requests providing other serials than the most recent one will be
+    /// ignored.
+    ZwpTabletPadDialV2SetFeedback {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// dial description
+        description: &'a str,
+        /// serial of the mode switch event
+        serial: u32,
+    },
+
+    /// zwp_tablet_pad_dial_v2:destroy request
+    /// This destroys the client's resource for this dial object.
+    ZwpTabletPadDialV2Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_pointer_gestures_v1:get_swipe_gesture request
+    /// Create a swipe gesture object. See the
+    /// wl_pointer_gesture_swipe interface for details.
+    ZwpPointerGesturesV1GetSwipeGesture {
+        /// id of the object to send a request to
+        sendto: u32,
+        pointer: u32,
+    },
+
+    /// zwp_pointer_gestures_v1:get_pinch_gesture request
+    /// Create a pinch gesture object. See the
+    /// wl_pointer_gesture_pinch interface for details.
+    ZwpPointerGesturesV1GetPinchGesture {
+        /// id of the object to send a request to
+        sendto: u32,
+        pointer: u32,
+    },
+
+    /// zwp_pointer_gestures_v1:release request
+    /// Destroy the pointer gesture object. Swipe, pinch and hold objects
+    /// created via this gesture object remain valid.
+    ZwpPointerGesturesV1Release {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_pointer_gestures_v1:get_hold_gesture request
+    /// Create a hold gesture object. See the
+    /// wl_pointer_gesture_hold interface for details.
+    ZwpPointerGesturesV1GetHoldGesture {
+        /// id of the object to send a request to
+        sendto: u32,
+        pointer: u32,
+    },
+
+    /// zwp_pointer_gesture_swipe_v1:destroy request
+    ZwpPointerGestureSwipeV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_pointer_gesture_pinch_v1:destroy request
+    ZwpPointerGesturePinchV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zwp_pointer_gesture_hold_v1:destroy request
+    ZwpPointerGestureHoldV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zxdg_output_manager_v1:destroy request
+    /// Using this request a client can tell the server that it is not
+    /// going to use the xdg_output_manager object anymore.
+    ///
+    /// Any objects already created through this instance are not affected.
+    ZxdgOutputManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// zxdg_output_manager_v1:get_xdg_output request
+    /// This creates a new xdg_output object for the given wl_output.
+    ZxdgOutputManagerV1GetXdgOutput {
+        /// id of the object to send a request to
+        sendto: u32,
+        output: u32,
+    },
+
+    /// zxdg_output_v1:destroy request
+    /// Using this request a client can tell the server that it is not
+    /// going to use the xdg_output object anymore.
+    ZxdgOutputV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_single_pixel_buffer_manager_v1:destroy request
+    /// Destroy the wp_single_pixel_buffer_manager_v1 object.
+    ///
+    /// The child objects created via this interface are unaffected.
+    WpSinglePixelBufferManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_single_pixel_buffer_manager_v1:create_u32_rgba_buffer request
+    /// Create a single-pixel buffer from four 32-bit RGBA values.
+    ///
+    /// Unless specified in another protocol extension, the RGBA values use
+    /// pre-multiplied alpha.
+    ///
+    /// The width and height of the buffer are 1.
+    ///
+    /// The r, g, b and a arguments valid range is from UINT32_MIN (0)
+    /// to UINT32_MAX (0xffffffff).
+    ///
+    /// These arguments should be interpreted as a percentage, i.e.
+    /// - UINT32_MIN = 0% of the given color component
+    /// - UINT32_MAX = 100% of the given color component
+    WpSinglePixelBufferManagerV1CreateU32RgbaBuffer {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// value of the buffer's red channel
+        r: u32,
+        /// value of the buffer's green channel
+        g: u32,
+        /// value of the buffer's blue channel
+        b: u32,
+        /// value of the buffer's alpha channel
+        a: u32,
+    },
+
+    /// wp_content_type_manager_v1:destroy request
+    /// Destroy the content type manager. This doesn't destroy objects created
+    /// with the manager.
+    WpContentTypeManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_content_type_manager_v1:get_surface_content_type request
+    /// Create a new content type object associated with the given surface.
+    ///
+    /// Creating a wp_content_type_v1 from a wl_surface which already has one
+    /// attached is a client error: already_constructed.
+    WpContentTypeManagerV1GetSurfaceContentType {
+        /// id of the object to send a request to
+        sendto: u32,
+        surface: u32,
+    },
+
+    /// wp_content_type_v1:destroy request
+    /// Switch back to not specifying the content type of this surface. This is
+    /// equivalent to setting the content type to none, including double
+    /// buffering semantics. See set_content_type for details.
+    WpContentTypeV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_content_type_v1:set_content_type request
+    /// Set the surface content type. This informs the compositor that the
+    /// client believes it is displaying buffers matching this content type.
+    ///
+    /// This is purely a hint for the compositor, which can be used to adjust
+    /// its behavior or hardware settings to fit the presented content best.
+    ///
+    /// The content type is double-buffered state, see wl_surface.commit for
+    /// details.
+    WpContentTypeV1SetContentType {
+        /// id of the object to send a request to
+        sendto: u32,
+        /// the content type
+        content_type: u32,
+    },
+
+    /// wp_tearing_control_manager_v1:destroy request
+    /// Destroy this tearing control factory object. Other objects, including
+    /// wp_tearing_control_v1 objects created by this factory, are not affected
+    /// by this request.
+    WpTearingControlManagerV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
+
+    /// wp_tearing_control_manager_v1:get_tearing_control request
+    /// Instantiate an interface extension for the given wl_surface to request
+    /// asynchronous page flips for presentation.
+    ///
+    /// If the given wl_surface already has a wp_tearing_control_v1 object
+    /// associated, the tearing_control_exists protocol error is raised.
+    WpTearingControlManagerV1GetTearingControl {
+        /// id of the object to send a request to
+        sendto: u32,
+        surface: u32,
+    },
+
+    /// wp_tearing_control_v1:set_presentation_hint request
+    /// Set the presentation hint for the associated wl_surface. This state is
+    /// double-buffered, see wl_surface.commit.
+    ///
+    /// The compositor is free to dynamically respect or ignore this hint based
+    /// on various conditions like hardware capabilities, surface state and
+    /// user preferences.
+    WpTearingControlV1SetPresentationHint {
+        /// id of the object to send a request to
+        sendto: u32,
+        hint: u32,
+    },
+
+    /// wp_tearing_control_v1:destroy request
+    /// Destroy this surface tearing object and revert the presentation hint to
+    /// vsync. The change will be applied on the next wl_surface.commit.
+    WpTearingControlV1Destroy {
+        /// id of the object to send a request to
+        sendto: u32,
+    },
 }
 
 